@@ -556,6 +556,52 @@ fn register_matrix_norm_benchmarks<const D: usize>(group: &mut BenchmarkGroup<'_
     });
 }
 
+/// Number of matrices per batch in the batched-API benchmarks.
+#[cfg(not(la_stack_v0_4_3_api))]
+const BATCH_SIZE: usize = 16;
+
+/// Register batched determinant and dot-product benchmarks against loops of
+/// single calls.
+#[cfg(not(la_stack_v0_4_3_api))]
+fn register_batch_benchmarks<const D: usize>(group: &mut BenchmarkGroup<'_, WallTime>) {
+    let mats = [la_matrix::<D>(); BATCH_SIZE];
+    let point = la_vector::<D>(0.0, "la_stack vector construction");
+    let normals = [la_vector::<D>(1.0, "la_stack vector construction"); BATCH_SIZE];
+
+    group.bench_function("la_stack_det_batch", |bencher| {
+        bencher.iter(|| {
+            for det in Matrix::det_batch(black_box(&mats)) {
+                black_box(det.or_abort("la_stack batched determinant"));
+            }
+        });
+    });
+
+    group.bench_function("la_stack_det_loop", |bencher| {
+        bencher.iter(|| {
+            for a in black_box(&mats) {
+                black_box(a.det().or_abort("la_stack determinant"));
+            }
+        });
+    });
+
+    group.bench_function("la_stack_dot_batch", |bencher| {
+        bencher.iter(|| {
+            for dot in black_box(&point).dot_batch(black_box(&normals)) {
+                black_box(dot.or_abort("la_stack batched dot"));
+            }
+        });
+    });
+
+    group.bench_function("la_stack_dot_loop", |bencher| {
+        bencher.iter(|| {
+            let point = black_box(&point);
+            for normal in black_box(&normals) {
+                black_box(point.dot(normal).or_abort("la_stack dot"));
+            }
+        });
+    });
+}
+
 /// Register D=8 stress cases that exercise pivoting, conditioning, and scaled products.
 fn register_stress_benchmarks(group: &mut BenchmarkGroup<'_, WallTime>) {
     let zero_tolerance = la_stack_tolerance(0.0).or_abort("zero benchmark tolerance");
@@ -580,6 +626,21 @@ fn register_stress_benchmarks(group: &mut BenchmarkGroup<'_, WallTime>) {
         );
     });
 
+    // Contrasts with `la_stack_solve_from_lu`, whose diagonally dominant input
+    // needs no row swaps and reads the right-hand side in place.
+    let pivoting_lu = pivoting
+        .lu(zero_tolerance)
+        .or_abort("pivoting LU factorization");
+    let rhs = la_vector::<8>(0.0, "la_stack RHS vector construction");
+    group.bench_function("la_stack_solve_from_lu_pivoting", |bencher| {
+        bencher.iter(|| {
+            let x = black_box(&pivoting_lu)
+                .solve(black_box(rhs))
+                .or_abort("pivoting LU solve");
+            let _ = black_box(x);
+        });
+    });
+
     group.bench_function("la_stack_lu_ill_conditioned", |bencher| {
         bencher.iter_batched(
             || ill_conditioned,
@@ -650,6 +711,8 @@ macro_rules! define_vs_linalg_benches_for_dim {
             register_precomputed_ldlt_determinant_benchmarks::<$d>(&mut group);
             register_vector_benchmarks::<$d>(&mut group);
             register_matrix_norm_benchmarks::<$d>(&mut group);
+            #[cfg(not(la_stack_v0_4_3_api))]
+            register_batch_benchmarks::<$d>(&mut group);
             $(
                 $register_stress(&mut group);
             )?
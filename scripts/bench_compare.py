@@ -134,6 +134,7 @@ VS_LINALG_STANDARD_BENCH_ORDER: list[str] = [
 
 VS_LINALG_D8_RELEASE_SIGNAL_BENCHES: list[str] = [
     "la_stack_lu_pivoting",
+    "la_stack_solve_from_lu_pivoting",
     "la_stack_lu_ill_conditioned",
     "la_stack_ldlt_ill_conditioned",
     "la_stack_det_from_lu_balanced_range",
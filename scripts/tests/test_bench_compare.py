@@ -616,6 +616,7 @@ def test_v043_adapter_allows_only_known_unavailable_d8_baselines(tmp_path: Path)
     special_gaps = [gap for gap in collection.gaps if gap.bench in bench_compare.VS_LINALG_D8_RELEASE_SIGNAL_BENCHES]
     assert [gap.bench for gap in special_gaps] == [
         "la_stack_lu_pivoting",
+        "la_stack_solve_from_lu_pivoting",
         "la_stack_lu_ill_conditioned",
         "la_stack_ldlt_ill_conditioned",
     ]
@@ -0,0 +1,495 @@
+#![forbid(unsafe_code)]
+
+//! Eigenvalues of general real matrices by the Francis double-shift QR
+//! algorithm.
+//!
+//! This module is only compiled when the `"complex"` Cargo feature is enabled:
+//! a real nonsymmetric matrix can have complex-conjugate eigenvalue pairs, so
+//! the results are [`Complex<f64>`] values from `num-complex`.
+//!
+//! The matrix is first reduced to upper-Hessenberg form with
+//! [`Matrix::to_hessenberg`]. Francis double-shift QR steps then drive the
+//! subdiagonal to zero from the bottom up while staying in real arithmetic:
+//! each step uses the eigenvalues of the trailing 2×2 block as an implicit
+//! shift pair and chases a 3×3 Householder bulge down the active block. A
+//! trailing 1×1 block deflates a real eigenvalue and a 2×2 block deflates a
+//! real or complex-conjugate pair. An exceptional shift after every 10
+//! iterations without deflation breaks the rare cycles of the plain shift
+//! strategy. See `REFERENCES.md` \[12\], Algorithm 7.5.2.
+
+use core::hint::cold_path;
+
+use num_complex::Complex;
+
+use crate::matrix::Matrix;
+use crate::{ArithmeticOperation, LaError, Tolerance};
+
+/// Iterations without a deflation after which an exceptional shift is used.
+const EXCEPTIONAL_SHIFT_PERIOD: usize = 10;
+
+impl<const D: usize> Matrix<D> {
+    /// All eigenvalues of a general real matrix by the Francis double-shift
+    /// QR algorithm.
+    ///
+    /// Real eigenvalues are returned with zero imaginary part, and each
+    /// complex-conjugate pair occupies adjacent slots with the positive
+    /// imaginary part first. The order otherwise follows deflation, from the
+    /// bottom of the Hessenberg form upward, and is not sorted. `A` is first
+    /// divided by its largest entry, which scales every eigenvalue by the
+    /// same factor and keeps the iteration free of overflow.
+    ///
+    /// A subdiagonal entry is treated as zero once
+    /// `|h[i][i−1]| <= tol (|h[i−1][i−1]| + |h[i][i]|)`, so `tol` should be a
+    /// small multiple of [`f64::EPSILON`] or larger. `max_iters` bounds the
+    /// total number of double-shift QR steps; convergence is usually quadratic,
+    /// needing about two steps per eigenvalue. Eigenvalues of a
+    /// non-normal matrix can be very sensitive to rounding; no certified error
+    /// bound is provided. For symmetric matrices prefer a symmetric solver,
+    /// whose eigenvalues are real by construction.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Companion matrix of x³ − 1: roots 1 and −½ ± (√3 / 2) i.
+    /// let a = Matrix::<3>::try_from_rows([
+    ///     [0.0, 0.0, 1.0],
+    ///     [1.0, 0.0, 0.0],
+    ///     [0.0, 1.0, 0.0],
+    /// ])?;
+    /// let eigenvalues = a.eigenvalues(Tolerance::try_new(1e-14)?, 100)?;
+    ///
+    /// let half_root3 = 3.0_f64.sqrt() / 2.0;
+    /// for expected in [
+    ///     Complex::new(1.0, 0.0),
+    ///     Complex::new(-0.5, half_root3),
+    ///     Complex::new(-0.5, -half_root3),
+    /// ] {
+    ///     assert!(eigenvalues.iter().any(|z| (z - expected).norm() <= 1e-12));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NotConverged`] with the smallest remaining relative
+    /// subdiagonal magnitude of the active block if `max_iters` QR steps do
+    /// not deflate every eigenvalue.
+    /// Returns [`LaError::NonFinite`] if an iterate or a rescaled eigenvalue
+    /// overflows.
+    #[inline]
+    pub fn eigenvalues(
+        self,
+        tol: Tolerance,
+        max_iters: usize,
+    ) -> Result<[Complex<f64>; D], LaError> {
+        let op = ArithmeticOperation::QrEigenvalues;
+        let max_abs = self
+            .as_rows()
+            .as_flattened()
+            .iter()
+            .fold(0.0_f64, |max, x| max.max(x.abs()));
+        if max_abs == 0.0 {
+            return Ok([Complex::new(0.0, 0.0); D]);
+        }
+
+        let scaled =
+            Self::from_computation(self.as_rows().map(|row| row.map(|x| x / max_abs)), op)?;
+        let mut h = scaled.to_hessenberg()?.0.into_rows();
+        let (re, im) = hessenberg_qr(&mut h, tol.get(), max_iters)?;
+
+        let mut eigenvalues = [Complex::new(0.0, 0.0); D];
+        for (i, (z, (&x, &y))) in eigenvalues.iter_mut().zip(re.iter().zip(&im)).enumerate() {
+            *z = Complex::new(x * max_abs, y * max_abs);
+            if !(z.re.is_finite() && z.im.is_finite()) {
+                cold_path();
+                return Err(LaError::non_finite_computation_step(op, i));
+            }
+        }
+        Ok(eigenvalues)
+    }
+}
+
+/// `|h[i][i−1]|` relative to its neighbouring diagonal entries, or to `norm`
+/// when both are zero.
+fn subdiagonal_ratio<const D: usize>(h: &[[f64; D]; D], i: usize, norm: f64) -> f64 {
+    let scale = h[i - 1][i - 1].abs() + h[i][i].abs();
+    h[i][i - 1].abs() / if scale == 0.0 { norm } else { scale }
+}
+
+/// Francis double-shift QR on the upper-Hessenberg `h`, deflating from the
+/// bottom; returns the real and imaginary parts of the eigenvalues.
+fn hessenberg_qr<const D: usize>(
+    h: &mut [[f64; D]; D],
+    tolerance: f64,
+    max_iters: usize,
+) -> Result<([f64; D], [f64; D]), LaError> {
+    let mut re = [0.0; D];
+    let mut im = [0.0; D];
+    let norm: f64 = h
+        .iter()
+        .enumerate()
+        .map(|(r, row)| {
+            row[r.saturating_sub(1)..]
+                .iter()
+                .map(|x| x.abs())
+                .sum::<f64>()
+        })
+        .sum();
+    // Exceptional shifts are applied explicitly and accumulated here.
+    let mut applied_shift = 0.0;
+    let mut iterations = 0;
+    let mut since_deflation: usize = 0;
+
+    // Rows `..active` are not yet deflated.
+    let mut active = D;
+    while active > 0 {
+        let last = active - 1;
+        // The active block `start..=last` is unreduced above row `start`.
+        let mut start = last;
+        while start > 0 {
+            if subdiagonal_ratio(h, start, norm) <= tolerance {
+                h[start][start - 1] = 0.0;
+                break;
+            }
+            start -= 1;
+        }
+
+        let bottom = h[last][last];
+        if start == last {
+            re[last] = bottom + applied_shift;
+            active -= 1;
+            since_deflation = 0;
+            continue;
+        }
+        let above = h[last - 1][last - 1];
+        let coupling = h[last][last - 1] * h[last - 1][last];
+        if start + 1 == last {
+            let (first, second) = block_eigenvalues(bottom, above, coupling, applied_shift);
+            (re[last - 1], im[last - 1]) = first;
+            (re[last], im[last]) = second;
+            active -= 2;
+            since_deflation = 0;
+            continue;
+        }
+
+        if iterations == max_iters {
+            cold_path();
+            let residual = (start + 1..=last)
+                .map(|i| subdiagonal_ratio(h, i, norm))
+                .fold(f64::INFINITY, f64::min);
+            return Err(LaError::not_converged(max_iters, residual, tolerance));
+        }
+        iterations += 1;
+
+        let shift_data =
+            if since_deflation > 0 && since_deflation.is_multiple_of(EXCEPTIONAL_SHIFT_PERIOD) {
+                // Apply the bottom entry as an explicit shift and replace the
+                // trailing-block shift pair by one derived from the subdiagonal.
+                applied_shift += bottom;
+                for (i, row) in h.iter_mut().enumerate().take(active) {
+                    row[i] -= bottom;
+                }
+                let magnitude = h[last][last - 1].abs() + h[last - 1][last - 2].abs();
+                let exceptional = 0.75 * magnitude;
+                (exceptional, exceptional, -0.4375 * magnitude * magnitude)
+            } else {
+                (bottom, above, coupling)
+            };
+        since_deflation += 1;
+        francis_step(h, start, last, shift_data);
+
+        if h.iter().flatten().any(|v| !v.is_finite()) {
+            cold_path();
+            return Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::QrEigenvalues,
+            ));
+        }
+    }
+    Ok((re, im))
+}
+
+/// Eigenvalues `(re, im)` of the trailing 2×2 block `[[above, ·], [·, bottom]]`
+/// whose off-diagonal product is `coupling`, moved back by the accumulated
+/// `shift`.
+fn block_eigenvalues(
+    bottom: f64,
+    above: f64,
+    coupling: f64,
+    shift: f64,
+) -> ((f64, f64), (f64, f64)) {
+    // The eigenvalues are bottom + half ± √(half² + coupling), where
+    // half = (above − bottom) / 2.
+    let half = 0.5 * (above - bottom);
+    let discriminant = half.mul_add(half, coupling);
+    let root = discriminant.abs().sqrt();
+    let base = bottom + shift;
+    if discriminant >= 0.0 {
+        // Take the root that adds to `half` without cancellation; the other
+        // offset follows from their product −coupling.
+        let offset = half + root.copysign(half);
+        let second = if offset == 0.0 {
+            base
+        } else {
+            base - coupling / offset
+        };
+        ((base + offset, 0.0), (second, 0.0))
+    } else {
+        ((base + half, root), (base + half, -root))
+    }
+}
+
+/// One implicit double-shift QR step on rows and columns `start..=last` of
+/// `h`, with shifts given by the trace and determinant data `(x, y, w)` of the
+/// trailing 2×2 block.
+#[expect(
+    clippy::many_single_char_names,
+    clippy::needless_range_loop,
+    reason = "names and row indexing follow the published bulge-chasing recurrence"
+)]
+fn francis_step<const D: usize>(
+    h: &mut [[f64; D]; D],
+    start: usize,
+    last: usize,
+    (x, y, w): (f64, f64, f64),
+) {
+    // Start the bulge at the lowest row where two consecutive small
+    // subdiagonal entries make the step decouple.
+    let mut m = last - 2;
+    let (mut p, mut q, mut r);
+    loop {
+        let z = h[m][m];
+        let xr = x - z;
+        let ys = y - z;
+        p = xr.mul_add(ys, -w) / h[m + 1][m] + h[m][m + 1];
+        q = h[m + 1][m + 1] - z - xr - ys;
+        r = h[m + 2][m + 1];
+        let s = p.abs() + q.abs() + r.abs();
+        p /= s;
+        q /= s;
+        r /= s;
+        if m == start {
+            break;
+        }
+        let u = h[m][m - 1].abs() * (q.abs() + r.abs());
+        let v = p.abs() * (h[m - 1][m - 1].abs() + z.abs() + h[m + 1][m + 1].abs());
+        if u <= f64::EPSILON * v {
+            break;
+        }
+        m -= 1;
+    }
+
+    for i in (m + 2)..=last {
+        h[i][i - 2] = 0.0;
+        if i != m + 2 {
+            h[i][i - 3] = 0.0;
+        }
+    }
+
+    let mut scale = 0.0;
+    for k in m..last {
+        let has_third = k + 1 != last;
+        if k != m {
+            p = h[k][k - 1];
+            q = h[k + 1][k - 1];
+            r = if has_third { h[k + 2][k - 1] } else { 0.0 };
+            scale = p.abs() + q.abs() + r.abs();
+            if scale != 0.0 {
+                p /= scale;
+                q /= scale;
+                r /= scale;
+            }
+        }
+        let s = p.mul_add(p, q.mul_add(q, r * r)).sqrt().copysign(p);
+        if s == 0.0 {
+            continue;
+        }
+        if k == m {
+            if start != m {
+                h[k][k - 1] = -h[k][k - 1];
+            }
+        } else {
+            h[k][k - 1] = -s * scale;
+        }
+
+        // Householder reflector I − [1, v₁, v₂]ᵀ [a, b, c] applied on both sides.
+        p += s;
+        let (a, b, c) = (p / s, q / s, r / s);
+        q /= p;
+        r /= p;
+        for j in k..=last {
+            let mut t = q.mul_add(h[k + 1][j], h[k][j]);
+            if has_third {
+                t = r.mul_add(h[k + 2][j], t);
+                h[k + 2][j] = (-t).mul_add(c, h[k + 2][j]);
+            }
+            h[k + 1][j] = (-t).mul_add(b, h[k + 1][j]);
+            h[k][j] = (-t).mul_add(a, h[k][j]);
+        }
+        for row in h.iter_mut().take(last.min(k + 3) + 1).skip(start) {
+            let mut t = a.mul_add(row[k], b * row[k + 1]);
+            if has_third {
+                t = c.mul_add(row[k + 2], t);
+                row[k + 2] = (-t).mul_add(r, row[k + 2]);
+            }
+            row[k + 1] = (-t).mul_add(q, row[k + 1]);
+            row[k] -= t;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::assert_matches;
+
+    use approx::assert_abs_diff_eq;
+    use pastey::paste;
+
+    use super::*;
+
+    /// Assert that `actual` is a permutation of `expected` within `epsilon`.
+    fn assert_same_spectrum<const D: usize>(
+        actual: &[Complex<f64>; D],
+        expected: &[Complex<f64>; D],
+        epsilon: f64,
+    ) {
+        let mut used = [false; D];
+        for want in expected {
+            let found = (0..D)
+                .filter(|&i| !used[i])
+                .min_by(|&i, &j| {
+                    (actual[i] - want)
+                        .norm()
+                        .total_cmp(&(actual[j] - want).norm())
+                })
+                .unwrap();
+            assert!(
+                (actual[found] - want).norm() <= epsilon,
+                "{want} not found in {actual:?}"
+            );
+            used[found] = true;
+        }
+    }
+
+    fn real(x: f64) -> Complex<f64> {
+        Complex::new(x, 0.0)
+    }
+
+    macro_rules! gen_eigenvalues_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<eigenvalues_of_triangular_are_its_diagonal_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate().skip(r) {
+                            let cf = f64::from(u32::try_from(c).unwrap());
+                            *entry = if r == c { cf - 1.5 } else { cf + 1.0 };
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+
+                    let eigenvalues = a.eigenvalues(Tolerance::try_new(1e-14).unwrap(), 100).unwrap();
+                    let expected = core::array::from_fn(|i| real(rows[i][i]));
+                    assert_same_spectrum(&eigenvalues, &expected, 1e-12);
+                }
+
+                #[test]
+                fn [<eigenvalues_of_second_difference_matrix_are_real_ $d d>]() {
+                    // tridiag(−1, 2, −1) has eigenvalues 2 − 2 cos(kπ / (D + 1)).
+                    let a = Matrix::<$d>::second_difference();
+
+                    let eigenvalues = a.eigenvalues(Tolerance::try_new(1e-14).unwrap(), 100).unwrap();
+                    let step = core::f64::consts::PI / f64::from($d + 1);
+                    let expected = core::array::from_fn(|k| {
+                        let kf = f64::from(u32::try_from(k).unwrap()) + 1.0;
+                        real((-2.0f64).mul_add((kf * step).cos(), 2.0))
+                    });
+                    assert_same_spectrum(&eigenvalues, &expected, 1e-12);
+                    for z in eigenvalues {
+                        assert_abs_diff_eq!(z.im, 0.0, epsilon = 0.0);
+                    }
+                }
+
+                #[test]
+                fn [<eigenvalues_of_zero_are_zero_ $d d>]() {
+                    let eigenvalues = Matrix::<$d>::zero().eigenvalues(Tolerance::ZERO, 0).unwrap();
+                    assert_eq!(eigenvalues, [Complex::new(0.0, 0.0); $d]);
+                }
+            }
+        };
+    }
+
+    gen_eigenvalues_tests!(2);
+    gen_eigenvalues_tests!(3);
+    gen_eigenvalues_tests!(4);
+    gen_eigenvalues_tests!(5);
+
+    #[test]
+    fn eigenvalues_of_companion_matrix_recover_complex_roots() {
+        // x⁴ − 4x³ + 6x² − 4x + 5 = (x² + 1)(x² − 4x + 5): roots ±i and 2 ± i.
+        let a = Matrix::<4>::try_from_rows([
+            [4.0, -6.0, 4.0, -5.0],
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+        ])
+        .unwrap();
+
+        let eigenvalues = a
+            .eigenvalues(Tolerance::try_new(1e-14).unwrap(), 100)
+            .unwrap();
+        let expected = [
+            Complex::new(0.0, 1.0),
+            Complex::new(0.0, -1.0),
+            Complex::new(2.0, 1.0),
+            Complex::new(2.0, -1.0),
+        ];
+        assert_same_spectrum(&eigenvalues, &expected, 1e-12);
+
+        // Conjugate pairs are adjacent with the positive imaginary part first.
+        for pair in eigenvalues.chunks(2) {
+            assert!(pair[0].im > 0.0);
+            assert_eq!(pair[1], pair[0].conj());
+        }
+    }
+
+    #[test]
+    fn eigenvalues_scale_with_large_and_small_matrices() {
+        for scale in [1e-300, 1e300] {
+            let a = Matrix::<2>::try_from_rows([[0.0, -scale], [scale, 0.0]]).unwrap();
+            let eigenvalues = a
+                .eigenvalues(Tolerance::try_new(1e-14).unwrap(), 10)
+                .unwrap();
+            assert_eq!(
+                eigenvalues,
+                [Complex::new(0.0, scale), Complex::new(0.0, -scale)]
+            );
+        }
+    }
+
+    #[test]
+    fn eigenvalues_need_exceptional_shifts_for_cyclic_permutation() {
+        // The trailing block of this orthogonal Hessenberg matrix gives the
+        // zero shift pair, under which a QR step reproduces the matrix.
+        let a = Matrix::<3>::try_from_rows([[0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]])
+            .unwrap();
+        let tol = Tolerance::try_new(1e-14).unwrap();
+
+        assert_matches!(
+            a.eigenvalues(tol, EXCEPTIONAL_SHIFT_PERIOD - 1),
+            Err(LaError::NotConverged { iterations, .. })
+                if iterations == EXCEPTIONAL_SHIFT_PERIOD - 1
+        );
+
+        let eigenvalues = a.eigenvalues(tol, 100).unwrap();
+        let half_root3 = 3.0_f64.sqrt() / 2.0;
+        let expected = [
+            real(1.0),
+            Complex::new(-0.5, half_root3),
+            Complex::new(-0.5, -half_root3),
+        ];
+        assert_same_spectrum(&eigenvalues, &expected, 1e-12);
+    }
+}
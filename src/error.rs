@@ -35,6 +35,130 @@ pub enum ArithmeticOperation {
     VectorDotProduct,
     /// Vector squared-norm calculation.
     VectorSquaredNorm,
+    /// Matrix-matrix product.
+    MatrixProduct,
+    /// Matrix inversion from a factorization.
+    MatrixInverse,
+    /// Orthogonal polar-factor iteration.
+    PolarDecomposition,
+    /// Signed matrix row sums.
+    MatrixRowSums,
+    /// Signed matrix column sums.
+    MatrixColumnSums,
+    /// Diagonal shift by a scaled identity.
+    ScaledIdentityShift,
+    /// Gershgorin disc bound calculation.
+    GershgorinBounds,
+    /// Isotropic/deviatoric split.
+    IsotropicSplit,
+    /// Row and column equilibration scaling.
+    Equilibration,
+    /// Weighted normal-equation assembly.
+    WeightedNormalEquations,
+    /// Matrix permanent calculation.
+    Permanent,
+    /// Vector rotation.
+    VectorRotation,
+    /// Matrix trace calculation.
+    Trace,
+    /// Trace of a matrix power.
+    MatrixPowerTrace,
+    /// Matrix-vector product `A v`.
+    MatrixVectorProduct,
+    /// Generalized Rayleigh quotient `(vᵀ A v) / (vᵀ B v)`.
+    RayleighQuotient,
+    /// Kronecker-vectorized Sylvester system `(I ⊗ A + Bᵀ ⊗ I) vec(X) = vec(C)`.
+    SylvesterSystem,
+    /// Running prefix sum of vector entries.
+    CumulativeSum,
+    /// Consecutive differences of vector entries.
+    VectorDifference,
+    /// Tridiagonal LU (Thomas algorithm) factorization.
+    TridiagonalFactorization,
+    /// Tridiagonal LU forward and back substitution.
+    TridiagonalSolve,
+    /// Circulant matrix-vector product computed from the first row.
+    CirculantProduct,
+    /// Diagonally weighted dot product `Σ wᵢ aᵢ bᵢ`.
+    WeightedDotProduct,
+    /// Mahalanobis distance `√(δᵀ Σ⁻¹ δ)` from LDLT factors.
+    MahalanobisDistance,
+    /// Row or column scaling by a diagonal matrix.
+    DiagonalScaling,
+    /// Power-iteration eigenpair residual.
+    PowerIteration,
+    /// Shift-and-invert (inverse) iteration eigenpair residual.
+    InverseIteration,
+    /// Integer matrix power `A^k`.
+    MatrixPower,
+    /// Relative residual of an LU solve.
+    LuResidual,
+    /// Component-wise (Hadamard) vector product.
+    ComponentProduct,
+    /// Component-wise vector quotient.
+    ComponentQuotient,
+    /// Rank-one update `A + w x xᵀ`.
+    OuterProductUpdate,
+    /// Spectral-norm estimate from the Gram matrix `AᵀA`.
+    SpectralNorm,
+    /// One-sided Jacobi singular value decomposition.
+    SingularValueDecomposition,
+    /// Moore–Penrose pseudoinverse from the singular value decomposition.
+    PseudoInverse,
+    /// In-place Householder reflector application.
+    HouseholderUpdate,
+    /// Householder reduction to upper-Hessenberg form.
+    HessenbergReduction,
+    /// Francis double-shift QR eigenvalue iteration.
+    QrEigenvalues,
+    /// Matrix exponential by scaling and squaring.
+    MatrixExponential,
+    /// Determinant gradient (cofactor matrix) from an LU factorization.
+    DeterminantGradient,
+    /// Schur complement formation and back-substitution of a 2x2 block system.
+    SchurComplement,
+    /// One-norm condition number from a matrix and its inverse.
+    ConditionNumber,
+    /// Orthogonal projector onto a column space.
+    Projector,
+    /// Inverse square root of a symmetric positive-definite matrix.
+    InverseSquareRoot,
+    /// Entrywise (Hadamard) reciprocal of a matrix.
+    EntrywiseReciprocal,
+    /// Scalar function applied to the spectrum of a symmetric matrix.
+    SpectralFunction,
+    /// Signed volume of a simplex from its vertices.
+    SimplexVolume,
+    /// In-sphere predicate on a simplex and a query point.
+    InSphere,
+    /// Average of a set of points.
+    Centroid,
+    /// Circumcenter of a simplex from its vertices.
+    Circumcenter,
+    /// Circumradius of a simplex from its vertices.
+    Circumradius,
+    /// Barycentric coordinates of a point in a simplex.
+    BarycentricCoordinates,
+    /// Affine map recovered from point correspondences.
+    AffineCorrespondence,
+    /// Matrix logarithm by inverse scaling and squaring.
+    MatrixLogarithm,
+    /// Component-wise vector square root.
+    ComponentSquareRoot,
+    /// Component-wise vector exponential.
+    ComponentExponential,
+    /// Frobenius inner product `tr(AᵀB)` of two matrices.
+    FrobeniusInnerProduct,
+    /// Pivot growth factor `max|U| / max|A|` of an LU factorization.
+    PivotGrowthFactor,
+    /// Eigendecomposition of a symmetric matrix.
+    SymmetricEigendecomposition,
+    /// Component-wise standardization `(xᵢ − μᵢ) / σᵢ`.
+    Standardization,
+    /// Alternating projections toward the nearest correlation matrix.
+    NearestCorrelation,
+    /// Inversion of a triangular matrix by substitution.
+    TriangularInverse,
 }
 
 impl fmt::Display for ArithmeticOperation {
@@ -50,6 +174,68 @@ impl fmt::Display for ArithmeticOperation {
             Self::DeterminantErrorBound => "determinant error bound",
             Self::VectorDotProduct => "vector dot product",
             Self::VectorSquaredNorm => "vector squared norm",
+            Self::MatrixProduct => "matrix product",
+            Self::MatrixInverse => "matrix inverse",
+            Self::PolarDecomposition => "polar decomposition",
+            Self::MatrixRowSums => "matrix row sums",
+            Self::MatrixColumnSums => "matrix column sums",
+            Self::ScaledIdentityShift => "scaled identity shift",
+            Self::GershgorinBounds => "Gershgorin bounds",
+            Self::IsotropicSplit => "isotropic-deviatoric split",
+            Self::Equilibration => "equilibration",
+            Self::WeightedNormalEquations => "weighted normal equations",
+            Self::Permanent => "permanent",
+            Self::VectorRotation => "vector rotation",
+            Self::Trace => "trace",
+            Self::MatrixPowerTrace => "matrix power trace",
+            Self::MatrixVectorProduct => "matrix-vector product",
+            Self::RayleighQuotient => "Rayleigh quotient",
+            Self::SylvesterSystem => "Sylvester Kronecker system",
+            Self::CumulativeSum => "cumulative sum",
+            Self::VectorDifference => "vector difference",
+            Self::TridiagonalFactorization => "tridiagonal LU factorization",
+            Self::TridiagonalSolve => "tridiagonal LU solve",
+            Self::CirculantProduct => "circulant product",
+            Self::WeightedDotProduct => "weighted dot product",
+            Self::MahalanobisDistance => "Mahalanobis distance",
+            Self::DiagonalScaling => "diagonal scaling",
+            Self::PowerIteration => "power iteration",
+            Self::InverseIteration => "inverse iteration",
+            Self::MatrixPower => "matrix power",
+            Self::LuResidual => "LU residual",
+            Self::ComponentProduct => "component-wise product",
+            Self::ComponentQuotient => "component-wise quotient",
+            Self::OuterProductUpdate => "outer-product update",
+            Self::SpectralNorm => "spectral norm",
+            Self::SingularValueDecomposition => "singular value decomposition",
+            Self::PseudoInverse => "pseudoinverse",
+            Self::HouseholderUpdate => "Householder update",
+            Self::HessenbergReduction => "Hessenberg reduction",
+            Self::QrEigenvalues => "QR eigenvalue iteration",
+            Self::MatrixExponential => "matrix exponential",
+            Self::DeterminantGradient => "determinant gradient",
+            Self::SchurComplement => "Schur complement",
+            Self::ConditionNumber => "condition number",
+            Self::Projector => "orthogonal projector",
+            Self::InverseSquareRoot => "inverse square root",
+            Self::EntrywiseReciprocal => "entrywise reciprocal",
+            Self::SpectralFunction => "spectral matrix function",
+            Self::SimplexVolume => "simplex volume",
+            Self::InSphere => "in-sphere predicate",
+            Self::Centroid => "centroid",
+            Self::Circumcenter => "circumcenter",
+            Self::Circumradius => "circumradius",
+            Self::BarycentricCoordinates => "barycentric coordinates",
+            Self::AffineCorrespondence => "affine correspondence",
+            Self::MatrixLogarithm => "matrix logarithm",
+            Self::ComponentSquareRoot => "component-wise square root",
+            Self::ComponentExponential => "component-wise exponential",
+            Self::FrobeniusInnerProduct => "Frobenius inner product",
+            Self::PivotGrowthFactor => "pivot growth factor",
+            Self::SymmetricEigendecomposition => "symmetric eigendecomposition",
+            Self::Standardization => "standardization",
+            Self::NearestCorrelation => "nearest correlation matrix",
+            Self::TriangularInverse => "triangular inverse",
         })
     }
 }
@@ -69,6 +255,10 @@ pub enum FactorizationKind {
     Lu,
     /// LDLT factorization without pivoting.
     Ldlt,
+    /// Tridiagonal LU factorization without pivoting (Thomas algorithm).
+    TridiagonalLu,
+    /// Diagonal system, whose entries are its own pivots.
+    Diagonal,
 }
 
 impl fmt::Display for FactorizationKind {
@@ -76,6 +266,8 @@ impl fmt::Display for FactorizationKind {
         f.write_str(match self {
             Self::Lu => "LU",
             Self::Ldlt => "LDLT",
+            Self::TridiagonalLu => "tridiagonal LU",
+            Self::Diagonal => "diagonal",
         })
     }
 }
@@ -134,6 +326,13 @@ pub enum NonFiniteLocation {
         /// Vector index.
         index: usize,
     },
+    /// Entry in a scalar weight array input, such as the weights of
+    /// [`Matrix::from_outer_sum`](crate::Matrix::from_outer_sum).
+    #[non_exhaustive]
+    WeightEntry {
+        /// Weight index.
+        index: usize,
+    },
     /// Indexed step in a factorization, solve, or reduction.
     #[non_exhaustive]
     Step {
@@ -363,6 +562,43 @@ pub enum LaError {
         /// Maximum absolute difference allowed by the symmetry check.
         allowed_abs_diff: f64,
     },
+    /// An operation that needs a direction received a vector whose entries are
+    /// all zero.
+    #[non_exhaustive]
+    ZeroVector {
+        /// Vector length `D`.
+        dim: usize,
+    },
+    /// Two indices that must name distinct axes are equal.
+    #[non_exhaustive]
+    RepeatedIndex {
+        /// Index supplied for both axes.
+        index: usize,
+    },
+    /// A structured factorization found a non-zero entry outside its band.
+    #[non_exhaustive]
+    OutsideBand {
+        /// Row of the first off-band non-zero entry in row-major order.
+        row: usize,
+        /// Column of the first off-band non-zero entry.
+        col: usize,
+        /// Matrix dimension `D`.
+        dim: usize,
+        /// Observed entry at `(row, col)`.
+        value: f64,
+        /// Largest `|row - col|` the factorization accepts.
+        bandwidth: usize,
+    },
+    /// A caller-supplied row permutation is not a permutation of `0..dim`.
+    #[non_exhaustive]
+    InvalidPermutation {
+        /// Position of the first out-of-range or repeated entry.
+        index: usize,
+        /// Entry stored at `index`.
+        value: usize,
+        /// Permutation length `D`; valid entries are less than this value.
+        dim: usize,
+    },
     /// A computed LDLT pivot violates the no-pivot positive-semidefinite
     /// factorization requirements.
     ///
@@ -375,6 +611,34 @@ pub enum LaError {
         /// Typed PSD-domain violation.
         violation: PositiveSemidefiniteViolation,
     },
+    /// An iterative method exhausted its iteration budget before its
+    /// convergence measure reached the requested tolerance.
+    #[non_exhaustive]
+    NotConverged {
+        /// Number of iterations performed.
+        iterations: usize,
+        /// Convergence measure observed after the final iteration.
+        residual: f64,
+        /// Tolerance the convergence measure had to reach.
+        tolerance: f64,
+    },
+    /// A flat input slice does not have the length the fixed dimension needs.
+    #[non_exhaustive]
+    DimensionMismatch {
+        /// Required slice length.
+        expected: usize,
+        /// Length of the supplied slice.
+        actual: usize,
+    },
+    /// A half-open sampling range `start..end` is empty, or its width
+    /// `end - start` overflows.
+    #[non_exhaustive]
+    InvalidRange {
+        /// Inclusive lower end of the range.
+        start: f64,
+        /// Exclusive upper end of the range.
+        end: f64,
+    },
 }
 
 impl LaError {
@@ -432,6 +696,17 @@ impl LaError {
         }
     }
 
+    /// Construct a [`LaError::NonFinite`] input error located at weight entry
+    /// `index`.
+    #[inline]
+    #[must_use]
+    pub const fn non_finite_input_weight(index: usize) -> Self {
+        Self::NonFinite {
+            location: NonFiniteLocation::WeightEntry { index },
+            origin: NonFiniteOrigin::Input,
+        }
+    }
+
     /// Construct a [`LaError::NonFinite`] input error for a scalar without an
     /// index or matrix coordinate.
     #[inline]
@@ -578,6 +853,57 @@ impl LaError {
         }
     }
 
+    /// Construct a [`LaError::ZeroVector`] error for a vector of length `dim`.
+    #[inline]
+    #[must_use]
+    pub const fn zero_vector(dim: usize) -> Self {
+        Self::ZeroVector { dim }
+    }
+
+    /// Construct a [`LaError::DimensionMismatch`] error for a slice of length
+    /// `actual` where `expected` values are required.
+    #[inline]
+    #[must_use]
+    pub const fn dimension_mismatch(expected: usize, actual: usize) -> Self {
+        Self::DimensionMismatch { expected, actual }
+    }
+
+    /// Construct a [`LaError::RepeatedIndex`] error for an index supplied for
+    /// two axes that must differ.
+    #[inline]
+    #[must_use]
+    pub const fn repeated_index(index: usize) -> Self {
+        Self::RepeatedIndex { index }
+    }
+
+    /// Construct a [`LaError::OutsideBand`] error retaining the offending
+    /// coordinates, its value, and the accepted bandwidth.
+    #[inline]
+    #[must_use]
+    pub const fn outside_band(
+        row: usize,
+        col: usize,
+        dim: usize,
+        value: f64,
+        bandwidth: usize,
+    ) -> Self {
+        Self::OutsideBand {
+            row,
+            col,
+            dim,
+            value,
+            bandwidth,
+        }
+    }
+
+    /// Construct a [`LaError::InvalidPermutation`] error retaining the first
+    /// offending position, its entry, and the permutation length.
+    #[inline]
+    #[must_use]
+    pub const fn invalid_permutation(index: usize, value: usize, dim: usize) -> Self {
+        Self::InvalidPermutation { index, value, dim }
+    }
+
     /// Construct a [`LaError::NotPositiveSemidefinite`] error for a computed
     /// negative LDLT diagonal pivot.
     #[inline]
@@ -604,6 +930,26 @@ impl LaError {
             violation: PositiveSemidefiniteViolation::ZeroPivotCoupling { row, value },
         }
     }
+
+    /// Construct a [`LaError::NotConverged`] error retaining the iteration
+    /// count, final convergence measure, and requested tolerance.
+    #[inline]
+    #[must_use]
+    pub const fn not_converged(iterations: usize, residual: f64, tolerance: f64) -> Self {
+        Self::NotConverged {
+            iterations,
+            residual,
+            tolerance,
+        }
+    }
+
+    /// Construct a [`LaError::InvalidRange`] error for a sampling range
+    /// `start..end` that is empty or too wide to represent.
+    #[inline]
+    #[must_use]
+    pub const fn invalid_range(start: f64, end: f64) -> Self {
+        Self::InvalidRange { start, end }
+    }
 }
 
 /// Write the structured location portion of [`LaError::NonFinite`]'s public
@@ -618,6 +964,7 @@ fn write_non_finite_location(
             write!(f, "matrix cell ({row}, {col})")
         }
         NonFiniteLocation::VectorEntry { index } => write!(f, "vector entry {index}"),
+        NonFiniteLocation::WeightEntry { index } => write!(f, "weight entry {index}"),
         NonFiniteLocation::Step { index } => write!(f, "step {index}"),
         NonFiniteLocation::Scalar => f.write_str("scalar value"),
     }
@@ -649,6 +996,10 @@ fn write_non_finite(
 }
 
 impl fmt::Display for LaError {
+    #[expect(
+        clippy::too_many_lines,
+        reason = "one match arm per public error variant keeps the display contract in one place"
+    )]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             Self::Singular {
@@ -721,6 +1072,29 @@ impl fmt::Display for LaError {
                 f,
                 "matrix is not symmetric for dimension {dim}: entry ({row}, {col}) = {upper} and entry ({col}, {row}) = {lower} differ by more than allowed absolute difference {allowed_abs_diff}"
             ),
+            Self::ZeroVector { dim } => {
+                write!(f, "vector of length {dim} is zero and has no direction")
+            }
+            Self::RepeatedIndex { index } => {
+                write!(
+                    f,
+                    "index {index} was supplied for two axes that must differ"
+                )
+            }
+            Self::OutsideBand {
+                row,
+                col,
+                dim,
+                value,
+                bandwidth,
+            } => write!(
+                f,
+                "matrix entry ({row}, {col}) = {value} lies outside bandwidth {bandwidth} for dimension {dim}"
+            ),
+            Self::InvalidPermutation { index, value, dim } => write!(
+                f,
+                "invalid permutation of length {dim}: entry {index} = {value} is out of range or repeated"
+            ),
             Self::NotPositiveSemidefinite {
                 pivot_col,
                 violation: PositiveSemidefiniteViolation::NegativePivot { value },
@@ -735,6 +1109,22 @@ impl fmt::Display for LaError {
                 f,
                 "LDLT rejected the matrix at pivot column {pivot_col}: computed zero diagonal has non-zero coupling at row {row} with value {value}"
             ),
+            Self::NotConverged {
+                iterations,
+                residual,
+                tolerance,
+            } => write!(
+                f,
+                "iteration did not converge after {iterations} iterations: residual {residual} > tolerance {tolerance}"
+            ),
+            Self::DimensionMismatch { expected, actual } => write!(
+                f,
+                "input of length {actual} does not match the required length {expected}"
+            ),
+            Self::InvalidRange { start, end } => write!(
+                f,
+                "sampling range {start}..{end} is empty or its width overflows"
+            ),
         }
     }
 }
@@ -749,6 +1139,10 @@ mod tests {
     use crate::MAX_STACK_MATRIX_DISPATCH_DIM;
 
     #[test]
+    #[expect(
+        clippy::too_many_lines,
+        reason = "one assertion per ArithmeticOperation variant keeps each display reviewable"
+    )]
     fn category_displays_are_concise() {
         assert_eq!(FactorizationKind::Lu.to_string(), "LU");
         assert_eq!(FactorizationKind::Ldlt.to_string(), "LDLT");
@@ -783,6 +1177,244 @@ mod tests {
             ArithmeticOperation::VectorSquaredNorm.to_string(),
             "vector squared norm"
         );
+        assert_eq!(
+            ArithmeticOperation::MatrixProduct.to_string(),
+            "matrix product"
+        );
+        assert_eq!(
+            ArithmeticOperation::MatrixInverse.to_string(),
+            "matrix inverse"
+        );
+        assert_eq!(
+            ArithmeticOperation::PolarDecomposition.to_string(),
+            "polar decomposition"
+        );
+        assert_eq!(
+            ArithmeticOperation::MatrixRowSums.to_string(),
+            "matrix row sums"
+        );
+        assert_eq!(
+            ArithmeticOperation::MatrixColumnSums.to_string(),
+            "matrix column sums"
+        );
+        assert_eq!(
+            ArithmeticOperation::ScaledIdentityShift.to_string(),
+            "scaled identity shift"
+        );
+        assert_eq!(
+            ArithmeticOperation::GershgorinBounds.to_string(),
+            "Gershgorin bounds"
+        );
+        assert_eq!(
+            ArithmeticOperation::IsotropicSplit.to_string(),
+            "isotropic-deviatoric split"
+        );
+        assert_eq!(
+            ArithmeticOperation::Equilibration.to_string(),
+            "equilibration"
+        );
+        assert_eq!(
+            ArithmeticOperation::WeightedNormalEquations.to_string(),
+            "weighted normal equations"
+        );
+        assert_eq!(ArithmeticOperation::Permanent.to_string(), "permanent");
+        assert_eq!(
+            ArithmeticOperation::VectorRotation.to_string(),
+            "vector rotation"
+        );
+        assert_eq!(ArithmeticOperation::Trace.to_string(), "trace");
+        assert_eq!(
+            ArithmeticOperation::MatrixPowerTrace.to_string(),
+            "matrix power trace"
+        );
+        assert_eq!(
+            ArithmeticOperation::MatrixVectorProduct.to_string(),
+            "matrix-vector product"
+        );
+        assert_eq!(
+            ArithmeticOperation::RayleighQuotient.to_string(),
+            "Rayleigh quotient"
+        );
+        assert_eq!(
+            ArithmeticOperation::SylvesterSystem.to_string(),
+            "Sylvester Kronecker system"
+        );
+        assert_eq!(
+            FactorizationKind::TridiagonalLu.to_string(),
+            "tridiagonal LU"
+        );
+        assert_eq!(FactorizationKind::Diagonal.to_string(), "diagonal");
+        assert_eq!(
+            ArithmeticOperation::CumulativeSum.to_string(),
+            "cumulative sum"
+        );
+        assert_eq!(
+            ArithmeticOperation::VectorDifference.to_string(),
+            "vector difference"
+        );
+        assert_eq!(
+            ArithmeticOperation::TridiagonalFactorization.to_string(),
+            "tridiagonal LU factorization"
+        );
+        assert_eq!(
+            ArithmeticOperation::TridiagonalSolve.to_string(),
+            "tridiagonal LU solve"
+        );
+        assert_eq!(
+            ArithmeticOperation::CirculantProduct.to_string(),
+            "circulant product"
+        );
+        assert_eq!(
+            ArithmeticOperation::WeightedDotProduct.to_string(),
+            "weighted dot product"
+        );
+        assert_eq!(
+            ArithmeticOperation::MahalanobisDistance.to_string(),
+            "Mahalanobis distance"
+        );
+        assert_eq!(
+            ArithmeticOperation::DiagonalScaling.to_string(),
+            "diagonal scaling"
+        );
+        assert_eq!(
+            ArithmeticOperation::PowerIteration.to_string(),
+            "power iteration"
+        );
+        assert_eq!(
+            ArithmeticOperation::InverseIteration.to_string(),
+            "inverse iteration"
+        );
+        assert_eq!(ArithmeticOperation::MatrixPower.to_string(), "matrix power");
+        assert_eq!(ArithmeticOperation::LuResidual.to_string(), "LU residual");
+        assert_eq!(
+            ArithmeticOperation::ComponentProduct.to_string(),
+            "component-wise product"
+        );
+        assert_eq!(
+            ArithmeticOperation::ComponentQuotient.to_string(),
+            "component-wise quotient"
+        );
+        assert_eq!(
+            ArithmeticOperation::OuterProductUpdate.to_string(),
+            "outer-product update"
+        );
+        assert_eq!(
+            ArithmeticOperation::SpectralNorm.to_string(),
+            "spectral norm"
+        );
+        assert_eq!(
+            ArithmeticOperation::SingularValueDecomposition.to_string(),
+            "singular value decomposition"
+        );
+        assert_eq!(
+            ArithmeticOperation::PseudoInverse.to_string(),
+            "pseudoinverse"
+        );
+        assert_eq!(
+            ArithmeticOperation::HouseholderUpdate.to_string(),
+            "Householder update"
+        );
+        assert_eq!(
+            ArithmeticOperation::HessenbergReduction.to_string(),
+            "Hessenberg reduction"
+        );
+        assert_eq!(
+            ArithmeticOperation::QrEigenvalues.to_string(),
+            "QR eigenvalue iteration"
+        );
+        assert_eq!(
+            ArithmeticOperation::MatrixExponential.to_string(),
+            "matrix exponential"
+        );
+        assert_eq!(
+            ArithmeticOperation::DeterminantGradient.to_string(),
+            "determinant gradient"
+        );
+        assert_eq!(
+            ArithmeticOperation::SchurComplement.to_string(),
+            "Schur complement"
+        );
+        assert_eq!(
+            ArithmeticOperation::ConditionNumber.to_string(),
+            "condition number"
+        );
+        assert_eq!(
+            ArithmeticOperation::Projector.to_string(),
+            "orthogonal projector"
+        );
+        assert_eq!(
+            ArithmeticOperation::InverseSquareRoot.to_string(),
+            "inverse square root"
+        );
+        assert_eq!(
+            ArithmeticOperation::EntrywiseReciprocal.to_string(),
+            "entrywise reciprocal"
+        );
+        assert_eq!(
+            ArithmeticOperation::SpectralFunction.to_string(),
+            "spectral matrix function"
+        );
+        assert_eq!(
+            ArithmeticOperation::SimplexVolume.to_string(),
+            "simplex volume"
+        );
+        assert_eq!(
+            ArithmeticOperation::InSphere.to_string(),
+            "in-sphere predicate"
+        );
+        assert_eq!(ArithmeticOperation::Centroid.to_string(), "centroid");
+        assert_eq!(
+            ArithmeticOperation::Circumcenter.to_string(),
+            "circumcenter"
+        );
+        assert_eq!(
+            ArithmeticOperation::Circumradius.to_string(),
+            "circumradius"
+        );
+        assert_eq!(
+            ArithmeticOperation::BarycentricCoordinates.to_string(),
+            "barycentric coordinates"
+        );
+        assert_eq!(
+            ArithmeticOperation::AffineCorrespondence.to_string(),
+            "affine correspondence"
+        );
+        assert_eq!(
+            ArithmeticOperation::MatrixLogarithm.to_string(),
+            "matrix logarithm"
+        );
+        assert_eq!(
+            ArithmeticOperation::ComponentSquareRoot.to_string(),
+            "component-wise square root"
+        );
+        assert_eq!(
+            ArithmeticOperation::ComponentExponential.to_string(),
+            "component-wise exponential"
+        );
+        assert_eq!(
+            ArithmeticOperation::FrobeniusInnerProduct.to_string(),
+            "Frobenius inner product"
+        );
+        assert_eq!(
+            ArithmeticOperation::PivotGrowthFactor.to_string(),
+            "pivot growth factor"
+        );
+        assert_eq!(
+            ArithmeticOperation::SymmetricEigendecomposition.to_string(),
+            "symmetric eigendecomposition"
+        );
+        assert_eq!(
+            ArithmeticOperation::Standardization.to_string(),
+            "standardization"
+        );
+        assert_eq!(
+            ArithmeticOperation::NearestCorrelation.to_string(),
+            "nearest correlation matrix"
+        );
+        assert_eq!(
+            ArithmeticOperation::TriangularInverse.to_string(),
+            "triangular inverse"
+        );
     }
 
     #[test]
@@ -835,6 +1467,17 @@ mod tests {
             LaError::non_finite_input_scalar().to_string(),
             "non-finite scalar input"
         );
+        assert_eq!(
+            LaError::non_finite_input_weight(2),
+            LaError::NonFinite {
+                location: NonFiniteLocation::WeightEntry { index: 2 },
+                origin: NonFiniteOrigin::Input,
+            }
+        );
+        assert_eq!(
+            LaError::non_finite_input_weight(2).to_string(),
+            "non-finite input value at weight entry 2"
+        );
         assert_eq!(
             LaError::non_finite_computation_matrix(ArithmeticOperation::LuFactorization, 2, 1)
                 .to_string(),
@@ -955,6 +1598,79 @@ mod tests {
             LaError::index_out_of_bounds(3, 0, 3).to_string(),
             "matrix index (3, 0) is out of bounds for dimension 3"
         );
+        assert_eq!(LaError::zero_vector(3), LaError::ZeroVector { dim: 3 });
+        assert_eq!(
+            LaError::zero_vector(3).to_string(),
+            "vector of length 3 is zero and has no direction"
+        );
+        assert_eq!(
+            LaError::dimension_mismatch(9, 8),
+            LaError::DimensionMismatch {
+                expected: 9,
+                actual: 8,
+            }
+        );
+        assert_eq!(
+            LaError::dimension_mismatch(9, 8).to_string(),
+            "input of length 8 does not match the required length 9"
+        );
+        assert_eq!(
+            LaError::repeated_index(1),
+            LaError::RepeatedIndex { index: 1 }
+        );
+        assert_eq!(
+            LaError::repeated_index(1).to_string(),
+            "index 1 was supplied for two axes that must differ"
+        );
+        assert_eq!(
+            LaError::outside_band(0, 2, 3, 0.5, 1),
+            LaError::OutsideBand {
+                row: 0,
+                col: 2,
+                dim: 3,
+                value: 0.5,
+                bandwidth: 1,
+            }
+        );
+        assert_eq!(
+            LaError::outside_band(0, 2, 3, 0.5, 1).to_string(),
+            "matrix entry (0, 2) = 0.5 lies outside bandwidth 1 for dimension 3"
+        );
+        assert_eq!(
+            LaError::invalid_permutation(2, 0, 3),
+            LaError::InvalidPermutation {
+                index: 2,
+                value: 0,
+                dim: 3,
+            }
+        );
+        assert_eq!(
+            LaError::invalid_permutation(2, 0, 3).to_string(),
+            "invalid permutation of length 3: entry 2 = 0 is out of range or repeated"
+        );
+        assert_eq!(
+            LaError::not_converged(20, 0.5, 1e-12),
+            LaError::NotConverged {
+                iterations: 20,
+                residual: 0.5,
+                tolerance: 1e-12,
+            }
+        );
+        assert_eq!(
+            LaError::not_converged(20, 0.5, 1e-12).to_string(),
+            "iteration did not converge after 20 iterations: residual 0.5 > tolerance 0.000000000001"
+        );
+        assert_eq!(
+            LaError::invalid_range(1.0, -1.0),
+            LaError::InvalidRange {
+                start: 1.0,
+                end: -1.0,
+            }
+        );
+        assert_eq!(
+            LaError::invalid_range(1.0, -1.0).to_string(),
+            "sampling range 1..-1 is empty or its width overflows"
+        );
     }
 
     #[test]
@@ -310,6 +310,15 @@ impl<const D: usize> Ldlt<D> {
         }
     }
 
+    /// Natural logarithm of the determinant, `Σ ln(D_ii)`.
+    ///
+    /// Every pivot of a constructed factorization is finite and strictly
+    /// positive, so each logarithm, and their sum of at most `D` terms, is
+    /// finite.
+    pub(crate) fn log_det(&self) -> f64 {
+        (0..D).map(|i| self.factors.diag(i).ln()).sum()
+    }
+
     /// Solve `A x = b` using this LDLT factorization.
     ///
     /// [`Vector`] is finite by construction, so this method only checks computed
@@ -429,6 +438,112 @@ impl<const D: usize> Ldlt<D> {
 
         Vector::from_computation(x, ArithmeticOperation::LdltSolve)
     }
+
+    /// Mahalanobis length `√(δᵀ Σ⁻¹ δ)` of `delta` under the factored
+    /// covariance `Σ = L D Lᵀ`.
+    ///
+    /// With `delta = x − μ` this is the Mahalanobis distance of `x` from the
+    /// mean `μ`. `Σ` is never inverted: the quadratic form equals `δ · y` for
+    /// the solution `y` of `Σ y = δ`, and is evaluated in the equivalent
+    /// half-solve form `Σᵢ zᵢ² / Dᵢᵢ` with `L z = δ`. Every term is
+    /// non-negative because successful factors have positive pivots, so
+    /// rounding can never produce the square root of a negative number. No
+    /// certified error bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let sigma = Matrix::<2>::try_from_rows([[4.0, 0.0], [0.0, 9.0]])?;
+    /// let ldlt = sigma.ldlt(DEFAULT_SINGULAR_TOL)?;
+    ///
+    /// // Two standard deviations along each axis.
+    /// let delta = Vector::<2>::try_new([4.0, 6.0])?;
+    /// let d = ldlt.mahalanobis(delta)?;
+    /// assert!((d - 8.0_f64.sqrt()).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] with the step index when forward
+    /// substitution, a scaled term, or the accumulated sum overflows.
+    #[inline]
+    pub fn mahalanobis(&self, delta: Vector<D>) -> Result<f64, LaError> {
+        let mut z = delta.into_array();
+        let mut acc = 0.0;
+        for i in 0..D {
+            let row = self.factors.row(i);
+            let mut sum = z[i];
+            for (&l, &z_j) in row.iter().zip(&z).take(i) {
+                sum = (-l).mul_add(z_j, sum);
+            }
+            z[i] = sum;
+
+            let scaled = sum / self.factors.diag(i);
+            acc = scaled.mul_add(sum, acc);
+            if !acc.is_finite() {
+                cold_path();
+                return Err(LaError::non_finite_computation_step(
+                    ArithmeticOperation::MahalanobisDistance,
+                    i,
+                ));
+            }
+        }
+        Ok(acc.sqrt())
+    }
+
+    /// Pivot spread `max(D_ii) / min(D_ii)`, a cheap conditioning proxy.
+    ///
+    /// Each pivot of an SPD matrix is a Schur complement diagonal, so it lies
+    /// between the extreme eigenvalues `λ_min ≤ D_ii ≤ λ_max`. The ratio is
+    /// therefore a lower bound on the 2-norm condition number `λ_max / λ_min`
+    /// and costs only `D` comparisons given the factorization. It can badly
+    /// underestimate: the pivots of `tridiag(−1, 2, −1)` all lie in `(1, 2]`,
+    /// while its condition number grows like `D²`. `Ldlt<0>` returns `1.0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[4.0, 2.0], [2.0, 3.0]])?;
+    /// let ldlt = a.ldlt(DEFAULT_SINGULAR_TOL)?;
+    ///
+    /// // Pivots 4 and 2; the eigenvalues are (7 ± √17) / 2.
+    /// assert_eq!(ldlt.diag_ratio()?, 2.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] if the ratio overflows, which requires
+    /// pivots more than the binary64 range apart.
+    #[inline]
+    pub const fn diag_ratio(&self) -> Result<f64, LaError> {
+        if D == 0 {
+            return Ok(1.0);
+        }
+        let mut max = self.factors.diag(0);
+        let mut min = max;
+        let mut i = 1;
+        while i < D {
+            let pivot = self.factors.diag(i);
+            max = max.max(pivot);
+            min = min.min(pivot);
+            i += 1;
+        }
+        let ratio = max / min;
+        if ratio.is_finite() {
+            Ok(ratio)
+        } else {
+            cold_path();
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::ConditionNumber,
+            ))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1119,4 +1234,124 @@ mod tests {
     gen_ldlt_const_eval_tests!(3);
     gen_ldlt_const_eval_tests!(4);
     gen_ldlt_const_eval_tests!(5);
+
+    macro_rules! gen_mahalanobis_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<mahalanobis_with_identity_covariance_is_euclidean_norm_ $d d>]() {
+                    let ldlt = Matrix::<$d>::identity().ldlt(DEFAULT_SINGULAR_TOL).unwrap();
+                    let mut delta = [0.0; $d];
+                    for (i, value) in delta.iter_mut().enumerate() {
+                        *value = f64::from(u32::try_from(i).unwrap()) - 1.5;
+                    }
+                    let delta = Vector::<$d>::try_new(delta).unwrap();
+                    assert_abs_diff_eq!(
+                        ldlt.mahalanobis(delta).unwrap(),
+                        delta.norm2_sq().unwrap().sqrt(),
+                        epsilon = 1e-14
+                    );
+                }
+
+                #[test]
+                fn [<mahalanobis_matches_solve_quadratic_form_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    for (i, row) in rows.iter_mut().enumerate() {
+                        for (j, value) in row.iter_mut().enumerate() {
+                            *value = if i == j { 4.0 } else { 1.0 / (1.0 + f64::from(u32::try_from(i + j).unwrap())) };
+                        }
+                    }
+                    let ldlt = Matrix::<$d>::try_from_rows(rows).unwrap().ldlt(DEFAULT_SINGULAR_TOL).unwrap();
+                    let mut delta = [0.0; $d];
+                    for (i, value) in delta.iter_mut().enumerate() {
+                        *value = 2.0 - f64::from(u32::try_from(i).unwrap());
+                    }
+                    let delta = Vector::<$d>::try_new(delta).unwrap();
+                    let y = ldlt.solve(delta).unwrap();
+                    assert_abs_diff_eq!(
+                        ldlt.mahalanobis(delta).unwrap(),
+                        delta.dot(&y).unwrap().sqrt(),
+                        epsilon = 1e-12
+                    );
+                }
+            }
+        };
+    }
+
+    gen_mahalanobis_tests!(2);
+    gen_mahalanobis_tests!(3);
+    gen_mahalanobis_tests!(4);
+    gen_mahalanobis_tests!(5);
+
+    macro_rules! gen_diag_ratio_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<diag_ratio_bounds_tridiagonal_eigenvalue_ratio_ $d d>]() {
+                    // tridiag(−1, 2, −1) has eigenvalues
+                    // 2 − 2cos(kπ / (D + 1)) = 4sin²(kπ / (2D + 2)).
+                    let ldlt = Matrix::<$d>::second_difference().ldlt(DEFAULT_SINGULAR_TOL).unwrap();
+                    let n = f64::from($d);
+                    let half_step = core::f64::consts::FRAC_PI_2 / (n + 1.0);
+                    let cond = ((n * half_step).sin() / half_step.sin()).powi(2);
+
+                    // Pivots are (k + 1) / k, largest first and smallest last.
+                    let ratio = ldlt.diag_ratio().unwrap();
+                    assert_abs_diff_eq!(ratio, 2.0 * n / (n + 1.0), epsilon = 1e-14);
+                    assert!(1.0 <= ratio && ratio <= cond);
+                }
+
+                #[test]
+                fn [<diag_ratio_equals_condition_number_for_diagonal_spd_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    for (i, row) in rows.iter_mut().enumerate() {
+                        row[i] = 0.5 * f64::from(u32::try_from(i).unwrap() + 1);
+                    }
+                    let ldlt = Matrix::<$d>::try_from_rows(rows).unwrap().ldlt(DEFAULT_SINGULAR_TOL).unwrap();
+                    assert_eq!(ldlt.diag_ratio(), Ok(f64::from($d)));
+                    let identity = Matrix::<$d>::identity().ldlt(DEFAULT_SINGULAR_TOL).unwrap();
+                    assert_eq!(identity.diag_ratio(), Ok(1.0));
+                }
+            }
+        };
+    }
+
+    gen_diag_ratio_tests!(2);
+    gen_diag_ratio_tests!(3);
+    gen_diag_ratio_tests!(4);
+    gen_diag_ratio_tests!(5);
+
+    #[test]
+    fn diag_ratio_reports_overflow_and_handles_empty_factorization() {
+        let ldlt = Matrix::<2>::try_from_rows([[1e200, 0.0], [0.0, 1e-200]])
+            .unwrap()
+            .ldlt(Tolerance::ZERO)
+            .unwrap();
+        assert_eq!(
+            ldlt.diag_ratio(),
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::ConditionNumber
+            ))
+        );
+        let empty = Matrix::<0>::zero().ldlt(DEFAULT_SINGULAR_TOL).unwrap();
+        assert_eq!(empty.diag_ratio(), Ok(1.0));
+    }
+
+    #[test]
+    fn mahalanobis_reports_overflowing_quadratic_form() {
+        let ldlt = Matrix::<2>::try_from_rows([[1e-10, 0.0], [0.0, 1.0]])
+            .unwrap()
+            .ldlt(Tolerance::ZERO)
+            .unwrap();
+        let delta = Vector::<2>::try_new([1e160, 0.0]).unwrap();
+        assert_eq!(
+            ldlt.mahalanobis(delta),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::MahalanobisDistance,
+                0
+            ))
+        );
+        let empty = Matrix::<0>::zero().ldlt(DEFAULT_SINGULAR_TOL).unwrap();
+        assert_eq!(empty.mahalanobis(Vector::zero()), Ok(0.0));
+    }
 }
@@ -194,20 +194,30 @@ mod readme_doctests {
     fn adaptive_precision_example() {}
 }
 
+#[cfg(feature = "complex")]
+mod eigen;
 mod error;
 #[cfg(feature = "exact")]
 mod exact;
 mod ldlt;
 mod lu;
 mod matrix;
+#[cfg(feature = "rand")]
+mod random;
 mod scaled_product;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+mod sym_matrix;
 mod tolerance;
+mod tri_lu;
 mod vector;
 
 #[cfg(feature = "exact")]
 pub use exact::{DeterminantSign, ExactF64Conversion};
 #[cfg(feature = "exact")]
 pub use num_bigint::BigInt;
+#[cfg(feature = "complex")]
+pub use num_complex::Complex;
 #[cfg(feature = "exact")]
 pub use num_rational::BigRational;
 #[cfg(feature = "exact")]
@@ -368,7 +378,9 @@ pub use error::{
 pub use ldlt::Ldlt;
 pub use lu::Lu;
 pub use matrix::{DeterminantWithErrorBound, Matrix};
+pub use sym_matrix::SymMatrix;
 pub use tolerance::{DEFAULT_SINGULAR_TOL, Tolerance};
+pub use tri_lu::TriLu;
 pub use vector::Vector;
 
 /// Fallibly dispatch a runtime dimension to a concrete stack-allocated matrix.
@@ -481,9 +493,12 @@ pub mod prelude {
         ArithmeticOperation, DEFAULT_SINGULAR_TOL, DeterminantWithErrorBound, FactorizationKind,
         InvalidToleranceReason, LaError, Ldlt, Lu, MAX_STACK_MATRIX_DISPATCH_DIM, Matrix,
         NonFiniteLocation, NonFiniteOrigin, PositiveSemidefiniteViolation, SingularityReason,
-        Tolerance, UnrepresentableReason, Vector, try_with_stack_matrix,
+        SymMatrix, Tolerance, TriLu, UnrepresentableReason, Vector, try_with_stack_matrix,
     };
 
+    #[cfg(feature = "complex")]
+    pub use crate::Complex;
+
     #[cfg(feature = "exact")]
     pub use crate::{
         BigInt, BigRational, DeterminantSign, ExactF64Conversion, FromPrimitive, Signed,
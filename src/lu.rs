@@ -7,6 +7,7 @@
 //! unconditional accuracy guarantee; see `REFERENCES.md` \[1-3, 11-12\] for
 //! stability analysis and standard algorithmic background.
 
+use core::cmp::Ordering;
 use core::hint::cold_path;
 
 use crate::matrix::Matrix;
@@ -72,6 +73,26 @@ impl<const D: usize> LuFactors<D> {
     const fn diag(&self, index: usize) -> f64 {
         self.storage[index][index]
     }
+
+    /// Reject the first diagonal entry of `U` whose magnitude is `<= tol`.
+    const fn check_pivots(&self, tol: Tolerance) -> Result<(), LaError> {
+        let tolerance = tol.get();
+        let mut k = 0;
+        while k < D {
+            let pivot_abs = self.diag(k).abs();
+            if pivot_abs <= tolerance {
+                cold_path();
+                return Err(LaError::singular_numerical(
+                    k,
+                    FactorizationKind::Lu,
+                    pivot_abs,
+                    tolerance,
+                ));
+            }
+            k += 1;
+        }
+        Ok(())
+    }
 }
 
 /// Source-row permutation and its determinant parity.
@@ -100,6 +121,38 @@ impl<const D: usize> RowPermutation<D> {
         }
     }
 
+    /// Validate caller-supplied source rows and derive their parity.
+    ///
+    /// Parity is recovered by sorting a copy with cycle-following swaps, so it
+    /// always agrees with the index mapping.
+    const fn try_from_source_rows(source_rows: [usize; D]) -> Result<Self, LaError> {
+        let mut seen = [false; D];
+        let mut index = 0;
+        while index < D {
+            let value = source_rows[index];
+            if value >= D || seen[value] {
+                return Err(LaError::invalid_permutation(index, value, D));
+            }
+            seen[value] = true;
+            index += 1;
+        }
+
+        let mut sorted = source_rows;
+        let mut odd = false;
+        let mut row = 0;
+        while row < D {
+            while sorted[row] != row {
+                let target = sorted[row];
+                sorted[row] = sorted[target];
+                sorted[target] = target;
+                odd = !odd;
+            }
+            row += 1;
+        }
+
+        Ok(Self { source_rows, odd })
+    }
+
     /// Apply one row swap and update parity atomically.
     const fn swap(&mut self, left: usize, right: usize) {
         if left != right {
@@ -110,6 +163,22 @@ impl<const D: usize> RowPermutation<D> {
         }
     }
 
+    /// Return whether every row holds its own source row.
+    ///
+    /// Computed on demand so factorization's row swaps carry no extra
+    /// bookkeeping; the `O(D)` scan is no more than the gather it can skip,
+    /// and it also recognizes swaps that cancel out.
+    const fn is_identity(&self) -> bool {
+        let mut row = 0;
+        while row < D {
+            if self.source_rows[row] != row {
+                return false;
+            }
+            row += 1;
+        }
+        true
+    }
+
     /// Return the original source row now occupying `row`.
     const fn source_row(&self, row: usize) -> usize {
         self.source_rows[row]
@@ -132,6 +201,23 @@ impl<const D: usize> Lu<D> {
     /// value produced during elimination.
     #[inline]
     pub(crate) fn factor_finite(a: Matrix<D>, tol: Tolerance) -> Result<Self, LaError> {
+        Self::factor::<true>(a, tol)
+    }
+
+    /// Factor a finite square matrix without row exchanges for
+    /// [`Matrix::lu_no_pivot`].
+    ///
+    /// Identical to [`factor_finite`](Self::factor_finite) except that the
+    /// diagonal entry is always the pivot, so the permutation stays the
+    /// identity.
+    #[inline]
+    pub(crate) fn factor_finite_no_pivot(a: Matrix<D>, tol: Tolerance) -> Result<Self, LaError> {
+        Self::factor::<false>(a, tol)
+    }
+
+    /// Shared elimination loop; `PIVOT` selects partial pivoting.
+    #[inline]
+    fn factor<const PIVOT: bool>(a: Matrix<D>, tol: Tolerance) -> Result<Self, LaError> {
         let mut rows = a.into_rows();
         let tolerance = tol.get();
         let mut permutation = RowPermutation::identity();
@@ -144,15 +230,17 @@ impl<const D: usize> Lu<D> {
                 let mut pivot_row = k;
                 let mut pivot_abs = rows[k][k].abs();
 
-                #[expect(
-                    clippy::needless_range_loop,
-                    reason = "the row index identifies the pivot later used for synchronized matrix and permutation swaps"
-                )]
-                for r in (k + 1)..D {
-                    let v = rows[r][k].abs();
-                    if v > pivot_abs {
-                        pivot_abs = v;
-                        pivot_row = r;
+                if PIVOT {
+                    #[expect(
+                        clippy::needless_range_loop,
+                        reason = "the row index identifies the pivot later used for synchronized matrix and permutation swaps"
+                    )]
+                    for r in (k + 1)..D {
+                        let v = rows[r][k].abs();
+                        if v > pivot_abs {
+                            pivot_abs = v;
+                            pivot_row = r;
+                        }
                     }
                 }
 
@@ -214,12 +302,95 @@ impl<const D: usize> Lu<D> {
         })
     }
 
+    /// Reconstruct an LU factorization from packed factors and a row
+    /// permutation, for example ones cached from an earlier
+    /// [`Matrix::lu`] call or produced by another library.
+    ///
+    /// `factors` uses the packed layout returned by [`into_parts`](Self::into_parts):
+    /// entries strictly below the diagonal are the multipliers of the
+    /// unit-lower-triangular `L`, and entries on and above the diagonal are
+    /// `U`. `source_rows[i]` is the row of `A` that became row `i` of `P A`;
+    /// the permutation parity used by [`det`](Self::det) is derived from it.
+    ///
+    /// The diagonal of `U` is checked against `tol` exactly as
+    /// [`Matrix::lu`] checks its pivots, so every reconstructed factorization
+    /// satisfies the same invariants as a freshly computed one. The factors
+    /// themselves are not checked against any original matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]])?;
+    /// let (factors, source_rows) = a.lu(DEFAULT_SINGULAR_TOL)?.into_parts();
+    ///
+    /// let lu = Lu::from_parts(factors, source_rows, DEFAULT_SINGULAR_TOL)?;
+    /// let x = lu.solve(Vector::<2>::try_new([5.0, 11.0])?)?.into_array();
+    /// assert!((x[0] - 1.0).abs() <= 1e-12);
+    /// assert!((x[1] - 2.0).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::InvalidPermutation`] if `source_rows` contains an
+    /// entry `>= D` or repeats an entry.
+    /// Returns [`LaError::Singular`] if a diagonal entry of `U` has magnitude
+    /// `<= tol`.
+    #[inline]
+    pub fn from_parts(
+        factors: Matrix<D>,
+        source_rows: [usize; D],
+        tol: Tolerance,
+    ) -> Result<Self, LaError> {
+        let permutation = RowPermutation::try_from_source_rows(source_rows)?;
+        let factors = LuFactors {
+            storage: factors.into_rows(),
+        };
+        factors.check_pivots(tol)?;
+
+        Ok(Self {
+            factors,
+            permutation,
+        })
+    }
+
+    /// Split this factorization into packed factors and source rows.
+    ///
+    /// This is the inverse of [`from_parts`](Self::from_parts); see it for the
+    /// packed layout.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]])?;
+    /// let (factors, source_rows) = a.lu(DEFAULT_SINGULAR_TOL)?.into_parts();
+    ///
+    /// // Partial pivoting moved row 1 (largest |a[r][0]|) to the top.
+    /// assert_eq!(source_rows, [1, 0]);
+    /// assert_eq!(factors.get(0, 0), Some(3.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn into_parts(self) -> (Matrix<D>, [usize; D]) {
+        (
+            Matrix::from_rows_unchecked(self.factors.storage),
+            self.permutation.source_rows,
+        )
+    }
+
     /// Solve `A x = b` using this LU factorization.
     ///
     /// [`Vector`] is finite by construction, so this method only checks computed
     /// substitution overflows. It performs floating-point forward/back
     /// substitution and does not provide a certified absolute rounding-error
-    /// bound for the returned solution.
+    /// bound for the returned solution. When factorization needed no row
+    /// swaps, as for diagonally dominant inputs, `b` is read in place instead
+    /// of through the permutation.
     ///
     /// # Examples
     /// ```
@@ -248,9 +419,13 @@ impl<const D: usize> Lu<D> {
         let mut i = 0;
 
         if D <= 4 {
-            while i < D {
-                x[i] = b[self.permutation.source_row(i)];
-                i += 1;
+            if self.permutation.is_identity() {
+                x = *b;
+            } else {
+                while i < D {
+                    x[i] = b[self.permutation.source_row(i)];
+                    i += 1;
+                }
             }
 
             // Tiny matrices benchmark better when pivoted RHS materialization
@@ -277,8 +452,13 @@ impl<const D: usize> Lu<D> {
         } else {
             // Larger fixed dimensions avoid an extra pass by reading the
             // pivoted right-hand side directly into forward substitution.
+            let identity = self.permutation.is_identity();
             while i < D {
-                let mut sum = b[self.permutation.source_row(i)];
+                let mut sum = if identity {
+                    b[i]
+                } else {
+                    b[self.permutation.source_row(i)]
+                };
                 let row = self.factors.row(i);
                 let mut j = 0;
                 while j < i {
@@ -333,6 +513,70 @@ impl<const D: usize> Lu<D> {
         Vector::from_computation(x, ArithmeticOperation::LuSolve)
     }
 
+    /// Solve `A x = b` and report the relative residual `‖b − A x‖₂ / ‖b‖₂`.
+    ///
+    /// `a` must be the matrix this factorization was computed from; it is
+    /// needed to form `A x`, which the factors alone do not reproduce exactly.
+    /// The residual measures backward quality: a backward-stable solve gives a
+    /// value near machine epsilon even when `x` itself is inaccurate because
+    /// `A` is ill-conditioned. Both norms are computed with max-abs scaling, so
+    /// they cannot overflow or underflow. A zero right-hand side gives
+    /// `x = 0` and a residual of `0.0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[4.0, 1.0], [2.0, 3.0]])?;
+    /// let lu = a.lu(DEFAULT_SINGULAR_TOL)?;
+    ///
+    /// let b = Vector::<2>::try_new([9.0, 13.0])?;
+    /// let (x, residual) = lu.solve_with_residual(&a, b)?;
+    ///
+    /// let [x0, x1] = x.into_array();
+    /// assert!((x0 - 1.4).abs() <= 1e-12 && (x1 - 3.4).abs() <= 1e-12);
+    /// assert!(residual <= 1e-15);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] if the substitution overflows (as in
+    /// [`solve`](Self::solve)), if forming `A x` overflows, or if the residual
+    /// ratio itself is not representable.
+    #[inline]
+    pub fn solve_with_residual(
+        &self,
+        a: &Matrix<D>,
+        b: Vector<D>,
+    ) -> Result<(Vector<D>, f64), LaError> {
+        let x = self.solve(b)?;
+        let ax = a.mul_vec(&x)?;
+
+        let mut residual = [0.0; D];
+        for ((r, &b_i), &ax_i) in residual.iter_mut().zip(b.as_array()).zip(ax.as_array()) {
+            *r = b_i - ax_i;
+        }
+        let (r_max, r_norm) =
+            Vector::from_computation(residual, ArithmeticOperation::LuResidual)?.scaled_norm2();
+        if r_max == 0.0 {
+            return Ok((x, 0.0));
+        }
+        // A nonzero residual implies a nonzero right-hand side, since `b = 0`
+        // solves to `x = 0` exactly.
+        let (b_max, b_norm) = b.scaled_norm2();
+        let ratio = (r_max / b_max) * (r_norm / b_norm);
+        if ratio.is_finite() {
+            Ok((x, ratio))
+        } else {
+            cold_path();
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::LuResidual,
+            ))
+        }
+    }
+
     /// Determinant of the original matrix.
     ///
     /// # Examples
@@ -378,6 +622,169 @@ impl<const D: usize> Lu<D> {
         Ok(det)
     }
 
+    /// Determinant as `(mantissa, exponent)` with `det = mantissa · 2^exponent`,
+    /// immune to overflow and underflow.
+    ///
+    /// Like C's `frexp`, but with `|mantissa|` in `[1, 2)` and the sign of the
+    /// determinant carried by `mantissa`. The pivots are multiplied as
+    /// normalized mantissa/exponent pairs, so determinants far outside the
+    /// binary64 range, where [`det`](Self::det) reports
+    /// [`LaError::NonFinite`] or rounds to zero, stay representable; only the
+    /// mantissa products are rounded. Every stored pivot is non-zero, since
+    /// factorization and [`from_parts`](Self::from_parts) reject `|p| ≤ tol`
+    /// even for a zero tolerance, so the mantissa is never zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[1e300, 0.0], [0.0, -1e300]])?;
+    /// let lu = a.lu(DEFAULT_SINGULAR_TOL)?;
+    /// assert!(lu.det().is_err());
+    ///
+    /// let (mantissa, exponent) = lu.det_scaled();
+    /// assert!((-2.0..=-1.0).contains(&mantissa));
+    /// assert_eq!(exponent, 1993); // 1e600 ≈ 1.11 · 2^1993
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "each pivot contributes at most 1075 to the exponent, so stack-sized D fits i32"
+    )]
+    pub const fn det_scaled(&self) -> (f64, i32) {
+        let mut product = ScaledProduct::new(self.permutation.is_odd());
+        let mut i = 0;
+        while i < D {
+            product.multiply(self.factors.diag(i));
+            i += 1;
+        }
+        let (mantissa, exponent) = product.finish_scaled();
+        (mantissa, exponent as i32)
+    }
+
+    /// Determinant of the original matrix, only if every pivot exceeds `tol`.
+    ///
+    /// A factorization built with a small tolerance can carry pivots that are
+    /// rounding noise; their product is numerically meaningless even though
+    /// [`det`](Self::det) returns it. This method re-checks every `|U[i][i]|`
+    /// against `tol` and returns the determinant only when all of them pass.
+    /// A `tol` no larger than the factorization tolerance always passes.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::assert_matches;
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Rank-deficient up to one unit in the last place.
+    /// let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [2.0, 4.000_000_000_000_001]])?;
+    /// let lu = a.lu(Tolerance::try_new(0.0)?)?;
+    ///
+    /// assert!(lu.det()?.abs() < 1e-14);
+    /// assert_matches!(lu.try_det(DEFAULT_SINGULAR_TOL), Err(LaError::Singular { .. }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::Singular`] for the first pivot column whose
+    /// `|U[i][i]| <= tol`.
+    /// Returns [`LaError::NonFinite`] under the same conditions as
+    /// [`det`](Self::det).
+    #[inline]
+    pub const fn try_det(&self, tol: Tolerance) -> Result<f64, LaError> {
+        if let Err(err) = self.factors.check_pivots(tol) {
+            return Err(err);
+        }
+        self.det()
+    }
+
+    /// Pivot growth factor `max|U[i][j]| / max|A[i][j]|`.
+    ///
+    /// `a` must be the matrix this factorization was computed from. Partial
+    /// pivoting bounds the multipliers in `L` by one, but `U` can still grow
+    /// by up to `2^(D-1)` (`REFERENCES.md` \[11\], Section 9.4); the backward
+    /// error of the solve scales with this factor, so a large value signals
+    /// potential instability even though every pivot passed the tolerance.
+    /// Values near one are typical. `Lu<0>` returns `1.0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Wilkinson's example: the last column of U doubles at every step.
+    /// let a = Matrix::<3>::try_from_rows([
+    ///     [1.0, 0.0, 1.0],
+    ///     [-1.0, 1.0, 1.0],
+    ///     [-1.0, -1.0, 1.0],
+    /// ])?;
+    /// let lu = a.lu(DEFAULT_SINGULAR_TOL)?;
+    /// assert_eq!(lu.growth_factor(&a)?, 4.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] if the ratio overflows, for example when
+    /// `a` is the zero matrix and so cannot be the factored matrix.
+    #[inline]
+    pub const fn growth_factor(&self, a: &Matrix<D>) -> Result<f64, LaError> {
+        if D == 0 {
+            return Ok(1.0);
+        }
+        let a = a.into_rows();
+        let mut max_u: f64 = 0.0;
+        let mut max_a: f64 = 0.0;
+        let mut i = 0;
+        while i < D {
+            let row = self.factors.row(i);
+            let mut j = 0;
+            while j < D {
+                if j >= i {
+                    max_u = max_u.max(row[j].abs());
+                }
+                max_a = max_a.max(a[i][j].abs());
+                j += 1;
+            }
+            i += 1;
+        }
+        let ratio = max_u / max_a;
+        if ratio.is_finite() {
+            Ok(ratio)
+        } else {
+            cold_path();
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::PivotGrowthFactor,
+            ))
+        }
+    }
+
+    /// Sign of the determinant from the permutation parity and the signs of
+    /// the `U` pivots, without forming their product.
+    ///
+    /// Factorization rejects pivots with `|U[i][i]| <= tol` for a
+    /// non-negative `tol`, so every stored pivot is nonzero.
+    pub(crate) const fn det_sign(&self) -> Ordering {
+        let mut negative = self.permutation.is_odd();
+        let mut i = 0;
+        while i < D {
+            if self.factors.diag(i).is_sign_negative() {
+                negative = !negative;
+            }
+            i += 1;
+        }
+        if negative {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    }
+
     /// Recompute the determinant with normalized mantissa/exponent scaling.
     #[cold]
     const fn scaled_det(&self) -> Result<f64, LaError> {
@@ -397,17 +804,70 @@ impl<const D: usize> Lu<D> {
             ))
         }
     }
+
+    /// Solve `A X = B` for a matrix of right-hand sides.
+    ///
+    /// Each column of `b` is solved with [`solve`](Self::solve), so column `j`
+    /// of the result is bitwise identical to `solve` on column `j` of `b`.
+    /// This computes `A⁻¹ B` without materializing the inverse; `b = I` gives
+    /// the inverse itself.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[2.0, 0.0], [0.0, 4.0]])?;
+    /// let lu = a.lu(DEFAULT_SINGULAR_TOL)?;
+    ///
+    /// let b = Matrix::<2>::try_from_rows([[2.0, 4.0], [8.0, 1.0]])?;
+    /// let x = lu.solve_matrix(&b)?;
+    /// assert_eq!(x, Matrix::<2>::try_from_rows([[1.0, 2.0], [2.0, 0.25]])?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] from the first column whose
+    /// substitution overflows, as reported by [`solve`](Self::solve).
+    #[inline]
+    pub fn solve_matrix(&self, b: &Matrix<D>) -> Result<Matrix<D>, LaError> {
+        let mut rows = [[0.0; D]; D];
+        for (col, column) in b.transpose().into_rows().into_iter().enumerate() {
+            let x = self.solve(Vector::from_computation(
+                column,
+                ArithmeticOperation::LuSolve,
+            )?)?;
+            for (row, value) in rows.iter_mut().zip(x.into_array()) {
+                row[col] = value;
+            }
+        }
+        Matrix::from_computation(rows, ArithmeticOperation::LuSolve)
+    }
+
+    /// Inverse of the original matrix, solved one basis column at a time.
+    pub(crate) fn inverse(&self) -> Result<Matrix<D>, LaError> {
+        let mut rows = [[0.0; D]; D];
+        for col in 0..D {
+            let x = self.solve(Vector::basis(col))?;
+            for (row, value) in rows.iter_mut().zip(x.into_array()) {
+                row[col] = value;
+            }
+        }
+        Matrix::from_computation(rows, ArithmeticOperation::MatrixInverse)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use core::assert_matches;
     use core::hint::black_box;
 
-    use approx::assert_abs_diff_eq;
+    use approx::{assert_abs_diff_eq, assert_relative_eq};
     use pastey::paste;
 
     use super::*;
-    use crate::DEFAULT_SINGULAR_TOL;
+    use crate::{DEFAULT_SINGULAR_TOL, SingularityReason};
 
     const TWO_NEG_800: f64 = f64::from_bits(223_u64 << 52);
     const TWO_POS_800: f64 = f64::from_bits(1823_u64 << 52);
@@ -434,8 +894,80 @@ mod tests {
             [3, 2, 1, 0]
         );
         assert!(!permutation.is_odd());
+        assert!(!permutation.is_identity());
+
+        // Undoing both swaps restores the identity, not just even parity.
+        permutation.swap(0, 3);
+        assert!(!permutation.is_identity());
+        permutation.swap(2, 1);
+        assert!(permutation.is_identity());
+        assert_eq!(permutation, RowPermutation::identity());
+        assert_eq!(
+            RowPermutation::try_from_source_rows([0, 1, 2, 3]),
+            Ok(permutation)
+        );
+        assert!(
+            !RowPermutation::try_from_source_rows([1, 0, 2, 3])
+                .unwrap()
+                .is_identity()
+        );
     }
 
+    macro_rules! gen_identity_permutation_solve_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<lu_solve_identity_permutation_fast_path_matches_gather_ $d d>]() {
+                    // Diagonal dominance keeps every pivot in place.
+                    let mut rows = [[0.0f64; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            *entry = if r == c {
+                                f64::from($d) + 1.0
+                            } else {
+                                0.1 / f64::from(u32::try_from(r + c + 1).unwrap())
+                            };
+                        }
+                    }
+                    let lu = Matrix::<$d>::try_from_rows(rows)
+                        .unwrap()
+                        .lu(DEFAULT_SINGULAR_TOL)
+                        .unwrap();
+                    assert!(lu.permutation.is_identity());
+
+                    let b = Vector::<$d>::new(core::array::from_fn(|i| {
+                        f64::from(u32::try_from(i).unwrap()) - 1.5
+                    }));
+
+                    // Reference substitution that always gathers through the
+                    // permutation, in the same operation order as `solve`.
+                    let mut x: [f64; $d] =
+                        core::array::from_fn(|i| b.as_array()[lu.permutation.source_row(i)]);
+                    for i in 0..$d {
+                        let row = lu.factors.row(i);
+                        for j in 0..i {
+                            x[i] = (-row[j]).mul_add(x[j], x[i]);
+                        }
+                    }
+                    for i in (0..$d).rev() {
+                        let row = lu.factors.row(i);
+                        for j in (i + 1)..$d {
+                            x[i] = (-row[j]).mul_add(x[j], x[i]);
+                        }
+                        x[i] /= row[i];
+                    }
+                    assert_eq!(lu.solve(b).unwrap().into_array().map(f64::to_bits), x.map(f64::to_bits));
+                }
+            }
+        };
+    }
+
+    gen_identity_permutation_solve_tests!(2);
+    gen_identity_permutation_solve_tests!(3);
+    gen_identity_permutation_solve_tests!(4);
+    gen_identity_permutation_solve_tests!(5);
+    gen_identity_permutation_solve_tests!(8);
+
     macro_rules! gen_pivoting_solve_and_det_tests {
         ($d:literal) => {
             paste! {
@@ -673,6 +1205,172 @@ mod tests {
         );
     }
 
+    macro_rules! gen_solve_matrix_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<solve_matrix_matches_column_solves_and_inverse_ $d d>]() {
+                    let a = Matrix::<$d>::try_from_rows(core::array::from_fn(|r| {
+                        core::array::from_fn(|c| {
+                            if r == c {
+                                f64::from($d + 1)
+                            } else {
+                                f64::from(u32::try_from(r + 2 * c).unwrap()) / 8.0 - 0.5
+                            }
+                        })
+                    }))
+                    .unwrap();
+                    let lu = a.lu(DEFAULT_SINGULAR_TOL).unwrap();
+
+                    let inverse = lu.solve_matrix(&Matrix::<$d>::identity()).unwrap();
+                    assert_eq!(inverse, lu.inverse().unwrap());
+
+                    let b = Matrix::<$d>::try_from_rows(core::array::from_fn(|r| {
+                        core::array::from_fn(|c| f64::from(u32::try_from(r * $d + c).unwrap()) - 3.0)
+                    }))
+                    .unwrap();
+                    let x = lu.solve_matrix(&b).unwrap();
+                    let x_rows = x.into_rows();
+                    let b_rows = b.into_rows();
+                    for col in 0..$d {
+                        let column = Vector::<$d>::new(core::array::from_fn(|r| b_rows[r][col]));
+                        let expected = lu.solve(column).unwrap().into_array();
+                        for r in 0..$d {
+                            assert_eq!(x_rows[r][col].to_bits(), expected[r].to_bits());
+                        }
+                    }
+                    let ax = a.matmul(&x, ArithmeticOperation::MatrixProduct).unwrap().into_rows();
+                    for r in 0..$d {
+                        for c in 0..$d {
+                            assert_abs_diff_eq!(ax[r][c], b_rows[r][c], epsilon = 1e-12);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    gen_solve_matrix_tests!(2);
+    gen_solve_matrix_tests!(3);
+    gen_solve_matrix_tests!(4);
+    gen_solve_matrix_tests!(5);
+
+    #[test]
+    fn solve_matrix_reports_substitution_overflow() {
+        let a = Matrix::<2>::try_from_rows([[1.0, 0.0], [-1.0, 1.0]]).unwrap();
+        let lu = a.lu(DEFAULT_SINGULAR_TOL).unwrap();
+        let b = Matrix::<2>::try_from_rows([[1.0, 1e308], [1.0, 1e308]]).unwrap();
+        assert_eq!(
+            lu.solve_matrix(&b),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::LuSolve,
+                1
+            ))
+        );
+    }
+
+    macro_rules! gen_growth_factor_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<growth_factor_of_wilkinson_matrix_doubles_per_column_ $d d>]() {
+                    // 1 on the diagonal, -1 below it, and 1 in the last column:
+                    // partial pivoting never swaps, and U[i][D-1] = 2^i.
+                    let a = Matrix::<$d>::try_from_rows(core::array::from_fn(|r| {
+                        core::array::from_fn(|c| {
+                            if c == $d - 1 || r == c {
+                                1.0
+                            } else if r > c {
+                                -1.0
+                            } else {
+                                0.0
+                            }
+                        })
+                    }))
+                    .unwrap();
+                    let lu = a.lu(DEFAULT_SINGULAR_TOL).unwrap();
+                    assert_eq!(lu.growth_factor(&a), Ok(f64::from(1_u32 << ($d - 1))));
+
+                    let identity = Matrix::<$d>::identity();
+                    let lu = identity.lu(DEFAULT_SINGULAR_TOL).unwrap();
+                    assert_eq!(lu.growth_factor(&identity), Ok(1.0));
+                    // Scaling A scales U by the same factor.
+                    let scaled = Matrix::<$d>::try_from_rows(core::array::from_fn(|r| {
+                        core::array::from_fn(|c| if r == c { 0.25 } else { 0.0 })
+                    }))
+                    .unwrap();
+                    let lu = scaled.lu(DEFAULT_SINGULAR_TOL).unwrap();
+                    assert_eq!(lu.growth_factor(&scaled), Ok(1.0));
+                }
+            }
+        };
+    }
+
+    gen_growth_factor_tests!(2);
+    gen_growth_factor_tests!(3);
+    gen_growth_factor_tests!(4);
+    gen_growth_factor_tests!(5);
+
+    #[test]
+    fn growth_factor_handles_empty_and_rejects_zero_source() {
+        let empty = Matrix::<0>::zero();
+        assert_eq!(
+            empty
+                .lu(DEFAULT_SINGULAR_TOL)
+                .unwrap()
+                .growth_factor(&empty),
+            Ok(1.0)
+        );
+        let lu = Matrix::<2>::identity().lu(DEFAULT_SINGULAR_TOL).unwrap();
+        assert_eq!(
+            lu.growth_factor(&Matrix::zero()),
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::PivotGrowthFactor
+            ))
+        );
+    }
+
+    macro_rules! gen_zero_column_singular_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<zero_column_reports_its_pivot_col_ $d d>]() {
+                    for zero_col in 0..$d {
+                        // Diagonally dominant, so every earlier pivot is accepted and
+                        // elimination keeps the zeroed column exactly zero.
+                        let rows: [[f64; $d]; $d] = core::array::from_fn(|r| {
+                            core::array::from_fn(|c| {
+                                if c == zero_col {
+                                    0.0
+                                } else if r == c {
+                                    f64::from($d + 1)
+                                } else if (r + c).is_multiple_of(2) {
+                                    1.0
+                                } else {
+                                    -1.0
+                                }
+                            })
+                        });
+                        let expected = LaError::singular_numerical(
+                            zero_col,
+                            FactorizationKind::Lu,
+                            0.0,
+                            DEFAULT_SINGULAR_TOL.get(),
+                        );
+                        let a = Matrix::<$d>::try_from_rows(black_box(rows)).unwrap();
+                        assert_eq!(a.lu(DEFAULT_SINGULAR_TOL).unwrap_err(), expected);
+                        assert_eq!(a.lu_no_pivot(DEFAULT_SINGULAR_TOL).unwrap_err(), expected);
+                    }
+                }
+            }
+        };
+    }
+
+    gen_zero_column_singular_tests!(2);
+    gen_zero_column_singular_tests!(3);
+    gen_zero_column_singular_tests!(4);
+    gen_zero_column_singular_tests!(5);
+
     #[test]
     fn singular_due_to_tolerance_at_first_pivot() {
         // Not exactly singular, but below DEFAULT_SINGULAR_TOL.
@@ -867,6 +1565,63 @@ mod tests {
         assert_eq!(negative_det.to_bits(), (-0.0f64).to_bits());
     }
 
+    #[test]
+    fn det_scaled_matches_det_and_survives_range_loss() {
+        let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]]).unwrap();
+        let lu = a.lu(DEFAULT_SINGULAR_TOL).unwrap();
+        let (mantissa, exponent) = lu.det_scaled();
+        assert_eq!(
+            (mantissa * 2.0_f64.powi(exponent)).to_bits(),
+            lu.det().unwrap().to_bits()
+        );
+        assert_eq!(exponent, 1);
+
+        // Each 1e100 pivot is about 2^332.19, so the product is about 2^1660.96.
+        let mut rows = [[0.0; 5]; 5];
+        for (i, row) in rows.iter_mut().enumerate() {
+            row[i] = 1.0e100;
+        }
+        let lu = Matrix::<5>::try_from_rows(rows)
+            .unwrap()
+            .lu(DEFAULT_SINGULAR_TOL)
+            .unwrap();
+        assert!(lu.det().is_err());
+        let (mantissa, exponent) = lu.det_scaled();
+        assert!((1.0..2.0).contains(&mantissa));
+        assert_eq!(exponent, 1660);
+
+        let zero_tolerance = Tolerance::try_new(0.0).unwrap();
+        let tiny = Matrix::<2>::try_from_rows([[-TWO_NEG_800, 0.0], [0.0, TWO_NEG_800]])
+            .unwrap()
+            .lu(zero_tolerance)
+            .unwrap();
+        assert_eq!(tiny.det().unwrap().to_bits(), (-0.0f64).to_bits());
+        assert_eq!(tiny.det_scaled(), (-1.0, -1600));
+    }
+
+    #[test]
+    fn det_scaled_handles_large_dimension_diagonals() {
+        // 40 diagonal entries of 1e10: det() overflows at 1e400.
+        let mut rows = [[0.0; 40]; 40];
+        for (i, row) in rows.iter_mut().enumerate() {
+            row[i] = 1.0e10;
+        }
+        let lu = Matrix::<40>::try_from_rows(rows)
+            .unwrap()
+            .lu(DEFAULT_SINGULAR_TOL)
+            .unwrap();
+        assert_matches!(lu.det(), Err(LaError::NonFinite { .. }));
+        let (mantissa, exponent) = lu.det_scaled();
+        assert!((1.0..2.0).contains(&mantissa));
+        // log2(1e400) ≈ 1328.77.
+        assert_eq!(exponent, 1328);
+        assert_relative_eq!(
+            mantissa.log2() + f64::from(exponent),
+            400.0 * 10.0_f64.log2(),
+            max_relative = 1e-14
+        );
+    }
+
     // -----------------------------------------------------------------------
     // Const-evaluability tests.
     //
@@ -931,4 +1686,251 @@ mod tests {
         assert!((x[0] - 1.0).abs() <= 1e-12);
         assert!((x[1] - 2.0).abs() <= 1e-12);
     }
+
+    #[test]
+    fn row_permutation_from_source_rows_derives_parity() {
+        let identity = RowPermutation::<3>::try_from_source_rows([0, 1, 2]).unwrap();
+        assert!(!identity.is_odd());
+
+        let transposition = RowPermutation::<3>::try_from_source_rows([2, 1, 0]).unwrap();
+        assert!(transposition.is_odd());
+
+        let three_cycle = RowPermutation::<3>::try_from_source_rows([1, 2, 0]).unwrap();
+        assert!(!three_cycle.is_odd());
+        assert_eq!(three_cycle.source_row(0), 1);
+    }
+
+    #[test]
+    fn row_permutation_from_source_rows_rejects_non_permutations() {
+        assert_eq!(
+            RowPermutation::<3>::try_from_source_rows([0, 3, 1]),
+            Err(LaError::invalid_permutation(1, 3, 3))
+        );
+        assert_eq!(
+            RowPermutation::<3>::try_from_source_rows([2, 1, 2]),
+            Err(LaError::invalid_permutation(2, 2, 3))
+        );
+    }
+
+    macro_rules! gen_from_parts_round_trip_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<from_parts_round_trip_solves_and_dets_ $d d>]() {
+                    // Strictly diagonally dominant with reversed rows so
+                    // partial pivoting records a non-trivial permutation.
+                    let mut rows = [[0.0f64; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            *entry = if r + c == $d - 1 { 10.0 } else { 1.0 };
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let lu = a.lu(DEFAULT_SINGULAR_TOL).unwrap();
+
+                    let (factors, source_rows) = lu.into_parts();
+                    let rebuilt = Lu::from_parts(factors, source_rows, DEFAULT_SINGULAR_TOL).unwrap();
+                    assert_eq!(rebuilt, lu);
+
+                    let b = Vector::<$d>::new(black_box([1.0; $d]));
+                    assert_eq!(rebuilt.solve(b), lu.solve(b));
+                    assert_eq!(rebuilt.det(), lu.det());
+                }
+            }
+        };
+    }
+
+    gen_from_parts_round_trip_tests!(2);
+    gen_from_parts_round_trip_tests!(3);
+    gen_from_parts_round_trip_tests!(4);
+    gen_from_parts_round_trip_tests!(5);
+
+    #[test]
+    fn from_parts_accepts_external_factors() {
+        // P A = L U with A = [[0, 2], [4, 6]], P swapping the rows,
+        // L = [[1, 0], [0, 1]], U = [[4, 6], [0, 2]].
+        let factors = Matrix::<2>::try_from_rows([[4.0, 6.0], [0.0, 2.0]]).unwrap();
+        let lu = Lu::from_parts(factors, [1, 0], DEFAULT_SINGULAR_TOL).unwrap();
+
+        assert_abs_diff_eq!(lu.det().unwrap(), -8.0, epsilon = 0.0);
+        let x = lu
+            .solve(Vector::<2>::new([2.0, 10.0]))
+            .unwrap()
+            .into_array();
+        assert_abs_diff_eq!(x[0], 1.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(x[1], 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn from_parts_rejects_invalid_permutation_and_small_pivots() {
+        let factors = Matrix::<2>::try_from_rows([[4.0, 6.0], [0.0, 1e-13]]).unwrap();
+        assert_eq!(
+            Lu::from_parts(factors, [1, 1], DEFAULT_SINGULAR_TOL),
+            Err(LaError::invalid_permutation(1, 1, 2))
+        );
+        assert_eq!(
+            Lu::from_parts(factors, [1, 0], DEFAULT_SINGULAR_TOL),
+            Err(LaError::singular_numerical(
+                1,
+                FactorizationKind::Lu,
+                1e-13,
+                DEFAULT_SINGULAR_TOL.get()
+            ))
+        );
+    }
+
+    #[test]
+    fn try_det_rejects_noise_pivots_of_singular_by_construction_matrix() {
+        // Row 1 is twice row 0 up to one ulp in the last entry.
+        let a = Matrix::<3>::try_from_rows([
+            [1.0, 2.0, 3.0],
+            [2.0, 4.0, 6.000_000_000_000_001],
+            [0.0, 1.0, 1.0],
+        ])
+        .unwrap();
+        let lu = a.lu(Tolerance::ZERO).unwrap();
+
+        assert!(lu.det().unwrap().abs() < 1e-14);
+        assert_matches!(
+            lu.try_det(DEFAULT_SINGULAR_TOL),
+            Err(LaError::Singular {
+                pivot_col: 2,
+                reason: SingularityReason::Numerical { .. }
+            })
+        );
+    }
+
+    #[test]
+    fn try_det_matches_det_when_pivots_pass() {
+        let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]]).unwrap();
+        let lu = a.lu(DEFAULT_SINGULAR_TOL).unwrap();
+
+        assert_eq!(lu.try_det(DEFAULT_SINGULAR_TOL), lu.det());
+        // U = [[3, 4], [0, 2/3]] after pivoting.
+        assert_eq!(lu.try_det(Tolerance::try_new(0.5).unwrap()), lu.det());
+        assert_eq!(
+            lu.try_det(Tolerance::try_new(1.0).unwrap()),
+            Err(LaError::singular_numerical(
+                1,
+                FactorizationKind::Lu,
+                lu.factors.diag(1).abs(),
+                1.0
+            ))
+        );
+    }
+
+    macro_rules! gen_solve_with_residual_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<solve_with_residual_is_tiny_for_well_conditioned_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            *entry = if r == c {
+                                4.0
+                            } else {
+                                1.0 / f64::from(u32::try_from(r + c + 2).unwrap())
+                            };
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let b = Vector::<$d>::try_new(core::array::from_fn(|i| {
+                        f64::from(u32::try_from(i).unwrap()) - 1.5
+                    }))
+                    .unwrap();
+                    let lu = a.lu(DEFAULT_SINGULAR_TOL).unwrap();
+
+                    let (x, residual) = lu.solve_with_residual(&a, b).unwrap();
+                    assert_eq!(x, lu.solve(b).unwrap());
+                    assert!(residual <= 1e-15, "residual = {residual}");
+
+                    let (zero, residual) = lu.solve_with_residual(&a, Vector::zero()).unwrap();
+                    assert_eq!(zero, Vector::zero());
+                    assert_eq!(residual.to_bits(), 0.0_f64.to_bits());
+                }
+            }
+        };
+    }
+
+    gen_solve_with_residual_tests!(2);
+    gen_solve_with_residual_tests!(3);
+    gen_solve_with_residual_tests!(4);
+    gen_solve_with_residual_tests!(5);
+
+    #[test]
+    fn solve_with_residual_measures_against_given_matrix() {
+        let a = Matrix::<2>::try_from_rows([[2.0, 0.0], [0.0, 4.0]]).unwrap();
+        let lu = a.lu(DEFAULT_SINGULAR_TOL).unwrap();
+        let b = Vector::<2>::try_new([3.0e-300, 4.0e-300]).unwrap();
+
+        // x = [1.5e-300, 1e-300]; against 2A the residual is b − 2b = −b.
+        let doubled = Matrix::<2>::try_from_rows([[4.0, 0.0], [0.0, 8.0]]).unwrap();
+        let (_, residual) = lu.solve_with_residual(&doubled, b).unwrap();
+        assert_abs_diff_eq!(residual, 1.0, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn solve_with_residual_reports_unrepresentable_ratio() {
+        // x = 1e-290, so against [[1e300]] the residual is ≈ −1e10 and the
+        // ratio to ‖b‖ = 1e-300 overflows.
+        let a = Matrix::<1>::try_from_rows([[1e-10]]).unwrap();
+        let lu = a.lu(Tolerance::ZERO).unwrap();
+        let b = Vector::<1>::try_new([1e-300]).unwrap();
+        let huge = Matrix::<1>::try_from_rows([[1e300]]).unwrap();
+        assert_eq!(
+            lu.solve_with_residual(&huge, b),
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::LuResidual
+            ))
+        );
+    }
+
+    #[test]
+    fn lu_no_pivot_keeps_identity_permutation_where_pivoting_swaps() {
+        // Row-dominant, but partial pivoting picks 2.0 over 1.0 in column 0.
+        let a = Matrix::<2>::try_from_rows([[1.0, 0.5], [2.0, 5.0]]).unwrap();
+        let pivoted = a.lu(DEFAULT_SINGULAR_TOL).unwrap();
+        let unpivoted = a.lu_no_pivot(DEFAULT_SINGULAR_TOL).unwrap();
+        assert_eq!(pivoted.into_parts().1, [1, 0]);
+
+        let (factors, source_rows) = unpivoted.into_parts();
+        assert_eq!(source_rows, [0, 1]);
+        assert_eq!(
+            factors,
+            Matrix::<2>::try_from_rows([[1.0, 0.5], [2.0, 4.0]]).unwrap()
+        );
+        assert_eq!(unpivoted.det(), Ok(4.0));
+        assert_abs_diff_eq!(pivoted.det().unwrap(), 4.0, epsilon = 1e-15);
+
+        let b = Vector::<2>::try_new([2.0, 8.0]).unwrap();
+        assert_eq!(
+            unpivoted.solve(b),
+            Ok(Vector::<2>::try_new([1.5, 1.0]).unwrap())
+        );
+    }
+
+    #[test]
+    fn lu_no_pivot_rejects_small_diagonal_pivot() {
+        let a = Matrix::<3>::try_from_rows([[1.0, 2.0, 0.0], [1.0, 2.0, 1.0], [0.0, 1.0, 1.0]])
+            .unwrap();
+        // Nonsingular (det = -1), but the second diagonal pivot eliminates to 0.
+        assert!(a.lu(DEFAULT_SINGULAR_TOL).is_ok());
+        assert_eq!(
+            a.lu_no_pivot(DEFAULT_SINGULAR_TOL),
+            Err(LaError::singular_numerical(
+                1,
+                FactorizationKind::Lu,
+                0.0,
+                DEFAULT_SINGULAR_TOL.get()
+            ))
+        );
+        assert_eq!(
+            Matrix::<0>::zero()
+                .lu_no_pivot(Tolerance::ZERO)
+                .unwrap()
+                .det(),
+            Ok(1.0)
+        );
+    }
 }
@@ -2,11 +2,17 @@
 
 //! Fixed-size, stack-allocated square matrices.
 
+use core::cmp::Ordering;
 use core::hint::cold_path;
 
 use crate::ldlt::Ldlt;
 use crate::lu::Lu;
-use crate::{ArithmeticOperation, ERR_COEFF_2, ERR_COEFF_3, ERR_COEFF_4, LaError, Tolerance};
+use crate::tri_lu::TriLu;
+use crate::vector::Vector;
+use crate::{
+    ArithmeticOperation, ERR_COEFF_2, ERR_COEFF_3, ERR_COEFF_4, FactorizationKind, LaError,
+    Tolerance,
+};
 
 /// A closed-form determinant and its certified absolute error bound.
 ///
@@ -220,9 +226,10 @@ impl<const D: usize> SymmetricMatrix<D> {
     ///
     /// This constructor is only for paths that have already validated exact
     /// mirrored-entry equality with the same predicate as
-    /// [`try_new`](Self::try_new). Finiteness is carried by [`Matrix`].
+    /// [`try_new`](Self::try_new), or that wrote each mirrored pair from a
+    /// single computed value. Finiteness is carried by [`Matrix`].
     #[inline]
-    const fn new_unchecked(matrix: Matrix<D>) -> Self {
+    pub(crate) const fn new_unchecked(matrix: Matrix<D>) -> Self {
         Self { matrix }
     }
 
@@ -239,7 +246,7 @@ impl<const D: usize> SymmetricMatrix<D> {
         clippy::float_cmp,
         reason = "LDLT requires exact mirrored-entry equality to factor the supplied operator"
     )]
-    fn try_new(matrix: Matrix<D>) -> Result<Self, LaError> {
+    pub(crate) fn try_new(matrix: Matrix<D>) -> Result<Self, LaError> {
         for row in 0..D {
             for col in (row + 1)..D {
                 let upper = matrix.rows[row][col];
@@ -256,6 +263,13 @@ impl<const D: usize> SymmetricMatrix<D> {
 }
 
 impl<const D: usize> Matrix<D> {
+    /// `D` as `f64`.
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "stack-allocated dimensions are far below 2^53 and convert exactly"
+    )]
+    const DIM_F64: f64 = D as f64;
+
     /// Try to create a finite matrix from row-major storage.
     ///
     /// This is the public raw-storage boundary for matrices. Successful
@@ -286,14 +300,31 @@ impl<const D: usize> Matrix<D> {
 
     /// Construct a matrix without checking that entries are finite.
     ///
-    /// This module-private escape hatch is reserved for finite literals and
+    /// This crate-private escape hatch is reserved for finite literals and
     /// algorithm outputs whose finite invariant is visible at the call site.
     /// Computed outputs must be validated before becoming observable API values.
     #[inline]
-    const fn from_rows_unchecked(rows: [[f64; D]; D]) -> Self {
+    pub(crate) const fn from_rows_unchecked(rows: [[f64; D]; D]) -> Self {
         Self { rows }
     }
 
+    /// Finalize matrix storage produced by an arithmetic operation.
+    ///
+    /// Keeping this validation in the type that owns the finite-storage
+    /// invariant prevents a new computation path from accidentally turning raw
+    /// non-finite storage into a [`Matrix`].
+    #[inline]
+    pub(crate) const fn from_computation(
+        rows: [[f64; D]; D],
+        operation: ArithmeticOperation,
+    ) -> Result<Self, LaError> {
+        if let Some((row, col)) = Self::first_non_finite_cell(&rows) {
+            Err(LaError::non_finite_computation_matrix(operation, row, col))
+        } else {
+            Ok(Self::from_rows_unchecked(rows))
+        }
+    }
+
     /// Borrow the finite row-major backing array.
     ///
     /// The returned view is tied to this [`Matrix`], so callers can inspect the
@@ -346,6 +377,65 @@ impl<const D: usize> Matrix<D> {
         self.rows
     }
 
+    /// Copy the entries into a heap-allocated, row-major `Vec` of length
+    /// `D²`.
+    ///
+    /// This bridges to dynamically sized APIs and serializers;
+    /// [`from_row_major_slice`](Self::from_row_major_slice) is the inverse.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let matrix = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]])?;
+    /// assert_eq!(matrix.to_row_major_vec(), vec![1.0, 2.0, 3.0, 4.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_row_major_vec(&self) -> Vec<f64> {
+        self.rows.as_flattened().to_vec()
+    }
+
+    /// Build a matrix from a flat row-major slice of length `D²`.
+    ///
+    /// Entry `(r, c)` is read from `values[r * D + c]`, matching
+    /// [`to_row_major_vec`](Self::to_row_major_vec).
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let matrix = Matrix::<2>::from_row_major_slice(&[1.0, 2.0, 3.0, 4.0])?;
+    /// assert_eq!(matrix.get(1, 0), Some(3.0));
+    ///
+    /// assert!(matches!(
+    ///     Matrix::<2>::from_row_major_slice(&[1.0, 2.0, 3.0]),
+    ///     Err(LaError::DimensionMismatch { expected: 4, actual: 3, .. })
+    /// ));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::DimensionMismatch`] if `values.len() != D * D`.
+    /// Returns [`LaError::NonFinite`] with matrix coordinates for the first
+    /// NaN or infinite entry in row-major order.
+    #[inline]
+    pub const fn from_row_major_slice(values: &[f64]) -> Result<Self, LaError> {
+        let mut rows = [[0.0; D]; D];
+        let flat = rows.as_flattened_mut();
+        if values.len() != flat.len() {
+            cold_path();
+            return Err(LaError::dimension_mismatch(flat.len(), values.len()));
+        }
+        flat.copy_from_slice(values);
+        Self::try_from_rows(rows)
+    }
+
     /// All-zeros finite matrix.
     ///
     /// # Examples
@@ -384,6 +474,171 @@ impl<const D: usize> Matrix<D> {
         m
     }
 
+    /// Test-only second-difference fixture `tridiag(-1, 2, -1)`, the 1D
+    /// Laplacian with eigenvalues `2 − 2cos(kπ / (D + 1))`.
+    #[cfg(test)]
+    #[inline]
+    pub(crate) const fn second_difference() -> Self {
+        let mut m = Self::zero();
+
+        let mut i = 0;
+        while i < D {
+            m.rows[i][i] = 2.0;
+            if i > 0 {
+                m.rows[i][i - 1] = -1.0;
+            }
+            if i + 1 < D {
+                m.rows[i][i + 1] = -1.0;
+            }
+            i += 1;
+        }
+
+        m
+    }
+
+    /// Circulant matrix whose first row is `first_row`.
+    ///
+    /// Each row is the previous row rotated one place to the right, so
+    /// `C[i][j] = c[(j − i) mod D]`. Circulant matrices are diagonalized by the
+    /// discrete Fourier transform and represent cyclic convolution:
+    /// `C x` correlates `c` with `x` cyclically; see
+    /// [`mul_vec_circulant`](Self::mul_vec_circulant).
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let c = Matrix::<3>::circulant(Vector::<3>::try_new([1.0, 2.0, 3.0])?);
+    /// let expected =
+    ///     Matrix::<3>::try_from_rows([[1.0, 2.0, 3.0], [3.0, 1.0, 2.0], [2.0, 3.0, 1.0]])?;
+    /// assert_eq!(c, expected);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn circulant(first_row: Vector<D>) -> Self {
+        let c = first_row.as_array();
+        let mut rows = [[0.0; D]; D];
+        let mut i = 0;
+        while i < D {
+            let mut j = 0;
+            while j < D {
+                rows[i][j] = c[(j + D - i) % D];
+                j += 1;
+            }
+            i += 1;
+        }
+        // Every entry is copied from a finite vector.
+        Self::from_rows_unchecked(rows)
+    }
+
+    /// Householder reflection `H = I − 2 v vᵀ / (vᵀ v)` across the hyperplane
+    /// orthogonal to `v`.
+    ///
+    /// `H` is symmetric and orthogonal, maps `v` to `−v`, and fixes every
+    /// vector orthogonal to `v`; it is the building block of Householder QR
+    /// \[11-12\]. `v` is first divided by its largest magnitude, which leaves
+    /// `H` unchanged but keeps `vᵀ v` in `[1, D]`, so no intermediate can
+    /// overflow or underflow. Entries are rounded; no certified error bound is
+    /// provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Reflection across the line y = x.
+    /// let h = Matrix::<2>::householder(Vector::<2>::try_new([1.0, -1.0])?)?;
+    ///
+    /// assert!(h.get(0, 0).is_some_and(|x| x.abs() <= 1e-15));
+    /// assert!(h.get(0, 1).is_some_and(|x| (x - 1.0).abs() <= 1e-15));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::ZeroVector`] if every entry of `v` is zero.
+    #[inline]
+    pub fn householder(v: Vector<D>) -> Result<Self, LaError> {
+        let v = v.into_array();
+        let max_abs = v.iter().fold(0.0_f64, |max, x| max.max(x.abs()));
+        if max_abs == 0.0 {
+            cold_path();
+            return Err(LaError::zero_vector(D));
+        }
+
+        let u = v.map(|x| x / max_abs);
+        let norm_sq = u.iter().fold(0.0, |acc, &x| x.mul_add(x, acc));
+        let factor = -2.0 / norm_sq;
+
+        let mut h = Self::identity();
+        for (row, &u_r) in h.rows.iter_mut().zip(&u) {
+            let scaled = factor * u_r;
+            for (entry, &u_c) in row.iter_mut().zip(&u) {
+                *entry = scaled.mul_add(u_c, *entry);
+            }
+        }
+        Ok(h)
+    }
+
+    /// Givens rotation by `theta` radians in the `(i, j)` coordinate plane.
+    ///
+    /// The result is the identity except for the block
+    ///
+    /// ```text
+    /// G[i][i] = cos θ    G[i][j] = −sin θ
+    /// G[j][i] = sin θ    G[j][j] =  cos θ
+    /// ```
+    ///
+    /// so it rotates `e_i` towards `e_j`, is orthogonal, and has determinant
+    /// one \[11-12\]. Choosing `θ` so that `G` zeroes a selected entry is the
+    /// elementary step of Givens QR and Jacobi eigenvalue sweeps. The sine and
+    /// cosine are rounded, so orthogonality holds to within a few ulps.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let g = Matrix::<3>::givens(0, 2, core::f64::consts::FRAC_PI_2)?;
+    ///
+    /// assert!(g.get(2, 0).is_some_and(|s| (s - 1.0).abs() <= 1e-15));
+    /// assert!(g.get(0, 2).is_some_and(|s| (s + 1.0).abs() <= 1e-15));
+    /// assert_eq!(g.get(1, 1), Some(1.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::IndexOutOfBounds`] if `i >= D` or `j >= D`.
+    /// Returns [`LaError::RepeatedIndex`] if `i == j`.
+    /// Returns [`LaError::NonFinite`] with scalar input location if `theta` is
+    /// NaN or infinite.
+    #[inline]
+    pub fn givens(i: usize, j: usize, theta: f64) -> Result<Self, LaError> {
+        if i >= D || j >= D {
+            cold_path();
+            return Err(LaError::index_out_of_bounds(i, j, D));
+        }
+        if i == j {
+            cold_path();
+            return Err(LaError::repeated_index(i));
+        }
+        if !theta.is_finite() {
+            cold_path();
+            return Err(LaError::non_finite_input_scalar());
+        }
+
+        let (sin, cos) = theta.sin_cos();
+        let mut g = Self::identity();
+        g.rows[i][i] = cos;
+        g.rows[i][j] = -sin;
+        g.rows[j][i] = sin;
+        g.rows[j][j] = cos;
+        Ok(g)
+    }
+
     /// Get a finite element with bounds checking.
     ///
     /// # Examples
@@ -578,2250 +833,11206 @@ impl<const D: usize> Matrix<D> {
         )
     }
 
-    /// Returns `true` if the matrix is approximately symmetric within a relative tolerance.
-    ///
-    /// Two entries `self[r][c]` and `self[c][r]` are considered equal (for the
-    /// purposes of symmetry) when
-    /// `|self[r][c] - self[c][r]| <= rel_tol * max(1.0, inf_norm(self))`.
-    /// This is a diagnostic predicate for applications that have an
-    /// approximation-specific symmetry threshold. It is not the precondition
-    /// used by [`ldlt`](Self::ldlt), which requires exact mirrored-entry
-    /// equality so the returned factors represent the original matrix.
-    ///
-    /// Use [`first_asymmetry`](Self::first_asymmetry) to locate the first
-    /// offending pair when this returns `Ok(false)`.
-    ///
-    /// The `rel_tol` argument is a [`Tolerance`], so raw caller input must be
-    /// finite and non-negative before it can reach this predicate. Use
-    /// [`Tolerance::try_new`] when accepting a raw `f64`; negative, NaN, and
-    /// infinite tolerances return
-    /// [`LaError::InvalidTolerance`].
+    /// Shift the diagonal by a scalar: `A + c I`.
     ///
-    /// # Overflow handling
-    /// A finite matrix can return [`LaError::NonFinite`] with matrix coordinates
-    /// if computing the scaled symmetry tolerance overflows to NaN or infinity.
-    /// If both stored entries are finite but their difference overflows to ±∞,
-    /// the pair is reported as asymmetric.
+    /// This is the damping step of Levenberg–Marquardt and Tikhonov
+    /// regularization. Each diagonal entry receives one rounded addition;
+    /// off-diagonal entries are copied unchanged.
     ///
     /// # Examples
     /// ```
     /// use la_stack::prelude::*;
     ///
     /// # fn main() -> Result<(), LaError> {
-    /// let a = Matrix::<2>::try_from_rows([[4.0, 2.0], [2.0, 3.0]])?;
-    /// let tol = Tolerance::try_new(1e-12)?;
-    /// assert!(a.is_symmetric(tol)?);
+    /// let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]])?;
+    /// let damped = a.add_scaled_identity(0.5)?;
     ///
-    /// let b = Matrix::<2>::try_from_rows([[4.0, 2.0], [3.0, 3.0]])?;
-    /// assert!(!b.is_symmetric(tol)?);
+    /// assert_eq!(damped.get(0, 0), Some(1.5));
+    /// assert_eq!(damped.get(0, 1), Some(2.0));
+    /// assert_eq!(damped.get(1, 1), Some(4.5));
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// # Errors
-    /// Returns [`LaError::NonFinite`] with matrix coordinates when computing the
-    /// scaled symmetry tolerance overflows to NaN or infinity.
+    /// Returns [`LaError::NonFinite`] with scalar input location if `c` is NaN
+    /// or infinite, or with matrix coordinates if a shifted diagonal entry
+    /// overflows.
     #[inline]
-    pub fn is_symmetric(&self, rel_tol: Tolerance) -> Result<bool, LaError> {
-        Ok(self.first_asymmetry(rel_tol)?.is_none())
+    pub const fn add_scaled_identity(&self, c: f64) -> Result<Self, LaError> {
+        if !c.is_finite() {
+            cold_path();
+            return Err(LaError::non_finite_input_scalar());
+        }
+
+        let mut rows = self.rows;
+        let mut i = 0;
+        while i < D {
+            rows[i][i] += c;
+            i += 1;
+        }
+        Self::from_computation(rows, ArithmeticOperation::ScaledIdentityShift)
     }
 
-    /// Returns the indices `(r, c)` (with `r < c`) of the first off-diagonal
-    /// pair that violates approximate symmetry, or `None` if the matrix is
-    /// symmetric within `rel_tol`.
-    ///
-    /// Iteration order is row-major over the strict upper triangle, so the
-    /// returned indices are the lexicographically smallest such pair.  The
-    /// predicate is the same as [`is_symmetric`](Self::is_symmetric):
-    /// `|self[r][c] - self[c][r]| <= rel_tol * max(1.0, inf_norm(self))`.
-    /// It is intentionally distinct from the exact equality required by
-    /// [`ldlt`](Self::ldlt).
-    ///
-    /// A finite matrix can return [`LaError::NonFinite`] with matrix coordinates
-    /// if computing the scaled symmetry tolerance overflows to NaN or infinity.
-    /// If both stored entries are finite but their difference overflows to ±∞,
-    /// the pair is reported as asymmetric.
+    /// Accumulate the weighted outer product `A ← A + w x xᵀ` in place.
     ///
-    /// The `rel_tol` argument is a [`Tolerance`], so raw caller input must be
-    /// finite and non-negative before it can reach this predicate. Use
-    /// [`Tolerance::try_new`] when accepting a raw `f64`; negative, NaN, and
-    /// infinite tolerances return
-    /// [`LaError::InvalidTolerance`].
+    /// Summing `x xᵀ` over samples builds a Gram (scatter) matrix, the core of
+    /// online covariance estimation. Each pair product `(w xᵢ) xⱼ` is formed
+    /// once and added to both `a[i][j]` and `a[j][i]` with a single
+    /// [`mul_add`](f64::mul_add) each, so a symmetric accumulator stays exactly
+    /// symmetric and can be passed to [`ldlt`](Self::ldlt). On error `self` is
+    /// left unchanged.
     ///
     /// # Examples
     /// ```
     /// use la_stack::prelude::*;
     ///
     /// # fn main() -> Result<(), LaError> {
-    /// let a = Matrix::<3>::try_from_rows([
-    ///     [1.0, 2.0, 0.0],
-    ///     [2.0, 4.0, 5.0],
-    ///     [0.0, 6.0, 9.0], // 6.0 breaks symmetry with a[1][2] = 5.0
-    /// ])?;
-    /// let tol = Tolerance::try_new(1e-12)?;
-    /// assert_eq!(a.first_asymmetry(tol)?, Some((1, 2)));
-    /// assert_eq!(Matrix::<3>::identity().first_asymmetry(tol)?, None);
+    /// let mut gram = Matrix::<2>::zero();
+    /// gram.add_outer(&Vector::<2>::try_new([1.0, 2.0])?, 1.0)?;
+    /// gram.add_outer(&Vector::<2>::try_new([3.0, -1.0])?, 0.5)?;
+    ///
+    /// assert_eq!(gram.into_rows(), [[5.5, 0.5], [0.5, 4.5]]);
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// # Errors
-    /// Returns [`LaError::NonFinite`] with matrix coordinates when computing the
-    /// scaled symmetry tolerance overflows to NaN or infinity.
+    /// Returns [`LaError::NonFinite`] for a NaN or infinite `weight`, or with
+    /// matrix coordinates if an updated entry overflows.
     #[inline]
-    pub fn first_asymmetry(&self, rel_tol: Tolerance) -> Result<Option<(usize, usize)>, LaError> {
-        let eps = self.symmetry_epsilon(rel_tol)?;
-        for r in 0..D {
-            for c in (r + 1)..D {
-                let upper = self.rows[r][c];
-                let lower = self.rows[c][r];
+    pub const fn add_outer(&mut self, x: &Vector<D>, weight: f64) -> Result<(), LaError> {
+        if !weight.is_finite() {
+            cold_path();
+            return Err(LaError::non_finite_input_scalar());
+        }
 
-                let diff = (upper - lower).abs();
-                if !diff.is_finite() || diff > eps {
-                    cold_path();
-                    return Ok(Some((r, c)));
-                }
+        let x = x.as_array();
+        let mut rows = self.rows;
+        let mut i = 0;
+        while i < D {
+            let scaled = weight * x[i];
+            let mut j = 0;
+            while j < i {
+                rows[i][j] = scaled.mul_add(x[j], rows[i][j]);
+                rows[j][i] = scaled.mul_add(x[j], rows[j][i]);
+                j += 1;
+            }
+            rows[i][i] = scaled.mul_add(x[i], rows[i][i]);
+            i += 1;
+        }
+        match Self::from_computation(rows, ArithmeticOperation::OuterProductUpdate) {
+            Ok(updated) => {
+                *self = updated;
+                Ok(())
             }
+            Err(err) => Err(err),
         }
-        Ok(None)
     }
 
-    /// Compute an LU decomposition with partial pivoting.
+    /// Weighted sum of outer products `Σ_k w_k v_k v_kᵀ` of a fixed batch.
     ///
-    /// `D = 0` follows the empty-matrix convention: factorization succeeds,
-    /// [`Lu::det`](crate::Lu::det) returns `1.0`, and solving a length-zero
-    /// right-hand side returns a length-zero [`Vector`](crate::Vector).
-    /// Partial pivoting is a practical finite-precision strategy, not a
-    /// certified accuracy guarantee; see `REFERENCES.md` \[1-3, 11-12\].
+    /// This is the batch form of [`add_outer`](Self::add_outer): entries are
+    /// accumulated with the same symmetric [`mul_add`](f64::mul_add) updates,
+    /// so the result equals repeated `add_outer` calls on [`zero`](Self::zero)
+    /// bit for bit and is exactly symmetric. With non-negative weights it is
+    /// positive semidefinite, a Gram matrix ready for [`ldlt`](Self::ldlt).
+    /// `N = 0` yields the zero matrix.
     ///
     /// # Examples
     /// ```
     /// use la_stack::prelude::*;
     ///
     /// # fn main() -> Result<(), LaError> {
-    /// let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]])?;
-    /// let lu = a.lu(DEFAULT_SINGULAR_TOL)?;
-    ///
-    /// let b = Vector::<2>::try_new([5.0, 11.0])?;
-    /// let x = lu.solve(b)?.into_array();
+    /// let vectors = [Vector::<2>::try_new([1.0, 2.0])?, Vector::<2>::try_new([3.0, -1.0])?];
+    /// let gram = Matrix::<2>::from_outer_sum(&vectors, &[1.0, 0.5])?;
     ///
-    /// assert!((x[0] - 1.0).abs() <= 1e-12);
-    /// assert!((x[1] - 2.0).abs() <= 1e-12);
+    /// assert_eq!(gram.into_rows(), [[5.5, 0.5], [0.5, 4.5]]);
     /// # Ok(())
     /// # }
     /// ```
     ///
-    /// Empty matrices use the standard empty-product convention:
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] with
+    /// [`NonFiniteLocation::WeightEntry`](crate::NonFiniteLocation::WeightEntry)
+    /// for the first NaN or infinite weight, or with matrix coordinates if an
+    /// accumulated entry overflows.
+    #[inline]
+    pub const fn from_outer_sum<const N: usize>(
+        vectors: &[Vector<D>; N],
+        weights: &[f64; N],
+    ) -> Result<Self, LaError> {
+        let mut k = 0;
+        while k < N {
+            if !weights[k].is_finite() {
+                cold_path();
+                return Err(LaError::non_finite_input_weight(k));
+            }
+            k += 1;
+        }
+
+        let mut rows = [[0.0; D]; D];
+        let mut k = 0;
+        while k < N {
+            let x = vectors[k].as_array();
+            let mut i = 0;
+            while i < D {
+                let scaled = weights[k] * x[i];
+                let mut j = 0;
+                while j < i {
+                    rows[i][j] = scaled.mul_add(x[j], rows[i][j]);
+                    rows[j][i] = scaled.mul_add(x[j], rows[j][i]);
+                    j += 1;
+                }
+                rows[i][i] = scaled.mul_add(x[i], rows[i][i]);
+                i += 1;
+            }
+            k += 1;
+        }
+        Self::from_computation(rows, ArithmeticOperation::OuterProductUpdate)
+    }
+
+    /// Apply the reflector `(I − β v vᵀ)` from the left, `A ← A − β v (vᵀ A)`,
+    /// in place.
     ///
+    /// This costs `O(D²)` instead of the `O(D³)` of forming the reflection
+    /// matrix and multiplying, and is the inner update of Householder QR. With
+    /// `(v, β)` from [`Vector::householder_vector`] of the first column, it
+    /// zeroes that column below the diagonal. With `β = 2 / (vᵀ v)` it matches
+    /// [`householder`](Self::householder) applied by a matrix product. On error
+    /// `self` is left unchanged.
+    ///
+    /// # Examples
     /// ```
     /// use la_stack::prelude::*;
     ///
     /// # fn main() -> Result<(), LaError> {
-    /// let lu = Matrix::<0>::zero().lu(DEFAULT_SINGULAR_TOL)?;
+    /// let mut a = Matrix::<2>::try_from_rows([[3.0, 1.0], [4.0, 2.0]])?;
+    /// let first_column = Vector::<2>::try_new([3.0, 4.0])?;
+    /// let (v, beta) = first_column.householder_vector();
     ///
-    /// assert_eq!(lu.det()?, 1.0);
-    /// assert!(lu.solve(Vector::<0>::zero())?.into_array().is_empty());
+    /// a.apply_householder_left(&v, beta)?;
+    /// assert!((a.get(0, 0).unwrap_or(f64::NAN) - 5.0).abs() <= 1e-15);
+    /// assert!(a.get(1, 0).is_some_and(|x| x.abs() <= 1e-15));
     /// # Ok(())
     /// # }
     /// ```
     ///
-    /// The `tol` argument is a [`Tolerance`], so raw caller input must be
-    /// finite and non-negative before it can reach factorization. Use
-    /// [`Tolerance::try_new`] when accepting a raw `f64`; negative, NaN, and
-    /// infinite tolerances return
-    /// [`LaError::InvalidTolerance`].
-    ///
     /// # Errors
-    /// Returns [`LaError::Singular`] if, for some column `k`, the largest-magnitude candidate pivot
-    /// in that column satisfies `|pivot| <= tol` (so no numerically usable pivot exists).
-    /// Returns [`LaError::NonFinite`] if an elimination intermediate overflows
-    /// to NaN/∞ before it can be stored in the returned [`Lu`].
+    /// Returns [`LaError::NonFinite`] for a NaN or infinite `beta`, or with
+    /// matrix coordinates if an updated entry overflows.
     #[inline]
-    pub fn lu(self, tol: Tolerance) -> Result<Lu<D>, LaError> {
-        Lu::factor_finite(self, tol)
+    pub const fn apply_householder_left(
+        &mut self,
+        v: &Vector<D>,
+        beta: f64,
+    ) -> Result<(), LaError> {
+        if !beta.is_finite() {
+            cold_path();
+            return Err(LaError::non_finite_input_scalar());
+        }
+
+        let v = v.as_array();
+        let mut rows = self.rows;
+        let mut c = 0;
+        while c < D {
+            let mut w = 0.0;
+            let mut r = 0;
+            while r < D {
+                w = v[r].mul_add(self.rows[r][c], w);
+                r += 1;
+            }
+            let scaled = -beta * w;
+            r = 0;
+            while r < D {
+                rows[r][c] = scaled.mul_add(v[r], rows[r][c]);
+                r += 1;
+            }
+            c += 1;
+        }
+        match Self::from_computation(rows, ArithmeticOperation::HouseholderUpdate) {
+            Ok(updated) => {
+                *self = updated;
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
     }
 
-    /// Compute an LDLT factorization (`A = L D Lᵀ`) without pivoting.
+    /// Row scaling `diag(d) · A`: row `i` is multiplied by `d[i]`.
     ///
-    /// `D = 0` follows the empty-matrix convention: factorization succeeds,
-    /// [`Ldlt::det`](crate::Ldlt::det) returns `1.0`, and solving a length-zero
-    /// right-hand side returns a length-zero [`Vector`](crate::Vector).
+    /// This costs `D²` multiplications instead of the `D³` of a dense product
+    /// with the diagonal matrix, and each entry is a single rounded product.
     ///
-    /// This is intended for exactly symmetric positive-definite matrices such
-    /// as nonsingular Gram matrices. Computed zero and tolerance-small positive
-    /// pivots are diagnosed as singular rather than returned in a usable
-    /// factorization. Because pivots are computed in binary64, success is not
-    /// an exact proof that the stored matrix is positive definite.
-    /// See `REFERENCES.md` \[4-6, 11-12\] for Cholesky/LDLT background and the
-    /// pivoted symmetric-indefinite alternative.
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
     ///
-    /// # Symmetry validation
-    /// The input matrix `self` must be exactly symmetric: every mirrored pair
-    /// must satisfy `self[i][j] == self[j][i]`. IEEE-754 signed zeros compare
-    /// equal and are therefore accepted. Exact equality is a correctness
-    /// invariant, not merely a performance hint: LDLT reads only the lower
-    /// triangle, so accepting an approximate mismatch would factor a different
-    /// operator than the matrix supplied by the caller. Asymmetric inputs return
-    /// [`LaError::Asymmetric`] with an allowed absolute difference of `0.0`
-    /// before factorization starts.
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]])?;
+    /// let d = Vector::<2>::try_new([10.0, -1.0])?;
+    /// let expected = Matrix::<2>::try_from_rows([[10.0, 20.0], [-3.0, -4.0]])?;
+    /// assert_eq!(a.scale_rows(d)?, expected);
+    /// # Ok(())
+    /// # }
+    /// ```
     ///
-    /// [`is_symmetric`](Self::is_symmetric) remains available as a
-    /// tolerance-based diagnostic, but `Ok(true)` from that method does not
-    /// establish this exact LDLT precondition. If you need a general-purpose
-    /// factorization for a non-symmetric matrix, use [`lu`](Self::lu) instead.
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] with the coordinates of the first scaled
+    /// entry that overflows, in row-major order.
+    #[inline]
+    pub const fn scale_rows(&self, d: Vector<D>) -> Result<Self, LaError> {
+        let scale = d.as_array();
+        let mut rows = self.rows;
+        let mut r = 0;
+        while r < D {
+            let mut c = 0;
+            while c < D {
+                rows[r][c] *= scale[r];
+                c += 1;
+            }
+            r += 1;
+        }
+        Self::from_computation(rows, ArithmeticOperation::DiagonalScaling)
+    }
+
+    /// Column scaling `A · diag(d)`: column `j` is multiplied by `d[j]`.
     ///
-    /// The `tol` argument is a [`Tolerance`], so raw caller input must be
-    /// finite and non-negative before it can reach factorization. Use
-    /// [`Tolerance::try_new`] when accepting a raw `f64`; negative, NaN, and
-    /// infinite tolerances return
-    /// [`LaError::InvalidTolerance`].
+    /// The column counterpart of [`scale_rows`](Self::scale_rows), with the
+    /// same cost and rounding.
     ///
     /// # Examples
     /// ```
     /// use la_stack::prelude::*;
     ///
     /// # fn main() -> Result<(), LaError> {
-    /// // Note the symmetric layout: a[0][1] == a[1][0] == 2.0.
-    /// let a = Matrix::<2>::try_from_rows([[4.0, 2.0], [2.0, 3.0]])?;
-    /// let ldlt = a.ldlt(DEFAULT_SINGULAR_TOL)?;
+    /// let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]])?;
+    /// let d = Vector::<2>::try_new([10.0, -1.0])?;
+    /// let expected = Matrix::<2>::try_from_rows([[10.0, -2.0], [30.0, -4.0]])?;
+    /// assert_eq!(a.scale_cols(d)?, expected);
+    /// # Ok(())
+    /// # }
+    /// ```
     ///
-    /// // det(A) = 8
-    /// assert!((ldlt.det()? - 8.0).abs() <= 1e-12);
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] with the coordinates of the first scaled
+    /// entry that overflows, in row-major order.
+    #[inline]
+    pub const fn scale_cols(&self, d: Vector<D>) -> Result<Self, LaError> {
+        let scale = d.as_array();
+        let mut rows = self.rows;
+        let mut r = 0;
+        while r < D {
+            let mut c = 0;
+            while c < D {
+                rows[r][c] *= scale[c];
+                c += 1;
+            }
+            r += 1;
+        }
+        Self::from_computation(rows, ArithmeticOperation::DiagonalScaling)
+    }
+
+    /// Correlation matrix `a[i][j] / (σᵢ σⱼ)` of a covariance, with
+    /// `σᵢ = √a[i][i]`.
     ///
-    /// // Solve A x = b
-    /// let b = Vector::<2>::try_new([1.0, 2.0])?;
-    /// let x = ldlt.solve(b)?.into_array();
-    /// assert!((x[0] - (-0.125)).abs() <= 1e-12);
-    /// assert!((x[1] - 0.75).abs() <= 1e-12);
+    /// This is the two-sided diagonal scaling `S⁻¹ A S⁻¹`, `S = diag(σ)`. Each
+    /// entry is divided by `σᵢ` and then by `σⱼ`, which avoids forming the
+    /// product `σᵢ σⱼ` and so cannot underflow it for tiny variances; the
+    /// division order is the same for `(i, j)` and `(j, i)`, so a symmetric
+    /// covariance gives an exactly symmetric result. The diagonal is set to
+    /// exactly `1.0`. Every variance is treated as a pivot: one with
+    /// `a[i][i] ≤ tol` carries no scale and is rejected. For a positive
+    /// semidefinite input every off-diagonal entry lies in `[-1, 1]` up to
+    /// rounding.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let covariance = Matrix::<2>::try_from_rows([[4.0, 3.0], [3.0, 9.0]])?;
+    /// let correlation = covariance.to_correlation(DEFAULT_SINGULAR_TOL)?;
+    /// assert_eq!(correlation, Matrix::<2>::try_from_rows([[1.0, 0.5], [0.5, 1.0]])?);
     /// # Ok(())
     /// # }
     /// ```
     ///
-    /// Empty matrices use the standard empty-product convention:
+    /// # Errors
+    /// Returns [`LaError::NotPositiveSemidefinite`] for the first negative
+    /// variance, which no covariance can have.
+    /// Returns [`LaError::Singular`] for the first variance that is exactly
+    /// zero ([`SingularityReason::Exact`](crate::SingularityReason::Exact)) or
+    /// satisfies `a[i][i] ≤ tol`
+    /// ([`SingularityReason::Numerical`](crate::SingularityReason::Numerical)
+    /// with [`FactorizationKind::Diagonal`]).
+    /// Returns [`LaError::NonFinite`] with the coordinates of the first scaled
+    /// entry that overflows, which requires an off-diagonal entry far larger
+    /// than the standard deviations allow.
+    #[inline]
+    pub fn to_correlation(&self, tol: Tolerance) -> Result<Self, LaError> {
+        let mut sigma = [0.0; D];
+        for (i, s) in sigma.iter_mut().enumerate() {
+            let variance = self.rows[i][i];
+            if variance < 0.0 {
+                cold_path();
+                return Err(LaError::not_positive_semidefinite_negative(i, variance));
+            }
+            if variance == 0.0 {
+                cold_path();
+                return Err(LaError::singular_exact(i));
+            }
+            if variance <= tol.get() {
+                cold_path();
+                return Err(LaError::singular_numerical(
+                    i,
+                    FactorizationKind::Diagonal,
+                    variance,
+                    tol.get(),
+                ));
+            }
+            *s = variance.sqrt();
+        }
+
+        let mut rows = self.rows;
+        for (r, row) in rows.iter_mut().enumerate() {
+            for (c, entry) in row.iter_mut().enumerate() {
+                *entry = if r == c {
+                    1.0
+                } else {
+                    let (first, second) = if r < c { (r, c) } else { (c, r) };
+                    *entry / sigma[first] / sigma[second]
+                };
+            }
+        }
+        Self::from_computation(rows, ArithmeticOperation::DiagonalScaling)
+    }
+
+    /// Entrywise (Hadamard) reciprocal `(1 / a[i][j])`, not the matrix
+    /// inverse.
+    ///
+    /// Useful for elementwise preconditioning and Hadamard division. As in
+    /// [`Vector::component_div`], each entry is treated as the pivot of a
+    /// diagonal system, here of length `D²` in row-major order, so an entry
+    /// with `|a[i][j]| ≤ tol` is rejected rather than producing a huge or
+    /// infinite result. Each reciprocal is correctly rounded.
     ///
+    /// # Examples
     /// ```
     /// use la_stack::prelude::*;
     ///
     /// # fn main() -> Result<(), LaError> {
-    /// let ldlt = Matrix::<0>::zero().ldlt(DEFAULT_SINGULAR_TOL)?;
+    /// let a = Matrix::<2>::try_from_rows([[2.0, -4.0], [0.5, 1.0]])?;
+    /// let r = a.reciprocal(DEFAULT_SINGULAR_TOL)?;
+    /// assert_eq!(r.into_rows(), [[0.5, -0.25], [2.0, 1.0]]);
     ///
-    /// assert_eq!(ldlt.det()?, 1.0);
-    /// assert!(ldlt.solve(Vector::<0>::zero())?.into_array().is_empty());
+    /// // Entry (1, 0) is index 1 · 2 + 0 = 2 in row-major order.
+    /// let with_zero = Matrix::<2>::try_from_rows([[1.0, 1.0], [0.0, 1.0]])?;
+    /// assert!(matches!(
+    ///     with_zero.reciprocal(DEFAULT_SINGULAR_TOL),
+    ///     Err(LaError::Singular { pivot_col: 2, .. })
+    /// ));
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// # Errors
-    /// Returns [`LaError::NotPositiveSemidefinite`] if a pivot is negative or a
-    /// zero pivot retains a non-zero coupling below it.
-    /// Returns [`LaError::Singular`] if a zero pivot has no remaining coupling,
-    /// or if a positive pivot satisfies `d <= tol`, treating PSD degeneracy as
-    /// singular.
-    /// Returns [`LaError::NonFinite`] if factorization computes a non-finite
-    /// intermediate.
-    /// Returns [`LaError::Asymmetric`] if the input matrix is not symmetric.
+    /// Returns [`LaError::Singular`] with row-major index `i·D + j` for the
+    /// first entry that is exactly zero
+    /// ([`SingularityReason::Exact`](crate::SingularityReason::Exact)) or has
+    /// `|a[i][j]| ≤ tol`
+    /// ([`SingularityReason::Numerical`](crate::SingularityReason::Numerical)
+    /// with [`FactorizationKind::Diagonal`](crate::FactorizationKind::Diagonal)).
+    /// Returns [`LaError::NonFinite`] with matrix coordinates if a reciprocal
+    /// of a subnormal entry overflows.
     #[inline]
-    pub fn ldlt(self, tol: Tolerance) -> Result<Ldlt<D>, LaError> {
-        Ldlt::factor_symmetric(SymmetricMatrix::try_new(self)?, tol)
-    }
-
-    /// Return the first non-finite stored cell in row-major order.
-    const fn first_non_finite_cell(rows: &[[f64; D]; D]) -> Option<(usize, usize)> {
+    pub const fn reciprocal(&self, tol: Tolerance) -> Result<Self, LaError> {
+        let mut rows = [[0.0; D]; D];
         let mut r = 0;
         while r < D {
             let mut c = 0;
             while c < D {
-                if !rows[r][c].is_finite() {
-                    return Some((r, c));
+                let value = self.rows[r][c];
+                if value == 0.0 {
+                    cold_path();
+                    return Err(LaError::singular_exact(r * D + c));
+                }
+                if value.abs() <= tol.get() {
+                    cold_path();
+                    return Err(LaError::singular_numerical(
+                        r * D + c,
+                        FactorizationKind::Diagonal,
+                        value.abs(),
+                        tol.get(),
+                    ));
                 }
+                rows[r][c] = 1.0 / value;
                 c += 1;
             }
             r += 1;
         }
-        None
+        Self::from_computation(rows, ArithmeticOperation::EntrywiseReciprocal)
     }
 
-    /// Compute the approximate-symmetry tolerance scale for a finite matrix.
+    /// Inverse of the upper triangle of `self` by back substitution.
     ///
-    /// This helper protects the public [`is_symmetric`](Self::is_symmetric) and
-    /// [`first_asymmetry`](Self::first_asymmetry) diagnostic contracts: the
-    /// documented norm-first formula is used whenever its intermediate is
-    /// representable, while an overflow-safe termwise fallback reports the
-    /// matrix cell that makes the scaled tolerance non-finite.
-    fn symmetry_epsilon(&self, rel_tol: Tolerance) -> Result<f64, LaError> {
-        let rel_tol = rel_tol.get();
-
-        if rel_tol == 0.0 {
-            return Ok(rel_tol);
+    /// Only the diagonal and the entries above it are read; the strictly lower
+    /// triangle is ignored, so the upper factor stored in a combined layout
+    /// can be passed directly. Column `j` of `U⁻¹` solves `U x = e_j` from
+    /// `x_j = 1 / u_jj` upwards, and the strictly lower triangle of the result
+    /// is exactly zero. This needs about `D³/6` multiply-adds, several times
+    /// fewer than an inverse through [`lu`](Self::lu). The diagonal entries are the
+    /// pivots and are all checked before any substitution. Substitution is
+    /// backward stable, but the inverse of an ill-conditioned triangular
+    /// matrix can still be inaccurate; no certified error bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let u = Matrix::<2>::try_from_rows([[2.0, 4.0], [0.0, 4.0]])?;
+    /// let inv = u.invert_upper_triangular(DEFAULT_SINGULAR_TOL)?;
+    /// assert_eq!(inv.into_rows(), [[0.5, -0.5], [0.0, 0.25]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::Singular`] for the first diagonal entry that is
+    /// exactly zero ([`SingularityReason::Exact`](crate::SingularityReason::Exact))
+    /// or has `|u_ii| ≤ tol`
+    /// ([`SingularityReason::Numerical`](crate::SingularityReason::Numerical)
+    /// with [`FactorizationKind::Diagonal`](crate::FactorizationKind::Diagonal)).
+    /// Returns [`LaError::NonFinite`] with the coordinates of the first entry
+    /// of the inverse that overflows.
+    #[inline]
+    pub const fn invert_upper_triangular(&self, tol: Tolerance) -> Result<Self, LaError> {
+        if let Err(err) = self.check_triangular_pivots(tol) {
+            return Err(err);
         }
-
-        if let Ok(norm) = self.inf_norm() {
-            let scale = if norm > 1.0 { norm } else { 1.0 };
-            let eps = rel_tol * scale;
-            if eps.is_finite() {
-                return Ok(eps);
+        let mut inv = [[0.0; D]; D];
+        let mut j = 0;
+        while j < D {
+            let mut ii = D - 1 - j;
+            while ii < D {
+                // Rows from j down to 0.
+                let i = D - 1 - ii;
+                let value = if i == j {
+                    1.0 / self.rows[j][j]
+                } else {
+                    let mut sum = 0.0;
+                    let mut k = i + 1;
+                    while k <= j {
+                        sum = self.rows[i][k].mul_add(inv[k][j], sum);
+                        k += 1;
+                    }
+                    -sum / self.rows[i][i]
+                };
+                if !value.is_finite() {
+                    cold_path();
+                    return Err(LaError::non_finite_computation_matrix(
+                        ArithmeticOperation::TriangularInverse,
+                        i,
+                        j,
+                    ));
+                }
+                inv[i][j] = value;
+                ii += 1;
             }
+            j += 1;
         }
+        Ok(Self::from_rows_unchecked(inv))
+    }
 
-        // If the unscaled row sum or the final multiplication overflows, apply
-        // the tolerance to each non-negative contribution before summing. A row
-        // can overflow only at magnitudes where multiplication by the smallest
-        // positive tolerance is normal, so this fallback cannot introduce the
-        // gradual-underflow discrepancy avoided by the direct path above.
-        let mut eps = rel_tol;
-
-        for r in 0..D {
-            let mut row_eps = 0.0;
-            for c in 0..D {
-                row_eps = rel_tol.mul_add(self.rows[r][c].abs(), row_eps);
-                if !row_eps.is_finite() {
+    /// Inverse of the lower triangle of `self` by forward substitution.
+    ///
+    /// The lower-triangular counterpart of
+    /// [`invert_upper_triangular`](Self::invert_upper_triangular): only the
+    /// diagonal and the entries below it are read, column `j` of `L⁻¹` solves
+    /// `L x = e_j` from `x_j = 1 / l_jj` downwards, and the strictly upper
+    /// triangle of the result is exactly zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let l = Matrix::<2>::try_from_rows([[2.0, 0.0], [4.0, 4.0]])?;
+    /// let inv = l.invert_lower_triangular(DEFAULT_SINGULAR_TOL)?;
+    /// assert_eq!(inv.into_rows(), [[0.5, 0.0], [-0.5, 0.25]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::Singular`] for the first diagonal entry that is
+    /// exactly zero ([`SingularityReason::Exact`](crate::SingularityReason::Exact))
+    /// or has `|l_ii| ≤ tol`
+    /// ([`SingularityReason::Numerical`](crate::SingularityReason::Numerical)
+    /// with [`FactorizationKind::Diagonal`](crate::FactorizationKind::Diagonal)).
+    /// Returns [`LaError::NonFinite`] with the coordinates of the first entry
+    /// of the inverse that overflows.
+    #[inline]
+    pub const fn invert_lower_triangular(&self, tol: Tolerance) -> Result<Self, LaError> {
+        if let Err(err) = self.check_triangular_pivots(tol) {
+            return Err(err);
+        }
+        let mut inv = [[0.0; D]; D];
+        let mut j = 0;
+        while j < D {
+            let mut i = j;
+            while i < D {
+                let value = if i == j {
+                    1.0 / self.rows[j][j]
+                } else {
+                    let mut sum = 0.0;
+                    let mut k = j;
+                    while k < i {
+                        sum = self.rows[i][k].mul_add(inv[k][j], sum);
+                        k += 1;
+                    }
+                    -sum / self.rows[i][i]
+                };
+                if !value.is_finite() {
                     cold_path();
                     return Err(LaError::non_finite_computation_matrix(
-                        ArithmeticOperation::SymmetryCheck,
-                        r,
-                        c,
+                        ArithmeticOperation::TriangularInverse,
+                        i,
+                        j,
                     ));
                 }
+                inv[i][j] = value;
+                i += 1;
             }
-            if row_eps > eps {
-                eps = row_eps;
-            }
+            j += 1;
         }
+        Ok(Self::from_rows_unchecked(inv))
+    }
 
-        Ok(eps)
+    /// Rejects the first diagonal entry of a triangular matrix that is zero or
+    /// has magnitude at most `tol`.
+    #[inline]
+    const fn check_triangular_pivots(&self, tol: Tolerance) -> Result<(), LaError> {
+        let mut i = 0;
+        while i < D {
+            let pivot = self.rows[i][i];
+            if pivot == 0.0 {
+                cold_path();
+                return Err(LaError::singular_exact(i));
+            }
+            if pivot.abs() <= tol.get() {
+                cold_path();
+                return Err(LaError::singular_numerical(
+                    i,
+                    FactorizationKind::Diagonal,
+                    pivot.abs(),
+                    tol.get(),
+                ));
+            }
+            i += 1;
+        }
+        Ok(())
     }
 
-    /// Closed-form determinant for dimensions 0–4, bypassing LU factorization.
-    ///
-    /// Returns `Ok(Some(det))` for `D` ∈ {0, 1, 2, 3, 4}, `Ok(None)` for D ≥ 5.
-    /// `D = 0` returns `Ok(Some(1.0))` (empty product).
-    /// This is a `const fn` (Rust 1.94+) and uses fused multiply-add (`mul_add`)
-    /// for improved accuracy and performance.
-    ///
-    /// For a determinant that works for any dimension (falling back to LU for D ≥ 5),
-    /// use [`det`](Self::det).
+    /// Trace `Σ a[i][i]`, the sum of the eigenvalues.
     ///
     /// # Examples
     /// ```
     /// use la_stack::prelude::*;
     ///
     /// # fn main() -> Result<(), LaError> {
-    /// let m = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]])?;
-    /// assert_eq!(m.det_direct()?, Some(-2.0));
-    ///
-    /// // D = 0 is the empty product.
-    /// assert_eq!(Matrix::<0>::zero().det_direct()?, Some(1.0));
-    ///
-    /// // D ≥ 5 returns None.
-    /// assert!(Matrix::<5>::identity().det_direct()?.is_none());
+    /// let a = Matrix::<2>::try_from_rows([[3.0, 1.0], [2.0, 5.0]])?;
+    /// assert_eq!(a.trace()?, 8.0);
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// # Errors
-    /// Returns [`LaError::NonFinite`] when the closed-form determinant overflows
-    /// to NaN or infinity.
+    /// Returns [`LaError::NonFinite`] if the sum overflows to infinity.
     #[inline]
-    pub const fn det_direct(&self) -> Result<Option<f64>, LaError> {
-        let Some(det) = self.det_direct_arithmetic::<false>() else {
+    pub const fn trace(&self) -> Result<f64, LaError> {
+        let mut trace = 0.0;
+        let mut i = 0;
+        while i < D {
+            trace += self.rows[i][i];
+            i += 1;
+        }
+        if trace.is_finite() {
+            Ok(trace)
+        } else {
             cold_path();
-            return Ok(None);
-        };
-
-        Self::computed_scalar_result(ArithmeticOperation::Determinant, det.value)
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::Trace,
+            ))
+        }
     }
 
-    /// Evaluate the closed-form determinant while certifying every rounded
-    /// operation against gradual underflow.
-    #[expect(
-        clippy::inline_always,
-        reason = "det_direct callers must eliminate unused filter-safety bookkeeping"
-    )]
-    #[inline(always)]
-    const fn det_direct_arithmetic<const TRACK_UNDERFLOW: bool>(
-        &self,
-    ) -> Option<FilterArithmetic<TRACK_UNDERFLOW>> {
-        match D {
-            0 => Some(FilterArithmetic {
-                value: 1.0,
-                underflow_safe: true,
-            }),
-            1 => Some(FilterArithmetic {
-                value: self.rows[0][0],
-                underflow_safe: true,
-            }),
-            2 => {
-                let a = self.rows[0][0];
-                let b = self.rows[0][1];
-                let c = self.rows[1][0];
-                let d = self.rows[1][1];
-                let subtrahend = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(b, c);
-                let mut det = FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(a, d, -subtrahend.value);
-                det.underflow_safe &= subtrahend.underflow_safe;
-                Some(det)
-            }
-            3 => Some(Self::det3_elements::<TRACK_UNDERFLOW>(
-                [self.rows[0][0], self.rows[0][1], self.rows[0][2]],
-                [self.rows[1][0], self.rows[1][1], self.rows[1][2]],
-                [self.rows[2][0], self.rows[2][1], self.rows[2][2]],
-            )),
-            4 => {
-                if !TRACK_UNDERFLOW && let Some(input) = Det4SharedMinorInput::try_new(self) {
-                    return Some(FilterArithmetic {
-                        value: Self::det4_dense_elements(input),
-                        underflow_safe: true,
-                    });
-                }
-
-                let r = &self.rows;
-                let mut det = if r[0][3] == 0.0 {
-                    FilterArithmetic {
-                        value: 0.0,
-                        underflow_safe: true,
-                    }
-                } else {
-                    let c03 = Self::det3_elements::<TRACK_UNDERFLOW>(
-                        [r[1][0], r[1][1], r[1][2]],
-                        [r[2][0], r[2][1], r[2][2]],
-                        [r[3][0], r[3][1], r[3][2]],
-                    );
-                    let mut term =
-                        FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r[0][3], c03.value);
-                    term.value = -term.value;
-                    term.underflow_safe &= c03.underflow_safe;
-                    term
-                };
-                if r[0][2] != 0.0 {
-                    let c02 = Self::det3_elements::<TRACK_UNDERFLOW>(
-                        [r[1][0], r[1][1], r[1][3]],
-                        [r[2][0], r[2][1], r[2][3]],
-                        [r[3][0], r[3][1], r[3][3]],
-                    );
-                    let prior_safe = det.underflow_safe && c02.underflow_safe;
-                    det =
-                        FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(r[0][2], c02.value, det.value);
-                    det.underflow_safe &= prior_safe;
-                }
-                if r[0][1] != 0.0 {
-                    let c01 = Self::det3_elements::<TRACK_UNDERFLOW>(
-                        [r[1][0], r[1][2], r[1][3]],
-                        [r[2][0], r[2][2], r[2][3]],
-                        [r[3][0], r[3][2], r[3][3]],
-                    );
-                    let prior_safe = det.underflow_safe && c01.underflow_safe;
-                    det = FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(
-                        -r[0][1], c01.value, det.value,
-                    );
-                    det.underflow_safe &= prior_safe;
-                }
-                if r[0][0] != 0.0 {
-                    let c00 = Self::det3_elements::<TRACK_UNDERFLOW>(
-                        [r[1][1], r[1][2], r[1][3]],
-                        [r[2][1], r[2][2], r[2][3]],
-                        [r[3][1], r[3][2], r[3][3]],
-                    );
-                    let prior_safe = det.underflow_safe && c00.underflow_safe;
-                    det =
-                        FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(r[0][0], c00.value, det.value);
-                    det.underflow_safe &= prior_safe;
-                }
-
-                Some(det)
-            }
-            _ => None,
-        }
-    }
-
-    /// Evaluate the proof-bearing 4×4 cofactor expansion with shared 2×2 minors.
-    ///
-    /// When no intermediate undergoes gradual underflow, the rounding error is
-    /// bounded by `ERR_COEFF_4 · p(|A|)`, where `p(|A|)` is the absolute Leibniz
-    /// sum. This helper returns only the determinant; use
-    /// [`Self::det_errbound`] or [`Self::det_direct_with_errbound`] to obtain the
-    /// certified bound.
-    #[expect(
-        clippy::inline_always,
-        reason = "the D=4 determinant hot path must inline its shared-minor expansion"
-    )]
-    #[inline(always)]
-    const fn det4_dense_elements(input: Det4SharedMinorInput<'_, D>) -> f64 {
-        let r = &input.matrix.rows;
-        let s23 = r[2][2].mul_add(r[3][3], -(r[2][3] * r[3][2]));
-        let s13 = r[2][1].mul_add(r[3][3], -(r[2][3] * r[3][1]));
-        let s12 = r[2][1].mul_add(r[3][2], -(r[2][2] * r[3][1]));
-        let s03 = r[2][0].mul_add(r[3][3], -(r[2][3] * r[3][0]));
-        let s02 = r[2][0].mul_add(r[3][2], -(r[2][2] * r[3][0]));
-        let s01 = r[2][0].mul_add(r[3][1], -(r[2][1] * r[3][0]));
-
-        let c00 = r[1][1].mul_add(s23, (-r[1][2]).mul_add(s13, r[1][3] * s12));
-        let c01 = r[1][0].mul_add(s23, (-r[1][2]).mul_add(s03, r[1][3] * s02));
-        let c02 = r[1][0].mul_add(s13, (-r[1][1]).mul_add(s03, r[1][3] * s01));
-        let c03 = r[1][0].mul_add(s12, (-r[1][1]).mul_add(s02, r[1][2] * s01));
-
-        r[0][0].mul_add(
-            c00,
-            (-r[0][1]).mul_add(c01, r[0][2].mul_add(c02, -(r[0][3] * c03))),
-        )
-    }
-
-    /// Evaluate the dense 4×4 absolute permanent with shared 2×2 minors.
+    /// Frobenius inner product `⟨A, B⟩ = tr(AᵀB) = Σ a[i][j] b[i][j]`.
     ///
-    /// The proof carried by `input` makes every shared minor part of an active
-    /// Leibniz term. The caller separately establishes a wide exponent margin,
-    /// so this branch-free kernel cannot hide gradual underflow or evaluate an
-    /// overflowing minor for a mathematically absent term.
-    #[expect(
-        clippy::inline_always,
-        reason = "the D=4 determinant filter must inline its shared-minor permanent"
-    )]
-    #[inline(always)]
-    const fn det4_dense_abs_permanent_elements(input: Det4SharedMinorInput<'_, D>) -> f64 {
-        let r = &input.matrix.rows;
-        let sp23 = (r[2][2] * r[3][3]).abs() + (r[2][3] * r[3][2]).abs();
-        let sp13 = (r[2][1] * r[3][3]).abs() + (r[2][3] * r[3][1]).abs();
-        let sp12 = (r[2][1] * r[3][2]).abs() + (r[2][2] * r[3][1]).abs();
-        let sp03 = (r[2][0] * r[3][3]).abs() + (r[2][3] * r[3][0]).abs();
-        let sp02 = (r[2][0] * r[3][2]).abs() + (r[2][2] * r[3][0]).abs();
-        let sp01 = (r[2][0] * r[3][1]).abs() + (r[2][1] * r[3][0]).abs();
-
-        let pc0 = r[1][3]
-            .abs()
-            .mul_add(sp12, r[1][2].abs().mul_add(sp13, r[1][1].abs() * sp23));
-        let pc1 = r[1][3]
-            .abs()
-            .mul_add(sp02, r[1][2].abs().mul_add(sp03, r[1][0].abs() * sp23));
-        let pc2 = r[1][3]
-            .abs()
-            .mul_add(sp01, r[1][1].abs().mul_add(sp03, r[1][0].abs() * sp13));
-        let pc3 = r[1][2]
-            .abs()
-            .mul_add(sp01, r[1][1].abs().mul_add(sp02, r[1][0].abs() * sp12));
-
-        r[0][3].abs().mul_add(
-            pc3,
-            r[0][2]
-                .abs()
-                .mul_add(pc2, r[0][1].abs().mul_add(pc1, r[0][0].abs() * pc0)),
-        )
-    }
-
-    /// Floating-point determinant, using closed-form formulas for D ≤ 4 and
-    /// LU decomposition for D ≥ 5.
-    ///
-    /// For D ∈ {1, 2, 3, 4}, this bypasses LU factorization entirely for a significant
-    /// speedup (see [`det_direct`](Self::det_direct)).
-    ///
-    /// Because this method mixes closed-form paths from
-    /// [`det_direct`](Self::det_direct) with an LU fallback, the returned value has
-    /// no certified absolute error bound. Use
-    /// [`det_errbound`](Self::det_errbound) for D ≤ 4 bounds, or the exact
-    /// determinant APIs when exact singularity classification or certified values
-    /// matter. For D ≥ 5, the zero-tolerance LU fallback surfaces
-    /// [`LaError::Singular`] when elimination cannot produce a non-zero pivot.
-    /// Floating-point elimination cannot in general distinguish an exactly
-    /// singular matrix from a non-singular matrix whose intermediate pivot
-    /// rounded to zero, so this method never converts that numerical failure into
-    /// an exact `0.0` result.
+    /// This is the matrix analogue of [`Vector::dot`]: entries are accumulated
+    /// row by row with [`f64::mul_add`], without forming `AᵀB`. `⟨A, A⟩` is the
+    /// squared Frobenius norm. Intermediate rounding occurs; no certified error
+    /// bound is provided.
     ///
     /// # Examples
     /// ```
     /// use la_stack::prelude::*;
     ///
     /// # fn main() -> Result<(), LaError> {
-    /// let det = Matrix::<3>::identity().det()?;
-    /// assert!((det - 1.0).abs() <= 1e-12);
+    /// let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]])?;
+    /// let b = Matrix::<2>::try_from_rows([[0.5, -1.0], [2.0, 0.0]])?;
+    /// assert_eq!(a.frobenius_inner(&b)?, 4.5);
+    /// assert_eq!(a.frobenius_inner(&a)?, 30.0);
     /// # Ok(())
     /// # }
     /// ```
     ///
-    /// The LU fallback accumulates its diagonal product with power-of-two
-    /// scaling, so factor order cannot cause premature overflow or underflow in
-    /// the final product. Elimination intermediates remain subject to binary64
-    /// rounding and range limits.
-    ///
     /// # Errors
-    /// Returns [`LaError::Singular`] if the D ≥ 5 LU fallback cannot produce a
-    /// non-zero pivot, including when a non-zero mathematical intermediate rounds
-    /// to zero during elimination. Returns [`LaError::NonFinite`] if a D ≤ 4
-    /// closed-form result is non-finite, if the LU fallback computes a
-    /// non-finite factorization cell, or if its final scaled determinant cannot
-    /// be represented as a finite `f64`.
+    /// Returns [`LaError::NonFinite`] if the accumulated sum overflows to
+    /// infinity.
     #[inline]
-    pub fn det(self) -> Result<f64, LaError> {
-        if let Some(d) = self.det_direct()? {
-            return Ok(d);
+    pub const fn frobenius_inner(&self, other: &Self) -> Result<f64, LaError> {
+        let mut acc = 0.0;
+        let mut r = 0;
+        while r < D {
+            let mut c = 0;
+            while c < D {
+                acc = self.rows[r][c].mul_add(other.rows[r][c], acc);
+                c += 1;
+            }
+            r += 1;
+        }
+        if acc.is_finite() {
+            Ok(acc)
+        } else {
+            cold_path();
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::FrobeniusInnerProduct,
+            ))
         }
-        self.lu(Tolerance::ZERO)?.det()
     }
 
-    /// Evaluate `det_direct()` and its absolute error bound together.
-    ///
-    /// Returns `Ok(Some(result))` for D ≤ 4 when the relative-error analysis
-    /// is valid. The result contains the closed-form determinant and a bound
-    /// such that `|result.determinant() - det_exact| ≤
-    /// result.absolute_error_bound()`. Returns `Ok(None)` when gradual
-    /// underflow could invalidate that analysis or for D ≥ 5, where no
-    /// closed-form bound is available.
+    /// Integer power `A^k`.
     ///
-    /// This is the preferred API when both values are needed: it evaluates the
-    /// determinant arithmetic tree once, then computes the matching bound for
-    /// the same matrix within that call.
+    /// Computed by repeated squaring in `O(D³ log k)` operations; `k = 0`
+    /// returns the identity, including for `Matrix<0>`. Products are rounded;
+    /// no certified error bound is provided.
     ///
     /// # Examples
     /// ```
     /// use la_stack::prelude::*;
     ///
     /// # fn main() -> Result<(), LaError> {
-    /// let matrix = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]])?;
-    /// if let Some(estimate) = matrix.det_direct_with_errbound()? {
-    ///     assert_eq!(estimate.determinant(), -2.0);
-    ///     assert!(estimate.absolute_error_bound() >= 0.0);
-    /// }
+    /// // Fibonacci matrix: [[1, 1], [1, 0]]^k = [[F(k+1), F(k)], [F(k), F(k-1)]].
+    /// let fib = Matrix::<2>::try_from_rows([[1.0, 1.0], [1.0, 0.0]])?;
+    /// let p = fib.pow(10)?;
+    /// assert_eq!(p.get(0, 1), Some(55.0));
+    /// assert_eq!(p.get(0, 0), Some(89.0));
+    /// assert_eq!(fib.pow(0)?, Matrix::<2>::identity());
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// # Errors
-    /// Returns [`LaError::NonFinite`] when the determinant or bound computation
-    /// overflows to NaN or infinity. Underflow-sensitive finite computations
-    /// return `Ok(None)` because they remain valid inputs for an exact fallback.
+    /// Returns [`LaError::NonFinite`] if an intermediate product overflows.
     #[inline]
-    pub const fn det_direct_with_errbound(
-        &self,
-    ) -> Result<Option<DeterminantWithErrorBound>, LaError> {
-        if self.det_bound_inputs_have_wide_exponent_margin() {
-            let Some(det) = self.det_direct_arithmetic::<false>() else {
-                cold_path();
-                return Ok(None);
-            };
-            return self.det_direct_with_errbound_from_arithmetic(det);
-        }
-
-        let Some(det) = self.det_direct_arithmetic::<true>() else {
-            cold_path();
-            return Ok(None);
-        };
-        self.det_direct_with_errbound_from_arithmetic(det)
+    pub fn pow(&self, k: u32) -> Result<Self, LaError> {
+        self.power_by_squaring(u64::from(k), ArithmeticOperation::MatrixPower)
     }
 
-    /// Conservative absolute error bound for `det_direct()`.
+    /// Trace of the `k`-th power, `tr(A^k) = Σ λ_i^k`.
     ///
-    /// Returns `Ok(Some(bound))` such that `|det_direct() - det_exact| ≤ bound`
-    /// when every rounded intermediate used by the closed-form determinant and
-    /// bound is normal (or an exact structural zero). Returns `Ok(None)` when
-    /// gradual underflow could invalidate the relative-error analysis, or for
-    /// D ≥ 5 where no fast bound is available.
+    /// These spectral moments count closed walks of length `k` when `A` is a
+    /// graph adjacency matrix. `A^(k−1)` is formed by repeated squaring, and
+    /// the final product is never materialized: only its diagonal is summed,
+    /// as `Σ_{i,j} A^(k−1)[i][j] · A[j][i]`. That costs `O(D³ log k)`
+    /// operations. `k = 0` returns `D`, the trace of the identity. Products are
+    /// rounded; no certified error bound is provided.
     ///
-    /// For D ≤ 4, the bound is derived from the absolute Leibniz sum using
-    /// Shewchuk-style error analysis (see `REFERENCES.md` \[8\] and the
-    /// per-constant docs on [`ERR_COEFF_2`], [`ERR_COEFF_3`], and
-    /// [`ERR_COEFF_4`]). For D = 0 or 1, returns
-    /// `Some(0.0)` since the determinant computation is exact (no
-    /// arithmetic).
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
     ///
-    /// This method does NOT require the `exact` feature — the bounds use
-    /// pure f64 arithmetic and are useful for custom adaptive-precision logic.
+    /// # fn main() -> Result<(), LaError> {
+    /// // Triangle graph: 0 closed walks of length 1, 6 of length 2 and 3.
+    /// let triangle = Matrix::<3>::try_from_rows([
+    ///     [0.0, 1.0, 1.0],
+    ///     [1.0, 0.0, 1.0],
+    ///     [1.0, 1.0, 0.0],
+    /// ])?;
+    /// assert_eq!(triangle.trace_of_power(1)?, 0.0);
+    /// assert_eq!(triangle.trace_of_power(2)?, 6.0);
+    /// assert_eq!(triangle.trace_of_power(3)?, 6.0);
+    /// # Ok(())
+    /// # }
+    /// ```
     ///
-    /// # When to use
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] if a matrix power or the final sum
+    /// overflows.
+    #[inline]
+    pub fn trace_of_power(&self, k: u32) -> Result<f64, LaError> {
+        let Some(exponent) = k.checked_sub(1) else {
+            return Ok(Self::DIM_F64);
+        };
+
+        let power =
+            self.power_by_squaring(u64::from(exponent), ArithmeticOperation::MatrixPowerTrace)?;
+
+        let mut trace = 0.0;
+        for (i, row) in power.rows.iter().enumerate() {
+            for (j, &value) in row.iter().enumerate() {
+                trace = value.mul_add(self.rows[j][i], trace);
+            }
+        }
+        if trace.is_finite() {
+            Ok(trace)
+        } else {
+            cold_path();
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::MatrixPowerTrace,
+            ))
+        }
+    }
+
+    /// Numerical nilpotency test: whether `‖A^D‖_∞ ≤ tol`.
     ///
-    /// Use [`det_direct_with_errbound`](Self::det_direct_with_errbound) when the
-    /// determinant and bound are both needed. This accessor is convenient when
-    /// only the bound is needed.
+    /// A `D×D` matrix is nilpotent exactly when `A^D = 0` (its index never
+    /// exceeds `D`), so this single power decides the question in exact
+    /// arithmetic. Strictly triangular matrices are nilpotent; any matrix with
+    /// a nonzero eigenvalue is not. `A^D` is formed with [`pow`](Self::pow),
+    /// so rounding in the products is absorbed by `tol`; an overflowing
+    /// `A^D` or norm is reported as an error rather than `false`.
+    /// `Matrix<0>` is nilpotent vacuously.
     ///
     /// # Examples
     /// ```
     /// use la_stack::prelude::*;
     ///
     /// # fn main() -> Result<(), LaError> {
-    /// let m = Matrix::<3>::try_from_rows([
-    ///     [1.0, 2.0, 3.0],
-    ///     [4.0, 5.0, 6.0],
-    ///     [7.0, 8.0, 9.0],
+    /// let shift = Matrix::<3>::try_from_rows([
+    ///     [0.0, 1.0, 2.0],
+    ///     [0.0, 0.0, 3.0],
+    ///     [0.0, 0.0, 0.0],
     /// ])?;
-    /// if let Some(bound) = m.det_errbound()? {
-    ///     assert!(bound >= 0.0);
-    /// }
+    /// let tol = Tolerance::try_new(1e-12)?;
+    /// assert!(shift.is_nilpotent(tol)?);
+    /// assert!(!Matrix::<3>::identity().is_nilpotent(tol)?);
     /// # Ok(())
     /// # }
     /// ```
     ///
-    /// # Adaptive precision pattern (requires `exact` feature)
-    /// ```ignore
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] if forming `A^D` or its infinity norm
+    /// overflows.
+    #[inline]
+    pub fn is_nilpotent(&self, tol: Tolerance) -> Result<bool, LaError> {
+        let power = self.power_by_squaring(D as u64, ArithmeticOperation::MatrixPower)?;
+        Ok(power.inf_norm()? <= tol.get())
+    }
+
+    /// Numerical idempotency test: whether `‖A² − A‖_∞ ≤ tol`.
+    ///
+    /// Orthogonal and oblique projectors satisfy `A² = A`; use this to
+    /// validate the output of [`projector`](Self::projector). `A²` is formed
+    /// with one matrix product, so rounding in it is absorbed by `tol`. An
+    /// overflowing `A²` is reported as an error; only a difference that
+    /// overflows after `A²` is formed yields `false`.
+    ///
+    /// # Examples
+    /// ```
     /// use la_stack::prelude::*;
     ///
-    /// fn adaptive_det_sign<const D: usize>(
-    ///     matrix: &Matrix<D>,
-    /// ) -> DeterminantSign {
-    ///     if let Ok(Some(estimate)) = matrix.det_direct_with_errbound() {
-    ///         if estimate.determinant().abs() > estimate.absolute_error_bound() {
-    ///             return if estimate.determinant() > 0.0 {
-    ///                 DeterminantSign::Positive
-    ///             } else {
-    ///                 DeterminantSign::Negative
-    ///             };
-    ///         }
-    ///     }
+    /// # fn main() -> Result<(), LaError> {
+    /// let tol = Tolerance::try_new(1e-12)?;
+    /// let onto_x = Matrix::<2>::try_from_rows([[1.0, 1.0], [0.0, 0.0]])?;
+    /// assert!(onto_x.is_idempotent(tol)?);
+    /// let stretch = Matrix::<2>::try_from_rows([[2.0, 0.0], [0.0, 1.0]])?;
+    /// assert!(!stretch.is_idempotent(tol)?);
+    /// # Ok(())
+    /// # }
+    /// ```
     ///
-    ///     matrix.det_sign_exact()
-    /// }
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] if forming `A²` overflows.
+    #[inline]
+    pub fn is_idempotent(&self, tol: Tolerance) -> Result<bool, LaError> {
+        let square = self.matmul(self, ArithmeticOperation::MatrixPower)?;
+        Ok(square.inf_distance(self) <= tol.get())
+    }
+
+    /// Numerical involution test: whether `‖A² − I‖_∞ ≤ tol`.
     ///
-    /// fn main() -> Result<(), LaError> {
-    ///     assert_eq!(
-    ///         adaptive_det_sign(&Matrix::<3>::identity()),
-    ///         DeterminantSign::Positive
-    ///     );
+    /// Reflections and signed permutations are their own inverses; use this
+    /// to validate the output of
+    /// [`householder_subspace_reflection`](Self::householder_subspace_reflection).
+    /// `A²` is formed with one matrix product, so rounding in it is absorbed
+    /// by `tol`. An overflowing `A²` is reported as an error; only a
+    /// difference that overflows after `A²` is formed yields `false`.
     ///
-    ///     let big = f64::MAX / 2.0;
-    ///     let overflowing = Matrix::<3>::try_from_rows([
-    ///         [0.0, 0.0, 1.0],
-    ///         [big, 0.0, 1.0],
-    ///         [0.0, big, 1.0],
-    ///     ])?;
-    ///     assert_eq!(
-    ///         adaptive_det_sign(&overflowing),
-    ///         DeterminantSign::Positive
-    ///     );
-    ///     Ok(())
-    /// }
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let tol = Tolerance::try_new(1e-12)?;
+    /// let swap = Matrix::<2>::try_from_rows([[0.0, 1.0], [1.0, 0.0]])?;
+    /// assert!(swap.is_involutory(tol)?);
+    /// let shear = Matrix::<2>::try_from_rows([[1.0, 1.0], [0.0, 1.0]])?;
+    /// assert!(!shear.is_involutory(tol)?);
+    /// # Ok(())
+    /// # }
     /// ```
     ///
     /// # Errors
-    /// Returns [`LaError::NonFinite`] when the bound computation overflows to
-    /// NaN or infinity. Underflow-sensitive finite computations return
-    /// `Ok(None)` instead because they are valid inputs for an exact fallback.
+    /// Returns [`LaError::NonFinite`] if forming `A²` overflows.
     #[inline]
-    pub const fn det_errbound(&self) -> Result<Option<f64>, LaError> {
-        match self.det_direct_with_errbound() {
-            Ok(Some(result)) => Ok(Some(result.absolute_error_bound)),
-            Ok(None) => Ok(None),
-            Err(error) => Err(error),
-        }
+    pub fn is_involutory(&self, tol: Tolerance) -> Result<bool, LaError> {
+        let square = self.matmul(self, ArithmeticOperation::MatrixPower)?;
+        Ok(square.inf_distance(&Self::identity()) <= tol.get())
     }
 
-    /// Return whether every non-zero entry is large enough that the complete
-    /// D≤4 determinant and permanent trees cannot gradually underflow.
+    /// Numerical normality test: whether `‖AAᵀ − AᵀA‖_∞ ≤ tol`.
     ///
-    /// The `2^-16` threshold leaves hundreds of binary exponent bits of margin
-    /// even after the D=4 tree's products, FMAs, and binary64 rounding steps.
-    /// Overflow remains possible and is classified after evaluation. Inputs
-    /// below this conservative threshold use per-operation tracking instead.
-    const fn det_bound_inputs_have_wide_exponent_margin(&self) -> bool {
-        const MIN_MAGNITUDE_BITS: u64 = 1007_u64 << 52; // 2^-16
-        const MAGNITUDE_MASK: u64 = !(1_u64 << 63);
+    /// Normal matrices are exactly the unitarily diagonalizable ones, so this
+    /// decides whether an eigensolver may assume orthogonal eigenvectors.
+    /// Symmetric, skew-symmetric, and orthogonal matrices are normal; a
+    /// nonzero triangular matrix with a nonzero off-diagonal entry is not.
+    /// Both Gram products are formed with one matrix product each, so their
+    /// rounding is absorbed by `tol`; for a scale-aware test pass a `tol`
+    /// proportional to `‖A‖²`. An overflowing Gram product is reported as
+    /// an error; only a difference that overflows after both are formed
+    /// yields `false`.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let tol = Tolerance::try_new(1e-12)?;
+    /// let symmetric = Matrix::<2>::try_from_rows([[2.0, 1.0], [1.0, 3.0]])?;
+    /// assert!(symmetric.is_normal(tol)?);
+    /// let shear = Matrix::<2>::try_from_rows([[1.0, 1.0], [0.0, 1.0]])?;
+    /// assert!(!shear.is_normal(tol)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] if forming `AAᵀ` or `AᵀA` overflows.
+    #[inline]
+    pub fn is_normal(&self, tol: Tolerance) -> Result<bool, LaError> {
+        let transpose = self.transpose();
+        let outer = self.matmul(&transpose, ArithmeticOperation::MatrixProduct)?;
+        let inner = transpose.matmul(self, ArithmeticOperation::MatrixProduct)?;
+        Ok(outer.inf_distance(&inner) <= tol.get())
+    }
 
-        if D > 4 {
-            return false;
+    /// Infinity norm of `self − other`, saturating to `+∞` on overflow.
+    #[inline]
+    const fn inf_distance(&self, other: &Self) -> f64 {
+        let mut max_row_sum: f64 = 0.0;
+        let mut r = 0;
+        while r < D {
+            let mut row_sum: f64 = 0.0;
+            let mut c = 0;
+            while c < D {
+                row_sum += (self.rows[r][c] - other.rows[r][c]).abs();
+                c += 1;
+            }
+            max_row_sum = max_row_sum.max(row_sum);
+            r += 1;
         }
+        max_row_sum
+    }
 
-        let mut row = 0;
-        while row < D {
-            let mut col = 0;
-            while col < D {
-                let magnitude_bits = self.rows[row][col].to_bits() & MAGNITUDE_MASK;
-                if magnitude_bits != 0 && magnitude_bits < MIN_MAGNITUDE_BITS {
-                    return false;
+    /// Matrix exponential `exp(A) = Σ_k A^k / k!` by scaling and squaring.
+    ///
+    /// `A` is first scaled by a power of two, `B = A / 2^s`, with the smallest
+    /// `s` for which `‖B‖_∞ ≤ 1/2`; the scaling is exact. `exp(B)` is then
+    /// approximated by its degree-`terms` Taylor polynomial in Horner form, and
+    /// the result is squared `s` times, `exp(A) = exp(B)^(2^s)`
+    /// (`REFERENCES.md` \[12\], Section 9.3, and \[16\]). With `‖B‖_∞ ≤ 1/2`
+    /// the truncation error is below `2^-(terms+1) / (terms+1)!`, so `terms = 14`
+    /// already reaches double precision; the squarings may amplify rounding,
+    /// so no certified error bound is provided. `terms = 0` returns the
+    /// identity, as does the zero matrix for any `terms`.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // exp of a rotation generator is a rotation by one radian.
+    /// let generator = Matrix::<2>::try_from_rows([[0.0, -1.0], [1.0, 0.0]])?;
+    /// let rotation = generator.exp(16)?;
+    /// let (sin, cos) = 1.0_f64.sin_cos();
+    /// assert!((rotation.get(0, 0).unwrap() - cos).abs() <= 1e-15);
+    /// assert!((rotation.get(1, 0).unwrap() - sin).abs() <= 1e-15);
+    /// assert_eq!(Matrix::<2>::zero().exp(16)?, Matrix::<2>::identity());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] if the infinity norm of `A`, a Taylor
+    /// product, or a squaring overflows.
+    #[inline]
+    pub fn exp(self, terms: usize) -> Result<Self, LaError> {
+        let operation = ArithmeticOperation::MatrixExponential;
+
+        let mut squarings = 0_u32;
+        let mut scale = 1.0;
+        let mut scaled_norm = self.inf_norm()?;
+        // At most about 1025 halvings, since the norm is finite.
+        for _ in 0..=f64::MAX_EXP {
+            if scaled_norm <= 0.5 {
+                break;
+            }
+            scaled_norm *= 0.5;
+            scale *= 0.5;
+            squarings += 1;
+        }
+        let mut scaled = self;
+        for row in &mut scaled.rows {
+            for entry in row.iter_mut() {
+                *entry *= scale;
+            }
+        }
+
+        // Horner form: T_k = I + B T_{k+1} / k, with T_{terms+1} = I.
+        let mut taylor = Self::identity();
+        for k in (1..=terms).rev() {
+            #[expect(
+                clippy::cast_precision_loss,
+                reason = "Taylor term counts are far below 2^53 and convert exactly"
+            )]
+            let inv_k = 1.0 / k as f64;
+            taylor = scaled.matmul(&taylor, operation)?;
+            for (i, row) in taylor.rows.iter_mut().enumerate() {
+                for entry in row.iter_mut() {
+                    *entry *= inv_k;
                 }
-                col += 1;
+                row[i] += 1.0;
             }
-            row += 1;
         }
-        true
-    }
 
-    /// Classify a completed determinant tree and construct its matching bound.
-    const fn det_direct_with_errbound_from_arithmetic<const TRACK_UNDERFLOW: bool>(
-        &self,
-        det: FilterArithmetic<TRACK_UNDERFLOW>,
-    ) -> Result<Option<DeterminantWithErrorBound>, LaError> {
-        let bound = match self.det_errbound_from_arithmetic(det) {
-            Ok(Some(bound)) => bound,
-            Ok(None) => return Ok(None),
-            Err(error) => return Err(error),
-        };
-        if !det.value.is_finite() {
-            cold_path();
-            return Err(LaError::non_finite_computation_scalar(
-                ArithmeticOperation::Determinant,
-            ));
+        for _ in 0..squarings {
+            taylor = taylor.matmul(&taylor, operation)?;
         }
-        Ok(Some(DeterminantWithErrorBound {
-            determinant: det.value,
-            absolute_error_bound: bound,
-        }))
+        Ok(taylor)
     }
 
-    /// Compute a bound after the matching determinant tree has been evaluated.
-    const fn det_errbound_from_arithmetic<const TRACK_UNDERFLOW: bool>(
-        &self,
-        det: FilterArithmetic<TRACK_UNDERFLOW>,
-    ) -> Result<Option<f64>, LaError> {
-        if !det.underflow_safe {
-            cold_path();
-            return Ok(None);
+    /// Principal matrix logarithm `log(A)` by inverse scaling and squaring.
+    ///
+    /// Square roots are taken with the Denman–Beavers iteration until
+    /// `X = A^(1/2^s)` satisfies `‖X − I‖_∞ ≤ 1/4`; then
+    /// `log(X) = Σ_k (−1)^(k+1) (X − I)^k / k` is summed to degree `terms` in
+    /// Horner form, and `log(A) = 2^s log(X)` (`REFERENCES.md` \[12\],
+    /// Section 9.4). The truncation error is below `4^-terms / (3 (terms + 1))`,
+    /// so `terms = 24` already reaches double precision; rounding in the
+    /// square roots is amplified by `2^s`, so no certified error bound is
+    /// provided. This inverts [`exp`](Self::exp) for matrices whose
+    /// eigenvalues have imaginary parts in `(−π, π)`. `terms = 0` returns the
+    /// zero matrix, as does the identity for any `terms`.
+    ///
+    /// A real logarithm needs `A` to have no eigenvalues on the closed
+    /// negative real axis. `tol` is only the LU pivot tolerance for the
+    /// inverses inside each square root, so a zero tolerance is valid.
+    /// Each square root stops independently of `tol`: once the relative
+    /// change `‖Y_{k+1} − Y_k‖_∞ / ‖Y_{k+1}‖_∞` is at most `√ε`, one more
+    /// step of the quadratically convergent iteration brings it to rounding
+    /// level and ends it.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[0.0, -1.0], [1.0, 0.0]])?;
+    /// let log = a.exp(20)?.log_via_series(24, DEFAULT_SINGULAR_TOL)?;
+    /// assert!((log.get(1, 0).unwrap_or(f64::NAN) - 1.0).abs() <= 1e-12);
+    /// assert_eq!(
+    ///     Matrix::<2>::identity().log_via_series(24, DEFAULT_SINGULAR_TOL)?,
+    ///     Matrix::<2>::zero()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::Singular`] if an iterate cannot be inverted under
+    /// `tol`, which happens for singular `A` and can happen for eigenvalues on
+    /// the negative real axis. Returns
+    /// [`LaError::NotConverged`] if a square root has not reached the `√ε`
+    /// threshold within 100 iterations, or if `A` is still farther than `1/4`
+    /// from the identity after 64 square roots; both typically mean `A` has an
+    /// eigenvalue on or near the negative real axis. Returns [`LaError::NonFinite`] if a
+    /// product, an inverse, or the final scaling overflows.
+    #[inline]
+    pub fn log_via_series(self, terms: usize, tol: Tolerance) -> Result<Self, LaError> {
+        let operation = ArithmeticOperation::MatrixLogarithm;
+        let identity = Self::identity();
+
+        let mut root = self;
+        let mut square_roots = 0;
+        let mut scale = 1.0;
+        loop {
+            let distance = root.inf_distance(&identity);
+            if distance <= 0.25 {
+                break;
+            }
+            if square_roots == Self::LOG_MAX_SQUARE_ROOTS {
+                cold_path();
+                return Err(LaError::not_converged(
+                    Self::LOG_MAX_SQUARE_ROOTS,
+                    distance,
+                    0.25,
+                ));
+            }
+            root = root.denman_beavers_sqrt(tol)?;
+            square_roots += 1;
+            scale *= 2.0;
         }
 
-        match D {
-            0 | 1 => Self::computed_scalar_result(ArithmeticOperation::DeterminantErrorBound, 0.0),
-            2 => {
-                let r = &self.rows;
-                let product_0 = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r[0][0], r[1][1]);
-                let product_1 = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r[0][1], r[1][0]);
-                let mut permanent = FilterArithmetic::<TRACK_UNDERFLOW>::add_non_negative(
-                    product_0.value.abs(),
-                    product_1.value.abs(),
-                );
-                permanent.underflow_safe &= product_0.underflow_safe && product_1.underflow_safe;
-                Self::certified_error_bound(ERR_COEFF_2, permanent)
+        // Horner form: P_k = c_k I + E P_{k+1} with c_k = (−1)^(k+1) / k,
+        // and log(I + E) = E P_1.
+        let mut e = root;
+        for (i, row) in e.rows.iter_mut().enumerate() {
+            row[i] -= 1.0;
+        }
+        let mut series = Self::zero();
+        for k in (1..=terms).rev() {
+            #[expect(
+                clippy::cast_precision_loss,
+                reason = "series term counts are far below 2^53 and convert exactly"
+            )]
+            let c_k = if k % 2 == 1 { 1.0 } else { -1.0 } / k as f64;
+            series = e.matmul(&series, operation)?;
+            for (i, row) in series.rows.iter_mut().enumerate() {
+                row[i] += c_k;
             }
-            3 => {
-                let r = &self.rows;
-                let permanent = Self::det3_abs_permanent_elements::<TRACK_UNDERFLOW>(
-                    [r[0][0], r[0][1], r[0][2]],
-                    [r[1][0], r[1][1], r[1][2]],
-                    [r[2][0], r[2][1], r[2][2]],
-                );
-                Self::certified_error_bound(ERR_COEFF_3, permanent)
+        }
+        let log_root = e.matmul(&series, operation)?;
+
+        let mut rows = log_root.rows;
+        for entry in rows.as_flattened_mut() {
+            *entry *= scale;
+        }
+        Self::from_computation(rows, operation)
+    }
+
+    /// Cap on the number of square roots [`log_via_series`](Self::log_via_series)
+    /// takes; each halves the logarithm, so 64 covers every finite input.
+    const LOG_MAX_SQUARE_ROOTS: usize = 64;
+
+    /// Iteration cap for each Denman–Beavers square root.
+    const DENMAN_BEAVERS_MAX_ITERS: usize = 100;
+
+    /// Relative change `√ε = 2^-26` after which one more Denman–Beavers step
+    /// reaches rounding level.
+    const DENMAN_BEAVERS_SETTLE: f64 = 1.490_116_119_384_765_6e-8;
+
+    /// Principal square root by the Denman–Beavers iteration
+    /// `Y ← (Y + Z⁻¹) / 2`, `Z ← (Z + Y⁻¹) / 2` from `Y = A`, `Z = I`, with
+    /// LU pivot tolerance `tol`.
+    fn denman_beavers_sqrt(self, tol: Tolerance) -> Result<Self, LaError> {
+        let operation = ArithmeticOperation::MatrixLogarithm;
+        let mut y = self;
+        let mut z = Self::identity();
+        let mut change = f64::INFINITY;
+        let mut settled = false;
+        for _ in 0..Self::DENMAN_BEAVERS_MAX_ITERS {
+            let y_inv = y.lu(tol)?.inverse()?;
+            let z_inv = z.lu(tol)?.inverse()?;
+            let mut next_y = y.rows;
+            let mut next_z = z.rows;
+            for ((ny, &zi), (nz, &yi)) in next_y
+                .as_flattened_mut()
+                .iter_mut()
+                .zip(z_inv.rows.as_flattened())
+                .zip(
+                    next_z
+                        .as_flattened_mut()
+                        .iter_mut()
+                        .zip(y_inv.rows.as_flattened()),
+                )
+            {
+                *ny = 0.5_f64.mul_add(*ny, 0.5 * zi);
+                *nz = 0.5_f64.mul_add(*nz, 0.5 * yi);
             }
-            4 => self.det4_errbound::<TRACK_UNDERFLOW>(),
-            _ => {
-                cold_path();
-                Ok(None)
+            let next_y = Self::from_computation(next_y, operation)?;
+            change = next_y.inf_distance(&y);
+            y = next_y;
+            z = Self::from_computation(next_z, operation)?;
+            if settled {
+                return Ok(y);
             }
+            settled = change <= Self::DENMAN_BEAVERS_SETTLE * y.inf_norm()?;
         }
+        cold_path();
+        Err(LaError::not_converged(
+            Self::DENMAN_BEAVERS_MAX_ITERS,
+            change,
+            Self::DENMAN_BEAVERS_SETTLE,
+        ))
     }
 
-    /// Compute the D=4 determinant error bound after the dimension dispatch.
-    const fn det4_errbound<const TRACK_UNDERFLOW: bool>(&self) -> Result<Option<f64>, LaError> {
-        if !TRACK_UNDERFLOW && let Some(input) = Det4SharedMinorInput::try_new(self) {
-            return Self::certified_error_bound(
-                ERR_COEFF_4,
-                FilterArithmetic::<TRACK_UNDERFLOW> {
-                    value: Self::det4_dense_abs_permanent_elements(input),
-                    underflow_safe: true,
-                },
-            );
+    /// Isotropic part `(tr(A) / D) I`.
+    ///
+    /// Together with [`deviatoric`](Self::deviatoric) this splits a tensor into
+    /// its spherical and trace-free parts, `A = iso(A) + dev(A)`. The mean
+    /// diagonal entry is accumulated as `Σ a[i][i] / D`, dividing each term
+    /// first so it cannot overflow. `Matrix<0>` has no diagonal and returns
+    /// itself.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[3.0, 1.0], [2.0, 5.0]])?;
+    /// let iso = a.isotropic_part();
+    ///
+    /// assert_eq!(iso.get(0, 0), Some(4.0));
+    /// assert_eq!(iso.get(0, 1), Some(0.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn isotropic_part(&self) -> Self {
+        let mean = self.mean_diagonal();
+        let mut rows = [[0.0; D]; D];
+        let mut i = 0;
+        while i < D {
+            rows[i][i] = mean;
+            i += 1;
         }
+        Self::from_rows_unchecked(rows)
+    }
 
-        let r = &self.rows;
-        let mut permanent = if r[0][3] == 0.0 {
-            FilterArithmetic {
-                value: 0.0,
-                underflow_safe: true,
+    /// Deviatoric (trace-free) part `A − (tr(A) / D) I`.
+    ///
+    /// Off-diagonal entries are copied unchanged and each diagonal entry has the
+    /// mean diagonal entry subtracted, so the result has zero trace up to
+    /// rounding. See [`isotropic_part`](Self::isotropic_part) for the
+    /// complementary part and the accumulation order.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[3.0, 1.0], [2.0, 5.0]])?;
+    /// let dev = a.deviatoric()?;
+    ///
+    /// assert_eq!(dev.get(0, 0), Some(-1.0));
+    /// assert_eq!(dev.get(1, 1), Some(1.0));
+    /// assert_eq!(dev.get(1, 0), Some(2.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] with matrix coordinates if subtracting the
+    /// mean from a diagonal entry overflows.
+    #[inline]
+    pub const fn deviatoric(&self) -> Result<Self, LaError> {
+        let mean = self.mean_diagonal();
+        let mut rows = self.rows;
+        let mut i = 0;
+        while i < D {
+            rows[i][i] -= mean;
+            i += 1;
+        }
+        Self::from_computation(rows, ArithmeticOperation::IsotropicSplit)
+    }
+
+    /// Symmetric part `(A + Aᵀ) / 2`.
+    ///
+    /// For a velocity gradient this is the strain-rate tensor; with
+    /// [`skew_symmetric_part`](Self::skew_symmetric_part) it splits
+    /// `A = sym(A) + skew(A)`. Each off-diagonal mean is computed once as
+    /// `a/2 + b/2` and mirrored, so the result is exactly symmetric and cannot
+    /// overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let grad = Matrix::<2>::try_from_rows([[1.0, 4.0], [0.0, 2.0]])?;
+    /// let strain = grad.symmetric_part();
+    ///
+    /// assert_eq!(strain.get(0, 1), Some(2.0));
+    /// assert_eq!(strain.get(1, 0), Some(2.0));
+    /// assert_eq!(strain.get(1, 1), Some(2.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn symmetric_part(&self) -> Self {
+        let mut rows = self.rows;
+        let mut r = 0;
+        while r < D {
+            let mut c = r + 1;
+            while c < D {
+                let mean = 0.5f64.mul_add(self.rows[r][c], 0.5 * self.rows[c][r]);
+                rows[r][c] = mean;
+                rows[c][r] = mean;
+                c += 1;
             }
-        } else {
-            let pc3 = Self::det3_abs_permanent_elements::<TRACK_UNDERFLOW>(
-                [r[1][0], r[1][1], r[1][2]],
-                [r[2][0], r[2][1], r[2][2]],
-                [r[3][0], r[3][1], r[3][2]],
-            );
-            let mut term = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r[0][3].abs(), pc3.value);
-            term.underflow_safe &= pc3.underflow_safe;
-            term
-        };
-        if r[0][2] != 0.0 {
-            let pc2 = Self::det3_abs_permanent_elements::<TRACK_UNDERFLOW>(
-                [r[1][0], r[1][1], r[1][3]],
-                [r[2][0], r[2][1], r[2][3]],
-                [r[3][0], r[3][1], r[3][3]],
-            );
-            let prior_safe = permanent.underflow_safe && pc2.underflow_safe;
-            permanent = FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(
-                r[0][2].abs(),
-                pc2.value,
-                permanent.value,
-            );
-            permanent.underflow_safe &= prior_safe;
+            r += 1;
         }
-        if r[0][1] != 0.0 {
-            let pc1 = Self::det3_abs_permanent_elements::<TRACK_UNDERFLOW>(
-                [r[1][0], r[1][2], r[1][3]],
-                [r[2][0], r[2][2], r[2][3]],
-                [r[3][0], r[3][2], r[3][3]],
-            );
-            let prior_safe = permanent.underflow_safe && pc1.underflow_safe;
-            permanent = FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(
-                r[0][1].abs(),
-                pc1.value,
-                permanent.value,
-            );
-            permanent.underflow_safe &= prior_safe;
+        // Halving and adding two finite values cannot overflow.
+        Self::from_rows_unchecked(rows)
+    }
+
+    /// Skew-symmetric part `(A − Aᵀ) / 2`.
+    ///
+    /// For a velocity gradient this is the spin (vorticity) tensor, the
+    /// complement of [`symmetric_part`](Self::symmetric_part). The diagonal is
+    /// zero and each lower entry is the exact negation of its upper mirror,
+    /// so the result is exactly skew-symmetric and cannot overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let grad = Matrix::<2>::try_from_rows([[1.0, 4.0], [0.0, 2.0]])?;
+    /// let spin = grad.skew_symmetric_part();
+    ///
+    /// assert_eq!(spin.get(0, 1), Some(2.0));
+    /// assert_eq!(spin.get(1, 0), Some(-2.0));
+    /// assert_eq!(spin.get(0, 0), Some(0.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn skew_symmetric_part(&self) -> Self {
+        let mut rows = [[0.0; D]; D];
+        let mut r = 0;
+        while r < D {
+            let mut c = r + 1;
+            while c < D {
+                let half_diff = 0.5f64.mul_add(self.rows[r][c], -0.5 * self.rows[c][r]);
+                rows[r][c] = half_diff;
+                rows[c][r] = -half_diff;
+                c += 1;
+            }
+            r += 1;
         }
-        if r[0][0] != 0.0 {
-            let pc0 = Self::det3_abs_permanent_elements::<TRACK_UNDERFLOW>(
-                [r[1][1], r[1][2], r[1][3]],
-                [r[2][1], r[2][2], r[2][3]],
-                [r[3][1], r[3][2], r[3][3]],
-            );
-            let prior_safe = permanent.underflow_safe && pc0.underflow_safe;
-            permanent = FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(
-                r[0][0].abs(),
-                pc0.value,
-                permanent.value,
-            );
-            permanent.underflow_safe &= prior_safe;
+        // Halving and subtracting two finite values cannot overflow.
+        Self::from_rows_unchecked(rows)
+    }
+
+    /// Mean diagonal entry `tr(A) / D`, or `0.0` for `D == 0`.
+    ///
+    /// Each term is divided by `D` before summation, so the sum is bounded by
+    /// the largest stored magnitude and cannot overflow.
+    const fn mean_diagonal(&self) -> f64 {
+        let mut mean = 0.0;
+        let mut i = 0;
+        while i < D {
+            mean += self.rows[i][i] / Self::DIM_F64;
+            i += 1;
         }
-        Self::certified_error_bound(ERR_COEFF_4, permanent)
+        mean
     }
 
-    /// Evaluate a 3×3 determinant expansion with a guarded sparse fallback.
+    /// Signed sum of each row, `(Σ_c a[r][c])_r`.
     ///
-    /// When all three first-row coefficients are non-zero, one branch-free
-    /// closed form is used. The sparse fallback protects the public
-    /// [`det_direct`](Self::det_direct) contract: a mathematically absent term
-    /// must not compute an overflowing minor and poison the determinant with
-    /// `0.0 * inf == NaN`. Nonzero terms keep the same fused multiply-add
-    /// ordering as the closed-form expansion.
-    #[expect(
-        clippy::inline_always,
-        reason = "det_direct callers must eliminate unused filter-safety bookkeeping"
-    )]
-    #[inline(always)]
-    const fn det3_elements<const TRACK_UNDERFLOW: bool>(
-        r0: [f64; 3],
-        r1: [f64; 3],
-        r2: [f64; 3],
-    ) -> FilterArithmetic<TRACK_UNDERFLOW> {
-        let dense = (r0[0] != 0.0) && (r0[1] != 0.0) && (r0[2] != 0.0);
-        if !TRACK_UNDERFLOW && dense {
-            let m00 = r1[1].mul_add(r2[2], -(r1[2] * r2[1]));
-            let m01 = r1[0].mul_add(r2[2], -(r1[2] * r2[0]));
-            let m02 = r1[0].mul_add(r2[1], -(r1[1] * r2[0]));
-            return FilterArithmetic {
-                value: r0[0].mul_add(m00, (-r0[1]).mul_add(m01, r0[2] * m02)),
-                underflow_safe: true,
-            };
+    /// Unlike [`inf_norm`](Self::inf_norm), entries are summed with their signs,
+    /// so a row-stochastic matrix has all row sums equal to one. Sums are
+    /// accumulated in `f64` with ordinary addition; no certified rounding bound
+    /// is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let m = Matrix::<2>::try_from_rows([[0.25, 0.75], [0.5, 0.5]])?;
+    /// assert_eq!(m.row_sums()?.into_array(), [1.0, 1.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] with the step index of the first row
+    /// whose sum overflows to NaN or infinity.
+    #[inline]
+    pub const fn row_sums(&self) -> Result<Vector<D>, LaError> {
+        let mut sums = [0.0; D];
+        let mut r = 0;
+        while r < D {
+            let mut c = 0;
+            while c < D {
+                sums[r] += self.rows[r][c];
+                c += 1;
+            }
+            r += 1;
         }
+        Vector::from_computation(sums, ArithmeticOperation::MatrixRowSums)
+    }
 
-        let mut det = if r0[2] == 0.0 {
-            FilterArithmetic {
-                value: 0.0,
-                underflow_safe: true,
+    /// Signed sum of each column, `(Σ_r a[r][c])_c`.
+    ///
+    /// A column-stochastic matrix has all column sums equal to one; together
+    /// with [`row_sums`](Self::row_sums) this checks double stochasticity.
+    /// Sums are accumulated in `f64` with ordinary addition; no certified
+    /// rounding bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let m = Matrix::<2>::try_from_rows([[0.25, 0.75], [0.75, 0.25]])?;
+    /// assert_eq!(m.row_sums()?.into_array(), [1.0, 1.0]);
+    /// assert_eq!(m.col_sums()?.into_array(), [1.0, 1.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] with the step index of the first column
+    /// whose sum overflows to NaN or infinity.
+    #[inline]
+    pub const fn col_sums(&self) -> Result<Vector<D>, LaError> {
+        let mut sums = [0.0; D];
+        let mut r = 0;
+        while r < D {
+            let mut c = 0;
+            while c < D {
+                sums[c] += self.rows[r][c];
+                c += 1;
             }
-        } else {
-            let subtrahend = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r1[1], r2[0]);
-            let mut m02 =
-                FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(r1[0], r2[1], -subtrahend.value);
-            m02.underflow_safe &= subtrahend.underflow_safe;
-            let mut term = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r0[2], m02.value);
-            term.underflow_safe &= m02.underflow_safe;
-            term
-        };
-        if r0[1] != 0.0 {
-            let subtrahend = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r1[2], r2[0]);
-            let mut m01 =
-                FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(r1[0], r2[2], -subtrahend.value);
-            m01.underflow_safe &= subtrahend.underflow_safe;
-            let prior_safe = det.underflow_safe && m01.underflow_safe;
-            det = FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(-r0[1], m01.value, det.value);
-            det.underflow_safe &= prior_safe;
+            r += 1;
         }
-        if r0[0] != 0.0 {
-            let subtrahend = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r1[2], r2[1]);
-            let mut m00 =
-                FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(r1[1], r2[2], -subtrahend.value);
-            m00.underflow_safe &= subtrahend.underflow_safe;
-            let prior_safe = det.underflow_safe && m00.underflow_safe;
-            det = FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(r0[0], m00.value, det.value);
-            det.underflow_safe &= prior_safe;
+        Vector::from_computation(sums, ArithmeticOperation::MatrixColumnSums)
+    }
+
+    /// Interval containing the real part of every eigenvalue, from the
+    /// Gershgorin circle theorem.
+    ///
+    /// Every eigenvalue lies in some disc centred at `a[i][i]` with radius
+    /// `r_i = Σ_{j≠i} |a[i][j]|`, so the returned `(lower, upper)` is
+    /// `(min_i (a[i][i] − r_i), max_i (a[i][i] + r_i))`; see `REFERENCES.md`
+    /// \[12\]. This costs `O(D²)` and is useful for stability checks and
+    /// positive-definiteness pre-screening: a symmetric matrix with
+    /// `lower > 0` is positive definite. Radii and disc endpoints are rounded
+    /// `f64` sums, so the bounds are not certified against rounding error.
+    /// For `Matrix<0>` there are no eigenvalues and `(0.0, 0.0)` is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Eigenvalues are (7 ± √5) / 2 ≈ 2.38 and 4.62.
+    /// let a = Matrix::<2>::try_from_rows([[4.0, 1.0], [1.0, 3.0]])?;
+    /// assert_eq!(a.gershgorin_bounds()?, (2.0, 5.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] with the row index as the step if a
+    /// radius or disc endpoint overflows.
+    #[inline]
+    pub fn gershgorin_bounds(&self) -> Result<(f64, f64), LaError> {
+        let mut bounds: Option<(f64, f64)> = None;
+        for (i, row) in self.rows.iter().enumerate() {
+            let radius: f64 = row
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, value)| value.abs())
+                .sum();
+            let lower = row[i] - radius;
+            let upper = row[i] + radius;
+            if !lower.is_finite() || !upper.is_finite() {
+                cold_path();
+                return Err(LaError::non_finite_computation_step(
+                    ArithmeticOperation::GershgorinBounds,
+                    i,
+                ));
+            }
+            bounds = Some(bounds.map_or((lower, upper), |(lo, hi)| (lo.min(lower), hi.max(upper))));
         }
-        det
+        Ok(bounds.unwrap_or((0.0, 0.0)))
     }
 
-    /// Evaluate a 3×3 absolute permanent while skipping zero coefficients.
+    /// Returns `true` if the matrix is approximately symmetric within a relative tolerance.
     ///
-    /// This mirrors [`det3_elements`](Self::det3_elements) for error-bound
-    /// computation: absent determinant terms should not force evaluation of an
-    /// overflowing absolute minor.
-    #[expect(
-        clippy::inline_always,
-        reason = "error-bound call-site specialization avoids tracked-helper overhead"
-    )]
-    #[inline(always)]
-    const fn det3_abs_permanent_elements<const TRACK_UNDERFLOW: bool>(
-        r0: [f64; 3],
-        r1: [f64; 3],
-        r2: [f64; 3],
-    ) -> FilterArithmetic<TRACK_UNDERFLOW> {
-        let dense = (r0[0] != 0.0) && (r0[1] != 0.0) && (r0[2] != 0.0);
-        if !TRACK_UNDERFLOW && dense {
-            let pm00 = (r1[1] * r2[2]).abs() + (r1[2] * r2[1]).abs();
-            let pm01 = (r1[0] * r2[2]).abs() + (r1[2] * r2[0]).abs();
-            let pm02 = (r1[0] * r2[1]).abs() + (r1[1] * r2[0]).abs();
-            return FilterArithmetic {
-                value: r0[2]
-                    .abs()
-                    .mul_add(pm02, r0[1].abs().mul_add(pm01, r0[0].abs() * pm00)),
-                underflow_safe: true,
-            };
-        }
-
-        let mut permanent = if r0[2] == 0.0 {
-            FilterArithmetic {
-                value: 0.0,
-                underflow_safe: true,
-            }
-        } else {
-            let product_0 = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r1[0], r2[1]);
-            let product_1 = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r1[1], r2[0]);
-            let mut pm02 = FilterArithmetic::<TRACK_UNDERFLOW>::add_non_negative(
-                product_0.value.abs(),
-                product_1.value.abs(),
-            );
-            pm02.underflow_safe &= product_0.underflow_safe && product_1.underflow_safe;
-            let mut term = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r0[2].abs(), pm02.value);
-            term.underflow_safe &= pm02.underflow_safe;
-            term
-        };
-        if r0[1] != 0.0 {
-            let product_0 = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r1[0], r2[2]);
-            let product_1 = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r1[2], r2[0]);
-            let mut pm01 = FilterArithmetic::<TRACK_UNDERFLOW>::add_non_negative(
-                product_0.value.abs(),
-                product_1.value.abs(),
-            );
-            pm01.underflow_safe &= product_0.underflow_safe && product_1.underflow_safe;
-            let prior_safe = permanent.underflow_safe && pm01.underflow_safe;
-            permanent = FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(
-                r0[1].abs(),
-                pm01.value,
-                permanent.value,
-            );
-            permanent.underflow_safe &= prior_safe;
-        }
-        if r0[0] != 0.0 {
-            let product_0 = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r1[1], r2[2]);
-            let product_1 = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r1[2], r2[1]);
-            let mut pm00 = FilterArithmetic::<TRACK_UNDERFLOW>::add_non_negative(
-                product_0.value.abs(),
-                product_1.value.abs(),
-            );
-            pm00.underflow_safe &= product_0.underflow_safe && product_1.underflow_safe;
-            let prior_safe = permanent.underflow_safe && pm00.underflow_safe;
-            permanent = FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(
-                r0[0].abs(),
-                pm00.value,
-                permanent.value,
-            );
-            permanent.underflow_safe &= prior_safe;
-        }
-        permanent
-    }
-
-    /// Finish a determinant error bound only when its full arithmetic tree is
-    /// outside the gradual-underflow regime.
-    const fn certified_error_bound<const TRACK_UNDERFLOW: bool>(
-        coefficient: f64,
-        permanent: FilterArithmetic<TRACK_UNDERFLOW>,
-    ) -> Result<Option<f64>, LaError> {
-        let mut bound = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(coefficient, permanent.value);
-        bound.underflow_safe &= permanent.underflow_safe;
-        if bound.underflow_safe {
-            Self::computed_scalar_result(ArithmeticOperation::DeterminantErrorBound, bound.value)
-        } else {
-            cold_path();
-            Ok(None)
-        }
-    }
-
-    /// Return a computed scalar result for a matrix with finite stored entries.
-    const fn computed_scalar_result(
-        operation: ArithmeticOperation,
-        value: f64,
-    ) -> Result<Option<f64>, LaError> {
-        if value.is_finite() {
-            Ok(Some(value))
-        } else {
-            Err(LaError::non_finite_computation_scalar(operation))
-        }
-    }
-}
-
-impl<const D: usize> Default for Matrix<D> {
+    /// Two entries `self[r][c]` and `self[c][r]` are considered equal (for the
+    /// purposes of symmetry) when
+    /// `|self[r][c] - self[c][r]| <= rel_tol * max(1.0, inf_norm(self))`.
+    /// This is a diagnostic predicate for applications that have an
+    /// approximation-specific symmetry threshold. It is not the precondition
+    /// used by [`ldlt`](Self::ldlt), which requires exact mirrored-entry
+    /// equality so the returned factors represent the original matrix.
+    ///
+    /// Use [`first_asymmetry`](Self::first_asymmetry) to locate the first
+    /// offending pair when this returns `Ok(false)`.
+    ///
+    /// The `rel_tol` argument is a [`Tolerance`], so raw caller input must be
+    /// finite and non-negative before it can reach this predicate. Use
+    /// [`Tolerance::try_new`] when accepting a raw `f64`; negative, NaN, and
+    /// infinite tolerances return
+    /// [`LaError::InvalidTolerance`].
+    ///
+    /// # Overflow handling
+    /// A finite matrix can return [`LaError::NonFinite`] with matrix coordinates
+    /// if computing the scaled symmetry tolerance overflows to NaN or infinity.
+    /// If both stored entries are finite but their difference overflows to ±∞,
+    /// the pair is reported as asymmetric.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[4.0, 2.0], [2.0, 3.0]])?;
+    /// let tol = Tolerance::try_new(1e-12)?;
+    /// assert!(a.is_symmetric(tol)?);
+    ///
+    /// let b = Matrix::<2>::try_from_rows([[4.0, 2.0], [3.0, 3.0]])?;
+    /// assert!(!b.is_symmetric(tol)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] with matrix coordinates when computing the
+    /// scaled symmetry tolerance overflows to NaN or infinity.
     #[inline]
-    fn default() -> Self {
-        Self::zero()
+    pub fn is_symmetric(&self, rel_tol: Tolerance) -> Result<bool, LaError> {
+        Ok(self.first_asymmetry(rel_tol)?.is_none())
     }
-}
 
-#[cfg(all(doc, feature = "exact"))]
-mod det_errbound_doctests {
-    /// ```rust
-    /// use la_stack::prelude::*;
+    /// Returns the indices `(r, c)` (with `r < c`) of the first off-diagonal
+    /// pair that violates approximate symmetry, or `None` if the matrix is
+    /// symmetric within `rel_tol`.
     ///
-    /// fn adaptive_det_sign<const D: usize>(
-    ///     matrix: &Matrix<D>,
-    /// ) -> DeterminantSign {
-    ///     if let Ok(Some(estimate)) = matrix.det_direct_with_errbound() {
-    ///         if estimate.determinant().abs() > estimate.absolute_error_bound() {
-    ///             return if estimate.determinant() > 0.0 {
-    ///                 DeterminantSign::Positive
-    ///             } else {
-    ///                 DeterminantSign::Negative
-    ///             };
-    ///         }
-    ///     }
+    /// Iteration order is row-major over the strict upper triangle, so the
+    /// returned indices are the lexicographically smallest such pair.  The
+    /// predicate is the same as [`is_symmetric`](Self::is_symmetric):
+    /// `|self[r][c] - self[c][r]| <= rel_tol * max(1.0, inf_norm(self))`.
+    /// It is intentionally distinct from the exact equality required by
+    /// [`ldlt`](Self::ldlt).
     ///
-    ///     matrix.det_sign_exact()
-    /// }
+    /// A finite matrix can return [`LaError::NonFinite`] with matrix coordinates
+    /// if computing the scaled symmetry tolerance overflows to NaN or infinity.
+    /// If both stored entries are finite but their difference overflows to ±∞,
+    /// the pair is reported as asymmetric.
     ///
-    /// # fn main() -> Result<(), LaError> {
-    /// let identity = Matrix::<3>::identity();
-    /// assert_eq!(
-    ///     adaptive_det_sign(&identity),
-    ///     DeterminantSign::Positive
-    /// );
+    /// The `rel_tol` argument is a [`Tolerance`], so raw caller input must be
+    /// finite and non-negative before it can reach this predicate. Use
+    /// [`Tolerance::try_new`] when accepting a raw `f64`; negative, NaN, and
+    /// infinite tolerances return
+    /// [`LaError::InvalidTolerance`].
     ///
-    /// let singular = Matrix::<3>::try_from_rows([
-    ///     [1.0, 2.0, 3.0],
-    ///     [4.0, 5.0, 6.0],
-    ///     [7.0, 8.0, 9.0],
-    /// ])?;
-    /// assert_eq!(adaptive_det_sign(&singular), DeterminantSign::Zero);
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
     ///
-    /// let big = f64::MAX / 2.0;
-    /// let overflowing = Matrix::<3>::try_from_rows([
-    ///     [0.0, 0.0, 1.0],
-    ///     [big, 0.0, 1.0],
-    ///     [0.0, big, 1.0],
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<3>::try_from_rows([
+    ///     [1.0, 2.0, 0.0],
+    ///     [2.0, 4.0, 5.0],
+    ///     [0.0, 6.0, 9.0], // 6.0 breaks symmetry with a[1][2] = 5.0
     /// ])?;
-    /// assert_eq!(
-    ///     adaptive_det_sign(&overflowing),
-    ///     DeterminantSign::Positive
-    /// );
+    /// let tol = Tolerance::try_new(1e-12)?;
+    /// assert_eq!(a.first_asymmetry(tol)?, Some((1, 2)));
+    /// assert_eq!(Matrix::<3>::identity().first_asymmetry(tol)?, None);
     /// # Ok(())
     /// # }
     /// ```
-    fn adaptive_precision_pattern() {}
-}
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] with matrix coordinates when computing the
+    /// scaled symmetry tolerance overflows to NaN or infinity.
+    #[inline]
+    pub fn first_asymmetry(&self, rel_tol: Tolerance) -> Result<Option<(usize, usize)>, LaError> {
+        let eps = self.symmetry_epsilon(rel_tol)?;
+        for r in 0..D {
+            for c in (r + 1)..D {
+                let upper = self.rows[r][c];
+                let lower = self.rows[c][r];
 
-#[cfg(test)]
-mod tests {
-    use core::hint::black_box;
+                let diff = (upper - lower).abs();
+                if !diff.is_finite() || diff > eps {
+                    cold_path();
+                    return Ok(Some((r, c)));
+                }
+            }
+        }
+        Ok(None)
+    }
 
-    use approx::assert_abs_diff_eq;
-    use pastey::paste;
+    /// Whether this is a permutation matrix, within `tol` entrywise.
+    ///
+    /// Every row and every column must contain exactly one entry with
+    /// `|a[r][c] − 1| ≤ tol`, and every other entry must satisfy
+    /// `|a[r][c]| ≤ tol`. Use `tol < 0.5` so no entry can be close to both `0`
+    /// and `1`. This validates permutation matrices assembled elsewhere, for
+    /// example from the `source_rows` returned by
+    /// [`Lu::into_parts`](crate::Lu::into_parts). `Matrix<0>` is the empty
+    /// permutation.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let tol = Tolerance::try_new(1e-12)?;
+    /// let swap = Matrix::<2>::try_from_rows([[0.0, 1.0], [1.0, 0.0]])?;
+    /// assert!(swap.is_permutation(tol));
+    ///
+    /// let scaled = Matrix::<2>::try_from_rows([[0.0, 2.0], [2.0, 0.0]])?;
+    /// assert!(!scaled.is_permutation(tol));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_permutation(&self, tol: Tolerance) -> bool {
+        let tol = tol.get();
+        let mut col_hits = [0_usize; D];
+        let mut r = 0;
+        while r < D {
+            let mut row_hits = 0_usize;
+            let mut c = 0;
+            while c < D {
+                let value = self.rows[r][c];
+                if (value - 1.0).abs() <= tol {
+                    row_hits += 1;
+                    col_hits[c] += 1;
+                    if row_hits > 1 || col_hits[c] > 1 {
+                        return false;
+                    }
+                } else if value.abs() > tol {
+                    return false;
+                }
+                c += 1;
+            }
+            if row_hits != 1 {
+                return false;
+            }
+            r += 1;
+        }
+        // D rows each contributed one hit and no column holds two, so every
+        // column holds exactly one.
+        true
+    }
+
+    /// Whether every row is diagonally dominant.
+    ///
+    /// Checks `|a[i][i]| ≥ Σ_{j≠i} |a[i][j]|` for each row `i`, or the strict
+    /// `>` when `strict` is `true`. Strictly row-dominant matrices are
+    /// nonsingular, and Gaussian elimination on them is stable without
+    /// pivoting (Golub & Van Loan, `REFERENCES.md` \[12\]). An off-diagonal
+    /// sum that overflows is larger than any finite diagonal, so the row fails.
+    /// `Matrix<0>` is vacuously dominant.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[3.0, -1.0], [1.0, 1.0]])?;
+    /// assert!(a.is_diagonally_dominant(false));
+    /// assert!(!a.is_diagonally_dominant(true)); // row 1 only ties
+    ///
+    /// let b = Matrix::<2>::try_from_rows([[1.0, 2.0], [0.0, 1.0]])?;
+    /// assert!(!b.is_diagonally_dominant(false));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_diagonally_dominant(&self, strict: bool) -> bool {
+        let mut r = 0;
+        while r < D {
+            let mut off_diagonal = 0.0;
+            let mut c = 0;
+            while c < D {
+                if c != r {
+                    off_diagonal += self.rows[r][c].abs();
+                }
+                c += 1;
+            }
+            let diagonal = self.rows[r][r].abs();
+            let dominant = if strict {
+                diagonal > off_diagonal
+            } else {
+                diagonal >= off_diagonal
+            };
+            if !dominant {
+                return false;
+            }
+            r += 1;
+        }
+        true
+    }
+
+    /// Whether this is a correlation matrix, within `tol`.
+    ///
+    /// Every mirrored pair must satisfy `|a[r][c] − a[c][r]| ≤ tol`, every
+    /// diagonal entry `|a[i][i] − 1| ≤ tol`, and every off-diagonal entry
+    /// `|a[r][c]| ≤ 1 + tol`. The matrix must also be positive definite. That
+    /// is checked by factoring its [`symmetric_part`](Self::symmetric_part)
+    /// with [`ldlt`](Self::ldlt) at the same `tol`, because `ldlt` itself
+    /// requires exact symmetry. A singular correlation matrix, such as one
+    /// for two perfectly correlated variables, therefore returns `false`.
+    /// `Matrix<0>` is the empty correlation matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let tol = Tolerance::try_new(1e-12)?;
+    /// let r = Matrix::<2>::try_from_rows([[1.0, 0.3], [0.3, 1.0]])?;
+    /// assert!(r.is_correlation(tol));
+    ///
+    /// let too_large = Matrix::<2>::try_from_rows([[1.0, 1.5], [1.5, 1.0]])?;
+    /// assert!(!too_large.is_correlation(tol));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_correlation(&self, tol: Tolerance) -> bool {
+        let t = tol.get();
+        for r in 0..D {
+            if (self.rows[r][r] - 1.0).abs() > t {
+                return false;
+            }
+            for c in (r + 1)..D {
+                let upper = self.rows[r][c];
+                let lower = self.rows[c][r];
+                // An overflowing difference is NaN-free and compares greater.
+                if (upper - lower).abs() > t || upper.abs() > 1.0 + t || lower.abs() > 1.0 + t {
+                    return false;
+                }
+            }
+        }
+        self.symmetric_part().ldlt(tol).is_ok()
+    }
+
+    /// Upper bandwidth: the largest `c − r` over nonzero entries `a[r][c]`
+    /// with `c > r`.
+    ///
+    /// Only exact zeros (either sign) count as zero. `0` means lower
+    /// triangular; with [`lower_bandwidth`](Self::lower_bandwidth) also at most
+    /// `1`, the matrix is tridiagonal and
+    /// [`lu_tridiagonal`](Self::lu_tridiagonal) applies. A dense matrix has
+    /// bandwidth `D − 1`; `Matrix<0>` has bandwidth `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<3>::try_from_rows([
+    ///     [1.0, 2.0, 3.0],
+    ///     [0.0, 4.0, 5.0],
+    ///     [0.0, 0.0, 6.0],
+    /// ])?;
+    /// assert_eq!(a.upper_bandwidth(), 2);
+    /// assert_eq!(a.lower_bandwidth(), 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn upper_bandwidth(&self) -> usize {
+        let mut bandwidth = 0;
+        let mut r = 0;
+        while r < D {
+            let mut c = r + 1 + bandwidth;
+            while c < D {
+                if self.rows[r][c] != 0.0 {
+                    bandwidth = c - r;
+                }
+                c += 1;
+            }
+            r += 1;
+        }
+        bandwidth
+    }
+
+    /// Lower bandwidth: the largest `r − c` over nonzero entries `a[r][c]`
+    /// with `r > c`.
+    ///
+    /// The mirror of [`upper_bandwidth`](Self::upper_bandwidth); `0` means
+    /// upper triangular.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<3>::try_from_rows([
+    ///     [2.0, -1.0, 0.0],
+    ///     [-1.0, 2.0, -1.0],
+    ///     [0.0, -1.0, 2.0],
+    /// ])?;
+    /// assert_eq!((a.lower_bandwidth(), a.upper_bandwidth()), (1, 1));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn lower_bandwidth(&self) -> usize {
+        let mut bandwidth = 0;
+        let mut c = 0;
+        while c < D {
+            let mut r = c + 1 + bandwidth;
+            while r < D {
+                if self.rows[r][c] != 0.0 {
+                    bandwidth = r - c;
+                }
+                r += 1;
+            }
+            c += 1;
+        }
+        bandwidth
+    }
+
+    /// Largest entrywise error relative to `reference`,
+    /// `max |a[r][c] − ref[r][c]| / max(|ref[r][c]|, 1)`.
+    ///
+    /// The `max(·, 1)` floor makes the measure absolute for entries of
+    /// magnitude below one, so exact zeros in `reference` do not divide by
+    /// zero. Both matrices are finite by construction, so there is no NaN
+    /// case; a difference that overflows is recomputed from halved operands,
+    /// and the result is always finite. `Matrix<0>` returns `0.0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let computed = Matrix::<2>::try_from_rows([[100.5, 0.25], [0.0, 1.0]])?;
+    /// let reference = Matrix::<2>::try_from_rows([[100.0, 0.0], [0.0, 1.0]])?;
+    ///
+    /// // 0.25 absolute at a zero entry beats 0.5 / 100 relative.
+    /// assert_eq!(computed.max_relative_error(&reference), 0.25);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn max_relative_error(&self, reference: &Self) -> f64 {
+        let mut max_error: f64 = 0.0;
+        let mut r = 0;
+        while r < D {
+            let mut c = 0;
+            while c < D {
+                let value = self.rows[r][c];
+                let expected = reference.rows[r][c];
+                let scale = expected.abs().max(1.0);
+                let diff = (value - expected).abs();
+                let error = if diff.is_finite() {
+                    diff / scale
+                } else {
+                    // Only opposite signs overflow; `scale` is then large
+                    // enough that the doubled ratio stays finite.
+                    2.0 * ((0.5 * value - 0.5 * expected).abs() / scale)
+                };
+                max_error = max_error.max(error);
+                c += 1;
+            }
+            r += 1;
+        }
+        max_error
+    }
+
+    /// Compute an LU decomposition with partial pivoting.
+    ///
+    /// `D = 0` follows the empty-matrix convention: factorization succeeds,
+    /// [`Lu::det`](crate::Lu::det) returns `1.0`, and solving a length-zero
+    /// right-hand side returns a length-zero [`Vector`](crate::Vector).
+    /// Partial pivoting is a practical finite-precision strategy, not a
+    /// certified accuracy guarantee; see `REFERENCES.md` \[1-3, 11-12\].
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]])?;
+    /// let lu = a.lu(DEFAULT_SINGULAR_TOL)?;
+    ///
+    /// let b = Vector::<2>::try_new([5.0, 11.0])?;
+    /// let x = lu.solve(b)?.into_array();
+    ///
+    /// assert!((x[0] - 1.0).abs() <= 1e-12);
+    /// assert!((x[1] - 2.0).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Empty matrices use the standard empty-product convention:
+    ///
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let lu = Matrix::<0>::zero().lu(DEFAULT_SINGULAR_TOL)?;
+    ///
+    /// assert_eq!(lu.det()?, 1.0);
+    /// assert!(lu.solve(Vector::<0>::zero())?.into_array().is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// The `tol` argument is a [`Tolerance`], so raw caller input must be
+    /// finite and non-negative before it can reach factorization. Use
+    /// [`Tolerance::try_new`] when accepting a raw `f64`; negative, NaN, and
+    /// infinite tolerances return
+    /// [`LaError::InvalidTolerance`].
+    ///
+    /// # Errors
+    /// Returns [`LaError::Singular`] if, for some column `k`, the largest-magnitude candidate pivot
+    /// in that column satisfies `|pivot| <= tol` (so no numerically usable pivot exists).
+    /// Returns [`LaError::NonFinite`] if an elimination intermediate overflows
+    /// to NaN/∞ before it can be stored in the returned [`Lu`].
+    #[inline]
+    pub fn lu(self, tol: Tolerance) -> Result<Lu<D>, LaError> {
+        Lu::factor_finite(self, tol)
+    }
+
+    /// Compute an LU decomposition without row exchanges.
+    ///
+    /// The diagonal entry is always taken as the pivot, which skips the
+    /// per-column pivot search of [`lu`](Self::lu) and leaves the permutation
+    /// as the identity (see [`Lu::into_parts`](crate::Lu::into_parts)). This
+    /// is stable for matrices that are strictly diagonally dominant (see
+    /// [`is_diagonally_dominant`](Self::is_diagonally_dominant)) or symmetric
+    /// positive definite; see `REFERENCES.md` \[11-12\]. For other inputs
+    /// elimination can fail on a small leading pivot, or lose accuracy
+    /// through growth, even when `A` is well conditioned.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[4.0, 1.0], [2.0, 3.0]])?;
+    /// assert!(a.is_diagonally_dominant(true));
+    ///
+    /// let (_, source_rows) = a.lu_no_pivot(DEFAULT_SINGULAR_TOL)?.into_parts();
+    /// assert_eq!(source_rows, [0, 1]);
+    ///
+    /// // Partial pivoting would swap these rows; without it the zero
+    /// // leading pivot is rejected.
+    /// let swap = Matrix::<2>::try_from_rows([[0.0, 1.0], [1.0, 0.0]])?;
+    /// assert!(matches!(
+    ///     swap.lu_no_pivot(DEFAULT_SINGULAR_TOL),
+    ///     Err(LaError::Singular { pivot_col: 0, .. })
+    /// ));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::Singular`] if, for some column `k`, the diagonal
+    /// pivot after elimination satisfies `|pivot| <= tol`.
+    /// Returns [`LaError::NonFinite`] if an elimination intermediate overflows
+    /// to NaN/∞ before it can be stored in the returned [`Lu`].
+    #[inline]
+    pub fn lu_no_pivot(self, tol: Tolerance) -> Result<Lu<D>, LaError> {
+        Lu::factor_finite_no_pivot(self, tol)
+    }
+
+    /// Factor each matrix of a fixed-size batch with [`lu`](Self::lu).
+    ///
+    /// Element `i` of the result is exactly `mats[i].lu(tol)`: a singular or
+    /// overflowing matrix reports its own error without affecting the others.
+    /// The batch size `N` is a compile-time constant, so the loop over the
+    /// batch is fully unrollable and the returned array lives on the stack.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let mats = [
+    ///     Matrix::<2>::try_from_rows([[2.0, 1.0], [1.0, 3.0]])?,
+    ///     Matrix::<2>::try_from_rows([[1.0, 2.0], [2.0, 4.0]])?, // singular
+    /// ];
+    /// let [first, second] = Matrix::lu_batch(&mats, DEFAULT_SINGULAR_TOL);
+    ///
+    /// assert!((first?.det()? - 5.0).abs() <= 1e-12);
+    /// assert!(matches!(second, Err(LaError::Singular { .. })));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn lu_batch<const N: usize>(
+        mats: &[Self; N],
+        tol: Tolerance,
+    ) -> [Result<Lu<D>, LaError>; N] {
+        mats.each_ref().map(|a| a.lu(tol))
+    }
+
+    /// Compute an LU factorization of a tridiagonal matrix with the Thomas
+    /// algorithm.
+    ///
+    /// Every entry with `|row - col| > 1` must be zero (either sign). The
+    /// factorization and each solve cost `O(D)` instead of the `O(D³)` and
+    /// `O(D²)` of [`lu`](Self::lu), and the factors occupy three length-`D`
+    /// bands. There is no pivoting, so a zero or tolerance-small pivot is
+    /// reported as singular even when row exchanges would succeed; diagonally
+    /// dominant and symmetric positive-definite tridiagonal matrices never need
+    /// them in exact arithmetic.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<4>::try_from_rows([
+    ///     [2.0, -1.0, 0.0, 0.0],
+    ///     [-1.0, 2.0, -1.0, 0.0],
+    ///     [0.0, -1.0, 2.0, -1.0],
+    ///     [0.0, 0.0, -1.0, 2.0],
+    /// ])?;
+    /// let tri = a.lu_tridiagonal(DEFAULT_SINGULAR_TOL)?;
+    /// assert!((tri.det()? - 5.0).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::OutsideBand`] for the first non-zero entry outside the
+    /// tridiagonal band in row-major order. Returns [`LaError::Singular`] with
+    /// [`FactorizationKind::TridiagonalLu`] when a pivot magnitude is at or
+    /// below `tol`, and [`LaError::NonFinite`] when a multiplier or pivot
+    /// overflows.
+    #[inline]
+    pub fn lu_tridiagonal(self, tol: Tolerance) -> Result<TriLu<D>, LaError> {
+        TriLu::factor(&self, tol)
+    }
+
+    /// Compute an LDLT factorization (`A = L D Lᵀ`) without pivoting.
+    ///
+    /// `D = 0` follows the empty-matrix convention: factorization succeeds,
+    /// [`Ldlt::det`](crate::Ldlt::det) returns `1.0`, and solving a length-zero
+    /// right-hand side returns a length-zero [`Vector`](crate::Vector).
+    ///
+    /// This is intended for exactly symmetric positive-definite matrices such
+    /// as nonsingular Gram matrices. Computed zero and tolerance-small positive
+    /// pivots are diagnosed as singular rather than returned in a usable
+    /// factorization. Because pivots are computed in binary64, success is not
+    /// an exact proof that the stored matrix is positive definite.
+    /// See `REFERENCES.md` \[4-6, 11-12\] for Cholesky/LDLT background and the
+    /// pivoted symmetric-indefinite alternative.
+    ///
+    /// # Symmetry validation
+    /// The input matrix `self` must be exactly symmetric: every mirrored pair
+    /// must satisfy `self[i][j] == self[j][i]`. IEEE-754 signed zeros compare
+    /// equal and are therefore accepted. Exact equality is a correctness
+    /// invariant, not merely a performance hint: LDLT reads only the lower
+    /// triangle, so accepting an approximate mismatch would factor a different
+    /// operator than the matrix supplied by the caller. Asymmetric inputs return
+    /// [`LaError::Asymmetric`] with an allowed absolute difference of `0.0`
+    /// before factorization starts.
+    ///
+    /// [`is_symmetric`](Self::is_symmetric) remains available as a
+    /// tolerance-based diagnostic, but `Ok(true)` from that method does not
+    /// establish this exact LDLT precondition. If you need a general-purpose
+    /// factorization for a non-symmetric matrix, use [`lu`](Self::lu) instead.
+    ///
+    /// The `tol` argument is a [`Tolerance`], so raw caller input must be
+    /// finite and non-negative before it can reach factorization. Use
+    /// [`Tolerance::try_new`] when accepting a raw `f64`; negative, NaN, and
+    /// infinite tolerances return
+    /// [`LaError::InvalidTolerance`].
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Note the symmetric layout: a[0][1] == a[1][0] == 2.0.
+    /// let a = Matrix::<2>::try_from_rows([[4.0, 2.0], [2.0, 3.0]])?;
+    /// let ldlt = a.ldlt(DEFAULT_SINGULAR_TOL)?;
+    ///
+    /// // det(A) = 8
+    /// assert!((ldlt.det()? - 8.0).abs() <= 1e-12);
+    ///
+    /// // Solve A x = b
+    /// let b = Vector::<2>::try_new([1.0, 2.0])?;
+    /// let x = ldlt.solve(b)?.into_array();
+    /// assert!((x[0] - (-0.125)).abs() <= 1e-12);
+    /// assert!((x[1] - 0.75).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Empty matrices use the standard empty-product convention:
+    ///
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let ldlt = Matrix::<0>::zero().ldlt(DEFAULT_SINGULAR_TOL)?;
+    ///
+    /// assert_eq!(ldlt.det()?, 1.0);
+    /// assert!(ldlt.solve(Vector::<0>::zero())?.into_array().is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NotPositiveSemidefinite`] if a pivot is negative or a
+    /// zero pivot retains a non-zero coupling below it.
+    /// Returns [`LaError::Singular`] if a zero pivot has no remaining coupling,
+    /// or if a positive pivot satisfies `d <= tol`, treating PSD degeneracy as
+    /// singular.
+    /// Returns [`LaError::NonFinite`] if factorization computes a non-finite
+    /// intermediate.
+    /// Returns [`LaError::Asymmetric`] if the input matrix is not symmetric.
+    #[inline]
+    pub fn ldlt(self, tol: Tolerance) -> Result<Ldlt<D>, LaError> {
+        Ldlt::factor_symmetric(SymmetricMatrix::try_new(self)?, tol)
+    }
+
+    /// Natural logarithm of the determinant of a symmetric positive-definite
+    /// matrix.
+    ///
+    /// The matrix is factored as `A = L D Lᵀ` with [`ldlt`](Self::ldlt), and
+    /// the result is `Σ ln(D_ii)`. Summing logarithms of the pivots never forms
+    /// the determinant itself, so the result stays finite when `det(A)` would
+    /// overflow or underflow binary64, as it does for the covariance matrices
+    /// behind Gaussian log-likelihoods and entropies. The sum is computed in
+    /// `f64`; no certified error bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[4.0, 2.0], [2.0, 3.0]])?;
+    ///
+    /// // det(A) = 8
+    /// let log_det = a.log_det_spd(DEFAULT_SINGULAR_TOL)?;
+    /// assert!((log_det - 8.0_f64.ln()).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the same errors as [`ldlt`](Self::ldlt):
+    /// [`LaError::Asymmetric`] for a non-symmetric input,
+    /// [`LaError::NotPositiveSemidefinite`] for an indefinite input,
+    /// [`LaError::Singular`] if a pivot is `<= tol`, and [`LaError::NonFinite`]
+    /// if factorization computes a non-finite intermediate.
+    #[inline]
+    pub fn log_det_spd(self, tol: Tolerance) -> Result<f64, LaError> {
+        Ok(self.ldlt(tol)?.log_det())
+    }
+
+    /// Inverse square root `A^(-1/2)` of a symmetric positive-definite matrix.
+    ///
+    /// `A` is first validated with [`ldlt`](Self::ldlt), then diagonalized
+    /// with the one-sided Jacobi [`svd`](Self::svd): for SPD `A` the singular
+    /// values are the eigenvalues `λ_k` and the columns `v_k` of `V` are the
+    /// eigenvectors. The result `W = Σ λ_k^(-1/2) v_k v_kᵀ` is accumulated with
+    /// [`from_outer_sum`](Self::from_outer_sum), so it is exactly symmetric and
+    /// `W A W = I` up to rounding. This is the symmetric (ZCA) whitening
+    /// transform for a covariance matrix `A`. `tol` serves as both the LDLT
+    /// pivot threshold and the Jacobi orthogonality tolerance; `max_iters`
+    /// bounds the Jacobi sweeps. No certified error bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[4.0, 0.0], [0.0, 0.25]])?;
+    /// let w = a.inv_sqrt_spd(DEFAULT_SINGULAR_TOL, 30)?;
+    /// assert!((w.get(0, 0).unwrap_or(f64::NAN) - 0.5).abs() <= 1e-15);
+    /// assert!((w.get(1, 1).unwrap_or(f64::NAN) - 2.0).abs() <= 1e-15);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the same errors as [`ldlt`](Self::ldlt) for inputs that are not
+    /// symmetric positive definite, and as [`svd`](Self::svd) if the Jacobi
+    /// iteration does not converge.
+    /// Returns [`LaError::NonFinite`] if an eigenvalue rounds to zero or an
+    /// accumulated entry overflows.
+    #[inline]
+    pub fn inv_sqrt_spd(self, tol: Tolerance, max_iters: usize) -> Result<Self, LaError> {
+        let _ = self.ldlt(tol)?;
+        let (_, lambda, v) = self.svd(tol, max_iters)?;
+
+        let mut eigenvectors = [Vector::zero(); D];
+        for (eigenvector, column) in eigenvectors.iter_mut().zip(v.transpose().rows) {
+            *eigenvector =
+                Vector::from_computation(column, ArithmeticOperation::InverseSquareRoot)?;
+        }
+        let weights = lambda.into_array().map(|l| 1.0 / l.sqrt());
+        if let Some(k) = weights.iter().position(|w| !w.is_finite()) {
+            cold_path();
+            return Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::InverseSquareRoot,
+                k,
+            ));
+        }
+        Self::from_outer_sum(&eigenvectors, &weights)
+    }
+
+    /// Spectral matrix function `f(A) = V diag(f(λ)) Vᵀ` of a symmetric matrix.
+    ///
+    /// The eigenvectors come from the one-sided Jacobi [`svd`](Self::svd) of
+    /// the shifted matrix `A + ‖A‖_∞ I`, which is positive semidefinite, so
+    /// its right singular vectors are eigenvectors of `A` even when `A` is
+    /// indefinite. Each eigenvalue is then recovered unshifted as the Rayleigh
+    /// quotient `λ_k = v_kᵀ A v_k`, `f` is applied to it, and the result is
+    /// accumulated with [`from_outer_sum`](Self::from_outer_sum), so it is
+    /// exactly symmetric. `f = sqrt` gives the principal square root of an SPD
+    /// matrix, `f = ln` its logarithm, and `f = exp` agrees with
+    /// [`exp`](Self::exp). `f` is only ever called with eigenvalue estimates,
+    /// which carry an absolute error of order `ε ‖A‖`; functions that are
+    /// not defined on a tiny negative rounding of a zero eigenvalue should
+    /// clamp their argument. `tol` and `max_iters` are passed to the Jacobi
+    /// sweeps. No certified error bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Eigenvalues 3 and −1 with eigenvectors (1, 1) and (1, −1).
+    /// let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [2.0, 1.0]])?;
+    /// let tol = Tolerance::try_new(1e-14)?;
+    ///
+    /// // Squaring the spectrum squares the matrix: A² = [[5, 4], [4, 5]].
+    /// let square = a.apply_spectral(|l| l * l, tol, 30)?;
+    /// assert!((square.get(0, 0).unwrap_or(f64::NAN) - 5.0).abs() <= 1e-13);
+    /// assert!((square.get(0, 1).unwrap_or(f64::NAN) - 4.0).abs() <= 1e-13);
+    ///
+    /// // The sign function maps A to the reflection swapping its eigenvectors.
+    /// let sign = a.apply_spectral(f64::signum, tol, 30)?;
+    /// assert!(sign.get(0, 0).unwrap_or(f64::NAN).abs() <= 1e-14);
+    /// assert!((sign.get(1, 0).unwrap_or(f64::NAN) - 1.0).abs() <= 1e-14);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::Asymmetric`] unless `A` is exactly symmetric, and
+    /// the same errors as [`svd`](Self::svd) if the Jacobi iteration does not
+    /// converge.
+    /// Returns [`LaError::NonFinite`] with the eigenvalue index if `f` returns
+    /// NaN or infinity, and if the shift or an accumulated entry overflows.
+    #[inline]
+    pub fn apply_spectral<F: Fn(f64) -> f64>(
+        self,
+        f: F,
+        tol: Tolerance,
+        max_iters: usize,
+    ) -> Result<Self, LaError> {
+        let operation = ArithmeticOperation::SpectralFunction;
+        let (lambdas, eigenvectors) = self.symmetric_eigenpairs(tol, max_iters, operation)?;
+
+        let mut weights = [0.0; D];
+        for (k, (weight, lambda)) in weights.iter_mut().zip(lambdas).enumerate() {
+            *weight = f(lambda);
+            if !weight.is_finite() {
+                cold_path();
+                return Err(LaError::non_finite_computation_step(operation, k));
+            }
+        }
+        Self::from_outer_sum(&eigenvectors, &weights)
+    }
+
+    /// Unsorted eigenpairs of a symmetric matrix, shared by the spectral
+    /// methods.
+    ///
+    /// Shifting by `‖A‖_∞` makes `A + ‖A‖_∞ I` positive semidefinite, so its
+    /// right singular vectors are eigenvectors of `A`; each eigenvalue is then
+    /// the Rayleigh quotient `v_kᵀ A v_k` of the unit vector `v_k`, which does
+    /// not depend on the sign of the singular vector.
+    fn symmetric_eigenpairs(
+        self,
+        tol: Tolerance,
+        max_iters: usize,
+        operation: ArithmeticOperation,
+    ) -> Result<([f64; D], [Vector<D>; D]), LaError> {
+        let a = SymmetricMatrix::try_new(self)?.into_matrix();
+        let shifted = a.add_scaled_identity(a.inf_norm()?)?;
+        let (_, _, v) = shifted.svd(tol, max_iters)?;
+
+        let mut lambdas = [0.0; D];
+        let mut eigenvectors = [Vector::zero(); D];
+        for ((lambda, eigenvector), column) in lambdas
+            .iter_mut()
+            .zip(&mut eigenvectors)
+            .zip(v.transpose().rows)
+        {
+            *eigenvector = Vector::from_computation(column, operation)?;
+            *lambda = eigenvector.dot(&a.mul_vec(eigenvector)?)?;
+        }
+        Ok((lambdas, eigenvectors))
+    }
+
+    /// Eigenpairs from [`symmetric_eigenpairs`](Self::symmetric_eigenpairs)
+    /// as `(λ, V)`, sorted ascending, or reversed into descending order.
+    fn sorted_symmetric_eigenpairs(
+        self,
+        tol: Tolerance,
+        max_iters: usize,
+        descending: bool,
+    ) -> Result<(Vector<D>, Self), LaError> {
+        let operation = ArithmeticOperation::SymmetricEigendecomposition;
+        let (lambdas, eigenvectors) = self.symmetric_eigenpairs(tol, max_iters, operation)?;
+
+        let mut order: [usize; D] = core::array::from_fn(|k| k);
+        order.sort_by(|&i, &j| lambdas[i].total_cmp(&lambdas[j]));
+        if descending {
+            order.reverse();
+        }
+
+        let mut rows = [[0.0; D]; D];
+        for (k, &source) in order.iter().enumerate() {
+            for (row, &value) in rows.iter_mut().zip(eigenvectors[source].as_array()) {
+                row[k] = value;
+            }
+        }
+        // Reordering keeps every entry finite.
+        Ok((
+            Vector::from_computation(order.map(|k| lambdas[k]), operation)?,
+            Self::from_rows_unchecked(rows),
+        ))
+    }
+
+    /// Eigendecomposition `A = V diag(λ) Vᵀ` of a symmetric matrix, with the
+    /// eigenvalues sorted ascending.
+    ///
+    /// Returns `(λ, V)`: column `k` of `V` is a unit eigenvector for `λ[k]`,
+    /// and `λ[0] ≤ λ[1] ≤ … ≤ λ[D-1]`, the order PCA and modal analysis
+    /// expect. The eigenvectors come from the one-sided Jacobi
+    /// [`svd`](Self::svd) of `A + ‖A‖_∞ I`, and each eigenvalue is the Rayleigh
+    /// quotient `v_kᵀ A v_k`, exactly as in
+    /// [`apply_spectral`](Self::apply_spectral); the columns are then
+    /// permuted to match the sorted eigenvalues. Equal eigenvalues keep the
+    /// order of their singular values. `V` is orthogonal to within the Jacobi
+    /// tolerance, and the eigenvectors of a repeated eigenvalue are an
+    /// orthonormal basis of its eigenspace rather than unique vectors. No
+    /// certified error bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Eigenvalues −1 and 3 with eigenvectors (1, −1)/√2 and (1, 1)/√2.
+    /// let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [2.0, 1.0]])?;
+    /// let (lambda, v) = a.sym_eigen_sorted(Tolerance::try_new(1e-14)?, 30)?;
+    ///
+    /// let [low, high] = lambda.into_array();
+    /// assert!((low + 1.0).abs() <= 1e-14 && (high - 3.0).abs() <= 1e-14);
+    /// let (v0, v1) = (v.get(0, 0).unwrap(), v.get(1, 0).unwrap());
+    /// assert!((v0 + v1).abs() <= 1e-14);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::Asymmetric`] unless `A` is exactly symmetric, and
+    /// the same errors as [`svd`](Self::svd) if the Jacobi iteration does not
+    /// converge.
+    /// Returns [`LaError::NonFinite`] if the shift or a Rayleigh quotient
+    /// overflows.
+    #[inline]
+    pub fn sym_eigen_sorted(
+        self,
+        tol: Tolerance,
+        max_iters: usize,
+    ) -> Result<(Vector<D>, Self), LaError> {
+        self.sorted_symmetric_eigenpairs(tol, max_iters, false)
+    }
+
+    /// Principal component analysis of a covariance matrix.
+    ///
+    /// Returns `(variances, components)`: the eigenvalues of `covariance`
+    /// sorted descending, which are the variances explained by each
+    /// component, and the matching unit principal directions as the columns
+    /// of `components`. This is [`sym_eigen_sorted`](Self::sym_eigen_sorted)
+    /// in reverse order, so projecting a centered sample `x` onto the leading
+    /// `k` components keeps the first `k` entries of `componentsᵀ x`. A
+    /// covariance is positive semidefinite, so its variances are non-negative
+    /// up to rounding of order `ε ‖C‖`; an input that is not a covariance
+    /// yields negative trailing values, which are returned unchanged. The
+    /// sign of each direction is arbitrary.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Variance 4 along (1, 1)/√2 and 1 along (1, −1)/√2.
+    /// let covariance = Matrix::<2>::try_from_rows([[2.5, 1.5], [1.5, 2.5]])?;
+    /// let (variances, components) = Matrix::pca(&covariance, Tolerance::try_new(1e-14)?, 30)?;
+    ///
+    /// let [first, second] = variances.into_array();
+    /// assert!((first - 4.0).abs() <= 1e-14 && (second - 1.0).abs() <= 1e-14);
+    /// let (c0, c1) = (components.get(0, 0).unwrap(), components.get(1, 0).unwrap());
+    /// assert!((c0 - c1).abs() <= 1e-14);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the same errors as [`sym_eigen_sorted`](Self::sym_eigen_sorted),
+    /// including [`LaError::Asymmetric`] unless `covariance` is exactly
+    /// symmetric.
+    #[inline]
+    pub fn pca(
+        covariance: &Self,
+        tol: Tolerance,
+        max_iters: usize,
+    ) -> Result<(Vector<D>, Self), LaError> {
+        covariance.sorted_symmetric_eigenpairs(tol, max_iters, true)
+    }
+
+    /// Weighted least-squares solve through the normal equations
+    /// `Aᵀ W A x = Aᵀ W b`, with `W = diag(w)`.
+    ///
+    /// Each weight scales the squared residual of one row (one observation),
+    /// as in heteroscedastic fits where `w_i = 1 / σ_i²`. The weighted Gram
+    /// matrix `Aᵀ W A` is assembled exactly symmetric and factored with the
+    /// same LDLT as [`ldlt`](Self::ldlt). Forming normal equations squares the condition
+    /// number of `A`, so this trades accuracy for simplicity; see
+    /// `REFERENCES.md` \[11-12\]. No certified error bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[2.0, 0.0], [1.0, 1.0]])?;
+    /// let w = Vector::<2>::try_new([4.0, 1.0])?;
+    /// let b = Vector::<2>::try_new([2.0, 3.0])?;
+    ///
+    /// // A is square and non-singular, so any positive weights give A⁻¹ b.
+    /// let x = a.weighted_solve(w, b, DEFAULT_SINGULAR_TOL)?.into_array();
+    /// assert!((x[0] - 1.0).abs() <= 1e-12);
+    /// assert!((x[1] - 2.0).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] if assembling `Aᵀ W A` or `Aᵀ W b`
+    /// overflows, or if factorization or substitution computes a non-finite
+    /// intermediate.
+    /// Returns [`LaError::Singular`] if an LDLT pivot is `<= tol`, for
+    /// example when zero weights leave too few observations.
+    /// Returns [`LaError::NotPositiveSemidefinite`] if negative weights make
+    /// the Gram matrix indefinite.
+    #[inline]
+    pub fn weighted_solve(
+        &self,
+        w: Vector<D>,
+        b: Vector<D>,
+        tol: Tolerance,
+    ) -> Result<Vector<D>, LaError> {
+        let w = w.as_array();
+        let b = b.as_array();
+
+        let mut gram = [[0.0; D]; D];
+        let mut rhs = [0.0; D];
+        for r in 0..D {
+            for c in 0..=r {
+                let mut acc = 0.0;
+                for (row, &weight) in self.rows.iter().zip(w) {
+                    acc = (weight * row[r]).mul_add(row[c], acc);
+                }
+                gram[r][c] = acc;
+                gram[c][r] = acc;
+            }
+            let mut acc = 0.0;
+            for ((row, &weight), &value) in self.rows.iter().zip(w).zip(b) {
+                acc = (weight * row[r]).mul_add(value, acc);
+            }
+            rhs[r] = acc;
+        }
+
+        let gram = Self::from_computation(gram, ArithmeticOperation::WeightedNormalEquations)?;
+        let rhs = Vector::from_computation(rhs, ArithmeticOperation::WeightedNormalEquations)?;
+        // Mirrored entries were written from the same accumulator.
+        Ldlt::factor_symmetric(SymmetricMatrix::new_unchecked(gram), tol)?.solve(rhs)
+    }
+
+    /// Solve `A x = b` after equilibrating the rows and columns of `A`.
+    ///
+    /// Partial pivoting compares magnitudes across rows, so a system whose rows
+    /// differ by many orders of magnitude in scale can choose pivots that
+    /// discard information from the small rows. This method first scales each
+    /// row so its largest magnitude lies in `[1, 2)`, then each column of the
+    /// row-scaled matrix the same way, giving `R A C`. It then LU-solves
+    /// `(R A C) y = R b` and returns `x = C y`; see `REFERENCES.md` \[11-12\].
+    ///
+    /// Every scale factor is a power of two, so applying `R` and `C` is exact
+    /// unless a scaled entry leaves the normal binary64 range. All-zero rows or
+    /// columns are left unscaled and surface as a singular factorization. The
+    /// solve itself has the same rounding behaviour as [`Lu::solve`]; no
+    /// certified error bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Row 0 is scaled by 1e8 relative to row 1.
+    /// let a = Matrix::<2>::try_from_rows([[1.0, 1e8], [1.0, 1.0]])?;
+    /// let b = Vector::<2>::try_new([1e8 + 1.0, 2.0])?;
+    ///
+    /// let x = a.solve_equilibrated(b, DEFAULT_SINGULAR_TOL)?.into_array();
+    /// assert!((x[0] - 1.0).abs() <= 1e-12);
+    /// assert!((x[1] - 1.0).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::Singular`] if the equilibrated matrix has a pivot
+    /// with magnitude `<= tol`.
+    /// Returns [`LaError::NonFinite`] if scaling `b` or the solution
+    /// overflows, or if factorization or substitution computes a non-finite
+    /// intermediate.
+    #[inline]
+    pub fn solve_equilibrated(self, b: Vector<D>, tol: Tolerance) -> Result<Vector<D>, LaError> {
+        let mut rows = self.rows;
+        let mut row_scale = [1.0; D];
+        for (row, scale) in rows.iter_mut().zip(&mut row_scale) {
+            *scale = power_of_two_reciprocal(row.iter().fold(0.0, |max, v| v.abs().max(max)));
+            for value in row.iter_mut() {
+                *value *= *scale;
+            }
+        }
+
+        let mut col_scale = [1.0; D];
+        for (c, scale) in col_scale.iter_mut().enumerate() {
+            *scale =
+                power_of_two_reciprocal(rows.iter().fold(0.0, |max, row| row[c].abs().max(max)));
+            for row in &mut rows {
+                row[c] *= *scale;
+            }
+        }
+
+        let mut scaled_b = b.into_array();
+        for (value, scale) in scaled_b.iter_mut().zip(row_scale) {
+            *value *= scale;
+        }
+        let scaled_b = Vector::from_computation(scaled_b, ArithmeticOperation::Equilibration)?;
+
+        // Power-of-two scaling toward unit magnitude keeps every entry finite.
+        let scaled = Self::from_computation(rows, ArithmeticOperation::Equilibration)?;
+        let mut x = scaled.lu(tol)?.solve(scaled_b)?.into_array();
+        for (value, scale) in x.iter_mut().zip(col_scale) {
+            *value *= scale;
+        }
+        Vector::from_computation(x, ArithmeticOperation::Equilibration)
+    }
+
+    /// Nearest orthogonal matrix: the orthogonal factor of the polar
+    /// decomposition.
+    ///
+    /// Every non-singular `A` factors uniquely as `A = Q H`, where `Q` is
+    /// orthogonal and `H` is symmetric positive definite. `Q` is the orthogonal
+    /// matrix nearest to `A` in the Frobenius norm, which makes this the
+    /// standard way to re-orthogonalize a rotation that has drifted after
+    /// repeated composition. It is computed with the Newton iteration
+    ///
+    /// ```text
+    /// Q₀ = A,    Q_{k+1} = ½ (Q_k + Q_k⁻ᵀ),
+    /// ```
+    ///
+    /// which converges quadratically for every non-singular `A`; see
+    /// `REFERENCES.md` \[14\]. Each step inverts `Q_k` through a zero-tolerance
+    /// LU factorization. Convergence is measured by the orthogonality defect
+    /// `max_{i,j} |(QᵀQ − I)[i][j]|`, and iteration stops as soon as the defect
+    /// is `<= tol`, so an input that is already orthogonal within `tol` is
+    /// returned unchanged without any iterations.
+    ///
+    /// The returned matrix is orthogonal only to within `tol` and binary64
+    /// rounding; no certified error bound is provided. For a singular `A`
+    /// the polar factor is not unique and the iteration is not defined.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // A rotation by 0.3 radians whose entries have drifted slightly.
+    /// let (s, c) = 0.3_f64.sin_cos();
+    /// let drifted = Matrix::<2>::try_from_rows([[c + 1e-4, -s], [s, c - 2e-4]])?;
+    ///
+    /// let q = drifted.nearest_orthogonal(Tolerance::try_new(1e-14)?, 20)?;
+    /// assert!((q.det()? - 1.0).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NotConverged`] if the orthogonality defect is still
+    /// greater than `tol` after `iters` Newton steps.
+    /// Returns [`LaError::Singular`] if an iterate cannot be LU-factored because
+    /// elimination produces an exactly zero pivot.
+    /// Returns [`LaError::NonFinite`] if an inversion, update, or defect
+    /// computation overflows to NaN or infinity.
+    #[inline]
+    pub fn nearest_orthogonal(self, tol: Tolerance, iters: usize) -> Result<Self, LaError> {
+        let tolerance = tol.get();
+        let mut q = self;
+        for _ in 0..iters {
+            if q.orthogonality_defect()? <= tolerance {
+                return Ok(q);
+            }
+
+            let inverse_transpose = q.lu(Tolerance::ZERO)?.inverse()?.transpose();
+            let mut next = [[0.0; D]; D];
+            for (r, row) in next.iter_mut().enumerate() {
+                for (c, entry) in row.iter_mut().enumerate() {
+                    // Halving before adding keeps the average of two finite
+                    // values finite.
+                    *entry = 0.5_f64.mul_add(q.rows[r][c], 0.5 * inverse_transpose.rows[r][c]);
+                }
+            }
+            q = Self::from_computation(next, ArithmeticOperation::PolarDecomposition)?;
+        }
+
+        let defect = q.orthogonality_defect()?;
+        if defect <= tolerance {
+            Ok(q)
+        } else {
+            cold_path();
+            Err(LaError::not_converged(iters, defect, tolerance))
+        }
+    }
+
+    /// Sweep budget for the one-sided Jacobi eigendecomposition inside each
+    /// [`nearest_correlation`](Self::nearest_correlation) projection.
+    const NEAREST_CORRELATION_EIGEN_SWEEPS: usize = 50;
+
+    /// Nearest correlation matrix by Higham's alternating projections.
+    ///
+    /// Empirical correlation matrices assembled from pairwise or incomplete
+    /// data are often indefinite. This finds the correlation matrix (positive
+    /// semidefinite with unit diagonal) nearest to `self` in the Frobenius
+    /// norm by alternating between the two convex sets with Dykstra's
+    /// correction applied to the positive semidefinite projection; see
+    /// `REFERENCES.md` \[17\]. That projection clips negative eigenvalues to
+    /// zero with [`apply_spectral`](Self::apply_spectral). Its one-sided
+    /// Jacobi sweeps receive the same `tol` but a fixed budget of 50 sweeps,
+    /// independent of `max_iters`, which only bounds the outer projections.
+    /// The unit-diagonal projection resets the diagonal to `1`.
+    ///
+    /// Iteration stops once the positive semidefinite iterate `X` already has
+    /// a unit diagonal within `tol`, `max_i |x_ii − 1| ≤ tol`, which is the
+    /// distance `‖Y − X‖_∞` between the two projections. The returned `Y` has
+    /// an exactly unit diagonal and is exactly symmetric; its smallest
+    /// eigenvalue may be negative by about `tol`. Convergence is linear, so
+    /// loose input may need hundreds of iterations. No certified error bound
+    /// is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Pairwise correlations of 1, 1 and 0 cannot hold together.
+    /// let a = Matrix::<3>::try_from_rows([[1.0, 1.0, 0.0], [1.0, 1.0, 1.0], [0.0, 1.0, 1.0]])?;
+    /// let tol = Tolerance::try_new(1e-12)?;
+    /// let c = a.nearest_correlation(tol, 500)?;
+    ///
+    /// assert_eq!(c.get(1, 1), Some(1.0));
+    /// assert!((c.get(0, 1).unwrap_or(f64::NAN) - 0.7607).abs() <= 1e-4);
+    /// assert!((c.get(0, 2).unwrap_or(f64::NAN) - 0.1573).abs() <= 1e-4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::Asymmetric`] unless `self` is exactly symmetric.
+    /// Returns [`LaError::NotConverged`] with `iterations: max_iters` and the
+    /// final diagonal defect if it still exceeds `tol` after `max_iters`
+    /// projections; `max_iters = 0` reports an infinite defect. A Jacobi
+    /// eigendecomposition that exhausts its 50 sweeps also returns
+    /// [`LaError::NotConverged`], with `iterations: 50` and the remaining
+    /// column correlation, as do the other errors of
+    /// [`apply_spectral`](Self::apply_spectral).
+    /// Returns [`LaError::NonFinite`] if the correction or its application
+    /// overflows, which needs entries near `f64::MAX`.
+    #[inline]
+    pub fn nearest_correlation(self, tol: Tolerance, max_iters: usize) -> Result<Self, LaError> {
+        let operation = ArithmeticOperation::NearestCorrelation;
+        let tolerance = tol.get();
+        let mut y = SymmetricMatrix::try_new(self)?.into_matrix();
+        // Dykstra's correction, `X − R` from the previous projection.
+        let mut correction = [[0.0; D]; D];
+        let mut defect = f64::INFINITY;
+        for _ in 0..max_iters {
+            let mut corrected = y.rows;
+            for (row, correction_row) in corrected.iter_mut().zip(&correction) {
+                for (entry, &delta) in row.iter_mut().zip(correction_row) {
+                    *entry -= delta;
+                }
+            }
+            let r = Self::from_computation(corrected, operation)?;
+            let x = r.apply_spectral(
+                |lambda| lambda.max(0.0),
+                tol,
+                Self::NEAREST_CORRELATION_EIGEN_SWEEPS,
+            )?;
+
+            for ((correction_row, x_row), r_row) in correction.iter_mut().zip(&x.rows).zip(&r.rows)
+            {
+                for ((delta, &projected), &unprojected) in
+                    correction_row.iter_mut().zip(x_row).zip(r_row)
+                {
+                    *delta = projected - unprojected;
+                }
+            }
+            correction = Self::from_computation(correction, operation)?.rows;
+
+            let mut unit = x.rows;
+            defect = 0.0;
+            for (i, row) in unit.iter_mut().enumerate() {
+                defect = defect.max((row[i] - 1.0).abs());
+                row[i] = 1.0;
+            }
+            // Resetting the diagonal keeps every entry finite.
+            y = Self::from_rows_unchecked(unit);
+            if defect <= tolerance {
+                return Ok(y);
+            }
+        }
+        cold_path();
+        Err(LaError::not_converged(max_iters, defect, tolerance))
+    }
+
+    /// Singular value decomposition `A = U diag(σ) Vᵀ` by one-sided Jacobi
+    /// rotations.
+    ///
+    /// Returns `(U, σ, V)` with orthogonal `U` and `V` and the singular values
+    /// in descending order `σ₀ ≥ σ₁ ≥ … ≥ 0`. The Hestenes variant applies plane
+    /// rotations to pairs of columns of `A`, accumulating them in `V`, until
+    /// all columns are mutually orthogonal; the column norms are then the
+    /// singular values and the normalized columns form `U`. The columns are
+    /// first divided by the largest entry of `A`, so no inner product
+    /// overflows; a column whose squared norm then underflows is treated as
+    /// zero. Columns of `U` belonging to exactly zero singular values are
+    /// completed to an orthonormal basis from the standard basis vectors. See
+    /// `REFERENCES.md` \[12\].
+    ///
+    /// A column pair `(p, q)` counts as orthogonal when
+    /// `|a_p · a_q| <= tol ‖a_p‖₂ ‖a_q‖₂`, and a pair that is not is rotated to
+    /// make it so. Iteration stops at the first sweep over all pairs that needs
+    /// no rotation; `max_iters` bounds the number of sweeps that do rotate.
+    /// Convergence is quadratic once the columns are nearly orthogonal, so a
+    /// handful of sweeps suffices at small `D`. The factors are orthogonal only
+    /// to within `tol` and binary64 rounding; no certified error bound is
+    /// provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[3.0, 0.0], [4.0, 5.0]])?;
+    /// let tol = Tolerance::try_new(1e-14)?;
+    ///
+    /// let (_u, sigma, _v) = a.svd(tol, 30)?;
+    /// let [s0, s1] = sigma.into_array();
+    /// assert!((s0 - 45.0_f64.sqrt()).abs() <= 1e-12);
+    /// assert!((s1 - 5.0_f64.sqrt()).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NotConverged`] with the largest remaining relative
+    /// column inner product if the sweep after `max_iters` rotating sweeps
+    /// still finds a pair that is not orthogonal within `tol`.
+    /// Returns [`LaError::NonFinite`] if a singular value overflows, which
+    /// needs entries within a factor `√D` of `f64::MAX`.
+    #[inline]
+    pub fn svd(self, tol: Tolerance, max_iters: usize) -> Result<(Self, Vector<D>, Self), LaError> {
+        let tolerance = tol.get();
+        // Work on columns: `cols[j]` is column `j` of the rotated `A / max_abs`.
+        let max_abs = self
+            .rows
+            .as_flattened()
+            .iter()
+            .fold(0.0_f64, |max, x| max.max(x.abs()));
+        let mut cols = if max_abs > 0.0 {
+            self.transpose().rows.map(|col| col.map(|x| x / max_abs))
+        } else {
+            [[0.0; D]; D]
+        };
+        let mut v = Self::identity().rows;
+
+        let mut sweeps = 0;
+        loop {
+            let correlation = Self::jacobi_sweep(&mut cols, &mut v, tolerance);
+            if correlation <= tolerance {
+                break;
+            }
+            if sweeps == max_iters {
+                cold_path();
+                return Err(LaError::not_converged(max_iters, correlation, tolerance));
+            }
+            sweeps += 1;
+        }
+
+        // Column norms are the singular values and the normalized columns form
+        // U; a column the sweeps treated as zero keeps σ = 0.
+        let mut sigma = [0.0; D];
+        let mut units = [[0.0; D]; D];
+        for ((s, unit), col) in sigma.iter_mut().zip(&mut units).zip(&cols) {
+            if col.iter().fold(0.0_f64, |acc, &x| x.mul_add(x, acc)) == 0.0 {
+                continue;
+            }
+            let column =
+                Vector::from_computation(*col, ArithmeticOperation::SingularValueDecomposition)?;
+            let (column_max, norm) = column.scaled_norm2();
+            *s = max_abs * column_max * norm;
+            *unit = column.normalize()?.into_array();
+        }
+
+        // Sort descending, keeping the columns of U and V aligned with σ.
+        let mut order: [usize; D] = core::array::from_fn(|j| j);
+        order.sort_by(|&i, &j| sigma[j].total_cmp(&sigma[i]));
+
+        let mut u = [[0.0; D]; D];
+        let mut v_sorted = [[0.0; D]; D];
+        let mut sigma_sorted = [0.0; D];
+        let mut rank = 0;
+        for (k, &j) in order.iter().enumerate() {
+            sigma_sorted[k] = sigma[j];
+            v_sorted[k] = v[j];
+            if sigma[j] > 0.0 {
+                u[k] = units[j];
+                rank += 1;
+            }
+        }
+        Self::complete_orthonormal_columns(&mut u, rank);
+
+        let op = ArithmeticOperation::SingularValueDecomposition;
+        Ok((
+            Self::from_computation(u, op)?.transpose(),
+            Vector::from_computation(sigma_sorted, op)?,
+            Self::from_computation(v_sorted, op)?.transpose(),
+        ))
+    }
+
+    /// Moore–Penrose pseudoinverse `A⁺ = V diag(σ⁺) Uᵀ` from [`svd`](Self::svd).
+    ///
+    /// Singular values `σ > tol` are inverted and the rest are set to zero, so
+    /// `A⁺ b` is the minimum-norm least-squares solution of `A x = b` even when
+    /// `A` is singular and [`lu`](Self::lu) rejects it. For non-singular,
+    /// well-conditioned `A` it agrees with the inverse. `tol` is also the
+    /// column-orthogonality tolerance of the Jacobi sweeps, and `max_iters`
+    /// bounds their number as in [`svd`](Self::svd).
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Rank one: A = x xᵀ with x = (1, 2), so A⁺ = A / ‖x‖⁴ = A / 25.
+    /// let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [2.0, 4.0]])?;
+    /// let pinv = a.pseudo_inverse(Tolerance::try_new(1e-12)?, 30)?;
+    ///
+    /// let x = pinv.mul_vec(&Vector::<2>::try_new([1.0, 0.0])?)?;
+    /// let [x0, x1] = x.into_array();
+    /// assert!((x0 - 0.04).abs() <= 1e-12 && (x1 - 0.08).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the same errors as [`svd`](Self::svd).
+    /// Returns [`LaError::NonFinite`] if inverting a singular value just above
+    /// `tol` overflows the product.
+    #[inline]
+    pub fn pseudo_inverse(self, tol: Tolerance, max_iters: usize) -> Result<Self, LaError> {
+        let cutoff = tol.get();
+        let (u, sigma, v) = self.svd(tol, max_iters)?;
+        let inverted = sigma
+            .into_array()
+            .map(|s| if s > cutoff { s.recip() } else { 0.0 });
+
+        let mut rows = [[0.0; D]; D];
+        for (r, row) in rows.iter_mut().enumerate() {
+            for (c, entry) in row.iter_mut().enumerate() {
+                for (k, &inv) in inverted.iter().enumerate() {
+                    *entry = (v.rows[r][k] * inv).mul_add(u.rows[c][k], *entry);
+                }
+            }
+        }
+        Self::from_computation(rows, ArithmeticOperation::PseudoInverse)
+    }
+
+    /// Numerical rank: the number of singular values `σ > tol`.
+    ///
+    /// Unlike a pivot count from [`lu`](Self::lu), the singular values reveal
+    /// rank reliably: the distance from `A` to the nearest matrix of rank `k`
+    /// in the 2-norm is exactly `σ_k`. `tol` is also the column-orthogonality
+    /// tolerance of the Jacobi sweeps, and `max_iters` bounds their number as
+    /// in [`svd`](Self::svd).
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Three nearly collinear points in homogeneous coordinates.
+    /// let a = Matrix::<3>::try_from_rows([
+    ///     [0.0, 0.0, 1.0],
+    ///     [1.0, 1.0, 1.0],
+    ///     [2.0, 2.0 + 1e-14, 1.0],
+    /// ])?;
+    /// assert_eq!(a.effective_rank(Tolerance::try_new(1e-10)?, 30)?, 2);
+    /// assert_eq!(Matrix::<3>::identity().effective_rank(Tolerance::try_new(1e-10)?, 30)?, 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the same errors as [`svd`](Self::svd).
+    #[inline]
+    pub fn effective_rank(self, tol: Tolerance, max_iters: usize) -> Result<usize, LaError> {
+        let cutoff = tol.get();
+        let (_, sigma, _) = self.svd(tol, max_iters)?;
+        Ok(sigma.into_array().iter().filter(|&&s| s > cutoff).count())
+    }
+
+    /// Orthogonal projector `P` onto the column space of `A`.
+    ///
+    /// For linearly independent columns this is `P = A (AᵀA)⁻¹ Aᵀ`. The
+    /// columns are instead orthonormalized by modified Gram-Schmidt with one
+    /// reorthogonalization pass (`REFERENCES.md` \[12\], Section 5.2.8), and
+    /// `P = Σ q qᵀ` is accumulated with [`from_outer_sum`](Self::from_outer_sum).
+    /// This avoids squaring the condition number in `AᵀA` and also accepts
+    /// dependent (spanning) columns: a column whose component orthogonal to
+    /// the previous ones has norm `<= tol · ‖column‖₂` adds no direction, and
+    /// zero columns are skipped. `P` is exactly symmetric, and `P² = P` up to
+    /// rounding. A full-rank `A` gives the identity up to rounding.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Columns (1, 1, 0) and (2, 2, 0) span the line x = y in the z = 0 plane.
+    /// let a = Matrix::<3>::try_from_rows([
+    ///     [1.0, 2.0, 0.0],
+    ///     [1.0, 2.0, 0.0],
+    ///     [0.0, 0.0, 0.0],
+    /// ])?;
+    /// let p = a.projector(Tolerance::try_new(1e-12)?)?;
+    /// let expected = [[0.5, 0.5, 0.0], [0.5, 0.5, 0.0], [0.0, 0.0, 0.0]];
+    /// for (row, want) in p.as_rows().iter().zip(expected) {
+    ///     for (x, w) in row.iter().zip(want) {
+    ///         assert!((x - w).abs() <= 1e-15);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] if normalizing a direction or
+    /// accumulating the projector produces a non-finite entry.
+    #[inline]
+    pub fn projector(self, tol: Tolerance) -> Result<Self, LaError> {
+        let mut basis = [Vector::zero(); D];
+        let mut weights = [0.0; D];
+        let mut rank = 0;
+        for column in self.transpose().rows {
+            let max_abs = column.iter().fold(0.0_f64, |max, x| max.max(x.abs()));
+            if max_abs == 0.0 {
+                continue;
+            }
+            // Work on the column divided by its largest entry, whose norm lies
+            // in [1, √D], so no intermediate overflows or underflows.
+            let mut w = column.map(|x| x / max_abs);
+            let norm = w.iter().fold(0.0_f64, |acc, &x| x.mul_add(x, acc)).sqrt();
+            for _ in 0..2 {
+                for q in &basis[..rank] {
+                    let q = q.as_array();
+                    let coefficient = q
+                        .iter()
+                        .zip(&w)
+                        .fold(0.0_f64, |acc, (&a, &b)| a.mul_add(b, acc));
+                    for (x, &qi) in w.iter_mut().zip(q) {
+                        *x = (-coefficient).mul_add(qi, *x);
+                    }
+                }
+            }
+            let residual = w.iter().fold(0.0_f64, |acc, &x| x.mul_add(x, acc)).sqrt();
+            if residual <= tol.get() * norm {
+                continue;
+            }
+            basis[rank] =
+                Vector::from_computation(w.map(|x| x / residual), ArithmeticOperation::Projector)?;
+            weights[rank] = 1.0;
+            rank += 1;
+        }
+        Self::from_outer_sum(&basis, &weights)
+    }
+
+    /// Reflection `R = 2P − I` across the column space of `A`, with `P` from
+    /// [`projector`](Self::projector).
+    ///
+    /// `R` fixes every vector in the subspace and negates its orthogonal
+    /// complement. For a single column `v` this is the negated Householder
+    /// reflector `−(I − 2 v vᵀ / vᵀv)`, and the same `tol` decides which
+    /// columns add a direction. `R` is exactly symmetric, orthogonal up to
+    /// rounding, and an involution, `R² = I`.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Reflect across the x-axis in the plane.
+    /// let a = Matrix::<2>::try_from_rows([[3.0, 0.0], [0.0, 0.0]])?;
+    /// let r = a.householder_subspace_reflection(DEFAULT_SINGULAR_TOL)?;
+    /// assert_eq!(r.into_rows(), [[1.0, 0.0], [0.0, -1.0]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the same errors as [`projector`](Self::projector).
+    #[inline]
+    pub fn householder_subspace_reflection(self, tol: Tolerance) -> Result<Self, LaError> {
+        let mut rows = self.projector(tol)?.rows;
+        for (i, row) in rows.iter_mut().enumerate() {
+            for value in row.iter_mut() {
+                *value *= 2.0;
+            }
+            row[i] -= 1.0;
+        }
+        // Projector entries are bounded by 1 in magnitude, so `2P − I` is finite.
+        Ok(Self::from_rows_unchecked(rows))
+    }
+
+    /// Upper-Hessenberg form `A = Q H Qᵀ` by Householder similarity transforms.
+    ///
+    /// Returns `(H, Q)` with `H[i][j] = 0` for `i > j + 1` and `Q` orthogonal.
+    /// Step `k` takes the compact reflector of `H[k + 1..][k]` from
+    /// [`Vector::householder_vector`] and applies it on both sides with
+    /// [`apply_householder_left`](Self::apply_householder_left), accumulating
+    /// it into `Q`; the eliminated entries are then stored as exact zeros
+    /// (`REFERENCES.md` \[12\], Algorithm 7.4.2). `H` has the eigenvalues of `A`,
+    /// and for symmetric `A` it is tridiagonal up to rounding. This is the
+    /// standard first step of the QR eigenvalue algorithm. The entries are
+    /// rounded; no certified error bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<3>::try_from_rows([
+    ///     [4.0, 1.0, 2.0],
+    ///     [3.0, 0.0, 1.0],
+    ///     [4.0, 5.0, 6.0],
+    /// ])?;
+    /// let (h, _q) = a.to_hessenberg()?;
+    ///
+    /// assert_eq!(h.get(2, 0), Some(0.0));
+    /// assert!((h.trace()? - a.trace()?).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] if a reflector update overflows, which
+    /// needs entries near `f64::MAX`.
+    #[inline]
+    pub fn to_hessenberg(self) -> Result<(Self, Self), LaError> {
+        let op = ArithmeticOperation::HessenbergReduction;
+        let mut h = self;
+        let mut q = Self::identity();
+        for k in 0..D.saturating_sub(2) {
+            // Reflector for the column segment below the subdiagonal entry,
+            // computed at the front of a padded vector and shifted into place.
+            let mut segment = [0.0; D];
+            for (entry, row) in segment.iter_mut().zip(&h.rows[k + 1..]) {
+                *entry = row[k];
+            }
+            let (w, beta) = Vector::from_computation(segment, op)?.householder_vector();
+            let mut v = [0.0; D];
+            v[k + 1..].copy_from_slice(&w.as_array()[..D - k - 1]);
+            let v = Vector::from_computation(v, op)?;
+
+            // H ← P H P and Q ← Q P, using P H = (H P)ᵀ for symmetric P.
+            h.apply_householder_left(&v, beta)?;
+            h = h.transpose();
+            h.apply_householder_left(&v, beta)?;
+            h = h.transpose();
+            q = q.transpose();
+            q.apply_householder_left(&v, beta)?;
+            q = q.transpose();
+
+            for row in &mut h.rows[k + 2..] {
+                row[k] = 0.0;
+            }
+        }
+        Ok((h, q))
+    }
+
+    /// Matrix-vector product `A v`.
+    ///
+    /// Each entry is accumulated in `f64` with fused multiply-add; no certified
+    /// rounding bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]])?;
+    /// let v = Vector::<2>::try_new([1.0, 1.0])?;
+    /// assert_eq!(a.mul_vec(&v)?.into_array(), [3.0, 7.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] with the first output index whose
+    /// accumulator overflows.
+    #[inline]
+    pub const fn mul_vec(&self, v: &Vector<D>) -> Result<Vector<D>, LaError> {
+        let x = v.as_array();
+        let mut out = [0.0; D];
+        let mut r = 0;
+        while r < D {
+            let mut acc = 0.0;
+            let mut c = 0;
+            while c < D {
+                acc = self.rows[r][c].mul_add(x[c], acc);
+                c += 1;
+            }
+            out[r] = acc;
+            r += 1;
+        }
+        Vector::from_computation(out, ArithmeticOperation::MatrixVectorProduct)
+    }
+
+    /// Product `C v` of the circulant matrix `C = circulant(first_row)` with
+    /// `v`, without forming `C`.
+    ///
+    /// Entry `i` is `Σ_j c[(j − i) mod D] · v[j]`, read directly from the
+    /// first row. This is the `O(D²)` structured reference for the product;
+    /// it stores no `D×D` matrix, but it does not use an FFT, so it performs the
+    /// same fused multiply-adds in the same order as
+    /// [`mul_vec`](Self::mul_vec) on the dense circulant and returns bitwise
+    /// identical results.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let c = Vector::<3>::try_new([1.0, 2.0, 3.0])?;
+    /// let v = Vector::<3>::try_new([1.0, 0.0, -1.0])?;
+    ///
+    /// let fast = Matrix::mul_vec_circulant(&c, &v)?;
+    /// assert_eq!(fast, Matrix::circulant(c).mul_vec(&v)?);
+    /// assert_eq!(fast.into_array(), [-2.0, 1.0, 1.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] with the first output index whose
+    /// accumulator overflows.
+    #[inline]
+    pub const fn mul_vec_circulant(
+        first_row: &Vector<D>,
+        v: &Vector<D>,
+    ) -> Result<Vector<D>, LaError> {
+        let coeffs = first_row.as_array();
+        let x = v.as_array();
+        let mut out = [0.0; D];
+        let mut i = 0;
+        while i < D {
+            let mut acc = 0.0;
+            let mut j = 0;
+            while j < D {
+                acc = coeffs[(j + D - i) % D].mul_add(x[j], acc);
+                j += 1;
+            }
+            out[i] = acc;
+            i += 1;
+        }
+        Vector::from_computation(out, ArithmeticOperation::CirculantProduct)
+    }
+
+    /// Generalized Rayleigh quotient `(vᵀ A v) / (vᵀ B v)`.
+    ///
+    /// For symmetric `A` and symmetric positive-definite `B`, the quotient lies
+    /// between the smallest and largest generalized eigenvalues of `A x = λ B x`
+    /// and equals one of them exactly when `v` is the matching eigenvector, which
+    /// makes it the usual eigenvalue estimate for iterative methods. Both forms
+    /// are evaluated with [`mul_vec`](Self::mul_vec) and [`Vector::dot`].
+    ///
+    /// A zero `v` has no direction to estimate along and is rejected up front.
+    /// Otherwise the denominator is treated as the pivot of the `1×1` system
+    /// `(vᵀ B v) q = vᵀ A v`, so it is rejected with the same LU pivot policy
+    /// as [`lu`](Self::lu): its magnitude must exceed `tol`.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[2.0, 0.0], [0.0, 8.0]])?;
+    /// let b = Matrix::<2>::identity();
+    /// let v = Vector::<2>::try_new([0.0, 3.0])?;
+    ///
+    /// let q = Matrix::rayleigh_quotient(&a, &b, &v, DEFAULT_SINGULAR_TOL)?;
+    /// assert!((q - 8.0).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::ZeroVector`] if every entry of `v` is zero.
+    /// Returns [`LaError::Singular`] when `|vᵀ B v| <= tol`: with
+    /// [`SingularityReason::Exact`](crate::SingularityReason::Exact) for an
+    /// exactly zero denominator and
+    /// [`SingularityReason::Numerical`](crate::SingularityReason::Numerical)
+    /// otherwise. Returns [`LaError::NonFinite`] when a product, either
+    /// quadratic form, or the quotient overflows.
+    #[inline]
+    pub fn rayleigh_quotient(
+        a: &Self,
+        b: &Self,
+        v: &Vector<D>,
+        tol: Tolerance,
+    ) -> Result<f64, LaError> {
+        if v.as_array().iter().all(|&x| x == 0.0) {
+            cold_path();
+            return Err(LaError::zero_vector(D));
+        }
+
+        let numerator = v.dot(&a.mul_vec(v)?)?;
+        let denominator = v.dot(&b.mul_vec(v)?)?;
+
+        let tolerance = tol.get();
+        if denominator == 0.0 {
+            cold_path();
+            return Err(LaError::singular_exact(0));
+        }
+        if denominator.abs() <= tolerance {
+            cold_path();
+            return Err(LaError::singular_numerical(
+                0,
+                FactorizationKind::Lu,
+                denominator.abs(),
+                tolerance,
+            ));
+        }
+
+        let quotient = numerator / denominator;
+        if quotient.is_finite() {
+            Ok(quotient)
+        } else {
+            cold_path();
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::RayleighQuotient,
+            ))
+        }
+    }
+
+    /// Dominant eigenpair `(λ, v)` by power iteration.
+    ///
+    /// Starting from the normalized all-ones vector, each step computes
+    /// `w = A v` with [`mul_vec`](Self::mul_vec), takes the Rayleigh quotient
+    /// `λ = v · w` of the unit vector `v`, and continues from
+    /// `v ← w / ‖w‖₂` via [`Vector::normalize`]. Iteration stops when the
+    /// eigen-residual `max |A v − λ v|` is at most `tol`; that measure does not
+    /// depend on the sign of `v`, so a negative dominant eigenvalue, whose
+    /// iterates flip sign every step, converges too.
+    ///
+    /// Convergence is linear with ratio `|λ₂ / λ₁|`. It fails when the two
+    /// largest-magnitude eigenvalues have equal magnitude (including complex
+    /// conjugate pairs) and stalls when the start vector has no component along
+    /// the dominant eigenvector. The returned `v` has unit Euclidean norm. No
+    /// certified error bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[2.0, 1.0], [1.0, 2.0]])?;
+    /// let tol = Tolerance::try_new(1e-12)?;
+    ///
+    /// let (lambda, v) = a.power_iteration(tol, 100)?;
+    /// assert!((lambda - 3.0).abs() <= 1e-12);
+    /// let [x, y] = v.into_array();
+    /// assert!((x - y).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NotConverged`] with the final residual when
+    /// `max_iters` updates do not reach `tol`. Returns [`LaError::NonFinite`]
+    /// when a product or residual overflows.
+    #[inline]
+    pub fn power_iteration(
+        &self,
+        tol: Tolerance,
+        max_iters: usize,
+    ) -> Result<(f64, Vector<D>), LaError> {
+        let tolerance = tol.get();
+        let mut v = Vector::from_computation([1.0; D], ArithmeticOperation::PowerIteration)?;
+        if D > 0 {
+            v = v.normalize()?;
+        }
+
+        let mut iteration = 0;
+        loop {
+            let (lambda, residual, w) =
+                self.eigen_residual(&v, ArithmeticOperation::PowerIteration)?;
+            if residual <= tolerance {
+                return Ok((lambda, v));
+            }
+            if iteration == max_iters {
+                cold_path();
+                return Err(LaError::not_converged(max_iters, residual, tolerance));
+            }
+
+            // `residual > 0` implies `w ≠ λ v`, so `w` is non-zero.
+            v = w.normalize()?;
+            iteration += 1;
+        }
+    }
+
+    /// Spectral radius estimate `ρ(A) = max |λ|` by power iteration.
+    ///
+    /// This is `|λ|` for the dominant eigenvalue returned by
+    /// [`power_iteration`](Self::power_iteration), with the same convergence
+    /// behaviour: when the dominant eigenvalues are a complex-conjugate pair or
+    /// otherwise share the largest magnitude, the iteration does not settle and
+    /// `NotConverged` is returned. `ρ(A) < 1` decides whether `Aᵏ → 0` and
+    /// whether stationary iterations such as Jacobi converge.
+    /// [`inf_norm`](Self::inf_norm) is a cheap upper bound on `ρ(A)`, as is
+    /// the larger endpoint magnitude of
+    /// [`gershgorin_bounds`](Self::gershgorin_bounds) for a real spectrum.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Eigenvalues 0.5 and -0.9.
+    /// let a = Matrix::<2>::try_from_rows([[0.5, 0.0], [1.0, -0.9]])?;
+    /// let tol = Tolerance::try_new(1e-12)?;
+    ///
+    /// let rho = a.spectral_radius_estimate(tol, 200)?;
+    /// assert!((rho - 0.9).abs() <= 1e-10);
+    /// assert!(rho < 1.0, "powers of A converge to zero");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the same errors as [`power_iteration`](Self::power_iteration).
+    #[inline]
+    pub fn spectral_radius_estimate(
+        &self,
+        tol: Tolerance,
+        max_iters: usize,
+    ) -> Result<f64, LaError> {
+        self.power_iteration(tol, max_iters)
+            .map(|(lambda, _)| lambda.abs())
+    }
+
+    /// Induced 2-norm `‖A‖₂ = σ_max(A)` by power iteration on `AᵀA`.
+    ///
+    /// The Gram matrix `AᵀA` is symmetric positive semidefinite, so its
+    /// dominant eigenvalue is `σ_max²` and the sign-flip and complex-pair
+    /// failure modes of [`power_iteration`](Self::power_iteration) cannot
+    /// occur; the estimate is `√λ`. `tol` bounds the eigen-residual of `AᵀA`,
+    /// so it is measured in squared units of `A`. Convergence is linear with
+    /// ratio `(σ₂ / σ₁)²`. [`inf_norm`](Self::inf_norm) is a cheap upper bound
+    /// when an estimate of the true 2-norm is not needed.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // A quarter-turn rotation preserves lengths, so ‖A‖₂ = 1.
+    /// let a = Matrix::<2>::try_from_rows([[0.0, -1.0], [1.0, 0.0]])?;
+    /// let tol = Tolerance::try_new(1e-12)?;
+    ///
+    /// let sigma = a.spectral_norm(tol, 100)?;
+    /// assert!((sigma - 1.0).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] when `AᵀA` overflows, and otherwise the
+    /// same errors as [`power_iteration`](Self::power_iteration).
+    #[inline]
+    pub fn spectral_norm(&self, tol: Tolerance, max_iters: usize) -> Result<f64, LaError> {
+        let gram = self
+            .transpose()
+            .matmul(self, ArithmeticOperation::SpectralNorm)?;
+        let (lambda, _) = gram.power_iteration(tol, max_iters)?;
+        // Rounding can leave the Rayleigh quotient of a PSD matrix just below 0.
+        Ok(lambda.max(0.0).sqrt())
+    }
+
+    /// Eigenpair `(λ, v)` with `λ` the eigenvalue nearest `sigma`, by
+    /// shift-and-invert iteration.
+    ///
+    /// `A − σI` is formed with [`add_scaled_identity`](Self::add_scaled_identity)
+    /// and factored once with [`lu`](Self::lu). Each step then solves
+    /// `(A − σI) w = v` and continues from `v ← w / ‖w‖₂`, which is power
+    /// iteration on `(A − σI)⁻¹`: its dominant eigenvalue `1 / (λ − σ)` belongs
+    /// to the eigenvalue of `A` closest to `σ`, so interior eigenvalues can be
+    /// targeted. The estimate is the Rayleigh quotient `λ = v · A v`, and
+    /// iteration stops when `max |A v − λ v| <= tol`.
+    ///
+    /// Convergence is linear with ratio `|λ − σ| / |λ' − σ|`, where `λ'` is the
+    /// second-nearest eigenvalue, so a good shift converges in a few steps.
+    /// Starting from the normalized all-ones vector, it stalls when that vector
+    /// has no component along the target eigenvector. The returned `v` has unit
+    /// Euclidean norm. No certified error bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<3>::try_from_rows([
+    ///     [2.0, -1.0, 0.0],
+    ///     [-1.0, 2.0, -1.0],
+    ///     [0.0, -1.0, 2.0],
+    /// ])?;
+    /// let tol = Tolerance::try_new(1e-12)?;
+    ///
+    /// // Eigenvalues are 2 − √2, 2, and 2 + √2; target the middle one.
+    /// let (lambda, _) = a.eigen_near(1.9, tol, 100)?;
+    /// assert!((lambda - 2.0).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] with scalar input location if `sigma` is
+    /// NaN or infinite. Returns [`LaError::Singular`] when `A − σI` has an
+    /// exactly zero LU pivot, typically because `σ` is itself an eigenvalue;
+    /// perturbing `σ` slightly avoids this. Returns [`LaError::NotConverged`]
+    /// with the final residual when `max_iters` updates do not reach `tol`, and
+    /// [`LaError::NonFinite`] when a product, solve, or residual overflows.
+    #[inline]
+    pub fn eigen_near(
+        self,
+        sigma: f64,
+        tol: Tolerance,
+        max_iters: usize,
+    ) -> Result<(f64, Vector<D>), LaError> {
+        let shifted = self.add_scaled_identity(-sigma)?.lu(Tolerance::ZERO)?;
+
+        let tolerance = tol.get();
+        let mut v = Vector::from_computation([1.0; D], ArithmeticOperation::InverseIteration)?;
+        if D > 0 {
+            v = v.normalize()?;
+        }
+
+        let mut iteration = 0;
+        loop {
+            let (lambda, residual, _) =
+                self.eigen_residual(&v, ArithmeticOperation::InverseIteration)?;
+            if residual <= tolerance {
+                return Ok((lambda, v));
+            }
+            if iteration == max_iters {
+                cold_path();
+                return Err(LaError::not_converged(max_iters, residual, tolerance));
+            }
+
+            // A non-singular solve maps the non-zero `v` to a non-zero `w`.
+            v = shifted.solve(v)?.normalize()?;
+            iteration += 1;
+        }
+    }
+
+    /// Rayleigh quotient `λ = v · A v` of a unit vector `v`, the eigen-residual
+    /// `max |A v − λ v|`, and the product `A v`.
+    fn eigen_residual(
+        &self,
+        v: &Vector<D>,
+        operation: ArithmeticOperation,
+    ) -> Result<(f64, f64, Vector<D>), LaError> {
+        let w = self.mul_vec(v)?;
+        let lambda = v.dot(&w)?;
+
+        let mut residual: f64 = 0.0;
+        for (&w_i, &v_i) in w.as_array().iter().zip(v.as_array()) {
+            residual = residual.max((-lambda).mul_add(v_i, w_i).abs());
+        }
+        if residual.is_finite() {
+            Ok((lambda, residual, w))
+        } else {
+            cold_path();
+            Err(LaError::non_finite_computation_scalar(operation))
+        }
+    }
+
+    /// Largest dimension accepted by [`solve_sylvester`](Self::solve_sylvester).
+    const MAX_SYLVESTER_DIM: usize = 8;
+
+    /// Solve the Sylvester equation `A X + X B = C` for `X`.
+    ///
+    /// The equation is vectorized row-major into the `D²×D²` Kronecker system
+    /// `(I ⊗ A + Bᵀ ⊗ I) vec(X) = vec(C)` and solved with [`lu`](Self::lu);
+    /// see `REFERENCES.md` \[12, 15\]. A unique solution exists exactly when
+    /// no eigenvalue of `A` is the negative of an eigenvalue of `B`; otherwise
+    /// the Kronecker system is singular.
+    ///
+    /// The `D²` blowup makes the system cost `O(D⁶)` operations and
+    /// `8·D⁴` bytes of stack, so only `D ≤ 8` is supported (a `64×64` system,
+    /// 32 KiB). No certified error bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [0.0, 3.0]])?;
+    /// let b = Matrix::<2>::try_from_rows([[4.0, 0.0], [1.0, 5.0]])?;
+    /// let x = Matrix::<2>::try_from_rows([[1.0, -1.0], [2.0, 0.5]])?;
+    ///
+    /// // C = A X + X B
+    /// let c = Matrix::<2>::try_from_rows([[8.0, -5.0], [14.5, 4.0]])?;
+    /// let solved = Matrix::solve_sylvester(&a, &b, &c, DEFAULT_SINGULAR_TOL)?;
+    /// for r in 0..2 {
+    ///     for col in 0..2 {
+    ///         assert!((solved.try_get(r, col)? - x.try_get(r, col)?).abs() <= 1e-12);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::UnsupportedDimension`] when `D > 8`.
+    /// Returns [`LaError::Singular`] when the Kronecker system has no usable LU
+    /// pivot above `tol`, and [`LaError::NonFinite`] when assembling or solving
+    /// the system overflows.
+    #[inline]
+    pub fn solve_sylvester(a: &Self, b: &Self, c: &Self, tol: Tolerance) -> Result<Self, LaError> {
+        match D {
+            0 => Self::solve_sylvester_kronecker::<0>(a, b, c, tol),
+            1 => Self::solve_sylvester_kronecker::<1>(a, b, c, tol),
+            2 => Self::solve_sylvester_kronecker::<4>(a, b, c, tol),
+            3 => Self::solve_sylvester_kronecker::<9>(a, b, c, tol),
+            4 => Self::solve_sylvester_kronecker::<16>(a, b, c, tol),
+            5 => Self::solve_sylvester_kronecker::<25>(a, b, c, tol),
+            6 => Self::solve_sylvester_kronecker::<36>(a, b, c, tol),
+            7 => Self::solve_sylvester_kronecker::<49>(a, b, c, tol),
+            8 => Self::solve_sylvester_kronecker::<64>(a, b, c, tol),
+            _ => {
+                cold_path();
+                Err(LaError::unsupported_dimension(D, Self::MAX_SYLVESTER_DIM))
+            }
+        }
+    }
+
+    /// Solve the continuous Lyapunov equation `A X + X Aᵀ = -Q` for `X`.
+    ///
+    /// This is the [`solve_sylvester`](Self::solve_sylvester) special case
+    /// `B = Aᵀ`, `C = -Q`, with the same `D ≤ 8` limit. A unique solution
+    /// exists when no two eigenvalues of `A` sum to zero, in particular when
+    /// `A` is stable (every eigenvalue has negative real part); then a
+    /// symmetric positive-definite `Q` gives a symmetric positive-definite `X`.
+    ///
+    /// The exact solution is symmetric whenever `Q` is. For an exactly
+    /// symmetric `Q`, the computed `X` is replaced by `½ (X + Xᵀ)` so the
+    /// returned matrix is exactly symmetric too and can be passed to
+    /// [`ldlt`](Self::ldlt). No certified error bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[-1.0, 0.0], [0.0, -2.0]])?;
+    /// let q = Matrix::<2>::identity();
+    ///
+    /// let x = Matrix::solve_lyapunov(&a, &q, DEFAULT_SINGULAR_TOL)?;
+    /// assert!((x.try_get(0, 0)? - 0.5).abs() <= 1e-12);
+    /// assert!((x.try_get(1, 1)? - 0.25).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the same errors as [`solve_sylvester`](Self::solve_sylvester).
+    #[inline]
+    pub fn solve_lyapunov(a: &Self, q: &Self, tol: Tolerance) -> Result<Self, LaError> {
+        let mut neg_q = q.rows;
+        for row in &mut neg_q {
+            for value in row {
+                *value = -*value;
+            }
+        }
+        // Negation is exact, so `-Q` stays finite.
+        let neg_q = Self::from_rows_unchecked(neg_q);
+
+        let solution = Self::solve_sylvester(a, &a.transpose(), &neg_q, tol)?;
+        if SymmetricMatrix::try_new(*q).is_err() {
+            return Ok(solution);
+        }
+
+        Ok(solution.symmetric_part())
+    }
+
+    /// Solve the 2×2 block system `[A B; C D] [x; y] = [f; g]` through the
+    /// Schur complement `S = D − C A⁻¹ B`.
+    ///
+    /// `A` is factored once with [`lu`](Self::lu) and reused for `A⁻¹ B` (one
+    /// solve per column) and `A⁻¹ f`; then `S y = g − C A⁻¹ f` is solved with a
+    /// second LU and `x = A⁻¹ f − A⁻¹ B y`. This suits saddle-point (KKT)
+    /// systems with an invertible leading block, without an indefinite
+    /// factorization of the assembled `2D×2D` matrix. Both `A` and `S` must be
+    /// invertible; the assembled matrix is then invertible too, with
+    /// `det = det(A) · det(S)`. A singular `A` is rejected even when the
+    /// assembled system is solvable. No certified error bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // KKT system projecting f onto x₀ + x₁ = 2; the second multiplier is padding.
+    /// let a = Matrix::<2>::identity();
+    /// let b = Matrix::<2>::try_from_rows([[1.0, 0.0], [1.0, 0.0]])?;
+    /// let c = Matrix::<2>::try_from_rows([[1.0, 1.0], [0.0, 0.0]])?;
+    /// let d = Matrix::<2>::try_from_rows([[0.0, 0.0], [0.0, 1.0]])?;
+    /// let f = Vector::<2>::try_new([3.0, 1.0])?;
+    /// let g = Vector::<2>::try_new([2.0, 0.0])?;
+    ///
+    /// let (x, y) = Matrix::solve_block_2x2(&a, &b, &c, &d, f, g, DEFAULT_SINGULAR_TOL)?;
+    /// let x = x.into_array();
+    /// assert!((x[0] - 2.0).abs() <= 1e-12 && x[1].abs() <= 1e-12);
+    /// assert!((y.into_array()[0] - 1.0).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::Singular`] if `A` or the Schur complement `S` has an
+    /// LU pivot with `|U[i][i]| <= tol`.
+    /// Returns [`LaError::NonFinite`] if a solve, product, or difference
+    /// overflows.
+    #[inline]
+    #[expect(
+        clippy::many_single_char_names,
+        reason = "the blocks and right-hand sides follow the standard [A B; C D] notation"
+    )]
+    pub fn solve_block_2x2(
+        a: &Self,
+        b: &Self,
+        c: &Self,
+        d: &Self,
+        f: Vector<D>,
+        g: Vector<D>,
+        tol: Tolerance,
+    ) -> Result<(Vector<D>, Vector<D>), LaError> {
+        let operation = ArithmeticOperation::SchurComplement;
+        let lu_a = a.lu(tol)?;
+
+        // W = A⁻¹ B, one column at a time.
+        let mut w = [[0.0; D]; D];
+        for col in 0..D {
+            let column =
+                Vector::from_computation(core::array::from_fn(|r| b.rows[r][col]), operation)?;
+            for (row, value) in w.iter_mut().zip(lu_a.solve(column)?.into_array()) {
+                row[col] = value;
+            }
+        }
+        let w = Self::from_computation(w, operation)?;
+
+        let cw = c.matmul(&w, operation)?;
+        let mut schur = d.rows;
+        for (s_row, cw_row) in schur.iter_mut().zip(&cw.rows) {
+            for (s, &cw) in s_row.iter_mut().zip(cw_row) {
+                *s -= cw;
+            }
+        }
+        let schur = Self::from_computation(schur, operation)?;
+
+        let z = lu_a.solve(f)?;
+        let cz = c.mul_vec(&z)?;
+        let mut rhs = g.into_array();
+        for (r, &cz) in rhs.iter_mut().zip(cz.as_array()) {
+            *r -= cz;
+        }
+        let y = schur
+            .lu(tol)?
+            .solve(Vector::from_computation(rhs, operation)?)?;
+
+        let wy = w.mul_vec(&y)?;
+        let mut x = z.into_array();
+        for (x, &wy) in x.iter_mut().zip(wy.as_array()) {
+            *x -= wy;
+        }
+        Ok((Vector::from_computation(x, operation)?, y))
+    }
+
+    /// Schur complement `S = A₂₂ − A₂₁ A₁₁⁻¹ A₁₂` of the leading `K×K` block.
+    ///
+    /// The matrix is split at index `K` into `[A₁₁ A₁₂; A₂₁ A₂₂]` with a
+    /// trailing `M×M` block. Stable Rust cannot name `Matrix<{D - K}>`, so both
+    /// block sizes are const parameters and `K + M = D` is checked at run
+    /// time. `A₁₁` is factored once with [`lu`](Self::lu) and solved against
+    /// each column of `A₁₂`, so `A₁₁⁻¹` is never formed. When `A₁₁` is
+    /// invertible, `det(A) = det(A₁₁) · det(S)` and `S⁻¹` is the trailing block
+    /// of `A⁻¹`. `K = 0` returns `A` itself. No certified error bound is
+    /// provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<3>::try_from_rows([
+    ///     [2.0, 1.0, 0.0],
+    ///     [1.0, 3.0, 1.0],
+    ///     [0.0, 1.0, 4.0],
+    /// ])?;
+    /// // S = 4 − [0 1] [[2 1] [1 3]]⁻¹ [0 1]ᵀ = 4 − 2/5.
+    /// let s = a.schur_complement::<2, 1>(DEFAULT_SINGULAR_TOL)?;
+    /// assert!((s.get(0, 0).unwrap() - 3.6).abs() <= 1e-12);
+    ///
+    /// assert!(matches!(
+    ///     a.schur_complement::<2, 2>(DEFAULT_SINGULAR_TOL),
+    ///     Err(LaError::DimensionMismatch { .. })
+    /// ));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::DimensionMismatch`] if `K + M != D`.
+    /// Returns [`LaError::Singular`] if `A₁₁` has an LU pivot with
+    /// `|U[i][i]| <= tol`.
+    /// Returns [`LaError::NonFinite`] if a solve or the update overflows.
+    #[inline]
+    pub fn schur_complement<const K: usize, const M: usize>(
+        &self,
+        tol: Tolerance,
+    ) -> Result<Matrix<M>, LaError> {
+        if K + M != D {
+            cold_path();
+            return Err(LaError::dimension_mismatch(D, K + M));
+        }
+        let operation = ArithmeticOperation::SchurComplement;
+        // Entries are copied from `self`, so the leading block stays finite.
+        let lu_11 = Matrix::<K>::from_rows_unchecked(core::array::from_fn(|r| {
+            core::array::from_fn(|c| self.rows[r][c])
+        }))
+        .lu(tol)?;
+
+        let mut schur: [[f64; M]; M] =
+            core::array::from_fn(|i| core::array::from_fn(|j| self.rows[K + i][K + j]));
+        for j in 0..M {
+            // w = A₁₁⁻¹ A₁₂[:, j]; copied entries are finite.
+            let column =
+                Vector::from_computation(core::array::from_fn(|r| self.rows[r][K + j]), operation)?;
+            let w = lu_11.solve(column)?.into_array();
+            for (i, row) in schur.iter_mut().enumerate() {
+                let a21 = &self.rows[K + i][..K];
+                for (&a, &w) in a21.iter().zip(&w) {
+                    row[j] = (-a).mul_add(w, row[j]);
+                }
+            }
+        }
+        Matrix::from_computation(schur, operation)
+    }
+
+    /// Assemble and LU-solve the `N = D²` Kronecker form of `A X + X B = C`.
+    ///
+    /// `X[i][j]` is unknown `i·D + j`. Row `i·D + j` of the system collects
+    /// `A[i][m]` at unknown `m·D + j` and `B[m][j]` at unknown `i·D + m`.
+    fn solve_sylvester_kronecker<const N: usize>(
+        a: &Self,
+        b: &Self,
+        c: &Self,
+        tol: Tolerance,
+    ) -> Result<Self, LaError> {
+        debug_assert!(N == D * D, "Kronecker system must be D² × D²");
+
+        let mut system = [[0.0; N]; N];
+        let mut rhs = [0.0; N];
+        for i in 0..D {
+            for j in 0..D {
+                let row = &mut system[i * D + j];
+                for m in 0..D {
+                    row[m * D + j] += a.rows[i][m];
+                    row[i * D + m] += b.rows[m][j];
+                }
+                rhs[i * D + j] = c.rows[i][j];
+            }
+        }
+
+        let system = Matrix::<N>::from_computation(system, ArithmeticOperation::SylvesterSystem)?;
+        let rhs = Vector::<N>::from_computation(rhs, ArithmeticOperation::SylvesterSystem)?;
+        let x = system.lu(tol)?.solve(rhs)?.into_array();
+
+        let mut rows = [[0.0; D]; D];
+        for (i, row) in rows.iter_mut().enumerate() {
+            row.copy_from_slice(&x[i * D..(i + 1) * D]);
+        }
+        // `Lu::solve` returns a finite vector.
+        Ok(Self::from_rows_unchecked(rows))
+    }
+
+    /// Transpose of a finite matrix.
+    #[inline]
+    pub(crate) const fn transpose(&self) -> Self {
+        let mut rows = [[0.0; D]; D];
+        let mut r = 0;
+        while r < D {
+            let mut c = 0;
+            while c < D {
+                rows[c][r] = self.rows[r][c];
+                c += 1;
+            }
+            r += 1;
+        }
+        Self::from_rows_unchecked(rows)
+    }
+
+    /// Matrix product `self · other`, attributing overflow to `operation`.
+    pub(crate) fn matmul(
+        &self,
+        other: &Self,
+        operation: ArithmeticOperation,
+    ) -> Result<Self, LaError> {
+        let mut rows = [[0.0; D]; D];
+        for (out_row, lhs_row) in rows.iter_mut().zip(&self.rows) {
+            for (c, out) in out_row.iter_mut().enumerate() {
+                let mut acc = 0.0;
+                for (lhs, rhs_row) in lhs_row.iter().zip(&other.rows) {
+                    acc = lhs.mul_add(rhs_row[c], acc);
+                }
+                *out = acc;
+            }
+        }
+        Self::from_computation(rows, operation)
+    }
+
+    /// `A^k` by repeated squaring, attributing overflow to `operation`.
+    fn power_by_squaring(&self, k: u64, operation: ArithmeticOperation) -> Result<Self, LaError> {
+        let mut power = Self::identity();
+        let mut base = *self;
+        let mut remaining = k;
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                power = power.matmul(&base, operation)?;
+            }
+            remaining >>= 1;
+            if remaining > 0 {
+                base = base.matmul(&base, operation)?;
+            }
+        }
+        Ok(power)
+    }
+
+    /// Largest absolute entry of `AᵀA − I`, the orthogonality defect used by
+    /// [`nearest_orthogonal`](Self::nearest_orthogonal).
+    fn orthogonality_defect(&self) -> Result<f64, LaError> {
+        let mut defect: f64 = 0.0;
+        for i in 0..D {
+            for j in i..D {
+                let mut acc = if i == j { -1.0 } else { 0.0 };
+                for row in &self.rows {
+                    acc = row[i].mul_add(row[j], acc);
+                }
+                if !acc.is_finite() {
+                    cold_path();
+                    return Err(LaError::non_finite_computation_matrix(
+                        ArithmeticOperation::PolarDecomposition,
+                        i,
+                        j,
+                    ));
+                }
+                defect = defect.max(acc.abs());
+            }
+        }
+        Ok(defect)
+    }
+
+    /// One cyclic one-sided Jacobi sweep over every column pair of `cols`,
+    /// applying the same rotations to the columns of `v`.
+    ///
+    /// Returns the largest relative inner product
+    /// `|a_p · a_q| / (‖a_p‖₂ ‖a_q‖₂)` seen before rotating; when it is
+    /// `<= tolerance` the sweep rotated nothing. The columns of `cols` must
+    /// be scaled so that no inner product overflows.
+    fn jacobi_sweep(cols: &mut [[f64; D]; D], v: &mut [[f64; D]; D], tolerance: f64) -> f64 {
+        let mut correlation: f64 = 0.0;
+        for p in 0..D {
+            for q in (p + 1)..D {
+                let (mut alpha, mut beta, mut gamma) = (0.0_f64, 0.0_f64, 0.0_f64);
+                for (&x, &y) in cols[p].iter().zip(&cols[q]) {
+                    alpha = x.mul_add(x, alpha);
+                    beta = y.mul_add(y, beta);
+                    gamma = x.mul_add(y, gamma);
+                }
+                // A column whose squared norm underflows is negligible next
+                // to the unit-scaled largest entry and counts as orthogonal.
+                if gamma == 0.0 || alpha == 0.0 || beta == 0.0 {
+                    continue;
+                }
+
+                let pair = gamma.abs() / (alpha.sqrt() * beta.sqrt());
+                correlation = correlation.max(pair);
+                if pair <= tolerance {
+                    continue;
+                }
+
+                // Rotation diagonalizing the 2×2 Gram block [[α, γ], [γ, β]].
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t = 1.0_f64.copysign(zeta) / (zeta.abs() + zeta.hypot(1.0));
+                let c = t.hypot(1.0).recip();
+                let s = c * t;
+                Self::rotate_columns(cols, p, q, c, s);
+                Self::rotate_columns(v, p, q, c, s);
+            }
+        }
+        correlation
+    }
+
+    /// Replace columns `p` and `q` by `c a_p − s a_q` and `s a_p + c a_q`.
+    fn rotate_columns(cols: &mut [[f64; D]; D], p: usize, q: usize, c: f64, s: f64) {
+        let (head, tail) = cols.split_at_mut(q);
+        for (x, y) in head[p].iter_mut().zip(&mut tail[0]) {
+            let (xp, yq) = (*x, *y);
+            *x = c.mul_add(xp, -s * yq);
+            *y = s.mul_add(xp, c * yq);
+        }
+    }
+
+    /// Overwrite `u[rank..]` with unit vectors orthogonal to `u[..rank]` and
+    /// to each other.
+    ///
+    /// Each new column is the standard basis vector with the largest residual
+    /// after two Gram–Schmidt passes against the columns already in place;
+    /// since fewer than `D` columns are in place, that residual is non-zero.
+    fn complete_orthonormal_columns(u: &mut [[f64; D]; D], rank: usize) {
+        for k in rank..D {
+            let mut best = [0.0_f64; D];
+            let mut best_norm = 0.0;
+            for e in 0..D {
+                let mut candidate = [0.0_f64; D];
+                candidate[e] = 1.0;
+                for _ in 0..2 {
+                    for basis in &u[..k] {
+                        let projection = candidate
+                            .iter()
+                            .zip(basis)
+                            .fold(0.0_f64, |acc, (&x, &b)| x.mul_add(b, acc));
+                        for (x, &b) in candidate.iter_mut().zip(basis) {
+                            *x = (-projection).mul_add(b, *x);
+                        }
+                    }
+                }
+                let norm = candidate
+                    .iter()
+                    .fold(0.0_f64, |acc, &x| x.mul_add(x, acc))
+                    .sqrt();
+                if norm > best_norm {
+                    best = candidate;
+                    best_norm = norm;
+                }
+            }
+            u[k] = best.map(|x| x / best_norm);
+        }
+    }
+
+    /// Return the first non-finite stored cell in row-major order.
+    const fn first_non_finite_cell(rows: &[[f64; D]; D]) -> Option<(usize, usize)> {
+        let mut r = 0;
+        while r < D {
+            let mut c = 0;
+            while c < D {
+                if !rows[r][c].is_finite() {
+                    return Some((r, c));
+                }
+                c += 1;
+            }
+            r += 1;
+        }
+        None
+    }
+
+    /// Compute the approximate-symmetry tolerance scale for a finite matrix.
+    ///
+    /// This helper protects the public [`is_symmetric`](Self::is_symmetric) and
+    /// [`first_asymmetry`](Self::first_asymmetry) diagnostic contracts: the
+    /// documented norm-first formula is used whenever its intermediate is
+    /// representable, while an overflow-safe termwise fallback reports the
+    /// matrix cell that makes the scaled tolerance non-finite.
+    fn symmetry_epsilon(&self, rel_tol: Tolerance) -> Result<f64, LaError> {
+        let rel_tol = rel_tol.get();
+
+        if rel_tol == 0.0 {
+            return Ok(rel_tol);
+        }
+
+        if let Ok(norm) = self.inf_norm() {
+            let scale = if norm > 1.0 { norm } else { 1.0 };
+            let eps = rel_tol * scale;
+            if eps.is_finite() {
+                return Ok(eps);
+            }
+        }
+
+        // If the unscaled row sum or the final multiplication overflows, apply
+        // the tolerance to each non-negative contribution before summing. A row
+        // can overflow only at magnitudes where multiplication by the smallest
+        // positive tolerance is normal, so this fallback cannot introduce the
+        // gradual-underflow discrepancy avoided by the direct path above.
+        let mut eps = rel_tol;
+
+        for r in 0..D {
+            let mut row_eps = 0.0;
+            for c in 0..D {
+                row_eps = rel_tol.mul_add(self.rows[r][c].abs(), row_eps);
+                if !row_eps.is_finite() {
+                    cold_path();
+                    return Err(LaError::non_finite_computation_matrix(
+                        ArithmeticOperation::SymmetryCheck,
+                        r,
+                        c,
+                    ));
+                }
+            }
+            if row_eps > eps {
+                eps = row_eps;
+            }
+        }
+
+        Ok(eps)
+    }
+
+    /// Closed-form determinant for dimensions 0–4, bypassing LU factorization.
+    ///
+    /// Returns `Ok(Some(det))` for `D` ∈ {0, 1, 2, 3, 4}, `Ok(None)` for D ≥ 5.
+    /// `D = 0` returns `Ok(Some(1.0))` (empty product).
+    /// This is a `const fn` (Rust 1.94+) and uses fused multiply-add (`mul_add`)
+    /// for improved accuracy and performance.
+    ///
+    /// For a determinant that works for any dimension (falling back to LU for D ≥ 5),
+    /// use [`det`](Self::det).
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let m = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]])?;
+    /// assert_eq!(m.det_direct()?, Some(-2.0));
+    ///
+    /// // D = 0 is the empty product.
+    /// assert_eq!(Matrix::<0>::zero().det_direct()?, Some(1.0));
+    ///
+    /// // D ≥ 5 returns None.
+    /// assert!(Matrix::<5>::identity().det_direct()?.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] when the closed-form determinant overflows
+    /// to NaN or infinity.
+    #[inline]
+    pub const fn det_direct(&self) -> Result<Option<f64>, LaError> {
+        let Some(det) = self.det_direct_arithmetic::<false>() else {
+            cold_path();
+            return Ok(None);
+        };
+
+        Self::computed_scalar_result(ArithmeticOperation::Determinant, det.value)
+    }
+
+    /// Evaluate the closed-form determinant while certifying every rounded
+    /// operation against gradual underflow.
+    #[expect(
+        clippy::inline_always,
+        reason = "det_direct callers must eliminate unused filter-safety bookkeeping"
+    )]
+    #[inline(always)]
+    const fn det_direct_arithmetic<const TRACK_UNDERFLOW: bool>(
+        &self,
+    ) -> Option<FilterArithmetic<TRACK_UNDERFLOW>> {
+        match D {
+            0 => Some(FilterArithmetic {
+                value: 1.0,
+                underflow_safe: true,
+            }),
+            1 => Some(FilterArithmetic {
+                value: self.rows[0][0],
+                underflow_safe: true,
+            }),
+            2 => {
+                let a = self.rows[0][0];
+                let b = self.rows[0][1];
+                let c = self.rows[1][0];
+                let d = self.rows[1][1];
+                let subtrahend = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(b, c);
+                let mut det = FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(a, d, -subtrahend.value);
+                det.underflow_safe &= subtrahend.underflow_safe;
+                Some(det)
+            }
+            3 => Some(Self::det3_elements::<TRACK_UNDERFLOW>(
+                [self.rows[0][0], self.rows[0][1], self.rows[0][2]],
+                [self.rows[1][0], self.rows[1][1], self.rows[1][2]],
+                [self.rows[2][0], self.rows[2][1], self.rows[2][2]],
+            )),
+            4 => {
+                if !TRACK_UNDERFLOW && let Some(input) = Det4SharedMinorInput::try_new(self) {
+                    return Some(FilterArithmetic {
+                        value: Self::det4_dense_elements(input),
+                        underflow_safe: true,
+                    });
+                }
+
+                let r = &self.rows;
+                let mut det = if r[0][3] == 0.0 {
+                    FilterArithmetic {
+                        value: 0.0,
+                        underflow_safe: true,
+                    }
+                } else {
+                    let c03 = Self::det3_elements::<TRACK_UNDERFLOW>(
+                        [r[1][0], r[1][1], r[1][2]],
+                        [r[2][0], r[2][1], r[2][2]],
+                        [r[3][0], r[3][1], r[3][2]],
+                    );
+                    let mut term =
+                        FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r[0][3], c03.value);
+                    term.value = -term.value;
+                    term.underflow_safe &= c03.underflow_safe;
+                    term
+                };
+                if r[0][2] != 0.0 {
+                    let c02 = Self::det3_elements::<TRACK_UNDERFLOW>(
+                        [r[1][0], r[1][1], r[1][3]],
+                        [r[2][0], r[2][1], r[2][3]],
+                        [r[3][0], r[3][1], r[3][3]],
+                    );
+                    let prior_safe = det.underflow_safe && c02.underflow_safe;
+                    det =
+                        FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(r[0][2], c02.value, det.value);
+                    det.underflow_safe &= prior_safe;
+                }
+                if r[0][1] != 0.0 {
+                    let c01 = Self::det3_elements::<TRACK_UNDERFLOW>(
+                        [r[1][0], r[1][2], r[1][3]],
+                        [r[2][0], r[2][2], r[2][3]],
+                        [r[3][0], r[3][2], r[3][3]],
+                    );
+                    let prior_safe = det.underflow_safe && c01.underflow_safe;
+                    det = FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(
+                        -r[0][1], c01.value, det.value,
+                    );
+                    det.underflow_safe &= prior_safe;
+                }
+                if r[0][0] != 0.0 {
+                    let c00 = Self::det3_elements::<TRACK_UNDERFLOW>(
+                        [r[1][1], r[1][2], r[1][3]],
+                        [r[2][1], r[2][2], r[2][3]],
+                        [r[3][1], r[3][2], r[3][3]],
+                    );
+                    let prior_safe = det.underflow_safe && c00.underflow_safe;
+                    det =
+                        FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(r[0][0], c00.value, det.value);
+                    det.underflow_safe &= prior_safe;
+                }
+
+                Some(det)
+            }
+            _ => None,
+        }
+    }
+
+    /// Evaluate the proof-bearing 4×4 cofactor expansion with shared 2×2 minors.
+    ///
+    /// When no intermediate undergoes gradual underflow, the rounding error is
+    /// bounded by `ERR_COEFF_4 · p(|A|)`, where `p(|A|)` is the absolute Leibniz
+    /// sum. This helper returns only the determinant; use
+    /// [`Self::det_errbound`] or [`Self::det_direct_with_errbound`] to obtain the
+    /// certified bound.
+    #[expect(
+        clippy::inline_always,
+        reason = "the D=4 determinant hot path must inline its shared-minor expansion"
+    )]
+    #[inline(always)]
+    const fn det4_dense_elements(input: Det4SharedMinorInput<'_, D>) -> f64 {
+        let r = &input.matrix.rows;
+        let s23 = r[2][2].mul_add(r[3][3], -(r[2][3] * r[3][2]));
+        let s13 = r[2][1].mul_add(r[3][3], -(r[2][3] * r[3][1]));
+        let s12 = r[2][1].mul_add(r[3][2], -(r[2][2] * r[3][1]));
+        let s03 = r[2][0].mul_add(r[3][3], -(r[2][3] * r[3][0]));
+        let s02 = r[2][0].mul_add(r[3][2], -(r[2][2] * r[3][0]));
+        let s01 = r[2][0].mul_add(r[3][1], -(r[2][1] * r[3][0]));
+
+        let c00 = r[1][1].mul_add(s23, (-r[1][2]).mul_add(s13, r[1][3] * s12));
+        let c01 = r[1][0].mul_add(s23, (-r[1][2]).mul_add(s03, r[1][3] * s02));
+        let c02 = r[1][0].mul_add(s13, (-r[1][1]).mul_add(s03, r[1][3] * s01));
+        let c03 = r[1][0].mul_add(s12, (-r[1][1]).mul_add(s02, r[1][2] * s01));
+
+        r[0][0].mul_add(
+            c00,
+            (-r[0][1]).mul_add(c01, r[0][2].mul_add(c02, -(r[0][3] * c03))),
+        )
+    }
+
+    /// Evaluate the dense 4×4 absolute permanent with shared 2×2 minors.
+    ///
+    /// The proof carried by `input` makes every shared minor part of an active
+    /// Leibniz term. The caller separately establishes a wide exponent margin,
+    /// so this branch-free kernel cannot hide gradual underflow or evaluate an
+    /// overflowing minor for a mathematically absent term.
+    #[expect(
+        clippy::inline_always,
+        reason = "the D=4 determinant filter must inline its shared-minor permanent"
+    )]
+    #[inline(always)]
+    const fn det4_dense_abs_permanent_elements(input: Det4SharedMinorInput<'_, D>) -> f64 {
+        let r = &input.matrix.rows;
+        let sp23 = (r[2][2] * r[3][3]).abs() + (r[2][3] * r[3][2]).abs();
+        let sp13 = (r[2][1] * r[3][3]).abs() + (r[2][3] * r[3][1]).abs();
+        let sp12 = (r[2][1] * r[3][2]).abs() + (r[2][2] * r[3][1]).abs();
+        let sp03 = (r[2][0] * r[3][3]).abs() + (r[2][3] * r[3][0]).abs();
+        let sp02 = (r[2][0] * r[3][2]).abs() + (r[2][2] * r[3][0]).abs();
+        let sp01 = (r[2][0] * r[3][1]).abs() + (r[2][1] * r[3][0]).abs();
+
+        let pc0 = r[1][3]
+            .abs()
+            .mul_add(sp12, r[1][2].abs().mul_add(sp13, r[1][1].abs() * sp23));
+        let pc1 = r[1][3]
+            .abs()
+            .mul_add(sp02, r[1][2].abs().mul_add(sp03, r[1][0].abs() * sp23));
+        let pc2 = r[1][3]
+            .abs()
+            .mul_add(sp01, r[1][1].abs().mul_add(sp03, r[1][0].abs() * sp13));
+        let pc3 = r[1][2]
+            .abs()
+            .mul_add(sp01, r[1][1].abs().mul_add(sp02, r[1][0].abs() * sp12));
+
+        r[0][3].abs().mul_add(
+            pc3,
+            r[0][2]
+                .abs()
+                .mul_add(pc2, r[0][1].abs().mul_add(pc1, r[0][0].abs() * pc0)),
+        )
+    }
+
+    /// Floating-point determinant, using closed-form formulas for D ≤ 4 and
+    /// LU decomposition for D ≥ 5.
+    ///
+    /// For D ∈ {1, 2, 3, 4}, this bypasses LU factorization entirely for a significant
+    /// speedup (see [`det_direct`](Self::det_direct)).
+    ///
+    /// Because this method mixes closed-form paths from
+    /// [`det_direct`](Self::det_direct) with an LU fallback, the returned value has
+    /// no certified absolute error bound. Use
+    /// [`det_errbound`](Self::det_errbound) for D ≤ 4 bounds, or the exact
+    /// determinant APIs when exact singularity classification or certified values
+    /// matter. For D ≥ 5, the zero-tolerance LU fallback surfaces
+    /// [`LaError::Singular`] when elimination cannot produce a non-zero pivot.
+    /// Floating-point elimination cannot in general distinguish an exactly
+    /// singular matrix from a non-singular matrix whose intermediate pivot
+    /// rounded to zero, so this method never converts that numerical failure into
+    /// an exact `0.0` result.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let det = Matrix::<3>::identity().det()?;
+    /// assert!((det - 1.0).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// The LU fallback accumulates its diagonal product with power-of-two
+    /// scaling, so factor order cannot cause premature overflow or underflow in
+    /// the final product. Elimination intermediates remain subject to binary64
+    /// rounding and range limits.
+    ///
+    /// # Errors
+    /// Returns [`LaError::Singular`] if the D ≥ 5 LU fallback cannot produce a
+    /// non-zero pivot, including when a non-zero mathematical intermediate rounds
+    /// to zero during elimination. Returns [`LaError::NonFinite`] if a D ≤ 4
+    /// closed-form result is non-finite, if the LU fallback computes a
+    /// non-finite factorization cell, or if its final scaled determinant cannot
+    /// be represented as a finite `f64`.
+    #[inline]
+    pub fn det(self) -> Result<f64, LaError> {
+        if let Some(d) = self.det_direct()? {
+            return Ok(d);
+        }
+        self.lu(Tolerance::ZERO)?.det()
+    }
+
+    /// Gradient of the determinant with respect to the matrix entries,
+    /// `∂det(A)/∂A = det(A) A⁻ᵀ`, the cofactor matrix of `A`.
+    ///
+    /// By Jacobi's formula, entry `(i, j)` is the sensitivity of `det(A)` to
+    /// `A[i][j]`. Both factors come from one partial-pivoting LU factorization:
+    /// the determinant from its pivots and `A⁻¹` from one solve per basis
+    /// column, in `O(D³)` operations. The cofactor matrix of a singular matrix
+    /// is still defined but cannot be formed this way, so LU pivots with
+    /// `|U[i][i]| <= tol` are rejected. No certified error bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // det = ad − bc, so the gradient is [[d, −c], [−b, a]].
+    /// let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]])?;
+    /// let grad = a.det_gradient(DEFAULT_SINGULAR_TOL)?;
+    /// for (got, want) in grad.as_rows().iter().zip([[4.0, -3.0], [-2.0, 1.0]]) {
+    ///     for (g, w) in got.iter().zip(want) {
+    ///         assert!((g - w).abs() <= 1e-12);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::Singular`] if an LU pivot satisfies
+    /// `|U[i][i]| <= tol`.
+    /// Returns [`LaError::NonFinite`] if the factorization, the determinant,
+    /// the inverse, or a scaled gradient entry overflows.
+    #[inline]
+    pub fn det_gradient(self, tol: Tolerance) -> Result<Self, LaError> {
+        let lu = self.lu(tol)?;
+        let det = lu.det()?;
+        let inverse = lu.inverse()?;
+
+        let mut rows = [[0.0; D]; D];
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry = det * inverse.rows[j][i];
+            }
+        }
+        Self::from_computation(rows, ArithmeticOperation::DeterminantGradient)
+    }
+
+    /// One-norm condition number `κ₁(A) = ‖A‖₁ ‖A⁻¹‖₁`.
+    ///
+    /// `‖·‖₁` is the maximum absolute column sum. `A⁻¹` is formed explicitly
+    /// from a partial-pivoting LU factorization, one solve per basis column,
+    /// so this costs `O(D³)` operations and is exact up to rounding rather
+    /// than an estimate. `κ₁ ≥ 1`, and `log₁₀ κ₁` roughly counts the decimal
+    /// digits a solve with `A` can lose. `Matrix<0>` has condition number `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[1.0, 0.0], [0.0, 1e-6]])?;
+    /// let cond = a.cond_1(DEFAULT_SINGULAR_TOL)?;
+    /// assert!((cond - 1e6).abs() <= 1e-6);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::Singular`] if an LU pivot satisfies
+    /// `|U[i][i]| <= tol`.
+    /// Returns [`LaError::NonFinite`] if the factorization or inverse
+    /// overflows, or if a column sum or the final product overflows.
+    #[inline]
+    pub fn cond_1(self, tol: Tolerance) -> Result<f64, LaError> {
+        let inverse = self.lu(tol)?.inverse()?;
+        let cond = self.max_abs_col_sum() * inverse.max_abs_col_sum();
+        if cond.is_finite() {
+            Ok(cond)
+        } else {
+            cold_path();
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::ConditionNumber,
+            ))
+        }
+    }
+
+    /// Maximum absolute column sum `‖A‖₁`; infinite if a sum overflows.
+    fn max_abs_col_sum(&self) -> f64 {
+        let mut col_sums = [0.0_f64; D];
+        for row in &self.rows {
+            for (sum, value) in col_sums.iter_mut().zip(row) {
+                *sum += value.abs();
+            }
+        }
+        col_sums.into_iter().fold(0.0, f64::max)
+    }
+
+    /// Determinant of each matrix of a fixed-size batch with
+    /// [`det`](Self::det).
+    ///
+    /// Element `i` of the result is exactly `mats[i].det()`, so D ≤ 4 batches
+    /// use the closed-form path throughout and each matrix reports its own
+    /// error. Like [`lu_batch`](Self::lu_batch), the batch size is a
+    /// compile-time constant and the results are returned on the stack.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Orientation of two triangles, via their 3×3 homogeneous matrices.
+    /// let mats = [
+    ///     Matrix::<3>::try_from_rows([[0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0]])?,
+    ///     Matrix::<3>::try_from_rows([[0.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 0.0, 1.0]])?,
+    /// ];
+    /// let [ccw, cw] = Matrix::det_batch(&mats);
+    ///
+    /// assert_eq!(ccw?, 1.0);
+    /// assert_eq!(cw?, -1.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn det_batch<const N: usize>(mats: &[Self; N]) -> [Result<f64, LaError>; N] {
+        mats.each_ref().map(|a| a.det())
+    }
+
+    /// Floating-point orientation: the sign of `det(A)` as an [`Ordering`].
+    ///
+    /// The sign is read from the LU permutation parity and the signs of the
+    /// `U` pivots, never from their product, so it cannot overflow or
+    /// underflow at any dimension. If elimination meets a pivot with
+    /// `|U[i][i]| <= tol` the matrix is reported as degenerate
+    /// ([`Ordering::Equal`]). Rounding during elimination can still flip the
+    /// sign of a nearly degenerate matrix; with the `exact` feature,
+    /// `det_sign_exact` gives a certified answer.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Orientation of the triangle (0,0), (1,0), (0,1) and its mirror image.
+    /// let ccw = Matrix::<3>::try_from_rows([[0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0]])?;
+    /// let cw = Matrix::<3>::try_from_rows([[0.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 0.0, 1.0]])?;
+    /// assert_eq!(ccw.orientation(DEFAULT_SINGULAR_TOL)?, Ordering::Greater);
+    /// assert_eq!(cw.orientation(DEFAULT_SINGULAR_TOL)?, Ordering::Less);
+    ///
+    /// // Collinear points.
+    /// let flat = Matrix::<3>::try_from_rows([[0.0, 0.0, 1.0], [1.0, 1.0, 1.0], [2.0, 2.0, 1.0]])?;
+    /// assert_eq!(flat.orientation(DEFAULT_SINGULAR_TOL)?, Ordering::Equal);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] if an elimination intermediate overflows,
+    /// as in [`lu`](Self::lu).
+    #[inline]
+    pub fn orientation(&self, tol: Tolerance) -> Result<Ordering, LaError> {
+        match self.lu(tol) {
+            Ok(lu) => Ok(lu.det_sign()),
+            Err(LaError::Singular { .. }) => Ok(Ordering::Equal),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Signed volume of the simplex with vertices `p₀, …, p_D`.
+    ///
+    /// Builds the edge matrix with rows `pᵢ − p₀` and returns `det / D!`, so a
+    /// triangle in the plane has positive area when its vertices run
+    /// counter-clockwise and a tetrahedron has positive volume when
+    /// `(p₁ − p₀) · ((p₂ − p₀) × (p₃ − p₀)) > 0`. Swapping two vertices flips
+    /// the sign, and degenerate simplices have volume zero. The determinant
+    /// is divided by `2, 3, …, D` in turn, so `D!` itself is never formed.
+    /// Rounding in the edge differences and in [`det`](Self::det) can change
+    /// the sign of a nearly degenerate simplex; use
+    /// [`orientation`](Self::orientation) or, with the `exact` feature,
+    /// `det_sign_exact` when only the sign matters.
+    ///
+    /// Stable Rust cannot yet name an array of length `D + 1` for a generic
+    /// `D`, so the vertex count is a separate parameter `N` checked at run
+    /// time.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let p0 = Vector::<2>::try_new([0.0, 0.0])?;
+    /// let p1 = Vector::<2>::try_new([4.0, 0.0])?;
+    /// let p2 = Vector::<2>::try_new([0.0, 3.0])?;
+    ///
+    /// assert_eq!(Matrix::simplex_volume(&[p0, p1, p2])?, 6.0);
+    /// assert_eq!(Matrix::simplex_volume(&[p0, p2, p1])?, -6.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::DimensionMismatch`] with `expected: D + 1` unless
+    /// exactly `D + 1` vertices are given. Returns [`LaError::NonFinite`] if
+    /// an edge difference or the determinant overflows.
+    #[inline]
+    pub fn simplex_volume<const N: usize>(points: &[Vector<D>; N]) -> Result<f64, LaError> {
+        if N != D + 1 {
+            cold_path();
+            return Err(LaError::dimension_mismatch(D + 1, N));
+        }
+        let origin = points[0].as_array();
+        let mut rows = [[0.0; D]; D];
+        for (row, point) in rows.iter_mut().zip(&points[1..]) {
+            for ((entry, &x), &o) in row.iter_mut().zip(point.as_array()).zip(origin) {
+                *entry = x - o;
+            }
+        }
+
+        let mut volume =
+            match Self::from_computation(rows, ArithmeticOperation::SimplexVolume)?.det() {
+                Ok(det) => det,
+                Err(LaError::Singular { .. }) => return Ok(0.0),
+                Err(err) => return Err(err),
+            };
+        let mut k = 1.0;
+        for _ in 1..D {
+            k += 1.0;
+            volume /= k;
+        }
+        Ok(volume)
+    }
+
+    /// Circumcenter of the simplex with vertices `p₀, …, p_D`: the point
+    /// equidistant from all of them.
+    ///
+    /// Writing the center as `p₀ + x`, equal distances to `p₀` and `pᵢ` give
+    /// the linear equations `(pᵢ − p₀) · x = ‖pᵢ − p₀‖² / 2`, one per edge
+    /// from `p₀`. The edge matrix is factored with [`lu`](Self::lu), whose
+    /// pivot tolerance `tol` rejects degenerate simplices; a nearly degenerate
+    /// simplex has a distant, ill-conditioned center. No certified error bound
+    /// is provided.
+    ///
+    /// `N` must equal `D + 1`; see [`simplex_volume`](Self::simplex_volume).
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // A right triangle's circumcenter is the midpoint of its hypotenuse.
+    /// let triangle = [
+    ///     Vector::<2>::try_new([1.0, 1.0])?,
+    ///     Vector::<2>::try_new([5.0, 1.0])?,
+    ///     Vector::<2>::try_new([1.0, 3.0])?,
+    /// ];
+    /// let center = Matrix::circumcenter(&triangle, DEFAULT_SINGULAR_TOL)?;
+    /// assert_eq!(center.into_array(), [3.0, 2.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::DimensionMismatch`] with `expected: D + 1` unless
+    /// exactly `D + 1` vertices are given. Returns [`LaError::Singular`] if the
+    /// simplex is degenerate (collinear, coplanar, …) under `tol`. Returns
+    /// [`LaError::NonFinite`] if an edge, its squared length, the solve, or the
+    /// translated center overflows.
+    #[inline]
+    pub fn circumcenter<const N: usize>(
+        points: &[Vector<D>; N],
+        tol: Tolerance,
+    ) -> Result<Vector<D>, LaError> {
+        let offset = Self::circumcenter_offset(points, tol)?;
+        let mut center = offset.into_array();
+        for (c, &o) in center.iter_mut().zip(points[0].as_array()) {
+            *c += o;
+        }
+        Vector::from_computation(center, ArithmeticOperation::Circumcenter)
+    }
+
+    /// Circumradius of the simplex with vertices `p₀, …, p_D`: the distance
+    /// from its [`circumcenter`](Self::circumcenter) to every vertex.
+    ///
+    /// The radius is the length of the center's offset `x` from `p₀`, taken
+    /// directly from the circumcenter system rather than from the translated
+    /// center, so translating the simplex far from the origin does not cost
+    /// accuracy. The length is evaluated with max-abs scaling and cannot
+    /// overflow while `x` is finite. No certified error bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // A 3-4-5 right triangle has circumradius 5 / 2.
+    /// let triangle = [
+    ///     Vector::<2>::try_new([0.0, 0.0])?,
+    ///     Vector::<2>::try_new([3.0, 0.0])?,
+    ///     Vector::<2>::try_new([0.0, 4.0])?,
+    /// ];
+    /// assert_eq!(Matrix::circumradius(&triangle, DEFAULT_SINGULAR_TOL)?, 2.5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the same errors as [`circumcenter`](Self::circumcenter) for a
+    /// wrong vertex count, a degenerate simplex, or an overflowing solve.
+    /// Returns [`LaError::NonFinite`] if the radius itself overflows.
+    #[inline]
+    pub fn circumradius<const N: usize>(
+        points: &[Vector<D>; N],
+        tol: Tolerance,
+    ) -> Result<f64, LaError> {
+        let (max_abs, scaled_norm) = Self::circumcenter_offset(points, tol)?.scaled_norm2();
+        let radius = max_abs * scaled_norm;
+        if radius.is_finite() {
+            Ok(radius)
+        } else {
+            cold_path();
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::Circumradius,
+            ))
+        }
+    }
+
+    /// Offset `x` of the circumcenter from `p₀`, solving
+    /// `(pᵢ − p₀) · x = ‖pᵢ − p₀‖² / 2`.
+    fn circumcenter_offset<const N: usize>(
+        points: &[Vector<D>; N],
+        tol: Tolerance,
+    ) -> Result<Vector<D>, LaError> {
+        if N != D + 1 {
+            cold_path();
+            return Err(LaError::dimension_mismatch(D + 1, N));
+        }
+        let operation = ArithmeticOperation::Circumcenter;
+
+        let origin = points[0].as_array();
+        let mut edges = [[0.0; D]; D];
+        let mut half_sq_lengths = [0.0; D];
+        for ((edge, half_sq), point) in edges.iter_mut().zip(&mut half_sq_lengths).zip(&points[1..])
+        {
+            let mut sq = 0.0;
+            for ((entry, &x), &o) in edge.iter_mut().zip(point.as_array()).zip(origin) {
+                *entry = x - o;
+                sq = entry.mul_add(*entry, sq);
+            }
+            *half_sq = 0.5 * sq;
+        }
+
+        Self::from_computation(edges, operation)?
+            .lu(tol)?
+            .solve(Vector::from_computation(half_sq_lengths, operation)?)
+    }
+
+    /// Barycentric coordinates `λ₀, …, λ_D` of `query` in the simplex with
+    /// vertices `p₀, …, p_D`.
+    ///
+    /// The coordinates satisfy `Σ λᵢ pᵢ = query` and `Σ λᵢ = 1`. Eliminating
+    /// `λ₀` leaves the `D × D` system `Σ_{i≥1} λᵢ (pᵢ − p₀) = query − p₀`,
+    /// whose matrix has the edges from `p₀` as columns; it is factored with
+    /// [`lu`](Self::lu), and `λ₀ = 1 − Σ_{i≥1} λᵢ`. `query` lies inside the
+    /// simplex when every coordinate is positive, on its boundary when the
+    /// smallest is zero, and outside when any is negative; rounding blurs
+    /// these cases near the boundary. No certified error bound is provided.
+    ///
+    /// `N` must equal `D + 1`; see [`simplex_volume`](Self::simplex_volume).
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let triangle = [
+    ///     Vector::<2>::try_new([0.0, 0.0])?,
+    ///     Vector::<2>::try_new([4.0, 0.0])?,
+    ///     Vector::<2>::try_new([0.0, 4.0])?,
+    /// ];
+    /// let inside = Vector::<2>::try_new([1.0, 2.0])?;
+    /// let coords = Matrix::barycentric(&triangle, &inside, DEFAULT_SINGULAR_TOL)?;
+    /// assert_eq!(coords, [0.25, 0.25, 0.5]);
+    ///
+    /// let outside = Vector::<2>::try_new([3.0, 3.0])?;
+    /// let coords = Matrix::barycentric(&triangle, &outside, DEFAULT_SINGULAR_TOL)?;
+    /// assert!(coords[0] < 0.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::DimensionMismatch`] with `expected: D + 1` unless
+    /// exactly `D + 1` vertices are given. Returns [`LaError::Singular`] if the
+    /// simplex is degenerate under `tol`. Returns [`LaError::NonFinite`] if an
+    /// edge, the query offset, the solve, or a coordinate overflows; a
+    /// coordinate is reported at its index.
+    #[inline]
+    pub fn barycentric<const N: usize>(
+        simplex: &[Vector<D>; N],
+        query: &Vector<D>,
+        tol: Tolerance,
+    ) -> Result<[f64; N], LaError> {
+        if N != D + 1 {
+            cold_path();
+            return Err(LaError::dimension_mismatch(D + 1, N));
+        }
+        let operation = ArithmeticOperation::BarycentricCoordinates;
+
+        let origin = simplex[0].as_array();
+        let mut edges = [[0.0; D]; D];
+        for (edge, vertex) in edges.iter_mut().zip(&simplex[1..]) {
+            for ((entry, &x), &o) in edge.iter_mut().zip(vertex.as_array()).zip(origin) {
+                *entry = x - o;
+            }
+        }
+        let mut offset = *query.as_array();
+        for (x, &o) in offset.iter_mut().zip(origin) {
+            *x -= o;
+        }
+
+        let weights = Self::from_computation(edges, operation)?
+            .transpose()
+            .lu(tol)?
+            .solve(Vector::from_computation(offset, operation)?)?;
+        let mut coords = [0.0; N];
+        if let Some((first, rest)) = coords.split_first_mut() {
+            *first = 1.0;
+            for (coord, &w) in rest.iter_mut().zip(weights.as_array()) {
+                *coord = w;
+                *first -= w;
+            }
+        }
+        if let Some(index) = coords.iter().position(|c| !c.is_finite()) {
+            cold_path();
+            return Err(LaError::non_finite_computation_step(operation, index));
+        }
+        Ok(coords)
+    }
+
+    /// Affine map `x ↦ A x + t` taking each `src[i]` to `dst[i]`, returned as
+    /// `(A, t)`.
+    ///
+    /// `D + 1` affinely independent points determine the map. Subtracting the
+    /// first correspondence removes `t`: `A (sᵢ − s₀) = dᵢ − d₀`, and
+    /// transposing gives `D` systems sharing the matrix of source edges, one
+    /// per row of `A`. That matrix is factored once with [`lu`](Self::lu),
+    /// and then `t = d₀ − A s₀`. With `src` and `dst` swapped the result is
+    /// the inverse map. No certified error bound is provided.
+    ///
+    /// `N` must equal `D + 1`; see [`simplex_volume`](Self::simplex_volume).
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Scale x by 2 and shift by (1, −1).
+    /// let src = [
+    ///     Vector::<2>::try_new([0.0, 0.0])?,
+    ///     Vector::<2>::try_new([1.0, 0.0])?,
+    ///     Vector::<2>::try_new([0.0, 1.0])?,
+    /// ];
+    /// let dst = [
+    ///     Vector::<2>::try_new([1.0, -1.0])?,
+    ///     Vector::<2>::try_new([3.0, -1.0])?,
+    ///     Vector::<2>::try_new([1.0, 0.0])?,
+    /// ];
+    /// let (a, t) = Matrix::affine_from_correspondences(&src, &dst, DEFAULT_SINGULAR_TOL)?;
+    /// assert_eq!(a, Matrix::<2>::try_from_rows([[2.0, 0.0], [0.0, 1.0]])?);
+    /// assert_eq!(t.into_array(), [1.0, -1.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::DimensionMismatch`] with `expected: D + 1` unless
+    /// exactly `D + 1` correspondences are given. Returns
+    /// [`LaError::Singular`] if the source points are affinely dependent under
+    /// `tol`. Returns [`LaError::NonFinite`] if an edge, a solve, or the
+    /// translation overflows.
+    #[inline]
+    pub fn affine_from_correspondences<const N: usize>(
+        src: &[Vector<D>; N],
+        dst: &[Vector<D>; N],
+        tol: Tolerance,
+    ) -> Result<(Self, Vector<D>), LaError> {
+        if N != D + 1 {
+            cold_path();
+            return Err(LaError::dimension_mismatch(D + 1, N));
+        }
+        let operation = ArithmeticOperation::AffineCorrespondence;
+
+        let edges = |points: &[Vector<D>; N]| {
+            let origin = points[0].as_array();
+            let mut rows = [[0.0; D]; D];
+            for (row, point) in rows.iter_mut().zip(&points[1..]) {
+                for ((entry, &x), &o) in row.iter_mut().zip(point.as_array()).zip(origin) {
+                    *entry = x - o;
+                }
+            }
+            Self::from_computation(rows, operation)
+        };
+        let lu = edges(src)?.lu(tol)?;
+        let dst_edges = edges(dst)?.transpose();
+
+        let mut linear = [[0.0; D]; D];
+        for (row, rhs) in linear.iter_mut().zip(dst_edges.rows) {
+            *row = lu
+                .solve(Vector::from_computation(rhs, operation)?)?
+                .into_array();
+        }
+        let linear = Self::from_computation(linear, operation)?;
+
+        let mut translation = *dst[0].as_array();
+        for (t, &image) in translation
+            .iter_mut()
+            .zip(linear.mul_vec(&src[0])?.as_array())
+        {
+            *t -= image;
+        }
+        Ok((linear, Vector::from_computation(translation, operation)?))
+    }
+
+    /// In-sphere predicate: where `query` lies relative to the circumsphere of
+    /// the `P`-simplex with vertices `simplex`.
+    ///
+    /// Returns the ordering of the circumradius against the query's distance
+    /// from the circumcenter: [`Ordering::Greater`] strictly inside,
+    /// [`Ordering::Equal`] on the sphere, and [`Ordering::Less`] strictly
+    /// outside. `Self` is the lifted `(P + 1) × (P + 1)` matrix with rows
+    /// `[pᵢ − q, ‖pᵢ − q‖²]`; its determinant sign, corrected by the simplex
+    /// orientation and the parity of `P`, decides the answer, so the result
+    /// does not depend on the vertex order. Both signs are read from LU
+    /// factorizations as in [`orientation`](Self::orientation), with a zero
+    /// pivot tolerance. Rounding in the lifted entries can still misclassify a
+    /// query within a few ulps of the sphere; there is no exact fallback.
+    ///
+    /// The simplex has `D = P + 1` vertices, so `D` is inferred from the array
+    /// length and `P + 1 == D` is checked at run time.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // The circumcircle of this right triangle has center (1, 1) and radius √2.
+    /// let triangle = [
+    ///     Vector::<2>::try_new([0.0, 0.0])?,
+    ///     Vector::<2>::try_new([2.0, 0.0])?,
+    ///     Vector::<2>::try_new([0.0, 2.0])?,
+    /// ];
+    /// let inside = Vector::<2>::try_new([1.5, 1.5])?;
+    /// let on = Vector::<2>::try_new([2.0, 2.0])?;
+    /// let outside = Vector::<2>::try_new([2.5, 2.5])?;
+    ///
+    /// assert_eq!(Matrix::in_sphere(&triangle, &inside)?, Ordering::Greater);
+    /// assert_eq!(Matrix::in_sphere(&triangle, &on)?, Ordering::Equal);
+    /// assert_eq!(Matrix::in_sphere(&triangle, &outside)?, Ordering::Less);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::DimensionMismatch`] with `expected: D` and
+    /// `actual: P + 1` unless `P + 1 == D`. Returns [`LaError::Singular`] if
+    /// the simplex is exactly degenerate, since it then has no circumsphere.
+    /// Returns [`LaError::NonFinite`] if an edge, a lifted entry, or an
+    /// elimination intermediate overflows.
+    #[inline]
+    pub fn in_sphere<const P: usize>(
+        simplex: &[Vector<P>; D],
+        query: &Vector<P>,
+    ) -> Result<Ordering, LaError> {
+        if P + 1 != D {
+            cold_path();
+            return Err(LaError::dimension_mismatch(D, P + 1));
+        }
+        let operation = ArithmeticOperation::InSphere;
+
+        let origin = simplex[0].as_array();
+        let mut edges = [[0.0; P]; P];
+        for (edge, vertex) in edges.iter_mut().zip(&simplex[1..]) {
+            for ((entry, &x), &o) in edge.iter_mut().zip(vertex.as_array()).zip(origin) {
+                *entry = x - o;
+            }
+        }
+        let orientation = Matrix::<P>::from_computation(edges, operation)?
+            .lu(Tolerance::ZERO)?
+            .det_sign();
+
+        let mut lifted = [[0.0; D]; D];
+        for (row, vertex) in lifted.iter_mut().zip(simplex) {
+            let mut lift = 0.0;
+            for ((entry, &x), &q) in row.iter_mut().zip(vertex.as_array()).zip(query.as_array()) {
+                *entry = x - q;
+                lift = entry.mul_add(*entry, lift);
+            }
+            row[P] = lift;
+        }
+        let lifted_sign =
+            Self::from_computation(lifted, operation)?.orientation(Tolerance::ZERO)?;
+
+        // For a positively oriented simplex the lifted determinant is
+        // positive inside the sphere when P is even and negative when P is odd.
+        let side = if orientation == Ordering::Less {
+            lifted_sign.reverse()
+        } else {
+            lifted_sign
+        };
+        Ok(if P.is_multiple_of(2) {
+            side
+        } else {
+            side.reverse()
+        })
+    }
+
+    /// Largest dimension accepted by [`permanent`](Self::permanent).
+    const MAX_PERMANENT_DIM: usize = 12;
+
+    /// Permanent `Σ_σ Π_i a[i][σ(i)]`, the determinant's Leibniz sum without
+    /// signs, for dimensions 0–12.
+    ///
+    /// No polynomial-time algorithm is known, so this uses Ryser's
+    /// inclusion–exclusion formula over all `2^D − 1` non-empty column
+    /// subsets, costing `O(2^D · D²)` operations. Returns `Ok(None)` for
+    /// `D > 12`, where that cost stops being a small-matrix computation.
+    /// `D = 0` returns `Ok(Some(1.0))` (empty product).
+    ///
+    /// Ryser's terms alternate in sign and can be far larger than the
+    /// permanent, even when every entry is positive: for the all-ones matrix
+    /// they reach `D^D` while the permanent is `D!`. The row sums of each
+    /// subset are therefore recomputed from the matrix rather than updated
+    /// incrementally, and the terms are accumulated with Neumaier's
+    /// compensated summation. For integer matrices whose row-sum products
+    /// stay below `2^53` every term is exact and so is the result; in
+    /// particular, for a 0/1 biadjacency matrix up to the 12×12 cap this is
+    /// the exact number of perfect matchings. Otherwise rounding in the row
+    /// sums and products is amplified by the cancellation, and no certified
+    /// error bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let m = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]])?;
+    /// assert_eq!(m.permanent()?, Some(10.0));
+    ///
+    /// // Complete bipartite graph K₃,₃ has 3! perfect matchings.
+    /// let ones = Matrix::<3>::try_from_rows([[1.0; 3]; 3])?;
+    /// assert_eq!(ones.permanent()?, Some(6.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] if a row sum, subset product, or the
+    /// accumulated sum overflows to NaN or infinity.
+    #[inline]
+    pub fn permanent(&self) -> Result<Option<f64>, LaError> {
+        if D > Self::MAX_PERMANENT_DIM {
+            return Ok(None);
+        }
+        if D == 0 {
+            return Ok(Some(1.0));
+        }
+
+        // Ryser: perm(A) = (-1)^D Σ_{S ≠ ∅} (-1)^{|S|} Π_i Σ_{j ∈ S} a[i][j].
+        let mut total = 0.0_f64;
+        let mut compensation = 0.0_f64;
+        for subset in 1..(1_u32 << D) {
+            let mut product = 1.0;
+            for row in &self.rows {
+                let mut sum = 0.0;
+                for (col, &entry) in row.iter().enumerate() {
+                    if subset & (1 << col) != 0 {
+                        sum += entry;
+                    }
+                }
+                product *= sum;
+            }
+            let term = if subset.count_ones().is_multiple_of(2) {
+                product
+            } else {
+                -product
+            };
+
+            // Neumaier: carry the rounding error of each addition exactly.
+            let next = total + term;
+            compensation += if total.abs() >= term.abs() {
+                (total - next) + term
+            } else {
+                (term - next) + total
+            };
+            total = next;
+        }
+
+        let sum = total + compensation;
+        let permanent = if D.is_multiple_of(2) { sum } else { -sum };
+        if permanent.is_finite() {
+            Ok(Some(permanent))
+        } else {
+            cold_path();
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::Permanent,
+            ))
+        }
+    }
+
+    /// Evaluate `det_direct()` and its absolute error bound together.
+    ///
+    /// Returns `Ok(Some(result))` for D ≤ 4 when the relative-error analysis
+    /// is valid. The result contains the closed-form determinant and a bound
+    /// such that `|result.determinant() - det_exact| ≤
+    /// result.absolute_error_bound()`. Returns `Ok(None)` when gradual
+    /// underflow could invalidate that analysis or for D ≥ 5, where no
+    /// closed-form bound is available.
+    ///
+    /// This is the preferred API when both values are needed: it evaluates the
+    /// determinant arithmetic tree once, then computes the matching bound for
+    /// the same matrix within that call.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let matrix = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]])?;
+    /// if let Some(estimate) = matrix.det_direct_with_errbound()? {
+    ///     assert_eq!(estimate.determinant(), -2.0);
+    ///     assert!(estimate.absolute_error_bound() >= 0.0);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] when the determinant or bound computation
+    /// overflows to NaN or infinity. Underflow-sensitive finite computations
+    /// return `Ok(None)` because they remain valid inputs for an exact fallback.
+    #[inline]
+    pub const fn det_direct_with_errbound(
+        &self,
+    ) -> Result<Option<DeterminantWithErrorBound>, LaError> {
+        if self.det_bound_inputs_have_wide_exponent_margin() {
+            let Some(det) = self.det_direct_arithmetic::<false>() else {
+                cold_path();
+                return Ok(None);
+            };
+            return self.det_direct_with_errbound_from_arithmetic(det);
+        }
+
+        let Some(det) = self.det_direct_arithmetic::<true>() else {
+            cold_path();
+            return Ok(None);
+        };
+        self.det_direct_with_errbound_from_arithmetic(det)
+    }
+
+    /// Conservative absolute error bound for `det_direct()`.
+    ///
+    /// Returns `Ok(Some(bound))` such that `|det_direct() - det_exact| ≤ bound`
+    /// when every rounded intermediate used by the closed-form determinant and
+    /// bound is normal (or an exact structural zero). Returns `Ok(None)` when
+    /// gradual underflow could invalidate the relative-error analysis, or for
+    /// D ≥ 5 where no fast bound is available.
+    ///
+    /// For D ≤ 4, the bound is derived from the absolute Leibniz sum using
+    /// Shewchuk-style error analysis (see `REFERENCES.md` \[8\] and the
+    /// per-constant docs on [`ERR_COEFF_2`], [`ERR_COEFF_3`], and
+    /// [`ERR_COEFF_4`]). For D = 0 or 1, returns
+    /// `Some(0.0)` since the determinant computation is exact (no
+    /// arithmetic).
+    ///
+    /// This method does NOT require the `exact` feature — the bounds use
+    /// pure f64 arithmetic and are useful for custom adaptive-precision logic.
+    ///
+    /// # When to use
+    ///
+    /// Use [`det_direct_with_errbound`](Self::det_direct_with_errbound) when the
+    /// determinant and bound are both needed. This accessor is convenient when
+    /// only the bound is needed.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let m = Matrix::<3>::try_from_rows([
+    ///     [1.0, 2.0, 3.0],
+    ///     [4.0, 5.0, 6.0],
+    ///     [7.0, 8.0, 9.0],
+    /// ])?;
+    /// if let Some(bound) = m.det_errbound()? {
+    ///     assert!(bound >= 0.0);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Adaptive precision pattern (requires `exact` feature)
+    /// ```ignore
+    /// use la_stack::prelude::*;
+    ///
+    /// fn adaptive_det_sign<const D: usize>(
+    ///     matrix: &Matrix<D>,
+    /// ) -> DeterminantSign {
+    ///     if let Ok(Some(estimate)) = matrix.det_direct_with_errbound() {
+    ///         if estimate.determinant().abs() > estimate.absolute_error_bound() {
+    ///             return if estimate.determinant() > 0.0 {
+    ///                 DeterminantSign::Positive
+    ///             } else {
+    ///                 DeterminantSign::Negative
+    ///             };
+    ///         }
+    ///     }
+    ///
+    ///     matrix.det_sign_exact()
+    /// }
+    ///
+    /// fn main() -> Result<(), LaError> {
+    ///     assert_eq!(
+    ///         adaptive_det_sign(&Matrix::<3>::identity()),
+    ///         DeterminantSign::Positive
+    ///     );
+    ///
+    ///     let big = f64::MAX / 2.0;
+    ///     let overflowing = Matrix::<3>::try_from_rows([
+    ///         [0.0, 0.0, 1.0],
+    ///         [big, 0.0, 1.0],
+    ///         [0.0, big, 1.0],
+    ///     ])?;
+    ///     assert_eq!(
+    ///         adaptive_det_sign(&overflowing),
+    ///         DeterminantSign::Positive
+    ///     );
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] when the bound computation overflows to
+    /// NaN or infinity. Underflow-sensitive finite computations return
+    /// `Ok(None)` instead because they are valid inputs for an exact fallback.
+    #[inline]
+    pub const fn det_errbound(&self) -> Result<Option<f64>, LaError> {
+        match self.det_direct_with_errbound() {
+            Ok(Some(result)) => Ok(Some(result.absolute_error_bound)),
+            Ok(None) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Return whether every non-zero entry is large enough that the complete
+    /// D≤4 determinant and permanent trees cannot gradually underflow.
+    ///
+    /// The `2^-16` threshold leaves hundreds of binary exponent bits of margin
+    /// even after the D=4 tree's products, FMAs, and binary64 rounding steps.
+    /// Overflow remains possible and is classified after evaluation. Inputs
+    /// below this conservative threshold use per-operation tracking instead.
+    const fn det_bound_inputs_have_wide_exponent_margin(&self) -> bool {
+        const MIN_MAGNITUDE_BITS: u64 = 1007_u64 << 52; // 2^-16
+        const MAGNITUDE_MASK: u64 = !(1_u64 << 63);
+
+        if D > 4 {
+            return false;
+        }
+
+        let mut row = 0;
+        while row < D {
+            let mut col = 0;
+            while col < D {
+                let magnitude_bits = self.rows[row][col].to_bits() & MAGNITUDE_MASK;
+                if magnitude_bits != 0 && magnitude_bits < MIN_MAGNITUDE_BITS {
+                    return false;
+                }
+                col += 1;
+            }
+            row += 1;
+        }
+        true
+    }
+
+    /// Classify a completed determinant tree and construct its matching bound.
+    const fn det_direct_with_errbound_from_arithmetic<const TRACK_UNDERFLOW: bool>(
+        &self,
+        det: FilterArithmetic<TRACK_UNDERFLOW>,
+    ) -> Result<Option<DeterminantWithErrorBound>, LaError> {
+        let bound = match self.det_errbound_from_arithmetic(det) {
+            Ok(Some(bound)) => bound,
+            Ok(None) => return Ok(None),
+            Err(error) => return Err(error),
+        };
+        if !det.value.is_finite() {
+            cold_path();
+            return Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::Determinant,
+            ));
+        }
+        Ok(Some(DeterminantWithErrorBound {
+            determinant: det.value,
+            absolute_error_bound: bound,
+        }))
+    }
+
+    /// Compute a bound after the matching determinant tree has been evaluated.
+    const fn det_errbound_from_arithmetic<const TRACK_UNDERFLOW: bool>(
+        &self,
+        det: FilterArithmetic<TRACK_UNDERFLOW>,
+    ) -> Result<Option<f64>, LaError> {
+        if !det.underflow_safe {
+            cold_path();
+            return Ok(None);
+        }
+
+        match D {
+            0 | 1 => Self::computed_scalar_result(ArithmeticOperation::DeterminantErrorBound, 0.0),
+            2 => {
+                let r = &self.rows;
+                let product_0 = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r[0][0], r[1][1]);
+                let product_1 = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r[0][1], r[1][0]);
+                let mut permanent = FilterArithmetic::<TRACK_UNDERFLOW>::add_non_negative(
+                    product_0.value.abs(),
+                    product_1.value.abs(),
+                );
+                permanent.underflow_safe &= product_0.underflow_safe && product_1.underflow_safe;
+                Self::certified_error_bound(ERR_COEFF_2, permanent)
+            }
+            3 => {
+                let r = &self.rows;
+                let permanent = Self::det3_abs_permanent_elements::<TRACK_UNDERFLOW>(
+                    [r[0][0], r[0][1], r[0][2]],
+                    [r[1][0], r[1][1], r[1][2]],
+                    [r[2][0], r[2][1], r[2][2]],
+                );
+                Self::certified_error_bound(ERR_COEFF_3, permanent)
+            }
+            4 => self.det4_errbound::<TRACK_UNDERFLOW>(),
+            _ => {
+                cold_path();
+                Ok(None)
+            }
+        }
+    }
+
+    /// Compute the D=4 determinant error bound after the dimension dispatch.
+    const fn det4_errbound<const TRACK_UNDERFLOW: bool>(&self) -> Result<Option<f64>, LaError> {
+        if !TRACK_UNDERFLOW && let Some(input) = Det4SharedMinorInput::try_new(self) {
+            return Self::certified_error_bound(
+                ERR_COEFF_4,
+                FilterArithmetic::<TRACK_UNDERFLOW> {
+                    value: Self::det4_dense_abs_permanent_elements(input),
+                    underflow_safe: true,
+                },
+            );
+        }
+
+        let r = &self.rows;
+        let mut permanent = if r[0][3] == 0.0 {
+            FilterArithmetic {
+                value: 0.0,
+                underflow_safe: true,
+            }
+        } else {
+            let pc3 = Self::det3_abs_permanent_elements::<TRACK_UNDERFLOW>(
+                [r[1][0], r[1][1], r[1][2]],
+                [r[2][0], r[2][1], r[2][2]],
+                [r[3][0], r[3][1], r[3][2]],
+            );
+            let mut term = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r[0][3].abs(), pc3.value);
+            term.underflow_safe &= pc3.underflow_safe;
+            term
+        };
+        if r[0][2] != 0.0 {
+            let pc2 = Self::det3_abs_permanent_elements::<TRACK_UNDERFLOW>(
+                [r[1][0], r[1][1], r[1][3]],
+                [r[2][0], r[2][1], r[2][3]],
+                [r[3][0], r[3][1], r[3][3]],
+            );
+            let prior_safe = permanent.underflow_safe && pc2.underflow_safe;
+            permanent = FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(
+                r[0][2].abs(),
+                pc2.value,
+                permanent.value,
+            );
+            permanent.underflow_safe &= prior_safe;
+        }
+        if r[0][1] != 0.0 {
+            let pc1 = Self::det3_abs_permanent_elements::<TRACK_UNDERFLOW>(
+                [r[1][0], r[1][2], r[1][3]],
+                [r[2][0], r[2][2], r[2][3]],
+                [r[3][0], r[3][2], r[3][3]],
+            );
+            let prior_safe = permanent.underflow_safe && pc1.underflow_safe;
+            permanent = FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(
+                r[0][1].abs(),
+                pc1.value,
+                permanent.value,
+            );
+            permanent.underflow_safe &= prior_safe;
+        }
+        if r[0][0] != 0.0 {
+            let pc0 = Self::det3_abs_permanent_elements::<TRACK_UNDERFLOW>(
+                [r[1][1], r[1][2], r[1][3]],
+                [r[2][1], r[2][2], r[2][3]],
+                [r[3][1], r[3][2], r[3][3]],
+            );
+            let prior_safe = permanent.underflow_safe && pc0.underflow_safe;
+            permanent = FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(
+                r[0][0].abs(),
+                pc0.value,
+                permanent.value,
+            );
+            permanent.underflow_safe &= prior_safe;
+        }
+        Self::certified_error_bound(ERR_COEFF_4, permanent)
+    }
+
+    /// Evaluate a 3×3 determinant expansion with a guarded sparse fallback.
+    ///
+    /// When all three first-row coefficients are non-zero, one branch-free
+    /// closed form is used. The sparse fallback protects the public
+    /// [`det_direct`](Self::det_direct) contract: a mathematically absent term
+    /// must not compute an overflowing minor and poison the determinant with
+    /// `0.0 * inf == NaN`. Nonzero terms keep the same fused multiply-add
+    /// ordering as the closed-form expansion.
+    #[expect(
+        clippy::inline_always,
+        reason = "det_direct callers must eliminate unused filter-safety bookkeeping"
+    )]
+    #[inline(always)]
+    const fn det3_elements<const TRACK_UNDERFLOW: bool>(
+        r0: [f64; 3],
+        r1: [f64; 3],
+        r2: [f64; 3],
+    ) -> FilterArithmetic<TRACK_UNDERFLOW> {
+        let dense = (r0[0] != 0.0) && (r0[1] != 0.0) && (r0[2] != 0.0);
+        if !TRACK_UNDERFLOW && dense {
+            let m00 = r1[1].mul_add(r2[2], -(r1[2] * r2[1]));
+            let m01 = r1[0].mul_add(r2[2], -(r1[2] * r2[0]));
+            let m02 = r1[0].mul_add(r2[1], -(r1[1] * r2[0]));
+            return FilterArithmetic {
+                value: r0[0].mul_add(m00, (-r0[1]).mul_add(m01, r0[2] * m02)),
+                underflow_safe: true,
+            };
+        }
+
+        let mut det = if r0[2] == 0.0 {
+            FilterArithmetic {
+                value: 0.0,
+                underflow_safe: true,
+            }
+        } else {
+            let subtrahend = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r1[1], r2[0]);
+            let mut m02 =
+                FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(r1[0], r2[1], -subtrahend.value);
+            m02.underflow_safe &= subtrahend.underflow_safe;
+            let mut term = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r0[2], m02.value);
+            term.underflow_safe &= m02.underflow_safe;
+            term
+        };
+        if r0[1] != 0.0 {
+            let subtrahend = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r1[2], r2[0]);
+            let mut m01 =
+                FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(r1[0], r2[2], -subtrahend.value);
+            m01.underflow_safe &= subtrahend.underflow_safe;
+            let prior_safe = det.underflow_safe && m01.underflow_safe;
+            det = FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(-r0[1], m01.value, det.value);
+            det.underflow_safe &= prior_safe;
+        }
+        if r0[0] != 0.0 {
+            let subtrahend = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r1[2], r2[1]);
+            let mut m00 =
+                FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(r1[1], r2[2], -subtrahend.value);
+            m00.underflow_safe &= subtrahend.underflow_safe;
+            let prior_safe = det.underflow_safe && m00.underflow_safe;
+            det = FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(r0[0], m00.value, det.value);
+            det.underflow_safe &= prior_safe;
+        }
+        det
+    }
+
+    /// Evaluate a 3×3 absolute permanent while skipping zero coefficients.
+    ///
+    /// This mirrors [`det3_elements`](Self::det3_elements) for error-bound
+    /// computation: absent determinant terms should not force evaluation of an
+    /// overflowing absolute minor.
+    #[expect(
+        clippy::inline_always,
+        reason = "error-bound call-site specialization avoids tracked-helper overhead"
+    )]
+    #[inline(always)]
+    const fn det3_abs_permanent_elements<const TRACK_UNDERFLOW: bool>(
+        r0: [f64; 3],
+        r1: [f64; 3],
+        r2: [f64; 3],
+    ) -> FilterArithmetic<TRACK_UNDERFLOW> {
+        let dense = (r0[0] != 0.0) && (r0[1] != 0.0) && (r0[2] != 0.0);
+        if !TRACK_UNDERFLOW && dense {
+            let pm00 = (r1[1] * r2[2]).abs() + (r1[2] * r2[1]).abs();
+            let pm01 = (r1[0] * r2[2]).abs() + (r1[2] * r2[0]).abs();
+            let pm02 = (r1[0] * r2[1]).abs() + (r1[1] * r2[0]).abs();
+            return FilterArithmetic {
+                value: r0[2]
+                    .abs()
+                    .mul_add(pm02, r0[1].abs().mul_add(pm01, r0[0].abs() * pm00)),
+                underflow_safe: true,
+            };
+        }
+
+        let mut permanent = if r0[2] == 0.0 {
+            FilterArithmetic {
+                value: 0.0,
+                underflow_safe: true,
+            }
+        } else {
+            let product_0 = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r1[0], r2[1]);
+            let product_1 = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r1[1], r2[0]);
+            let mut pm02 = FilterArithmetic::<TRACK_UNDERFLOW>::add_non_negative(
+                product_0.value.abs(),
+                product_1.value.abs(),
+            );
+            pm02.underflow_safe &= product_0.underflow_safe && product_1.underflow_safe;
+            let mut term = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r0[2].abs(), pm02.value);
+            term.underflow_safe &= pm02.underflow_safe;
+            term
+        };
+        if r0[1] != 0.0 {
+            let product_0 = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r1[0], r2[2]);
+            let product_1 = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r1[2], r2[0]);
+            let mut pm01 = FilterArithmetic::<TRACK_UNDERFLOW>::add_non_negative(
+                product_0.value.abs(),
+                product_1.value.abs(),
+            );
+            pm01.underflow_safe &= product_0.underflow_safe && product_1.underflow_safe;
+            let prior_safe = permanent.underflow_safe && pm01.underflow_safe;
+            permanent = FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(
+                r0[1].abs(),
+                pm01.value,
+                permanent.value,
+            );
+            permanent.underflow_safe &= prior_safe;
+        }
+        if r0[0] != 0.0 {
+            let product_0 = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r1[1], r2[2]);
+            let product_1 = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(r1[2], r2[1]);
+            let mut pm00 = FilterArithmetic::<TRACK_UNDERFLOW>::add_non_negative(
+                product_0.value.abs(),
+                product_1.value.abs(),
+            );
+            pm00.underflow_safe &= product_0.underflow_safe && product_1.underflow_safe;
+            let prior_safe = permanent.underflow_safe && pm00.underflow_safe;
+            permanent = FilterArithmetic::<TRACK_UNDERFLOW>::mul_add(
+                r0[0].abs(),
+                pm00.value,
+                permanent.value,
+            );
+            permanent.underflow_safe &= prior_safe;
+        }
+        permanent
+    }
+
+    /// Finish a determinant error bound only when its full arithmetic tree is
+    /// outside the gradual-underflow regime.
+    const fn certified_error_bound<const TRACK_UNDERFLOW: bool>(
+        coefficient: f64,
+        permanent: FilterArithmetic<TRACK_UNDERFLOW>,
+    ) -> Result<Option<f64>, LaError> {
+        let mut bound = FilterArithmetic::<TRACK_UNDERFLOW>::multiply(coefficient, permanent.value);
+        bound.underflow_safe &= permanent.underflow_safe;
+        if bound.underflow_safe {
+            Self::computed_scalar_result(ArithmeticOperation::DeterminantErrorBound, bound.value)
+        } else {
+            cold_path();
+            Ok(None)
+        }
+    }
+
+    /// Return a computed scalar result for a matrix with finite stored entries.
+    const fn computed_scalar_result(
+        operation: ArithmeticOperation,
+        value: f64,
+    ) -> Result<Option<f64>, LaError> {
+        if value.is_finite() {
+            Ok(Some(value))
+        } else {
+            Err(LaError::non_finite_computation_scalar(operation))
+        }
+    }
+}
+
+/// Power of two `2^-e` with `2^e <= max_abs < 2^(e+1)`, clamped to the
+/// normal binary64 range; `1.0` for zero.
+///
+/// Multiplying by the result moves a magnitude of `max_abs` into `[1, 2)`
+/// without rounding.
+fn power_of_two_reciprocal(max_abs: f64) -> f64 {
+    // Biased exponent of 2^0 is 1023, so 2^-e has biased exponent 2046 - (e + 1023).
+    const RECIPROCAL_BIAS_SUM: u64 = 2046;
+    if max_abs == 0.0 {
+        return 1.0;
+    }
+    let biased = (max_abs.to_bits() >> 52) & 0x7ff;
+    let reciprocal = RECIPROCAL_BIAS_SUM
+        .saturating_sub(biased)
+        .clamp(1, RECIPROCAL_BIAS_SUM);
+    f64::from_bits(reciprocal << 52)
+}
+
+impl<const D: usize> Default for Matrix<D> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+#[cfg(all(doc, feature = "exact"))]
+mod det_errbound_doctests {
+    /// ```rust
+    /// use la_stack::prelude::*;
+    ///
+    /// fn adaptive_det_sign<const D: usize>(
+    ///     matrix: &Matrix<D>,
+    /// ) -> DeterminantSign {
+    ///     if let Ok(Some(estimate)) = matrix.det_direct_with_errbound() {
+    ///         if estimate.determinant().abs() > estimate.absolute_error_bound() {
+    ///             return if estimate.determinant() > 0.0 {
+    ///                 DeterminantSign::Positive
+    ///             } else {
+    ///                 DeterminantSign::Negative
+    ///             };
+    ///         }
+    ///     }
+    ///
+    ///     matrix.det_sign_exact()
+    /// }
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let identity = Matrix::<3>::identity();
+    /// assert_eq!(
+    ///     adaptive_det_sign(&identity),
+    ///     DeterminantSign::Positive
+    /// );
+    ///
+    /// let singular = Matrix::<3>::try_from_rows([
+    ///     [1.0, 2.0, 3.0],
+    ///     [4.0, 5.0, 6.0],
+    ///     [7.0, 8.0, 9.0],
+    /// ])?;
+    /// assert_eq!(adaptive_det_sign(&singular), DeterminantSign::Zero);
+    ///
+    /// let big = f64::MAX / 2.0;
+    /// let overflowing = Matrix::<3>::try_from_rows([
+    ///     [0.0, 0.0, 1.0],
+    ///     [big, 0.0, 1.0],
+    ///     [0.0, big, 1.0],
+    /// ])?;
+    /// assert_eq!(
+    ///     adaptive_det_sign(&overflowing),
+    ///     DeterminantSign::Positive
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn adaptive_precision_pattern() {}
+}
+
+#[cfg(test)]
+mod tests {
+    use core::assert_matches;
+    use core::hint::black_box;
+
+    use approx::{assert_abs_diff_eq, assert_relative_eq};
+    use pastey::paste;
+
+    use super::*;
+    use crate::{DEFAULT_SINGULAR_TOL, FactorizationKind};
+
+    macro_rules! gen_matrix_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<matrix_try_from_rows_get_set_bounds_checked_ $d d>]() {
+                    let mut rows = [[0.0f64; $d]; $d];
+                    rows[0][0] = 1.0;
+                    rows[$d - 1][$d - 1] = -2.0;
+
+                    let mut m = Matrix::<$d>::try_from_rows(rows).unwrap();
+
+                    assert_eq!(m.get(0, 0), Some(1.0));
+                    assert_eq!(m.get($d - 1, $d - 1), Some(-2.0));
+                    assert_eq!(m.try_get(0, 0), Ok(1.0));
+                    assert_eq!(m.try_get($d - 1, $d - 1), Ok(-2.0));
+
+                    // Out-of-bounds is None.
+                    assert_eq!(m.get($d, 0), None);
+                    assert_eq!(
+                        m.try_get($d, 0),
+                        Err(LaError::IndexOutOfBounds {
+                            row: $d,
+                            col: 0,
+                            dim: $d,
+                        })
+                    );
+
+                    // Out-of-bounds set fails.
+                    let before_failed_set = m;
+                    assert_eq!(
+                        m.set($d, 0, 3.0),
+                        Err(LaError::IndexOutOfBounds {
+                            row: $d,
+                            col: 0,
+                            dim: $d,
+                        })
+                    );
+                    assert_eq!(m, before_failed_set);
+                    assert_eq!(
+                        m.set(0, $d, 3.0),
+                        Err(LaError::IndexOutOfBounds {
+                            row: 0,
+                            col: $d,
+                            dim: $d,
+                        })
+                    );
+                    assert_eq!(m, before_failed_set);
+                    assert_eq!(m.get(0, 0), Some(1.0));
+
+                    // In-bounds set works.
+                    assert_eq!(m.set(0, $d - 1, 3.0), Ok(()));
+                    assert_eq!(m.get(0, $d - 1), Some(3.0));
+                    assert_eq!(m.set($d - 1, 0, 4.0), Ok(()));
+                    assert_eq!(m.try_get($d - 1, 0), Ok(4.0));
+                }
+
+                #[test]
+                fn [<matrix_set_rejects_non_finite_and_preserves_storage_ $d d>]() {
+                    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+                        let mut m = Matrix::<$d>::identity();
+                        let before = m;
+                        assert_eq!(
+                            m.set($d - 1, 0, value),
+                            Err(LaError::non_finite_input_matrix($d - 1, 0))
+                        );
+                        assert_eq!(m, before);
+                    }
+                }
+
+                #[test]
+                fn [<matrix_try_from_rows_rejects_non_finite_ $d d>]() {
+                    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+                        let mut rows = [[0.0f64; $d]; $d];
+                        rows[$d - 1][$d - 1] = value;
+                        assert_eq!(
+                            Matrix::<$d>::try_from_rows(rows),
+                            Err(LaError::non_finite_input_matrix($d - 1, $d - 1))
+                        );
+                    }
+
+                    let mut rows = [[0.0f64; $d]; $d];
+                    rows[0][$d - 1] = f64::INFINITY;
+                    rows[$d - 1][0] = f64::NAN;
+                    assert_eq!(
+                        Matrix::<$d>::try_from_rows(rows),
+                        Err(LaError::non_finite_input_matrix(0, $d - 1))
+                    );
+                }
+
+                #[test]
+                fn [<matrix_zero_and_default_are_zero_ $d d>]() {
+                    let z = Matrix::<$d>::zero();
+                    assert_abs_diff_eq!(z.inf_norm().unwrap(), 0.0, epsilon = 0.0);
+
+                    let d = Matrix::<$d>::default();
+                    assert_abs_diff_eq!(d.inf_norm().unwrap(), 0.0, epsilon = 0.0);
+                }
+
+                #[test]
+                fn [<matrix_inf_norm_max_row_sum_ $d d>]() {
+                    let mut rows = [[0.0f64; $d]; $d];
+
+                    // Row 0 has a smaller absolute row sum.
+                    for c in 0..$d {
+                        rows[0][c] = 0.5;
+                    }
+
+                    // The last row has absolute row sum = D.
+                    for c in 0..$d {
+                        rows[$d - 1][c] = -1.0;
+                    }
+
+                    let m = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    assert_abs_diff_eq!(m.inf_norm().unwrap(), f64::from($d), epsilon = 0.0);
+                }
+
+                #[test]
+                fn [<matrix_inf_norm_reports_first_overflowing_column_ $d d>]() {
+                    let mut rows = [[0.0f64; $d]; $d];
+                    rows[$d - 1][0] = f64::MAX;
+                    rows[$d - 1][1] = f64::MAX;
+
+                    let m = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    assert_eq!(
+                        m.inf_norm(),
+                        Err(LaError::non_finite_computation_matrix(
+                            ArithmeticOperation::MatrixInfinityNorm,
+                            $d - 1,
+                            1,
+                        ))
+                    );
+                }
+
+                #[test]
+                fn [<matrix_inf_norm_reports_first_overflowing_row_ $d d>]() {
+                    let mut rows = [[0.0f64; $d]; $d];
+                    rows[0][0] = f64::MAX;
+                    rows[0][$d - 1] = f64::MAX;
+                    rows[$d - 1][0] = f64::MAX;
+                    rows[$d - 1][1] = f64::MAX;
+
+                    let m = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    assert_eq!(
+                        m.inf_norm(),
+                        Err(LaError::non_finite_computation_matrix(
+                            ArithmeticOperation::MatrixInfinityNorm,
+                            0,
+                            $d - 1,
+                        ))
+                    );
+                }
+
+                #[test]
+                fn [<matrix_identity_lu_det_solve_ $d d>]() {
+                    let m = Matrix::<$d>::identity();
+
+                    // Identity has ones on diag and zeros off diag.
+                    for r in 0..$d {
+                        for c in 0..$d {
+                            let expected = if r == c { 1.0 } else { 0.0 };
+                            assert_abs_diff_eq!(m.get(r, c).unwrap(), expected, epsilon = 0.0);
+                        }
+                    }
+
+                    // Determinant is 1.
+                    let det = m.det().unwrap();
+                    assert_abs_diff_eq!(det, 1.0, epsilon = 1e-12);
+
+                    // LU solve on identity returns the RHS.
+                    let lu = m.lu(DEFAULT_SINGULAR_TOL).unwrap();
+
+                    let b_arr = {
+                        let mut arr = [0.0f64; $d];
+                        let values = [1.0f64, 2.0, 3.0, 4.0, 5.0];
+                        for (dst, src) in arr.iter_mut().zip(values.iter()) {
+                            *dst = *src;
+                        }
+                        arr
+                    };
+
+                    let b = Vector::<$d>::new(b_arr);
+                    let x = lu.solve(b).unwrap().into_array();
+
+                    for (x_i, b_i) in x.iter().zip(b_arr.iter()) {
+                        assert_abs_diff_eq!(*x_i, *b_i, epsilon = 1e-12);
+                    }
+                }
+
+            }
+        };
+    }
+
+    // Mirror delaunay-style multi-dimension tests.
+    gen_matrix_tests!(2);
+    gen_matrix_tests!(3);
+    gen_matrix_tests!(4);
+    gen_matrix_tests!(5);
+
+    #[test]
+    fn matrix_inf_norm_preserves_left_to_right_row_sum_order() {
+        let large = 9_007_199_254_740_992.0;
+        let matrix =
+            Matrix::<4>::try_from_rows([[large, 1.0, 1.0, 1.0], [0.0; 4], [0.0; 4], [0.0; 4]])
+                .unwrap();
+
+        assert_eq!(matrix.inf_norm(), Ok(large));
+    }
+
+    // === det_direct tests ===
+
+    #[test]
+    fn det_direct_d0_is_one() {
+        assert_eq!(Matrix::<0>::zero().det_direct(), Ok(Some(1.0)));
+    }
+
+    #[test]
+    fn det_direct_d1_returns_element() {
+        let m = Matrix::<1>::try_from_rows([[42.0]]).unwrap();
+        assert_eq!(m.det_direct(), Ok(Some(42.0)));
+    }
+
+    #[test]
+    fn det_direct_d2_known_value() {
+        // [[1,2],[3,4]] → det = 1*4 - 2*3 = -2
+        // black_box prevents compile-time constant folding of the const fn.
+        let m = black_box(Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]]).unwrap());
+        assert_abs_diff_eq!(m.det_direct().unwrap().unwrap(), -2.0, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn det_direct_d3_known_value() {
+        // Classic 3×3: det = 0
+        let m = black_box(
+            Matrix::<3>::try_from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]])
+                .unwrap(),
+        );
+        assert_abs_diff_eq!(m.det_direct().unwrap().unwrap(), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn det_direct_d3_dense_known_value() {
+        // det = 1*(5*8 - 7*6) - 2*(4*8 - 7*2) + 3*(4*6 - 5*2) = 4
+        let m = black_box(
+            Matrix::<3>::try_from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 7.0], [2.0, 6.0, 8.0]])
+                .unwrap(),
+        );
+        let direct = m.det_direct().unwrap().unwrap();
+        let paired = m.det_direct_with_errbound().unwrap().unwrap();
+
+        assert_abs_diff_eq!(direct, 4.0, epsilon = 1e-12);
+        assert_eq!(paired.determinant().to_bits(), direct.to_bits());
+    }
+
+    #[test]
+    fn det_direct_d3_dense_reports_legitimate_overflow() {
+        // The unscaled matrix has determinant 54, so scaling every entry by
+        // 1.6e102 gives a determinant of approximately 2.21e308.
+        let scale = 1.6e102;
+        let m = black_box(
+            Matrix::<3>::try_from_rows([
+                [4.0 * scale, scale, scale],
+                [scale, 4.0 * scale, scale],
+                [scale, scale, 4.0 * scale],
+            ])
+            .unwrap(),
+        );
+        let expected = LaError::non_finite_computation_scalar(ArithmeticOperation::Determinant);
+
+        assert_eq!(m.det_direct(), Err(expected));
+        assert_eq!(m.det(), Err(expected));
+    }
+
+    #[test]
+    fn det_errbound_d3_dense_reports_legitimate_overflow() {
+        // The unscaled matrix has determinant 54, so scaling every entry by
+        // 1.6e102 gives a determinant of approximately 2.21e308.
+        let scale = 1.6e102;
+        let m = black_box(
+            Matrix::<3>::try_from_rows([
+                [4.0 * scale, scale, scale],
+                [scale, 4.0 * scale, scale],
+                [scale, scale, 4.0 * scale],
+            ])
+            .unwrap(),
+        );
+        let expected =
+            LaError::non_finite_computation_scalar(ArithmeticOperation::DeterminantErrorBound);
+
+        assert_eq!(m.det_errbound(), Err(expected));
+        assert_eq!(m.det_direct_with_errbound(), Err(expected));
+    }
+
+    #[test]
+    fn det_direct_d3_nonsingular() {
+        // [[2,1,0],[0,3,1],[1,0,2]] → det = 2*(6-0) - 1*(0-1) + 0 = 13
+        let m = black_box(
+            Matrix::<3>::try_from_rows([[2.0, 1.0, 0.0], [0.0, 3.0, 1.0], [1.0, 0.0, 2.0]])
+                .unwrap(),
+        );
+        assert_abs_diff_eq!(m.det_direct().unwrap().unwrap(), 13.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn det_direct_d3_skips_zero_coefficient_minor_that_would_overflow() {
+        let m = black_box(
+            Matrix::<3>::try_from_rows([
+                [1.0, 0.0, 0.0],
+                [1.0e300, 1.0, 1.0e300],
+                [1.0e300, 0.0, 1.0e300],
+            ])
+            .unwrap(),
+        );
+        assert_eq!(m.det_direct(), Ok(Some(1.0e300)));
+    }
+
+    #[test]
+    fn det_direct_d4_known_value() {
+        // Diagonal matrix: det = product of diagonal entries.
+        let mut rows = [[0.0f64; 4]; 4];
+        rows[0][0] = 2.0;
+        rows[1][1] = 3.0;
+        rows[2][2] = 5.0;
+        rows[3][3] = 7.0;
+        let m = black_box(Matrix::<4>::try_from_rows(rows).unwrap());
+        assert_abs_diff_eq!(m.det_direct().unwrap().unwrap(), 210.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn det_direct_d4_dense_known_value() {
+        let m = black_box(
+            Matrix::<4>::try_from_rows([
+                [4.0, 1.0, 3.0, 2.0],
+                [1.0, 5.0, 2.0, 1.0],
+                [7.0, 2.0, 6.0, 3.0],
+                [1.0, 8.0, 4.0, 9.0],
+            ])
+            .unwrap(),
+        );
+        let direct = m.det_direct().unwrap().unwrap();
+        let paired = m.det_direct_with_errbound().unwrap().unwrap();
+
+        assert_abs_diff_eq!(direct, 112.0, epsilon = 1e-12);
+        assert_eq!(paired.determinant().to_bits(), direct.to_bits());
+    }
+
+    #[test]
+    fn det_direct_d4_dense_reports_legitimate_overflow() {
+        // The unscaled matrix has determinant 189, so scaling every entry by
+        // 3.2e76 gives a determinant of approximately 1.98e308.
+        let scale = 3.2e76;
+        let m = black_box(
+            Matrix::<4>::try_from_rows([
+                [4.0 * scale, scale, scale, scale],
+                [scale, 4.0 * scale, scale, scale],
+                [scale, scale, 4.0 * scale, scale],
+                [scale, scale, scale, 4.0 * scale],
+            ])
+            .unwrap(),
+        );
+        let expected = LaError::non_finite_computation_scalar(ArithmeticOperation::Determinant);
+
+        assert_eq!(m.det_direct(), Err(expected));
+        assert_eq!(m.det(), Err(expected));
+    }
+
+    #[test]
+    fn det_errbound_d4_dense_reports_legitimate_overflow() {
+        // The unscaled matrix has determinant 189, so scaling every entry by
+        // 3.2e76 gives a determinant of approximately 1.98e308.
+        let scale = 3.2e76;
+        let m = black_box(
+            Matrix::<4>::try_from_rows([
+                [4.0 * scale, scale, scale, scale],
+                [scale, 4.0 * scale, scale, scale],
+                [scale, scale, 4.0 * scale, scale],
+                [scale, scale, scale, 4.0 * scale],
+            ])
+            .unwrap(),
+        );
+        let expected =
+            LaError::non_finite_computation_scalar(ArithmeticOperation::DeterminantErrorBound);
+
+        assert_eq!(m.det_errbound(), Err(expected));
+        assert_eq!(m.det_direct_with_errbound(), Err(expected));
+    }
+
+    #[test]
+    fn det_direct_d4_skips_zero_coefficient_cofactors_that_would_overflow() {
+        let m = black_box(
+            Matrix::<4>::try_from_rows([
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0],
+                [1.0e300, 0.0, 1.0e300, 1.0e300],
+                [1.0e300, 0.0, 1.0e300, -1.0e300],
+            ])
+            .unwrap(),
+        );
+        assert_eq!(m.det_direct(), Ok(Some(0.0)));
+    }
+
+    #[test]
+    fn det_direct_d4_sparse_second_row_skips_inactive_overflowing_minors() {
+        let m = black_box(
+            Matrix::<4>::try_from_rows([
+                [1.0e-300, 1.0, 1.0, 1.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 1.0e300, 1.0, 1.0e300],
+                [0.0, 1.0e300, 0.0, 1.0e300],
+            ])
+            .unwrap(),
+        );
+
+        assert_eq!(m.det_direct(), Ok(Some(1.0)));
+        assert_eq!(m.det(), Ok(1.0));
+    }
+
+    #[test]
+    fn det_direct_d5_returns_none() {
+        assert_eq!(Matrix::<5>::identity().det_direct(), Ok(None));
+    }
+
+    #[test]
+    fn det_direct_d8_returns_none() {
+        assert_eq!(Matrix::<8>::zero().det_direct(), Ok(None));
+    }
+
+    #[test]
+    fn det_direct_rejects_computed_overflow() {
+        let m = Matrix::<2>::try_from_rows([[1e300, 0.0], [0.0, 1e300]]).unwrap();
+        assert_eq!(
+            m.det_direct(),
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::Determinant
+            ))
+        );
+    }
+
+    #[test]
+    fn det_d5_rejects_lu_product_overflow() {
+        let m = Matrix::<5>::try_from_rows([
+            [1.0e100, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0e100, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0e100, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0e100, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 1.0e100],
+        ])
+        .unwrap();
+        assert_eq!(
+            m.det(),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::Determinant,
+                4
+            ))
+        );
+    }
+
+    #[test]
+    fn det_d5_rejects_lu_trailing_update_overflow() {
+        let m = Matrix::<5>::try_from_rows([
+            [1.0, f64::MAX, 0.0, 0.0, 0.0],
+            [-1.0, f64::MAX, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 1.0],
+        ])
+        .unwrap();
+
+        assert_eq!(
+            m.det(),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::LuFactorization,
+                1,
+                1
+            ))
+        );
+    }
+
+    macro_rules! gen_det_direct_agrees_with_lu {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                #[expect(
+                    clippy::cast_precision_loss,
+                    reason = "r, c, and D are tiny test integers exactly representable as f64"
+                )]
+                fn [<det_direct_agrees_with_lu_ $d d>]() {
+                    // Well-conditioned matrix: diagonally dominant.
+                    let mut rows = [[0.0f64; $d]; $d];
+                    for r in 0..$d {
+                        for c in 0..$d {
+                            rows[r][c] = if r == c {
+                                (r as f64) + f64::from($d) + 1.0
+                            } else {
+                                0.1 / ((r + c + 1) as f64)
+                            };
+                        }
+                    }
+                    let m = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let direct = m.det_direct().unwrap().unwrap();
+                    let lu_det = m.lu(DEFAULT_SINGULAR_TOL).unwrap().det().unwrap();
+                    let eps = lu_det.abs().mul_add(1e-12, 1e-12);
+                    assert_abs_diff_eq!(direct, lu_det, epsilon = eps);
+                }
+            }
+        };
+    }
+
+    gen_det_direct_agrees_with_lu!(1);
+    gen_det_direct_agrees_with_lu!(2);
+    gen_det_direct_agrees_with_lu!(3);
+    gen_det_direct_agrees_with_lu!(4);
+
+    #[test]
+    fn det_direct_identity_all_dims() {
+        assert_abs_diff_eq!(
+            Matrix::<1>::identity().det_direct().unwrap().unwrap(),
+            1.0,
+            epsilon = 0.0
+        );
+        assert_abs_diff_eq!(
+            Matrix::<2>::identity().det_direct().unwrap().unwrap(),
+            1.0,
+            epsilon = 0.0
+        );
+        assert_abs_diff_eq!(
+            Matrix::<3>::identity().det_direct().unwrap().unwrap(),
+            1.0,
+            epsilon = 0.0
+        );
+        assert_abs_diff_eq!(
+            Matrix::<4>::identity().det_direct().unwrap().unwrap(),
+            1.0,
+            epsilon = 0.0
+        );
+    }
+
+    #[test]
+    fn det_direct_zero_matrix() {
+        assert_abs_diff_eq!(
+            Matrix::<2>::zero().det_direct().unwrap().unwrap(),
+            0.0,
+            epsilon = 0.0
+        );
+        assert_abs_diff_eq!(
+            Matrix::<3>::zero().det_direct().unwrap().unwrap(),
+            0.0,
+            epsilon = 0.0
+        );
+        assert_abs_diff_eq!(
+            Matrix::<4>::zero().det_direct().unwrap().unwrap(),
+            0.0,
+            epsilon = 0.0
+        );
+    }
+
+    macro_rules! gen_det_singular_zero_matrix_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<det_singular_zero_matrix_returns_zero_ $d d>]() {
+                    assert_abs_diff_eq!(
+                        Matrix::<$d>::zero().det().unwrap(),
+                        0.0,
+                        epsilon = 0.0
+                    );
+                }
+            }
+        };
+    }
+
+    gen_det_singular_zero_matrix_tests!(2);
+    gen_det_singular_zero_matrix_tests!(3);
+    gen_det_singular_zero_matrix_tests!(4);
+
+    #[test]
+    fn det_singular_zero_matrix_d5_preserves_lu_error() {
+        assert_eq!(
+            Matrix::<5>::zero().det(),
+            Err(LaError::singular_numerical(
+                0,
+                FactorizationKind::Lu,
+                0.0,
+                0.0
+            ))
+        );
+    }
+
+    #[test]
+    fn det_d5_does_not_turn_elimination_underflow_into_exact_zero() {
+        let min_subnormal = f64::from_bits(1);
+        let two_pow_800 = f64::from_bits(1823_u64 << 52);
+        let m = Matrix::<5>::try_from_rows([
+            [2.0, min_subnormal, 0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, two_pow_800, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 1.0],
+        ])
+        .unwrap();
+
+        assert_eq!(
+            m.det(),
+            Err(LaError::singular_numerical(
+                1,
+                FactorizationKind::Lu,
+                0.0,
+                0.0
+            ))
+        );
+    }
+
+    #[test]
+    fn det_d5_ignores_pivot_tolerance_for_tiny_nonsingular_matrix() {
+        // A small nonzero determinant is still a determinant. `det` must not
+        // flatten the value to zero merely because the default LU tolerance
+        // would reject a pivot this small.
+        let m = Matrix::<5>::try_from_rows([
+            [1e-13, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 1.0],
+        ])
+        .unwrap();
+
+        assert_abs_diff_eq!(m.det().unwrap(), 1e-13, epsilon = 0.0);
+        assert_eq!(
+            m.lu(DEFAULT_SINGULAR_TOL),
+            Err(LaError::singular_numerical(
+                0,
+                FactorizationKind::Lu,
+                1e-13,
+                DEFAULT_SINGULAR_TOL.get()
+            ))
+        );
+    }
+
+    #[test]
+    fn det_returns_non_finite_error_for_overflow_with_finite_entries() {
+        // det_direct produces an overflowing f64 (1e300 * 1e300 = ∞) even
+        // though every matrix entry is finite. The entry scan in `det`
+        // falls through and reports a computed determinant overflow rather
+        // than a NaN/∞ input.
+        let m = Matrix::<2>::try_from_rows([[1e300, 0.0], [0.0, 1e300]]).unwrap();
+        assert_eq!(
+            m.det(),
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::Determinant
+            ))
+        );
+    }
+
+    // === det_direct const-evaluability tests (D = 2..=5) ===
+    //
+    // Every dimension hits a distinct arm of the `match D { … }` body inside
+    // `det_direct`, so exercising each at compile time is the tightest
+    // const-fn proof available.
+
+    macro_rules! gen_det_direct_const_eval_tests {
+        ($d:literal) => {
+            paste! {
+                /// `Matrix::<D>::det_direct()` on the identity must const-evaluate
+                /// to `Ok(Some(1.0))` for every closed-form dimension `D ∈ {1, 2, 3, 4}`.
+                #[test]
+                fn [<det_direct_const_eval_ $d d>]() {
+                    const DET: Result<Option<f64>, LaError> = Matrix::<$d>::identity().det_direct();
+                    assert_eq!(DET, Ok(Some(1.0)));
+                }
+            }
+        };
+    }
+
+    gen_det_direct_const_eval_tests!(2);
+    gen_det_direct_const_eval_tests!(3);
+    gen_det_direct_const_eval_tests!(4);
+
+    #[test]
+    fn det_direct_const_eval_d5_is_none() {
+        // D ≥ 5 has no closed-form arm; `det_direct` returns `Ok(None)`.  Verify
+        // that the wildcard arm is reachable in a `const { … }` context.
+        const DET: Result<Option<f64>, LaError> = Matrix::<5>::identity().det_direct();
+        assert_eq!(DET, Ok(None));
+    }
+
+    // === det_errbound tests (no `exact` feature required) ===
+
+    #[test]
+    fn det_errbound_matches_documented_coefficient_scale() {
+        let m2 = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]]).unwrap();
+        let expected_2 = ERR_COEFF_2 * ((1.0_f64 * 4.0).abs() + (2.0_f64 * 3.0).abs());
+        assert_abs_diff_eq!(
+            m2.det_errbound().unwrap().unwrap(),
+            expected_2,
+            epsilon = 0.0
+        );
+
+        assert_abs_diff_eq!(
+            Matrix::<3>::identity().det_errbound().unwrap().unwrap(),
+            ERR_COEFF_3,
+            epsilon = 0.0
+        );
+        assert_abs_diff_eq!(
+            Matrix::<4>::identity().det_errbound().unwrap().unwrap(),
+            ERR_COEFF_4,
+            epsilon = 0.0
+        );
+    }
+
+    #[test]
+    fn det_errbound_d3_skips_zero_coefficient_minor_that_would_overflow() {
+        let m = Matrix::<3>::try_from_rows([
+            [1.0, 0.0, 0.0],
+            [1.0e300, 1.0, 1.0e300],
+            [1.0e300, 0.0, 1.0e300],
+        ])
+        .unwrap();
+
+        assert_eq!(m.det_errbound(), Ok(Some(ERR_COEFF_3 * 1.0e300)));
+    }
+
+    #[test]
+    fn det_errbound_d4_skips_zero_coefficient_cofactors_that_would_overflow() {
+        let m = Matrix::<4>::try_from_rows([
+            [0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0],
+            [1.0e300, 0.0, 1.0e300, 1.0e300],
+            [1.0e300, 0.0, 1.0e300, -1.0e300],
+        ])
+        .unwrap();
+
+        assert_eq!(m.det_errbound(), Ok(Some(0.0)));
+    }
+
+    #[test]
+    fn det_errbound_d5_returns_none() {
+        // D=5 has no fast filter
+        assert_eq!(Matrix::<5>::identity().det_errbound(), Ok(None));
+    }
+
+    #[test]
+    fn combined_det_bound_wide_exponent_fast_path_matches_tracked_arithmetic() {
+        let threshold = f64::from_bits(1007_u64 << 52); // 2^-16
+        let at_threshold = Matrix::<2>::try_from_rows([[threshold, 0.0], [0.0, 2.0]]).unwrap();
+        assert!(at_threshold.det_bound_inputs_have_wide_exponent_margin());
+
+        let tracked = at_threshold
+            .det_direct_with_errbound_from_arithmetic(
+                at_threshold
+                    .det_direct_arithmetic::<true>()
+                    .expect("D=2 has direct arithmetic"),
+            )
+            .unwrap();
+        assert_eq!(at_threshold.det_direct_with_errbound().unwrap(), tracked);
+
+        let just_below = f64::from_bits(threshold.to_bits() - 1);
+        let below_threshold = Matrix::<2>::try_from_rows([[just_below, 0.0], [0.0, 2.0]]).unwrap();
+        assert!(!below_threshold.det_bound_inputs_have_wide_exponent_margin());
+        assert!(!Matrix::<5>::identity().det_bound_inputs_have_wide_exponent_margin());
+    }
+
+    #[test]
+    fn det_direct_with_errbound_covers_zero_and_one_dimensions() {
+        let empty = Matrix::<0>::zero()
+            .det_direct_with_errbound()
+            .unwrap()
+            .unwrap();
+        assert_abs_diff_eq!(empty.determinant(), 1.0, epsilon = 0.0);
+        assert_abs_diff_eq!(empty.absolute_error_bound(), 0.0, epsilon = 0.0);
+
+        let scalar = Matrix::<1>::try_from_rows([[-7.0]])
+            .unwrap()
+            .det_direct_with_errbound()
+            .unwrap()
+            .unwrap();
+        assert_abs_diff_eq!(scalar.determinant(), -7.0, epsilon = 0.0);
+        assert_abs_diff_eq!(scalar.absolute_error_bound(), 0.0, epsilon = 0.0);
+    }
+
+    #[test]
+    fn det_direct_with_errbound_pairs_the_closed_form_values() {
+        let matrix = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]]).unwrap();
+        let estimate = matrix.det_direct_with_errbound().unwrap().unwrap();
+
+        assert_abs_diff_eq!(
+            estimate.determinant(),
+            matrix.det_direct().unwrap().unwrap(),
+            epsilon = 0.0
+        );
+        assert_abs_diff_eq!(
+            estimate.absolute_error_bound(),
+            ERR_COEFF_2 * (4.0_f64 + 6.0_f64),
+            epsilon = 0.0
+        );
+    }
+
+    #[test]
+    fn det_direct_with_errbound_d5_returns_none() {
+        assert_eq!(Matrix::<5>::identity().det_direct_with_errbound(), Ok(None));
+    }
+
+    #[test]
+    fn det_errbound_rejects_computed_overflow() {
+        let m = Matrix::<2>::try_from_rows([[1e300, 0.0], [0.0, 1e300]]).unwrap();
+        assert_eq!(
+            m.det_errbound(),
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::DeterminantErrorBound
+            ))
+        );
+    }
+
+    // === det_errbound const-evaluability tests (D = 2..=5) ===
+
+    macro_rules! gen_det_errbound_const_eval_tests {
+        ($d:literal) => {
+            paste! {
+                /// `Matrix::<D>::det_errbound()` on the identity must const-evaluate
+                /// to `Ok(Some(bound))` with `bound > 0` for every closed-form dimension
+                /// `D ∈ {2, 3, 4}`.  Each dimension hits a distinct arm of
+                /// `det_errbound` with a dimension-specific permanent computation.
+                #[test]
+                fn [<det_errbound_const_eval_ $d d>]() {
+                    const BOUND: Result<Option<f64>, LaError> = Matrix::<$d>::identity().det_errbound();
+                    assert!(BOUND.unwrap().unwrap() > 0.0);
+                }
+            }
+        };
+    }
+
+    gen_det_errbound_const_eval_tests!(2);
+    gen_det_errbound_const_eval_tests!(3);
+    gen_det_errbound_const_eval_tests!(4);
+
+    #[test]
+    fn det_errbound_const_eval_d5_is_none() {
+        // D ≥ 5 has no fast-filter bound; `det_errbound` returns `Ok(None)`.
+        const BOUND: Result<Option<f64>, LaError> = Matrix::<5>::identity().det_errbound();
+        assert_eq!(BOUND, Ok(None));
+    }
+
+    // === inf_norm const-evaluability tests (D = 2..=5) ===
+
+    macro_rules! gen_inf_norm_const_eval_tests {
+        ($d:literal) => {
+            paste! {
+                /// `Matrix::<D>::inf_norm()` on the identity must const-evaluate
+                /// to `1.0` for every `D ≥ 1` — each row has a single `1.0`
+                /// entry, so the max absolute row sum is exactly `1.0`.
+                #[test]
+                fn [<inf_norm_const_eval_ $d d>]() {
+                    const NORM: Result<f64, LaError> = Matrix::<$d>::identity().inf_norm();
+                    assert!((NORM.unwrap() - 1.0).abs() <= 1e-12);
+                }
+            }
+        };
+    }
+
+    gen_inf_norm_const_eval_tests!(2);
+    gen_inf_norm_const_eval_tests!(3);
+    gen_inf_norm_const_eval_tests!(4);
+    gen_inf_norm_const_eval_tests!(5);
+
+    // === is_symmetric / first_asymmetry (public LDLT preconditions helpers) ===
+
+    macro_rules! gen_is_symmetric_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<is_symmetric_true_for_identity_ $d d>]() {
+                    let m = Matrix::<$d>::identity();
+                    assert!(m.is_symmetric(Tolerance::try_new(1e-12).unwrap()).unwrap());
+                    assert_eq!(m.first_asymmetry(Tolerance::try_new(1e-12).unwrap()).unwrap(), None);
+                }
+
+                #[test]
+                fn [<is_symmetric_true_for_zero_ $d d>]() {
+                    let m = Matrix::<$d>::zero();
+                    assert!(m.is_symmetric(Tolerance::try_new(1e-12).unwrap()).unwrap());
+                    assert_eq!(m.first_asymmetry(Tolerance::try_new(1e-12).unwrap()).unwrap(), None);
+                }
+
+                #[test]
+                fn [<is_symmetric_true_for_constructed_symmetric_ $d d>]() {
+                    // Construct A = M + Mᵀ so A is provably symmetric.
+                    let mut m = [[0.0f64; $d]; $d];
+                    for r in 0..$d {
+                        for c in 0..$d {
+                            #[expect(
+                                clippy::cast_precision_loss,
+                                reason = "matrix test indices are at most five and exactly representable as f64"
+                            )]
+                            {
+                                m[r][c] = (r * $d + c) as f64;
+                            }
+                        }
+                    }
+                    let mut sym = [[0.0f64; $d]; $d];
+                    for r in 0..$d {
+                        for c in 0..$d {
+                            sym[r][c] = m[r][c] + m[c][r];
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(sym).unwrap();
+                    assert!(a.is_symmetric(Tolerance::try_new(1e-12).unwrap()).unwrap());
+                    assert_eq!(a.first_asymmetry(Tolerance::try_new(1e-12).unwrap()).unwrap(), None);
+                }
+
+                #[test]
+                fn [<is_symmetric_false_for_asymmetric_offdiagonal_ $d d>]() {
+                    // Perturb a single off-diagonal entry so symmetry fails.
+                    let mut rows = [[0.0f64; $d]; $d];
+                    for i in 0..$d {
+                        rows[i][i] = 1.0;
+                    }
+                    rows[0][$d - 1] = 1.0;
+                    rows[$d - 1][0] = -1.0; // breaks symmetry
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    assert!(!a.is_symmetric(Tolerance::try_new(1e-12).unwrap()).unwrap());
+                    assert_eq!(
+                        a.first_asymmetry(Tolerance::try_new(1e-12).unwrap()).unwrap(),
+                        Some((0, $d - 1))
+                    );
+                }
+
+            }
+        };
+    }
+
+    gen_is_symmetric_tests!(2);
+    gen_is_symmetric_tests!(3);
+    gen_is_symmetric_tests!(4);
+    gen_is_symmetric_tests!(5);
+
+    macro_rules! gen_ldlt_symmetry_proof_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<matrix_ldlt_accepts_exact_symmetric_spd_ $d d>]() {
+                    // This exactly mirrored, strictly diagonally dominant
+                    // tridiagonal matrix is positive definite.
+                    let mut rows = [[0.0_f64; $d]; $d];
+                    for (index, row) in rows.iter_mut().enumerate() {
+                        row[index] = 2.0;
+                    }
+                    for index in 1..$d {
+                        rows[index - 1][index] = 0.5;
+                        rows[index][index - 1] = 0.5;
+                    }
+
+                    let matrix = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let symmetric = SymmetricMatrix::try_new(matrix).unwrap();
+                    assert_eq!(symmetric.into_matrix(), matrix);
+
+                    let ldlt = matrix.ldlt(DEFAULT_SINGULAR_TOL).unwrap();
+                    assert!(ldlt.det().unwrap() > 0.0);
+                }
+
+                #[test]
+                fn [<symmetric_matrix_try_new_rejects_finite_asymmetric_ $d d>]() {
+                    let mut rows = [[0.0f64; $d]; $d];
+                    for (i, row) in rows.iter_mut().enumerate() {
+                        row[i] = 1.0;
+                    }
+                    rows[0][$d - 1] = 1.0;
+                    rows[$d - 1][0] = -1.0;
+
+                    assert_eq!(
+                        Matrix::<$d>::try_from_rows(rows).and_then(SymmetricMatrix::try_new),
+                        Err(LaError::asymmetric(0, $d - 1, $d, 1.0, -1.0, 0.0))
+                    );
+                }
+            }
+        };
+    }
+
+    gen_ldlt_symmetry_proof_tests!(2);
+    gen_ldlt_symmetry_proof_tests!(3);
+    gen_ldlt_symmetry_proof_tests!(4);
+    gen_ldlt_symmetry_proof_tests!(5);
+
+    #[test]
+    fn symmetric_matrix_into_matrix_roundtrips_storage_internally() {
+        let a = Matrix::<2>::try_from_rows([[2.0, 1.0], [1.0, 3.0]]).unwrap();
+        let symmetric = SymmetricMatrix::try_new(a).unwrap();
+
+        assert_eq!(symmetric.into_matrix(), a);
+    }
+
+    #[test]
+    fn matrix_ldlt_accepts_opposite_signed_zero_mirrors() {
+        let matrix = Matrix::<2>::try_from_rows([[2.0, 0.0], [-0.0, 2.0]]).unwrap();
+        let ldlt = matrix.ldlt(DEFAULT_SINGULAR_TOL).unwrap();
+
+        assert_eq!(ldlt.det(), Ok(4.0));
+    }
+
+    #[test]
+    fn is_symmetric_tolerance_scales_with_inf_norm() {
+        // Off-diagonal entries differ by 1e-6.  With inf_norm ≈ 2e6, the
+        // relative tolerance 1e-12 yields eps ≈ 2e-6, which accepts the gap;
+        // a stricter tol of 1e-15 rejects it.
+        let a = Matrix::<2>::try_from_rows([[1.0e6, 1.0e6 + 1.0e-6], [1.0e6, 1.0e6]]).unwrap();
+        assert!(a.is_symmetric(Tolerance::try_new(1e-12).unwrap()).unwrap());
+        assert!(!a.is_symmetric(Tolerance::try_new(1e-15).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn symmetry_epsilon_multiplies_after_row_sum_near_subnormal_boundary() {
+        let min_subnormal = f64::from_bits(1);
+        let mut rows = [[0.0; 5]; 5];
+        let mut col = 0;
+        while col < 4 {
+            rows[0][col] = 0.4;
+            rows[col][0] = 0.4;
+            col += 1;
+        }
+        rows[0][4] = 2.0 * min_subnormal;
+        rows[4][0] = 0.0;
+
+        let matrix = Matrix::<5>::try_from_rows(rows).unwrap();
+        let tolerance = Tolerance::try_new(min_subnormal).unwrap();
+        let expected_epsilon = tolerance.get() * matrix.inf_norm().unwrap().max(1.0);
+
+        assert_eq!(expected_epsilon.to_bits(), 2);
+        assert_eq!(matrix.first_asymmetry(tolerance), Ok(None));
+        assert_eq!(matrix.is_symmetric(tolerance), Ok(true));
+    }
+
+    #[test]
+    fn symmetry_epsilon_scales_terms_when_row_sum_overflows() {
+        let matrix =
+            Matrix::<2>::try_from_rows([[f64::MAX, f64::MAX], [f64::MAX / 2.0, f64::MAX]]).unwrap();
+
+        assert_eq!(
+            matrix.inf_norm(),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::MatrixInfinityNorm,
+                0,
+                1
+            ))
+        );
+        assert_eq!(
+            matrix.first_asymmetry(Tolerance::try_new(0.25).unwrap()),
+            Ok(None)
+        );
+        assert_eq!(
+            matrix.first_asymmetry(Tolerance::try_new(0.125).unwrap()),
+            Ok(Some((0, 1)))
+        );
+    }
+
+    #[test]
+    fn first_asymmetry_returns_lexicographically_first_pair() {
+        // Two asymmetric pairs: (0, 2) and (1, 2).  We must get (0, 2) first.
+        let a = Matrix::<3>::try_from_rows([[1.0, 0.0, 2.0], [0.0, 1.0, 3.0], [-2.0, -3.0, 1.0]])
+            .unwrap();
+        assert_eq!(
+            a.first_asymmetry(Tolerance::try_new(1e-12).unwrap())
+                .unwrap(),
+            Some((0, 2))
+        );
+    }
+
+    #[test]
+    fn first_asymmetry_strict_tol_survives_row_sum_overflow() {
+        let a = Matrix::<3>::try_from_rows([
+            [1.0, 1.0, 0.0],
+            [2.0, f64::MAX, f64::MAX],
+            [0.0, 0.0, 1.0],
+        ])
+        .unwrap();
+
+        assert_eq!(
+            a.inf_norm(),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::MatrixInfinityNorm,
+                1,
+                2
+            ))
+        );
+        assert_eq!(
+            a.first_asymmetry(Tolerance::try_new(0.0).unwrap()).unwrap(),
+            Some((0, 1))
+        );
+        assert!(!a.is_symmetric(Tolerance::try_new(0.0).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn first_asymmetry_rejects_scaled_epsilon_overflow() {
+        let a = Matrix::<2>::try_from_rows([[0.0, 0.0], [2.0, 1.0]]).unwrap();
+        let tol = Tolerance::try_new(f64::MAX).unwrap();
+
+        assert_eq!(
+            a.first_asymmetry(tol),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::SymmetryCheck,
+                1,
+                0
+            ))
+        );
+        assert_eq!(
+            a.is_symmetric(tol),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::SymmetryCheck,
+                1,
+                0
+            ))
+        );
+    }
+
+    #[test]
+    fn first_asymmetry_flags_overflowed_finite_difference() {
+        let a = Matrix::<2>::try_from_rows([[1.0, f64::MAX], [-f64::MAX, 1.0]]).unwrap();
+        assert_eq!(
+            a.first_asymmetry(Tolerance::try_new(1e-12).unwrap())
+                .unwrap(),
+            Some((0, 1))
+        );
+        assert!(!a.is_symmetric(Tolerance::try_new(1e-12).unwrap()).unwrap());
+    }
+
+    // === to_row_major_vec / from_row_major_slice ===
+
+    macro_rules! gen_row_major_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<row_major_vec_round_trips_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            *entry = f64::from(u32::try_from(r * 10 + c).unwrap()) - 0.5;
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+
+                    let flat = a.to_row_major_vec();
+                    assert_eq!(flat.len(), $d * $d);
+                    for (i, value) in flat.iter().enumerate() {
+                        assert_eq!(value.to_bits(), rows[i / $d][i % $d].to_bits());
+                    }
+                    assert_eq!(Matrix::<$d>::from_row_major_slice(&flat), Ok(a));
+
+                    assert_eq!(
+                        Matrix::<$d>::from_row_major_slice(&flat[1..]),
+                        Err(LaError::dimension_mismatch($d * $d, $d * $d - 1))
+                    );
+                    let mut bad = flat;
+                    bad[$d + 1] = f64::NAN;
+                    assert_eq!(
+                        Matrix::<$d>::from_row_major_slice(&bad),
+                        Err(LaError::non_finite_input_matrix(1, 1))
+                    );
+                }
+            }
+        };
+    }
+
+    gen_row_major_tests!(2);
+    gen_row_major_tests!(3);
+    gen_row_major_tests!(4);
+    gen_row_major_tests!(5);
+
+    #[test]
+    fn row_major_vec_of_empty_matrix_is_empty() {
+        assert!(Matrix::<0>::zero().to_row_major_vec().is_empty());
+        assert_eq!(Matrix::<0>::from_row_major_slice(&[]), Ok(Matrix::zero()));
+        assert_eq!(
+            Matrix::<0>::from_row_major_slice(&[1.0]),
+            Err(LaError::dimension_mismatch(0, 1))
+        );
+    }
+
+    // === is_permutation ===
+
+    macro_rules! gen_is_permutation_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<is_permutation_accepts_identity_and_cycle_ $d d>]() {
+                    let tol = Tolerance::try_new(1e-12).unwrap();
+                    assert!(Matrix::<$d>::identity().is_permutation(tol));
+
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        row[(r + 1) % $d] = 1.0;
+                    }
+                    let cycle = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    assert!(cycle.is_permutation(tol));
+
+                    rows[0][1] = 1.0 + 1e-14;
+                    rows[$d - 1][$d - 1] = -1e-14;
+                    let noisy = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    assert!(noisy.is_permutation(tol));
+                    assert!(!noisy.is_permutation(Tolerance::ZERO));
+
+                    let scaled = Matrix::<$d>::identity().add_scaled_identity(1.0).unwrap();
+                    assert!(!scaled.is_permutation(tol));
+                    assert!(!Matrix::<$d>::zero().is_permutation(tol));
+                }
+            }
+        };
+    }
+
+    gen_is_permutation_tests!(2);
+    gen_is_permutation_tests!(3);
+    gen_is_permutation_tests!(4);
+    gen_is_permutation_tests!(5);
+
+    #[test]
+    fn is_permutation_rejects_repeated_rows_and_columns() {
+        let tol = Tolerance::try_new(1e-12).unwrap();
+        // Each row has one unit entry, but both sit in column 0.
+        let same_col = Matrix::<2>::try_from_rows([[1.0, 0.0], [1.0, 0.0]]).unwrap();
+        assert!(!same_col.is_permutation(tol));
+        // Row 0 has two unit entries.
+        let two_in_row =
+            Matrix::<3>::try_from_rows([[1.0, 1.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, 0.0]])
+                .unwrap();
+        assert!(!two_in_row.is_permutation(tol));
+        assert!(Matrix::<0>::zero().is_permutation(tol));
+    }
+
+    #[test]
+    fn is_permutation_accepts_lu_source_rows() {
+        let a = Matrix::<3>::try_from_rows([[1.0, 2.0, 3.0], [7.0, 5.0, 6.0], [4.0, 8.0, 9.0]])
+            .unwrap();
+        let (_, source_rows) = a.lu(DEFAULT_SINGULAR_TOL).unwrap().into_parts();
+        let mut rows = [[0.0; 3]; 3];
+        for (row, &source) in rows.iter_mut().zip(&source_rows) {
+            row[source] = 1.0;
+        }
+        let p = Matrix::<3>::try_from_rows(rows).unwrap();
+        assert!(p.is_permutation(Tolerance::ZERO));
+        assert_ne!(p, Matrix::<3>::identity());
+    }
+
+    // === is_diagonally_dominant ===
+
+    macro_rules! gen_is_diagonally_dominant_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<is_diagonally_dominant_strict_and_weak_ $d d>]() {
+                    // tridiag(-1, 2, -1): strict in the end rows, ties inside.
+                    let laplacian = Matrix::<$d>::second_difference();
+                    assert!(laplacian.is_diagonally_dominant(false));
+                    assert_eq!(laplacian.is_diagonally_dominant(true), $d == 2);
+
+                    let shifted = laplacian.add_scaled_identity(0.5).unwrap();
+                    assert!(shifted.is_diagonally_dominant(true));
+
+                    let mut rows = [[1.0; $d]; $d];
+                    rows[$d - 1][$d - 1] = 0.5;
+                    let weak_last_row = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    assert!(!weak_last_row.is_diagonally_dominant(false));
+                    assert!(Matrix::<$d>::identity().is_diagonally_dominant(true));
+                    assert!(!Matrix::<$d>::zero().is_diagonally_dominant(true));
+                }
+            }
+        };
+    }
+
+    gen_is_diagonally_dominant_tests!(2);
+    gen_is_diagonally_dominant_tests!(3);
+    gen_is_diagonally_dominant_tests!(4);
+    gen_is_diagonally_dominant_tests!(5);
+
+    #[test]
+    fn is_diagonally_dominant_handles_overflowing_row_sum() {
+        let a = Matrix::<3>::try_from_rows([
+            [f64::MAX, f64::MAX, f64::MAX],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0],
+        ])
+        .unwrap();
+        assert!(!a.is_diagonally_dominant(false));
+        assert!(Matrix::<0>::zero().is_diagonally_dominant(true));
+    }
+
+    // === is_correlation ===
+
+    macro_rules! gen_is_correlation_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<is_correlation_accepts_valid_and_rejects_out_of_range_ $d d>]() {
+                    let tol = Tolerance::try_new(1e-12).unwrap();
+                    // Equicorrelation with ρ = 0.5 is positive definite.
+                    let mut rows = [[0.5; $d]; $d];
+                    for (i, row) in rows.iter_mut().enumerate() {
+                        row[i] = 1.0;
+                    }
+                    let valid = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    assert!(valid.is_correlation(tol));
+                    assert!(Matrix::<$d>::identity().is_correlation(tol));
+
+                    let mut out_of_range = rows;
+                    out_of_range[0][$d - 1] = 1.25;
+                    out_of_range[$d - 1][0] = 1.25;
+                    let out_of_range = Matrix::<$d>::try_from_rows(out_of_range).unwrap();
+                    assert!(!out_of_range.is_correlation(tol));
+
+                    let mut asymmetric = rows;
+                    asymmetric[$d - 1][0] = 0.25;
+                    let asymmetric = Matrix::<$d>::try_from_rows(asymmetric).unwrap();
+                    assert!(!asymmetric.is_correlation(tol));
+                    assert!(asymmetric.is_correlation(Tolerance::try_new(0.3).unwrap()));
+
+                    let mut bad_diagonal = rows;
+                    bad_diagonal[$d - 1][$d - 1] = 1.0 + 1e-9;
+                    let bad_diagonal = Matrix::<$d>::try_from_rows(bad_diagonal).unwrap();
+                    assert!(!bad_diagonal.is_correlation(tol));
+
+                    // All entries one: a unit diagonal in range, but singular.
+                    assert!(!Matrix::<$d>::try_from_rows([[1.0; $d]; $d])
+                        .unwrap()
+                        .is_correlation(tol));
+                }
+            }
+        };
+    }
+
+    gen_is_correlation_tests!(2);
+    gen_is_correlation_tests!(3);
+    gen_is_correlation_tests!(4);
+    gen_is_correlation_tests!(5);
+
+    #[test]
+    fn is_correlation_rejects_in_range_indefinite_matrix() {
+        // Pairwise correlations of -0.9 cannot hold among three variables.
+        let a =
+            Matrix::<3>::try_from_rows([[1.0, -0.9, -0.9], [-0.9, 1.0, -0.9], [-0.9, -0.9, 1.0]])
+                .unwrap();
+        let tol = Tolerance::try_new(1e-12).unwrap();
+        assert!(!a.is_correlation(tol));
+        assert!(Matrix::<0>::zero().is_correlation(tol));
+        assert!(
+            !Matrix::<2>::try_from_rows([[1.0, f64::MAX], [-f64::MAX, 1.0]])
+                .unwrap()
+                .is_correlation(tol)
+        );
+    }
+
+    // === upper_bandwidth / lower_bandwidth ===
+
+    macro_rules! gen_bandwidth_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<bandwidth_of_tridiagonal_and_dense_ $d d>]() {
+                    let tridiagonal = Matrix::<$d>::second_difference();
+                    assert_eq!(tridiagonal.upper_bandwidth(), 1);
+                    assert_eq!(tridiagonal.lower_bandwidth(), 1);
+                    assert!(tridiagonal.lu_tridiagonal(DEFAULT_SINGULAR_TOL).is_ok());
+
+                    let dense = Matrix::<$d>::try_from_rows([[1.0; $d]; $d]).unwrap();
+                    assert_eq!(dense.upper_bandwidth(), $d - 1);
+                    assert_eq!(dense.lower_bandwidth(), $d - 1);
+
+                    // A lone corner entry sets the bandwidth; signed zeros do not.
+                    let mut rows = [[0.0; $d]; $d];
+                    rows[$d - 1][0] = 3.0;
+                    rows[0][$d - 1] = -0.0;
+                    let corner = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    assert_eq!(corner.lower_bandwidth(), $d - 1);
+                    assert_eq!(corner.upper_bandwidth(), 0);
+
+                    assert_eq!(Matrix::<$d>::identity().upper_bandwidth(), 0);
+                    assert_eq!(Matrix::<$d>::identity().lower_bandwidth(), 0);
+                }
+            }
+        };
+    }
+
+    gen_bandwidth_tests!(2);
+    gen_bandwidth_tests!(3);
+    gen_bandwidth_tests!(4);
+    gen_bandwidth_tests!(5);
+
+    #[test]
+    fn bandwidth_of_empty_and_single_matrices_is_zero() {
+        assert_eq!(Matrix::<0>::zero().upper_bandwidth(), 0);
+        assert_eq!(Matrix::<0>::zero().lower_bandwidth(), 0);
+        let one = Matrix::<1>::try_from_rows([[5.0]]).unwrap();
+        assert_eq!((one.lower_bandwidth(), one.upper_bandwidth()), (0, 0));
+    }
+
+    // === max_relative_error ===
+
+    macro_rules! gen_max_relative_error_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<max_relative_error_of_perturbed_copy_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            *entry = f64::from(u32::try_from(r * $d + c).unwrap()).mul_add(1e3, -2.0);
+                        }
+                    }
+                    let reference = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    assert_eq!(reference.max_relative_error(&reference).to_bits(), 0.0_f64.to_bits());
+
+                    // Relative perturbation of a large entry, absolute of a small one.
+                    let last = rows[$d - 1][$d - 1];
+                    rows[$d - 1][$d - 1] = last * (1.0 + 1e-9);
+                    rows[0][0] += 1e-12;
+                    let perturbed = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let error = perturbed.max_relative_error(&reference);
+                    assert_relative_eq!(error, 1e-9, max_relative = 1e-6);
+                }
+            }
+        };
+    }
+
+    gen_max_relative_error_tests!(2);
+    gen_max_relative_error_tests!(3);
+    gen_max_relative_error_tests!(4);
+    gen_max_relative_error_tests!(5);
+
+    #[test]
+    fn max_relative_error_stays_finite_on_overflowing_difference() {
+        let a = Matrix::<1>::try_from_rows([[f64::MAX]]).unwrap();
+        let b = Matrix::<1>::try_from_rows([[-f64::MAX]]).unwrap();
+        assert_relative_eq!(a.max_relative_error(&b), 2.0, max_relative = 1e-15);
+
+        // |MAX + 1e300| overflows, but the ratio to 1e300 is about 1.8e8.
+        let c = Matrix::<1>::try_from_rows([[-1e300]]).unwrap();
+        assert_relative_eq!(
+            a.max_relative_error(&c),
+            f64::MAX / 1e300 + 1.0,
+            max_relative = 1e-15
+        );
+        assert_eq!(
+            Matrix::<0>::zero()
+                .max_relative_error(&Matrix::zero())
+                .to_bits(),
+            0.0_f64.to_bits()
+        );
+    }
+
+    // === add_scaled_identity ===
+
+    macro_rules! gen_add_scaled_identity_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<add_scaled_identity_shifts_only_diagonal_ $d d>]() {
+                    let mut rows = [[1.0f64; $d]; $d];
+                    rows[0][$d - 1] = -2.0;
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+
+                    let shifted = a.add_scaled_identity(0.25).unwrap();
+                    for r in 0..$d {
+                        for c in 0..$d {
+                            let expected = if r == c { rows[r][c] + 0.25 } else { rows[r][c] };
+                            assert_abs_diff_eq!(shifted.rows[r][c], expected, epsilon = 0.0);
+                        }
+                    }
+                    assert_eq!(Matrix::<$d>::zero().add_scaled_identity(1.0), Ok(Matrix::<$d>::identity()));
+                }
+            }
+        };
+    }
+
+    gen_add_scaled_identity_tests!(2);
+    gen_add_scaled_identity_tests!(3);
+    gen_add_scaled_identity_tests!(4);
+    gen_add_scaled_identity_tests!(5);
+
+    #[test]
+    fn add_scaled_identity_makes_near_singular_matrix_solvable() {
+        let a = Matrix::<2>::try_from_rows([[1.0, 1.0], [1.0, 1.0 + 1e-14]]).unwrap();
+        assert_matches!(a.lu(DEFAULT_SINGULAR_TOL), Err(LaError::Singular { .. }));
+
+        let damped = a.add_scaled_identity(1e-3).unwrap();
+        let lu = damped.lu(DEFAULT_SINGULAR_TOL).unwrap();
+        let x = lu.solve(Vector::<2>::new([2.0, 2.0])).unwrap().into_array();
+        // The damped system is symmetric in its unknowns, so x[0] ≈ x[1] ≈ 2 / 2.001.
+        assert_abs_diff_eq!(x[0], 2.0 / 2.001, epsilon = 1e-9);
+        assert_abs_diff_eq!(x[1], 2.0 / 2.001, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn add_scaled_identity_rejects_non_finite_shift_and_overflow() {
+        let a = Matrix::<2>::try_from_rows([[1.0, 0.0], [0.0, f64::MAX]]).unwrap();
+        for c in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(
+                a.add_scaled_identity(c),
+                Err(LaError::non_finite_input_scalar())
+            );
+        }
+        assert_eq!(
+            a.add_scaled_identity(f64::MAX),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::ScaledIdentityShift,
+                1,
+                1
+            ))
+        );
+    }
+
+    // === add_outer ===
+
+    macro_rules! gen_add_outer_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<add_outer_accumulation_matches_batch_gram_ $d d>]() {
+                    const SAMPLES: usize = 2 * $d + 1;
+                    let samples: [[f64; $d]; SAMPLES] = core::array::from_fn(|k| {
+                        core::array::from_fn(|i| {
+                            let value = f64::from(u32::try_from((3 * k + 5 * i) % 7).unwrap());
+                            value.mul_add(0.25, -0.75)
+                        })
+                    });
+
+                    let mut gram = Matrix::<$d>::zero();
+                    for sample in &samples {
+                        gram.add_outer(&Vector::new(*sample), 1.0).unwrap();
+                    }
+
+                    // Batch Gram matrix XᵀX, with X holding one sample per row.
+                    let mut batch = [[0.0; $d]; $d];
+                    for (i, row) in batch.iter_mut().enumerate() {
+                        for (j, entry) in row.iter_mut().enumerate() {
+                            *entry = samples.iter().map(|x| x[i] * x[j]).sum();
+                        }
+                    }
+                    let batch = Matrix::<$d>::try_from_rows(batch).unwrap();
+
+                    // Quarter-integer samples make every product and sum exact.
+                    assert_eq!(gram, batch);
+                    let ldlt = gram.ldlt(DEFAULT_SINGULAR_TOL).unwrap();
+                    let batch_ldlt = batch.ldlt(DEFAULT_SINGULAR_TOL).unwrap();
+                    assert_eq!(ldlt.det(), batch_ldlt.det());
+                }
+            }
+        };
+    }
+
+    gen_add_outer_tests!(2);
+    gen_add_outer_tests!(3);
+    gen_add_outer_tests!(4);
+    gen_add_outer_tests!(5);
+
+    #[test]
+    fn add_outer_keeps_symmetry_and_rejects_bad_input() {
+        let mut gram = Matrix::<3>::identity();
+        let x = Vector::new([0.1, -0.7, 1.3]);
+        gram.add_outer(&x, 0.3).unwrap();
+        gram.add_outer(&Vector::new([2.0 / 3.0, 0.2, -0.9]), 1.7)
+            .unwrap();
+        assert!(SymmetricMatrix::try_new(gram).is_ok());
+
+        let before = gram;
+        assert_eq!(
+            gram.add_outer(&x, f64::NAN),
+            Err(LaError::non_finite_input_scalar())
+        );
+        assert_eq!(
+            gram.add_outer(&Vector::new([0.0, 1e200, 1e200]), 1.0),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::OuterProductUpdate,
+                1,
+                1
+            ))
+        );
+        assert_eq!(gram, before);
+    }
+
+    macro_rules! gen_from_outer_sum_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<from_outer_sum_matches_add_outer_loop_ $d d>]() {
+                    const SAMPLES: usize = $d + 2;
+                    let vectors: [Vector<$d>; SAMPLES] = core::array::from_fn(|k| {
+                        Vector::new(core::array::from_fn(|i| {
+                            let value = f64::from(u32::try_from((3 * k + 5 * i) % 7).unwrap());
+                            value.mul_add(0.3, -0.7)
+                        }))
+                    });
+                    let weights: [f64; SAMPLES] =
+                        core::array::from_fn(|k| 1.0 / f64::from(u32::try_from(k + 1).unwrap()));
+
+                    let mut expected = Matrix::<$d>::zero();
+                    for (v, &w) in vectors.iter().zip(&weights) {
+                        expected.add_outer(v, w).unwrap();
+                    }
+
+                    let gram = Matrix::<$d>::from_outer_sum(&vectors, &weights).unwrap();
+                    assert_eq!(gram, expected);
+                    assert!(gram.is_symmetric(Tolerance::ZERO).unwrap());
+                    assert!(gram.ldlt(DEFAULT_SINGULAR_TOL).is_ok());
+                    assert_eq!(
+                        Matrix::<$d>::from_outer_sum(&[], &[]).unwrap(),
+                        Matrix::<$d>::zero()
+                    );
+                }
+            }
+        };
+    }
+
+    gen_from_outer_sum_tests!(2);
+    gen_from_outer_sum_tests!(3);
+    gen_from_outer_sum_tests!(4);
+    gen_from_outer_sum_tests!(5);
+
+    #[test]
+    fn from_outer_sum_rejects_bad_weights_and_overflow() {
+        let vectors = [Vector::<2>::new([1.0, 0.0]), Vector::<2>::new([0.0, 1e200])];
+        assert_eq!(
+            Matrix::<2>::from_outer_sum(&vectors, &[1.0, f64::INFINITY]),
+            Err(LaError::non_finite_input_weight(1))
+        );
+        assert_eq!(
+            Matrix::<2>::from_outer_sum(&vectors, &[1.0, 1.0]),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::OuterProductUpdate,
+                1,
+                1
+            ))
+        );
+    }
+
+    macro_rules! gen_apply_householder_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<apply_householder_left_matches_reflection_product_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    let mut normal = [0.0; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        let rf = f64::from(u32::try_from(r).unwrap());
+                        normal[r] = rf - 0.5;
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            let cf = f64::from(u32::try_from(c).unwrap());
+                            *entry = rf.mul_add(2.0, 1.0) / cf.mul_add(3.0, 1.0) - cf;
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let v = Vector::<$d>::new(normal);
+                    let expected = Matrix::<$d>::householder(v)
+                        .unwrap()
+                        .matmul(&a, ArithmeticOperation::MatrixProduct)
+                        .unwrap();
+
+                    let mut updated = a;
+                    updated
+                        .apply_householder_left(&v, 2.0 / v.norm2_sq().unwrap())
+                        .unwrap();
+                    for (u_row, e_row) in updated.rows.iter().zip(&expected.rows) {
+                        for (&u, &e) in u_row.iter().zip(e_row) {
+                            assert_abs_diff_eq!(u, e, epsilon = 1e-13);
+                        }
+                    }
+
+                    // The compact reflector of the first column zeroes it below row 0.
+                    let first_column = Vector::<$d>::new(core::array::from_fn(|r| rows[r][0]));
+                    let (v, beta) = first_column.householder_vector();
+                    let mut reduced = a;
+                    reduced.apply_householder_left(&v, beta).unwrap();
+                    let (max_abs, norm) = first_column.scaled_norm2();
+                    assert_relative_eq!(reduced.rows[0][0], max_abs * norm, max_relative = 1e-14);
+                    for row in &reduced.rows[1..] {
+                        assert_abs_diff_eq!(row[0], 0.0, epsilon = 1e-14);
+                    }
+                }
+            }
+        };
+    }
+
+    gen_apply_householder_tests!(2);
+    gen_apply_householder_tests!(3);
+    gen_apply_householder_tests!(4);
+    gen_apply_householder_tests!(5);
+
+    #[test]
+    fn apply_householder_left_rejects_bad_beta_and_overflow() {
+        let original = Matrix::<2>::try_from_rows([[f64::MAX, 0.0], [f64::MAX, 1.0]]).unwrap();
+        let v = Vector::<2>::new([1.0, 1.0]);
+        let mut a = original;
+        assert_eq!(
+            a.apply_householder_left(&v, f64::NAN),
+            Err(LaError::non_finite_input_scalar())
+        );
+        assert_eq!(
+            a.apply_householder_left(&v, 1.0),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::HouseholderUpdate,
+                0,
+                0
+            ))
+        );
+        assert_eq!(a, original);
+    }
+
+    // === row_sums / col_sums ===
+
+    macro_rules! gen_row_col_sums_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<row_and_col_sums_of_identity_are_ones_ $d d>]() {
+                    let m = Matrix::<$d>::identity();
+                    assert_eq!(m.row_sums(), Ok(Vector::<$d>::new([1.0; $d])));
+                    assert_eq!(m.col_sums(), Ok(Vector::<$d>::new([1.0; $d])));
+                }
+
+                #[test]
+                fn [<row_and_col_sums_keep_signs_ $d d>]() {
+                    let mut rows = [[0.0f64; $d]; $d];
+                    rows[0][0] = 2.0;
+                    rows[0][$d - 1] = -3.0;
+                    rows[$d - 1][0] += 5.0;
+                    let m = Matrix::<$d>::try_from_rows(rows).unwrap();
+
+                    let row_sums = m.row_sums().unwrap().into_array();
+                    let col_sums = m.col_sums().unwrap().into_array();
+                    assert_abs_diff_eq!(row_sums[0], -1.0, epsilon = 0.0);
+                    assert_abs_diff_eq!(row_sums[$d - 1], 5.0, epsilon = 0.0);
+                    assert_abs_diff_eq!(col_sums[0], 7.0, epsilon = 0.0);
+                    assert_abs_diff_eq!(col_sums[$d - 1], -3.0, epsilon = 0.0);
+                }
+            }
+        };
+    }
+
+    gen_row_col_sums_tests!(2);
+    gen_row_col_sums_tests!(3);
+    gen_row_col_sums_tests!(4);
+    gen_row_col_sums_tests!(5);
+
+    #[test]
+    fn row_and_col_sums_report_overflowed_index() {
+        let m = Matrix::<2>::try_from_rows([[1.0, 1.0], [f64::MAX, f64::MAX]]).unwrap();
+        assert_eq!(
+            m.row_sums(),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::MatrixRowSums,
+                1
+            ))
+        );
+        assert_eq!(
+            m.transpose().col_sums(),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::MatrixColumnSums,
+                1
+            ))
+        );
+    }
+
+    // === trace / trace_of_power ===
+
+    macro_rules! gen_trace_of_power_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<trace_of_power_matches_trace_and_explicit_products_ $d d>]() {
+                    let mut rows = [[0.0f64; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            #[expect(
+                                clippy::cast_precision_loss,
+                                reason = "matrix test indices are at most five and exactly representable as f64"
+                            )]
+                            {
+                                *entry = ((r + 2 * c) % 3) as f64 - 1.0;
+                            }
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+
+                    let dim = f64::from($d);
+                    assert_abs_diff_eq!(a.trace_of_power(0).unwrap(), dim, epsilon = 0.0);
+                    assert_abs_diff_eq!(a.trace_of_power(1).unwrap(), a.trace().unwrap(), epsilon = 0.0);
+
+                    let mut explicit = a;
+                    for k in 2..=6 {
+                        explicit = explicit.matmul(&a, ArithmeticOperation::MatrixProduct).unwrap();
+                        assert_abs_diff_eq!(
+                            a.trace_of_power(k).unwrap(),
+                            explicit.trace().unwrap(),
+                            epsilon = 1e-9
+                        );
+                    }
+                }
+            }
+        };
+    }
+
+    gen_trace_of_power_tests!(2);
+    gen_trace_of_power_tests!(3);
+    gen_trace_of_power_tests!(4);
+    gen_trace_of_power_tests!(5);
+
+    #[test]
+    fn trace_of_power_of_diagonal_sums_eigenvalue_powers() {
+        let a = Matrix::<3>::try_from_rows([[2.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 0.5]])
+            .unwrap();
+        assert_eq!(a.trace_of_power(2), Ok(4.0 + 1.0 + 0.25));
+        assert_eq!(a.trace_of_power(5), Ok(32.0 - 1.0 + 0.031_25));
+        assert_eq!(Matrix::<0>::zero().trace_of_power(3), Ok(0.0));
+    }
+
+    #[test]
+    fn trace_and_trace_of_power_report_overflow() {
+        let a = Matrix::<2>::try_from_rows([[f64::MAX, 0.0], [0.0, f64::MAX]]).unwrap();
+        assert_eq!(
+            a.trace(),
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::Trace
+            ))
+        );
+        assert_eq!(
+            a.trace_of_power(3),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::MatrixPowerTrace,
+                0,
+                0
+            ))
+        );
+        let b = Matrix::<2>::try_from_rows([[1e200, 0.0], [0.0, 1e200]]).unwrap();
+        assert_eq!(b.trace_of_power(1), Ok(2e200));
+        assert_eq!(
+            b.trace_of_power(2),
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::MatrixPowerTrace
+            ))
+        );
+    }
+
+    // === frobenius_inner ===
+
+    macro_rules! gen_frobenius_inner_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<frobenius_inner_matches_trace_of_transpose_product_ $d d>]() {
+                    let a = Matrix::<$d>::try_from_rows(core::array::from_fn(|r| {
+                        core::array::from_fn(|c| {
+                            f64::from(u32::try_from(r + 2 * c).unwrap()) - 2.5
+                        })
+                    }))
+                    .unwrap();
+                    let b = Matrix::<$d>::try_from_rows(core::array::from_fn(|r| {
+                        core::array::from_fn(|c| if (r + c).is_multiple_of(2) { 0.5 } else { -2.0 })
+                    }))
+                    .unwrap();
+
+                    let mut norm_sq = 0.0_f64;
+                    for row in &a.rows {
+                        for &x in row {
+                            norm_sq = x.mul_add(x, norm_sq);
+                        }
+                    }
+                    assert_eq!(a.frobenius_inner(&a).unwrap().to_bits(), norm_sq.to_bits());
+
+                    let inner = a.frobenius_inner(&b).unwrap();
+                    assert_eq!(inner.to_bits(), b.frobenius_inner(&a).unwrap().to_bits());
+                    let at_b = a
+                        .transpose()
+                        .matmul(&b, ArithmeticOperation::MatrixProduct)
+                        .unwrap();
+                    assert_abs_diff_eq!(inner, at_b.trace().unwrap(), epsilon = 1e-12);
+                    assert_eq!(a.frobenius_inner(&Matrix::<$d>::identity()), a.trace());
+                    assert_eq!(a.frobenius_inner(&Matrix::<$d>::zero()), Ok(0.0));
+                }
+            }
+        };
+    }
+
+    gen_frobenius_inner_tests!(2);
+    gen_frobenius_inner_tests!(3);
+    gen_frobenius_inner_tests!(4);
+    gen_frobenius_inner_tests!(5);
+
+    #[test]
+    fn frobenius_inner_reports_overflow() {
+        let a = Matrix::<2>::try_from_rows([[1e200, 0.0], [0.0, 1.0]]).unwrap();
+        assert_eq!(
+            a.frobenius_inner(&a),
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::FrobeniusInnerProduct
+            ))
+        );
+        assert_eq!(
+            Matrix::<0>::zero().frobenius_inner(&Matrix::zero()),
+            Ok(0.0)
+        );
+    }
+
+    // === lu_batch ===
+
+    macro_rules! gen_lu_batch_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<lu_batch_matches_individual_lu_ $d d>]() {
+                    let mut mats: [Matrix<$d>; 4] = core::array::from_fn(|k| {
+                        let shift = f64::from(u32::try_from(k).unwrap());
+                        let mut rows = [[0.0; $d]; $d];
+                        for (r, row) in rows.iter_mut().enumerate() {
+                            for (c, entry) in row.iter_mut().enumerate() {
+                                *entry = if r == c {
+                                    shift + 2.0
+                                } else {
+                                    1.0 / f64::from(u32::try_from(r + c + 1).unwrap())
+                                };
+                            }
+                        }
+                        Matrix::<$d>::try_from_rows(rows).unwrap()
+                    });
+                    // A singular member must not affect its neighbours.
+                    mats[2] = Matrix::<$d>::zero();
+
+                    let batch = Matrix::lu_batch(&mats, DEFAULT_SINGULAR_TOL);
+                    for (a, result) in mats.iter().zip(batch) {
+                        assert_eq!(result, a.lu(DEFAULT_SINGULAR_TOL));
+                    }
+                }
+            }
+        };
+    }
+
+    gen_lu_batch_tests!(2);
+    gen_lu_batch_tests!(3);
+    gen_lu_batch_tests!(4);
+    gen_lu_batch_tests!(5);
+
+    #[test]
+    fn lu_batch_accepts_empty_batch() {
+        let batch = Matrix::<3>::lu_batch::<0>(&[], DEFAULT_SINGULAR_TOL);
+        assert!(batch.is_empty());
+    }
+
+    // === det_batch ===
+
+    macro_rules! gen_det_batch_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<det_batch_matches_individual_det_ $d d>]() {
+                    let mut mats: [Matrix<$d>; 4] = core::array::from_fn(|k| {
+                        let scale = f64::from(u32::try_from(k + 1).unwrap());
+                        let mut rows = [[0.0; $d]; $d];
+                        for (r, row) in rows.iter_mut().enumerate() {
+                            for (c, entry) in row.iter_mut().enumerate() {
+                                let offset = f64::from(u32::try_from((r * 3 + c * k) % 5).unwrap());
+                                *entry = if r == c { scale + offset } else { offset - 2.0 };
+                            }
+                        }
+                        Matrix::<$d>::try_from_rows(rows).unwrap()
+                    });
+                    mats[1] = Matrix::<$d>::zero();
+
+                    let batch = Matrix::det_batch(&mats);
+                    for (a, result) in mats.iter().zip(batch) {
+                        assert_eq!(result, a.det());
+                    }
+                }
+            }
+        };
+    }
+
+    gen_det_batch_tests!(2);
+    gen_det_batch_tests!(3);
+    gen_det_batch_tests!(4);
+    gen_det_batch_tests!(5);
+
+    // === det_gradient ===
+
+    macro_rules! gen_det_gradient_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<det_gradient_matches_central_differences_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            let offset = f64::from(u32::try_from((r * 3 + c) % 5).unwrap());
+                            *entry = if r == c { 4.0 + offset } else { offset - 2.0 };
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let grad = a.det_gradient(DEFAULT_SINGULAR_TOL).unwrap();
+
+                    let h = 1e-6;
+                    for r in 0..$d {
+                        for c in 0..$d {
+                            let mut plus = rows;
+                            plus[r][c] += h;
+                            let mut minus = rows;
+                            minus[r][c] -= h;
+                            let fd = (Matrix::<$d>::try_from_rows(plus).unwrap().det().unwrap()
+                                - Matrix::<$d>::try_from_rows(minus).unwrap().det().unwrap())
+                                / (2.0 * h);
+                            assert_relative_eq!(
+                                grad.get(r, c).unwrap(),
+                                fd,
+                                epsilon = 1e-6,
+                                max_relative = 1e-6
+                            );
+                        }
+                    }
+                }
+
+                #[test]
+                fn [<det_gradient_of_identity_is_identity_ $d d>]() {
+                    assert_eq!(
+                        Matrix::<$d>::identity().det_gradient(DEFAULT_SINGULAR_TOL).unwrap(),
+                        Matrix::<$d>::identity()
+                    );
+                }
+            }
+        };
+    }
+
+    gen_det_gradient_tests!(2);
+    gen_det_gradient_tests!(3);
+    gen_det_gradient_tests!(4);
+    gen_det_gradient_tests!(5);
+
+    #[test]
+    fn det_gradient_rejects_singular_and_reports_overflow() {
+        let singular = Matrix::<2>::try_from_rows([[1.0, 2.0], [2.0, 4.0]]).unwrap();
+        assert_matches!(
+            singular.det_gradient(DEFAULT_SINGULAR_TOL),
+            Err(LaError::Singular { .. })
+        );
+        assert_eq!(
+            Matrix::<0>::zero().det_gradient(DEFAULT_SINGULAR_TOL),
+            Ok(Matrix::<0>::zero())
+        );
+
+        // det = 1e300 · 1e300 overflows before the gradient is scaled.
+        let big = Matrix::<2>::try_from_rows([[1e300, 0.0], [0.0, 1e300]]).unwrap();
+        assert_matches!(
+            big.det_gradient(DEFAULT_SINGULAR_TOL),
+            Err(LaError::NonFinite { .. })
+        );
+    }
+
+    macro_rules! gen_cond_1_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<cond_1_of_identity_and_scaled_diagonal_ $d d>]() {
+                    assert_eq!(Matrix::<$d>::identity().cond_1(DEFAULT_SINGULAR_TOL), Ok(1.0));
+
+                    // diag(1, 2, 4, …) has κ₁ = 2^(D−1).
+                    let mut rows = [[0.0; $d]; $d];
+                    let mut scale = 1.0;
+                    for (i, row) in rows.iter_mut().enumerate() {
+                        row[i] = scale;
+                        scale *= 2.0;
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    assert_eq!(a.cond_1(DEFAULT_SINGULAR_TOL), Ok(scale / 2.0));
+                }
+            }
+        };
+    }
+
+    gen_cond_1_tests!(2);
+    gen_cond_1_tests!(3);
+    gen_cond_1_tests!(4);
+    gen_cond_1_tests!(5);
+
+    #[test]
+    fn cond_1_grows_near_singularity_and_reports_failures() {
+        // A⁻¹ = 1e10 · [[1 + 1e-10, −1], [−1, 1]], so κ₁ ≈ 4e10.
+        let near = Matrix::<2>::try_from_rows([[1.0, 1.0], [1.0, 1.0 + 1e-10]]).unwrap();
+        assert_relative_eq!(
+            near.cond_1(Tolerance::ZERO).unwrap(),
+            4e10,
+            max_relative = 1e-5
+        );
+
+        let singular = Matrix::<2>::try_from_rows([[1.0, 2.0], [2.0, 4.0]]).unwrap();
+        assert_matches!(
+            singular.cond_1(DEFAULT_SINGULAR_TOL),
+            Err(LaError::Singular { .. })
+        );
+
+        let wide = Matrix::<2>::try_from_rows([[f64::MAX, 0.0], [0.0, 0.5]]).unwrap();
+        assert_eq!(
+            wide.cond_1(DEFAULT_SINGULAR_TOL),
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::ConditionNumber
+            ))
+        );
+        assert_eq!(Matrix::<0>::zero().cond_1(DEFAULT_SINGULAR_TOL), Ok(0.0));
+    }
+
+    // === orientation ===
+
+    macro_rules! gen_orientation_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<orientation_matches_det_sign_ $d d>]() {
+                    let tol = DEFAULT_SINGULAR_TOL;
+                    assert_eq!(Matrix::<$d>::identity().orientation(tol), Ok(Ordering::Greater));
+
+                    // Swapping two rows flips the orientation.
+                    let mut rows = Matrix::<$d>::identity().rows;
+                    rows.swap(0, 1);
+                    let swapped = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    assert_eq!(swapped.orientation(tol), Ok(Ordering::Less));
+
+                    // A huge diagonal has an overflowing determinant but a clear sign.
+                    let mut rows = [[0.0; $d]; $d];
+                    for (i, row) in rows.iter_mut().enumerate() {
+                        row[i] = if i == 0 { -1e300 } else { 1e300 };
+                    }
+                    let huge = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    assert!(huge.det().is_err());
+                    assert_eq!(huge.orientation(tol), Ok(Ordering::Less));
+
+                    assert_eq!(Matrix::<$d>::zero().orientation(tol), Ok(Ordering::Equal));
+                }
+            }
+        };
+    }
+
+    gen_orientation_tests!(2);
+    gen_orientation_tests!(3);
+    gen_orientation_tests!(4);
+    gen_orientation_tests!(5);
+
+    #[test]
+    fn orientation_treats_near_degenerate_as_equal() {
+        // Three points at distance ~1e-14 from a common line.
+        let nearly_flat =
+            Matrix::<3>::try_from_rows([[0.0, 0.0, 1.0], [1.0, 1.0, 1.0], [2.0, 2.0 + 1e-14, 1.0]])
+                .unwrap();
+        assert_eq!(
+            nearly_flat.orientation(DEFAULT_SINGULAR_TOL),
+            Ok(Ordering::Equal)
+        );
+        assert_eq!(
+            nearly_flat.orientation(Tolerance::ZERO),
+            Ok(Ordering::Greater)
+        );
+        assert_eq!(
+            Matrix::<0>::zero().orientation(Tolerance::ZERO),
+            Ok(Ordering::Greater)
+        );
+    }
+
+    // === simplex_volume ===
+
+    macro_rules! gen_simplex_volume_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<simplex_volume_of_unit_right_simplex_is_reciprocal_factorial_ $d d>]() {
+                    let mut points = [Vector::<$d>::zero(); $d + 1];
+                    for (i, point) in points.iter_mut().skip(1).enumerate() {
+                        *point = Vector::basis(i);
+                    }
+                    let factorial = (1..=$d).map(|k| f64::from(u32::try_from(k).unwrap())).product::<f64>();
+                    assert_relative_eq!(
+                        Matrix::simplex_volume(&points).unwrap(),
+                        1.0 / factorial,
+                        max_relative = 1e-15
+                    );
+
+                    // Swapping two vertices flips the sign; translation does not.
+                    let mut swapped = points;
+                    swapped.swap(1, 2);
+                    assert_relative_eq!(
+                        Matrix::simplex_volume(&swapped).unwrap(),
+                        -1.0 / factorial,
+                        max_relative = 1e-15
+                    );
+                    let translated = points.map(|p| Vector::<$d>::new(p.into_array().map(|x| x + 3.5)));
+                    assert_relative_eq!(
+                        Matrix::simplex_volume(&translated).unwrap(),
+                        1.0 / factorial,
+                        max_relative = 1e-14
+                    );
+
+                    // A repeated vertex is degenerate.
+                    let mut flat = points;
+                    flat[$d] = flat[1];
+                    assert_eq!(Matrix::simplex_volume(&flat), Ok(0.0));
+                }
+            }
+        };
+    }
+
+    gen_simplex_volume_tests!(2);
+    gen_simplex_volume_tests!(3);
+    gen_simplex_volume_tests!(4);
+    gen_simplex_volume_tests!(5);
+
+    #[test]
+    fn simplex_volume_rejects_wrong_count_and_overflowing_edges() {
+        let p = Vector::<2>::new([0.0, 0.0]);
+        assert_eq!(
+            Matrix::simplex_volume(&[p, p]),
+            Err(LaError::dimension_mismatch(3, 2))
+        );
+        assert_eq!(
+            Matrix::simplex_volume(&[p; 4]),
+            Err(LaError::dimension_mismatch(3, 4))
+        );
+
+        let far = Vector::<2>::new([-f64::MAX, 0.0]);
+        let near = Vector::<2>::new([0.0, 1.0]);
+        assert_eq!(
+            Matrix::simplex_volume(&[Vector::new([f64::MAX, 0.0]), far, near]),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::SimplexVolume,
+                0,
+                0
+            ))
+        );
+        assert_eq!(Matrix::<0>::simplex_volume(&[Vector::zero()]), Ok(1.0));
+    }
+
+    // === circumcenter ===
+
+    macro_rules! gen_circumcenter_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<circumcenter_is_equidistant_from_all_vertices_ $d d>]() {
+                    let mut points = [Vector::<$d>::new([1.5; $d]); $d + 1];
+                    for (i, point) in points.iter_mut().skip(1).enumerate() {
+                        let mut coords = [1.5; $d];
+                        coords[i] += f64::from(u32::try_from(i).unwrap()) + 1.0;
+                        coords[(i + 1) % $d] -= 0.5;
+                        *point = Vector::new(coords);
+                    }
+                    let center = Matrix::circumcenter(&points, DEFAULT_SINGULAR_TOL).unwrap();
+
+                    let distance_sq = |p: &Vector<$d>| {
+                        p.as_array()
+                            .iter()
+                            .zip(center.as_array())
+                            .map(|(&x, &c)| (x - c) * (x - c))
+                            .sum::<f64>()
+                    };
+                    let radius_sq = distance_sq(&points[0]);
+                    for point in &points[1..] {
+                        assert_relative_eq!(distance_sq(point), radius_sq, max_relative = 1e-13);
+                    }
+
+                    // Unit right simplex: the center is (½, …, ½).
+                    let mut unit = [Vector::<$d>::zero(); $d + 1];
+                    for (i, vertex) in unit.iter_mut().skip(1).enumerate() {
+                        *vertex = Vector::basis(i);
+                    }
+                    assert_eq!(
+                        Matrix::circumcenter(&unit, DEFAULT_SINGULAR_TOL),
+                        Ok(Vector::new([0.5; $d]))
+                    );
+
+                    let mut flat = unit;
+                    flat[$d] = flat[0];
+                    assert_matches!(
+                        Matrix::circumcenter(&flat, DEFAULT_SINGULAR_TOL),
+                        Err(LaError::Singular { .. })
+                    );
+                }
+            }
+        };
+    }
+
+    gen_circumcenter_tests!(2);
+    gen_circumcenter_tests!(3);
+    gen_circumcenter_tests!(4);
+    gen_circumcenter_tests!(5);
+
+    #[test]
+    fn circumcenter_of_right_triangle_is_hypotenuse_midpoint() {
+        let a = Vector::<2>::new([-1.0, 2.0]);
+        let b = Vector::<2>::new([5.0, 2.0]);
+        let c = Vector::<2>::new([-1.0, 10.0]);
+        let center = Matrix::circumcenter(&[a, b, c], DEFAULT_SINGULAR_TOL).unwrap();
+        assert_eq!(center, b.midpoint(c));
+
+        // The right angle may sit at any vertex.
+        let rotated = Matrix::circumcenter(&[b, c, a], DEFAULT_SINGULAR_TOL).unwrap();
+        assert_eq!(rotated, b.midpoint(c));
+    }
+
+    #[test]
+    fn circumcenter_rejects_wrong_count_and_nearly_collinear_vertices() {
+        let p = Vector::<2>::new([0.0, 0.0]);
+        assert_eq!(
+            Matrix::circumcenter(&[p, p], DEFAULT_SINGULAR_TOL),
+            Err(LaError::dimension_mismatch(3, 2))
+        );
+
+        let nearly_collinear = [
+            Vector::<2>::new([0.0, 0.0]),
+            Vector::<2>::new([1.0, 1.0]),
+            Vector::<2>::new([2.0, 2.0 + 1e-14]),
+        ];
+        assert_matches!(
+            Matrix::circumcenter(&nearly_collinear, DEFAULT_SINGULAR_TOL),
+            Err(LaError::Singular { .. })
+        );
+        assert!(Matrix::circumcenter(&nearly_collinear, Tolerance::ZERO).is_ok());
+    }
+
+    #[test]
+    fn circumradius_of_equilateral_triangle_is_side_over_sqrt_3() {
+        let side = 2.0;
+        let height = 3.0_f64.sqrt();
+        let triangle = [
+            Vector::<2>::new([0.0, 0.0]),
+            Vector::<2>::new([side, 0.0]),
+            Vector::<2>::new([1.0, height]),
+        ];
+        let radius = Matrix::circumradius(&triangle, DEFAULT_SINGULAR_TOL).unwrap();
+        assert_relative_eq!(radius, side / 3.0_f64.sqrt(), max_relative = 1e-15);
+
+        // For an equilateral triangle the circumcenter is the centroid.
+        let center = Matrix::circumcenter(&triangle, DEFAULT_SINGULAR_TOL).unwrap();
+        for (c, g) in center
+            .into_array()
+            .into_iter()
+            .zip(Vector::centroid(&triangle).unwrap().into_array())
+        {
+            assert_abs_diff_eq!(c, g, epsilon = 1e-15);
+        }
+    }
+
+    macro_rules! gen_circumradius_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<circumradius_of_unit_right_simplex_is_half_sqrt_d_ $d d>]() {
+                    let mut unit = [Vector::<$d>::zero(); $d + 1];
+                    for (i, vertex) in unit.iter_mut().skip(1).enumerate() {
+                        *vertex = Vector::basis(i);
+                    }
+                    let expected = 0.5 * f64::from($d).sqrt();
+                    assert_relative_eq!(
+                        Matrix::circumradius(&unit, DEFAULT_SINGULAR_TOL).unwrap(),
+                        expected,
+                        max_relative = 1e-15
+                    );
+
+                    // Far from the origin, the offset keeps the radius accurate.
+                    let shifted = unit.map(|p| Vector::<$d>::new(p.into_array().map(|x| x + 1e8)));
+                    assert_relative_eq!(
+                        Matrix::circumradius(&shifted, DEFAULT_SINGULAR_TOL).unwrap(),
+                        expected,
+                        max_relative = 1e-15
+                    );
+
+                    let mut flat = unit;
+                    flat[$d] = flat[0];
+                    assert_matches!(
+                        Matrix::circumradius(&flat, DEFAULT_SINGULAR_TOL),
+                        Err(LaError::Singular { .. })
+                    );
+                }
+            }
+        };
+    }
+
+    gen_circumradius_tests!(2);
+    gen_circumradius_tests!(3);
+    gen_circumradius_tests!(4);
+    gen_circumradius_tests!(5);
+
+    // === barycentric ===
+
+    macro_rules! gen_barycentric_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<barycentric_coordinates_of_centroid_are_equal_ $d d>]() {
+                    let mut simplex = [Vector::<$d>::new([-1.0; $d]); $d + 1];
+                    for (i, vertex) in simplex.iter_mut().skip(1).enumerate() {
+                        let mut coords = [-1.0; $d];
+                        coords[i] += f64::from(u32::try_from(i).unwrap()) + 2.0;
+                        *vertex = Vector::new(coords);
+                    }
+                    let tol = DEFAULT_SINGULAR_TOL;
+                    let centroid = Vector::centroid(&simplex).unwrap();
+                    let coords = Matrix::barycentric(&simplex, &centroid, tol).unwrap();
+                    let share = 1.0 / (f64::from($d) + 1.0);
+                    for &c in &coords {
+                        assert_abs_diff_eq!(c, share, epsilon = 1e-15);
+                    }
+
+                    for (k, vertex) in simplex.iter().enumerate() {
+                        let coords = Matrix::barycentric(&simplex, vertex, tol).unwrap();
+                        for (i, &c) in coords.iter().enumerate() {
+                            let expected = if i == k { 1.0 } else { 0.0 };
+                            assert_abs_diff_eq!(c, expected, epsilon = 1e-15);
+                        }
+                    }
+
+                    // Reflecting a vertex through the centroid leaves the simplex.
+                    let far = Vector::<$d>::new(core::array::from_fn(|i| {
+                        2.0f64.mul_add(centroid.as_array()[i], -simplex[1].as_array()[i])
+                    }));
+                    let coords = Matrix::barycentric(&simplex, &far, tol).unwrap();
+                    assert!(coords[1] < 0.0);
+                    assert_abs_diff_eq!(coords.iter().sum::<f64>(), 1.0, epsilon = 1e-14);
+
+                    let mut flat = simplex;
+                    flat[$d] = flat[0];
+                    assert_matches!(
+                        Matrix::barycentric(&flat, &centroid, tol),
+                        Err(LaError::Singular { .. })
+                    );
+                }
+            }
+        };
+    }
+
+    gen_barycentric_tests!(2);
+    gen_barycentric_tests!(3);
+    gen_barycentric_tests!(4);
+    gen_barycentric_tests!(5);
+
+    #[test]
+    fn barycentric_of_triangle_centroid_is_one_third_each() {
+        let triangle = [
+            Vector::<2>::new([1.0, 1.0]),
+            Vector::<2>::new([7.0, 2.0]),
+            Vector::<2>::new([4.0, 9.0]),
+        ];
+        let centroid = Vector::<2>::new([4.0, 4.0]);
+        let coords = Matrix::barycentric(&triangle, &centroid, DEFAULT_SINGULAR_TOL).unwrap();
+        for c in coords {
+            assert_abs_diff_eq!(c, 1.0 / 3.0, epsilon = 1e-15);
+        }
+        assert_eq!(
+            Matrix::barycentric(&[triangle[0], triangle[1]], &centroid, DEFAULT_SINGULAR_TOL),
+            Err(LaError::dimension_mismatch(3, 2))
+        );
+    }
+
+    #[test]
+    fn barycentric_reports_overflowing_first_coordinate() {
+        let triangle = [
+            Vector::<2>::new([0.0, 0.0]),
+            Vector::<2>::new([1.0, 0.0]),
+            Vector::<2>::new([0.0, 1.0]),
+        ];
+        let far = Vector::<2>::new([f64::MAX, f64::MAX]);
+        assert_eq!(
+            Matrix::barycentric(&triangle, &far, DEFAULT_SINGULAR_TOL),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::BarycentricCoordinates,
+                0
+            ))
+        );
+    }
+
+    // === affine_from_correspondences ===
+
+    macro_rules! gen_affine_from_correspondences_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<affine_from_correspondences_recovers_linear_map_and_shift_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            *entry = if r == c { 3.0 } else { f64::from(u32::try_from((r + 2 * c) % 3).unwrap()) - 1.0 };
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let t = Vector::<$d>::new(core::array::from_fn(|i| 0.5 - f64::from(u32::try_from(i).unwrap())));
+
+                    let mut src = [Vector::<$d>::new([1.0; $d]); $d + 1];
+                    for (i, point) in src.iter_mut().skip(1).enumerate() {
+                        let mut coords = [1.0; $d];
+                        coords[i] = -2.0;
+                        coords[(i + 1) % $d] += 0.5;
+                        *point = Vector::new(coords);
+                    }
+                    let dst = src.map(|p| {
+                        let image = a.mul_vec(&p).unwrap().into_array();
+                        Vector::<$d>::new(core::array::from_fn(|i| image[i] + t.as_array()[i]))
+                    });
+
+                    let tol = DEFAULT_SINGULAR_TOL;
+                    let (linear, shift) = Matrix::affine_from_correspondences(&src, &dst, tol).unwrap();
+                    for (got, want) in linear.rows.iter().zip(&a.rows) {
+                        for (&g, &w) in got.iter().zip(want) {
+                            assert_abs_diff_eq!(g, w, epsilon = 1e-13);
+                        }
+                    }
+                    for (&g, &w) in shift.as_array().iter().zip(t.as_array()) {
+                        assert_abs_diff_eq!(g, w, epsilon = 1e-13);
+                    }
+
+                    let (identity, zero) = Matrix::affine_from_correspondences(&src, &src, tol).unwrap();
+                    for (r, row) in identity.rows.iter().enumerate() {
+                        for (c, &entry) in row.iter().enumerate() {
+                            assert_abs_diff_eq!(entry, if r == c { 1.0 } else { 0.0 }, epsilon = 1e-15);
+                        }
+                        assert_abs_diff_eq!(zero.as_array()[r], 0.0, epsilon = 1e-15);
+                    }
+
+                    let mut flat = src;
+                    flat[$d] = flat[0];
+                    assert_matches!(
+                        Matrix::affine_from_correspondences(&flat, &dst, tol),
+                        Err(LaError::Singular { .. })
+                    );
+                }
+            }
+        };
+    }
+
+    gen_affine_from_correspondences_tests!(2);
+    gen_affine_from_correspondences_tests!(3);
+    gen_affine_from_correspondences_tests!(4);
+    gen_affine_from_correspondences_tests!(5);
+
+    #[test]
+    fn affine_from_correspondences_recovers_rotation_and_translation() {
+        let (sin, cos) = 0.7_f64.sin_cos();
+        let rotation = Matrix::<2>::try_from_rows([[cos, -sin], [sin, cos]]).unwrap();
+        let shift = [4.0, -2.5];
+        let src = [
+            Vector::<2>::new([0.0, 0.0]),
+            Vector::<2>::new([2.0, 1.0]),
+            Vector::<2>::new([-1.0, 3.0]),
+        ];
+        let dst = src.map(|p| {
+            let [x, y] = rotation.mul_vec(&p).unwrap().into_array();
+            Vector::<2>::new([x + shift[0], y + shift[1]])
+        });
+
+        let (a, t) = Matrix::affine_from_correspondences(&src, &dst, DEFAULT_SINGULAR_TOL).unwrap();
+        for (got, want) in a.rows.iter().zip(&rotation.rows) {
+            for (&g, &w) in got.iter().zip(want) {
+                assert_abs_diff_eq!(g, w, epsilon = 1e-15);
+            }
+        }
+        for (&g, &w) in t.as_array().iter().zip(&shift) {
+            assert_abs_diff_eq!(g, w, epsilon = 1e-15);
+        }
+        assert_abs_diff_eq!(a.det().unwrap(), 1.0, epsilon = 1e-15);
+
+        // Swapping the point sets gives the inverse map.
+        let (inverse, _) =
+            Matrix::affine_from_correspondences(&dst, &src, DEFAULT_SINGULAR_TOL).unwrap();
+        assert_abs_diff_eq!(inverse.rows[0][1], sin, epsilon = 1e-14);
+        assert_eq!(
+            Matrix::affine_from_correspondences(
+                &[src[0], src[1]],
+                &[dst[0], dst[1]],
+                DEFAULT_SINGULAR_TOL
+            ),
+            Err(LaError::dimension_mismatch(3, 2))
+        );
+    }
+
+    // === in_sphere ===
+
+    macro_rules! gen_in_sphere_tests {
+        ($p:literal, $d:literal) => {
+            paste! {
+                #[test]
+                fn [<in_sphere_classifies_points_around_unit_right_simplex_ $p d>]() {
+                    // Vertices 0 and e_i: circumcenter (½, …, ½), radius² P / 4.
+                    let mut simplex = [Vector::<$p>::zero(); $d];
+                    for (i, vertex) in simplex.iter_mut().skip(1).enumerate() {
+                        *vertex = Vector::basis(i);
+                    }
+                    let center = Vector::<$p>::new([0.5; $p]);
+                    let mut far = [0.5; $p];
+                    far[0] = 0.5 + f64::from($p);
+                    let far = Vector::<$p>::new(far);
+                    let all_ones = Vector::<$p>::new([1.0; $p]);
+
+                    assert_eq!(Matrix::in_sphere(&simplex, &center), Ok(Ordering::Greater));
+                    assert_eq!(Matrix::in_sphere(&simplex, &far), Ok(Ordering::Less));
+                    // (1, …, 1) is the antipode of the origin.
+                    assert_eq!(Matrix::in_sphere(&simplex, &all_ones), Ok(Ordering::Equal));
+                    for vertex in &simplex {
+                        assert_eq!(Matrix::in_sphere(&simplex, vertex), Ok(Ordering::Equal));
+                    }
+
+                    // Reordering the vertices flips the orientation but not the answer.
+                    let mut reversed = simplex;
+                    reversed.swap(0, 1);
+                    assert_eq!(Matrix::in_sphere(&reversed, &center), Ok(Ordering::Greater));
+                    assert_eq!(Matrix::in_sphere(&reversed, &far), Ok(Ordering::Less));
+
+                    let mut flat = simplex;
+                    flat[$p] = flat[0];
+                    assert_matches!(
+                        Matrix::in_sphere(&flat, &center),
+                        Err(LaError::Singular { .. })
+                    );
+                }
+            }
+        };
+    }
+
+    gen_in_sphere_tests!(1, 2);
+    gen_in_sphere_tests!(2, 3);
+    gen_in_sphere_tests!(3, 4);
+    gen_in_sphere_tests!(4, 5);
+
+    #[test]
+    fn in_sphere_matches_circumcircle_of_scalene_triangle() {
+        // Circumcenter (2, 1) and radius² 5 for (0, 0), (4, 0), (0, 2).
+        let triangle = [
+            Vector::<2>::new([0.0, 0.0]),
+            Vector::<2>::new([4.0, 0.0]),
+            Vector::<2>::new([0.0, 2.0]),
+        ];
+        let cases = [
+            ([2.0, 1.0], Ordering::Greater),
+            ([4.0, 2.0], Ordering::Equal),
+            ([4.1, 2.0], Ordering::Less),
+            ([-0.2, 1.0], Ordering::Greater),
+            ([-0.3, 1.0], Ordering::Less),
+            ([0.0, 1.0], Ordering::Greater),
+        ];
+        for (query, expected) in cases {
+            assert_eq!(
+                Matrix::in_sphere(&triangle, &Vector::new(query)),
+                Ok(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn in_sphere_rejects_wrong_dimension_and_overflow() {
+        let p = Vector::<2>::new([0.0, 0.0]);
+        assert_eq!(
+            Matrix::<2>::in_sphere(&[p, p], &p),
+            Err(LaError::dimension_mismatch(2, 3))
+        );
+
+        let triangle = [
+            Vector::<2>::new([0.0, 0.0]),
+            Vector::<2>::new([1.0, 0.0]),
+            Vector::<2>::new([0.0, 1.0]),
+        ];
+        let far = Vector::<2>::new([1e200, 0.0]);
+        assert_eq!(
+            Matrix::in_sphere(&triangle, &far),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::InSphere,
+                0,
+                2
+            ))
+        );
+    }
+
+    // === pow / is_nilpotent ===
+
+    macro_rules! gen_pow_nilpotent_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<pow_matches_repeated_products_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            *entry = f64::from(u32::try_from((r + 2 * c) % 3).unwrap()) - 1.0;
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+
+                    assert_eq!(a.pow(0).unwrap(), Matrix::<$d>::identity());
+                    let mut expected = Matrix::<$d>::identity();
+                    for k in 1..=6 {
+                        expected = expected.matmul(&a, ArithmeticOperation::MatrixPower).unwrap();
+                        assert_eq!(a.pow(k).unwrap(), expected);
+                    }
+                }
+
+                #[test]
+                fn [<is_nilpotent_accepts_strictly_upper_and_rejects_identity_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for entry in row.iter_mut().skip(r + 1) {
+                            *entry = 1.0;
+                        }
+                    }
+                    let strictly_upper = Matrix::<$d>::try_from_rows(rows).unwrap();
+
+                    assert!(strictly_upper.is_nilpotent(Tolerance::ZERO).unwrap());
+                    assert!(!Matrix::<$d>::identity().is_nilpotent(DEFAULT_SINGULAR_TOL).unwrap());
+                    // One step short of the index is still nonzero.
+                    assert_ne!(strictly_upper.pow($d - 1).unwrap(), Matrix::<$d>::zero());
+                }
+            }
+        };
+    }
+
+    gen_pow_nilpotent_tests!(2);
+    gen_pow_nilpotent_tests!(3);
+    gen_pow_nilpotent_tests!(4);
+    gen_pow_nilpotent_tests!(5);
+
+    macro_rules! gen_idempotent_involutory_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<is_idempotent_and_is_involutory_classify_projectors_and_reflections_ $d d>]() {
+                    let tol = Tolerance::try_new(1e-12).unwrap();
+                    let identity = Matrix::<$d>::identity();
+                    assert!(identity.is_idempotent(Tolerance::ZERO).unwrap());
+                    assert!(identity.is_involutory(Tolerance::ZERO).unwrap());
+                    assert!(Matrix::<$d>::zero().is_idempotent(Tolerance::ZERO).unwrap());
+                    assert!(!Matrix::<$d>::zero().is_involutory(tol).unwrap());
+
+                    // A single nonzero column spans a line, a proper subspace.
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        row[0] = f64::from(u32::try_from(r).unwrap()) + 1.0;
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let p = a.projector(tol).unwrap();
+                    assert!(p.is_idempotent(tol).unwrap());
+                    assert!(!p.is_involutory(tol).unwrap());
+
+                    let h = a.householder_subspace_reflection(tol).unwrap();
+                    assert!(h.is_involutory(tol).unwrap());
+                    assert!(!h.is_idempotent(tol).unwrap());
+                }
+            }
+        };
+    }
+
+    gen_idempotent_involutory_tests!(2);
+    gen_idempotent_involutory_tests!(3);
+    gen_idempotent_involutory_tests!(4);
+    gen_idempotent_involutory_tests!(5);
+
+    #[test]
+    fn is_idempotent_treats_overflowing_difference_as_failure() {
+        let big = Matrix::<2>::try_from_rows([[0.0, 1e300], [0.0, 0.0]]).unwrap();
+        assert_eq!(
+            big.matmul(&big, ArithmeticOperation::MatrixPower),
+            Ok(Matrix::<2>::zero())
+        );
+        assert!(!big.is_idempotent(DEFAULT_SINGULAR_TOL).unwrap());
+        let cancel = Matrix::<2>::try_from_rows([[f64::MAX, 0.0], [0.0, 0.0]]).unwrap();
+        assert_eq!(
+            Matrix::<2>::zero().inf_distance(&cancel).to_bits(),
+            f64::MAX.to_bits()
+        );
+        let opposite = Matrix::<2>::try_from_rows([[-f64::MAX, 0.0], [0.0, 0.0]]).unwrap();
+        assert!(cancel.inf_distance(&opposite).is_infinite());
+        assert!(cancel.is_idempotent(DEFAULT_SINGULAR_TOL).is_err());
+    }
+
+    macro_rules! gen_is_normal_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<is_normal_accepts_symmetric_skew_and_orthogonal_rejects_triangular_ $d d>]() {
+                    let tol = Tolerance::try_new(1e-12).unwrap();
+                    let general = Matrix::<$d>::try_from_rows(core::array::from_fn(|r| {
+                        core::array::from_fn(|c| {
+                            f64::from(u32::try_from((r + 3 * c) % 5).unwrap()) / 4.0 - 0.5
+                        })
+                    }))
+                    .unwrap();
+                    let symmetric = general.symmetric_part();
+                    assert!(symmetric.is_normal(Tolerance::ZERO).unwrap());
+
+                    let skew = Matrix::<$d>::try_from_rows(core::array::from_fn(|r| {
+                        core::array::from_fn(|c| symmetric.rows[r][c] * if r < c { 1.0 } else if r > c { -1.0 } else { 0.0 })
+                    }))
+                    .unwrap();
+                    assert!(skew.is_normal(tol).unwrap());
+
+                    let mut cycle = [[0.0; $d]; $d];
+                    for (r, row) in cycle.iter_mut().enumerate() {
+                        row[(r + 1) % $d] = 1.0;
+                    }
+                    assert!(Matrix::<$d>::try_from_rows(cycle).unwrap().is_normal(Tolerance::ZERO).unwrap());
+
+                    let mut shear = Matrix::<$d>::identity().into_rows();
+                    shear[0][$d - 1] = 1.0;
+                    let shear = Matrix::<$d>::try_from_rows(shear).unwrap();
+                    assert!(!shear.is_normal(tol).unwrap());
+                    // ‖AAᵀ − AᵀA‖_∞ = 2 for a single off-diagonal unit entry.
+                    assert!(shear.is_normal(Tolerance::try_new(2.0).unwrap()).unwrap());
+                }
+            }
+        };
+    }
+
+    gen_is_normal_tests!(2);
+    gen_is_normal_tests!(3);
+    gen_is_normal_tests!(4);
+    gen_is_normal_tests!(5);
+
+    #[test]
+    fn is_normal_reports_gram_product_overflow() {
+        let big = Matrix::<2>::try_from_rows([[1e200, 0.0], [0.0, 1.0]]).unwrap();
+        assert_eq!(
+            big.is_normal(DEFAULT_SINGULAR_TOL),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::MatrixProduct,
+                0,
+                0
+            ))
+        );
+        assert!(Matrix::<0>::zero().is_normal(Tolerance::ZERO).unwrap());
+    }
+
+    #[test]
+    fn is_nilpotent_uses_tolerance_and_reports_overflow() {
+        // [[ε, 1], [0, 0]]² = [[ε², ε], [0, 0]], so ‖A²‖∞ = ε² + ε.
+        let eps = 1e-10;
+        let near = Matrix::<2>::try_from_rows([[eps, 1.0], [0.0, 0.0]]).unwrap();
+        assert!(
+            !near
+                .is_nilpotent(Tolerance::try_new(1e-11).unwrap())
+                .unwrap()
+        );
+        assert!(
+            near.is_nilpotent(Tolerance::try_new(1e-9).unwrap())
+                .unwrap()
+        );
+        assert!(Matrix::<0>::zero().is_nilpotent(Tolerance::ZERO).unwrap());
+
+        let big = Matrix::<2>::try_from_rows([[1e200, 0.0], [0.0, 1.0]]).unwrap();
+        assert_eq!(
+            big.is_nilpotent(DEFAULT_SINGULAR_TOL),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::MatrixPower,
+                0,
+                0
+            ))
+        );
+        assert_eq!(
+            big.pow(2),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::MatrixPower,
+                0,
+                0
+            ))
+        );
+    }
+
+    // === exp ===
+
+    macro_rules! gen_exp_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<exp_of_zero_is_identity_ $d d>]() {
+                    assert_eq!(Matrix::<$d>::zero().exp(16).unwrap(), Matrix::<$d>::identity());
+                }
+
+                #[test]
+                fn [<exp_of_diagonal_matches_scalar_exp_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    let mut d = [0.0; $d];
+                    for (i, (row, value)) in rows.iter_mut().zip(&mut d).enumerate() {
+                        *value = 1.5_f64.mul_add(f64::from(u32::try_from(i).unwrap()), -2.0);
+                        row[i] = *value;
+                    }
+                    let e = Matrix::<$d>::try_from_rows(rows).unwrap().exp(16).unwrap();
+
+                    for (r, row) in e.as_rows().iter().enumerate() {
+                        for (c, &entry) in row.iter().enumerate() {
+                            if r == c {
+                                assert_relative_eq!(entry, d[r].exp(), max_relative = 1e-13);
+                            } else {
+                                assert_abs_diff_eq!(entry, 0.0, epsilon = 0.0);
+                            }
+                        }
+                    }
+                }
+
+                #[test]
+                fn [<exp_of_negation_is_inverse_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            *entry = f64::from(u32::try_from((r + 2 * c) % 3).unwrap()) - 1.0;
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    for row in &mut rows {
+                        for entry in row.iter_mut() {
+                            *entry = -*entry;
+                        }
+                    }
+                    let neg = Matrix::<$d>::try_from_rows(rows).unwrap();
+
+                    let product = a
+                        .exp(16)
+                        .unwrap()
+                        .matmul(&neg.exp(16).unwrap(), ArithmeticOperation::MatrixExponential)
+                        .unwrap();
+                    let identity = Matrix::<$d>::identity();
+                    for (got, want) in product.as_rows().iter().zip(identity.as_rows()) {
+                        for (&g, &w) in got.iter().zip(want) {
+                            assert_abs_diff_eq!(g, w, epsilon = 1e-12);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    gen_exp_tests!(2);
+    gen_exp_tests!(3);
+    gen_exp_tests!(4);
+    gen_exp_tests!(5);
+
+    #[test]
+    fn exp_handles_nilpotent_truncation_and_overflow() {
+        // exp([[0, t], [0, 0]]) = [[1, t], [0, 1]] exactly, even after squaring.
+        let shear = Matrix::<2>::try_from_rows([[0.0, 3.0], [0.0, 0.0]]).unwrap();
+        assert_eq!(
+            shear.exp(16).unwrap(),
+            Matrix::<2>::try_from_rows([[1.0, 3.0], [0.0, 1.0]]).unwrap()
+        );
+        assert_eq!(shear.exp(0).unwrap(), Matrix::<2>::identity());
+        assert_eq!(
+            Matrix::<0>::zero().exp(16).unwrap(),
+            Matrix::<0>::identity()
+        );
+
+        let big = Matrix::<2>::try_from_rows([[1000.0, 0.0], [0.0, 1.0]]).unwrap();
+        assert_eq!(
+            big.exp(16),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::MatrixExponential,
+                0,
+                0
+            ))
+        );
+    }
+
+    // === log_via_series ===
+
+    macro_rules! gen_log_via_series_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<log_via_series_inverts_exp_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            *entry = 0.3 * (f64::from(u32::try_from((2 * r + 3 * c) % 5).unwrap()) - 2.0);
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let log = a.exp(20).unwrap().log_via_series(24, DEFAULT_SINGULAR_TOL).unwrap();
+                    for (l_row, a_row) in log.rows.iter().zip(&a.rows) {
+                        for (&l, &x) in l_row.iter().zip(a_row) {
+                            assert_abs_diff_eq!(l, x, epsilon = 1e-12);
+                        }
+                    }
+
+                    let identity = Matrix::<$d>::identity();
+                    assert_eq!(
+                        identity.log_via_series(24, DEFAULT_SINGULAR_TOL),
+                        Ok(Matrix::<$d>::zero())
+                    );
+                }
+
+                #[test]
+                fn [<log_via_series_of_diagonal_matches_scalar_ln_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    for (i, row) in rows.iter_mut().enumerate() {
+                        row[i] = 0.25 * f64::from(u32::try_from(i * i).unwrap() + 1);
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let log = a.log_via_series(24, DEFAULT_SINGULAR_TOL).unwrap();
+                    for (r, row) in log.rows.iter().enumerate() {
+                        for (c, &entry) in row.iter().enumerate() {
+                            let expected = if r == c { a.rows[r][r].ln() } else { 0.0 };
+                            assert_abs_diff_eq!(entry, expected, epsilon = 1e-13);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    gen_log_via_series_tests!(2);
+    gen_log_via_series_tests!(3);
+    gen_log_via_series_tests!(4);
+    gen_log_via_series_tests!(5);
+
+    #[test]
+    fn log_via_series_converges_with_zero_pivot_tolerance() {
+        // The pivot tolerance no longer doubles as the stopping threshold.
+        let a = Matrix::<3>::try_from_rows([[4.0, 1.0, 0.0], [0.0, 2.0, 0.5], [0.0, 0.0, 9.0]])
+            .unwrap();
+        let log = a.log_via_series(24, Tolerance::ZERO).unwrap();
+        let expected = a.log_via_series(24, DEFAULT_SINGULAR_TOL).unwrap();
+        assert!(log.inf_distance(&expected) <= 1e-13);
+        assert!(log.exp(20).unwrap().inf_distance(&a) <= 1e-12);
+    }
+
+    #[test]
+    fn log_via_series_rejects_singular_and_negative_spectra() {
+        let tol = DEFAULT_SINGULAR_TOL;
+        let singular = Matrix::<2>::try_from_rows([[1.0, 2.0], [2.0, 4.0]]).unwrap();
+        assert_matches!(
+            singular.log_via_series(24, tol),
+            Err(LaError::Singular { .. })
+        );
+        // −I has no real logarithm: its first square-root step is exactly zero.
+        let negative = Matrix::<2>::try_from_rows([[-1.0, 0.0], [0.0, -1.0]]).unwrap();
+        assert_matches!(
+            negative.log_via_series(24, tol),
+            Err(LaError::Singular { .. })
+        );
+        // For a lone negative eigenvalue the Newton-like iteration wanders.
+        let mixed = Matrix::<2>::try_from_rows([[-2.0, 0.0], [0.0, 1.0]]).unwrap();
+        assert_matches!(
+            mixed.log_via_series(24, tol),
+            Err(LaError::NotConverged { .. })
+        );
+        // A rotation by nearly π still has a real principal logarithm.
+        let (sin, cos) = 3.0_f64.sin_cos();
+        let rotation = Matrix::<2>::try_from_rows([[cos, -sin], [sin, cos]]).unwrap();
+        let generator = rotation.log_via_series(24, tol).unwrap();
+        assert_abs_diff_eq!(generator.rows[0][0], 0.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(generator.rows[1][0], 3.0, epsilon = 1e-12);
+        assert_eq!(
+            Matrix::<2>::try_from_rows([[1.1, 0.0], [0.0, 0.9]])
+                .unwrap()
+                .log_via_series(0, tol),
+            Ok(Matrix::<2>::zero())
+        );
+        assert_eq!(
+            Matrix::<0>::zero().log_via_series(24, tol),
+            Ok(Matrix::<0>::zero())
+        );
+    }
 
-    use super::*;
-    use crate::{DEFAULT_SINGULAR_TOL, FactorizationKind, Vector};
+    // === isotropic_part / deviatoric ===
 
-    macro_rules! gen_matrix_tests {
+    macro_rules! gen_isotropic_deviatoric_tests {
         ($d:literal) => {
             paste! {
                 #[test]
-                fn [<matrix_try_from_rows_get_set_bounds_checked_ $d d>]() {
+                fn [<isotropic_plus_deviatoric_recovers_original_ $d d>]() {
                     let mut rows = [[0.0f64; $d]; $d];
-                    rows[0][0] = 1.0;
-                    rows[$d - 1][$d - 1] = -2.0;
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            #[expect(
+                                clippy::cast_precision_loss,
+                                reason = "matrix test indices are at most five and exactly representable as f64"
+                            )]
+                            {
+                                *entry = (r * $d + c) as f64 - 3.5;
+                            }
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
 
-                    let mut m = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let iso = a.isotropic_part();
+                    let dev = a.deviatoric().unwrap();
+                    let mut dev_trace = 0.0;
+                    for r in 0..$d {
+                        dev_trace += dev.rows[r][r];
+                        for c in 0..$d {
+                            assert_abs_diff_eq!(
+                                iso.rows[r][c] + dev.rows[r][c],
+                                rows[r][c],
+                                epsilon = 1e-12
+                            );
+                            if r != c {
+                                assert_abs_diff_eq!(iso.rows[r][c], 0.0, epsilon = 0.0);
+                            }
+                        }
+                    }
+                    assert_abs_diff_eq!(dev_trace, 0.0, epsilon = 1e-12);
+                }
+            }
+        };
+    }
 
-                    assert_eq!(m.get(0, 0), Some(1.0));
-                    assert_eq!(m.get($d - 1, $d - 1), Some(-2.0));
-                    assert_eq!(m.try_get(0, 0), Ok(1.0));
-                    assert_eq!(m.try_get($d - 1, $d - 1), Ok(-2.0));
+    gen_isotropic_deviatoric_tests!(2);
+    gen_isotropic_deviatoric_tests!(3);
+    gen_isotropic_deviatoric_tests!(4);
+    gen_isotropic_deviatoric_tests!(5);
 
-                    // Out-of-bounds is None.
-                    assert_eq!(m.get($d, 0), None);
-                    assert_eq!(
-                        m.try_get($d, 0),
-                        Err(LaError::IndexOutOfBounds {
-                            row: $d,
-                            col: 0,
-                            dim: $d,
-                        })
-                    );
+    #[test]
+    fn isotropic_part_does_not_overflow_on_large_trace() {
+        let a = Matrix::<2>::try_from_rows([[f64::MAX, 0.0], [0.0, f64::MAX]]).unwrap();
+        assert_eq!(a.isotropic_part(), a);
+        assert_eq!(a.deviatoric(), Ok(Matrix::<2>::zero()));
+        assert_eq!(Matrix::<0>::zero().isotropic_part(), Matrix::<0>::zero());
+    }
 
-                    // Out-of-bounds set fails.
-                    let before_failed_set = m;
-                    assert_eq!(
-                        m.set($d, 0, 3.0),
-                        Err(LaError::IndexOutOfBounds {
-                            row: $d,
-                            col: 0,
-                            dim: $d,
-                        })
-                    );
-                    assert_eq!(m, before_failed_set);
-                    assert_eq!(
-                        m.set(0, $d, 3.0),
-                        Err(LaError::IndexOutOfBounds {
-                            row: 0,
-                            col: $d,
-                            dim: $d,
-                        })
-                    );
-                    assert_eq!(m, before_failed_set);
-                    assert_eq!(m.get(0, 0), Some(1.0));
+    #[test]
+    fn deviatoric_reports_overflowing_diagonal() {
+        let a = Matrix::<2>::try_from_rows([[-f64::MAX, 0.0], [0.0, f64::MAX]]).unwrap();
+        assert_eq!(a.isotropic_part(), Matrix::<2>::zero());
+
+        let b = Matrix::<3>::try_from_rows([
+            [f64::MAX, 0.0, 0.0],
+            [0.0, -f64::MAX, 0.0],
+            [0.0, 0.0, -f64::MAX],
+        ])
+        .unwrap();
+        assert_eq!(
+            b.deviatoric(),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::IsotropicSplit,
+                0,
+                0
+            ))
+        );
+    }
 
-                    // In-bounds set works.
-                    assert_eq!(m.set(0, $d - 1, 3.0), Ok(()));
-                    assert_eq!(m.get(0, $d - 1), Some(3.0));
-                    assert_eq!(m.set($d - 1, 0, 4.0), Ok(()));
-                    assert_eq!(m.try_get($d - 1, 0), Ok(4.0));
-                }
+    // === symmetric_part / skew_symmetric_part ===
 
+    macro_rules! gen_symmetric_skew_tests {
+        ($d:literal) => {
+            paste! {
                 #[test]
-                fn [<matrix_set_rejects_non_finite_and_preserves_storage_ $d d>]() {
-                    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
-                        let mut m = Matrix::<$d>::identity();
-                        let before = m;
-                        assert_eq!(
-                            m.set($d - 1, 0, value),
-                            Err(LaError::non_finite_input_matrix($d - 1, 0))
-                        );
-                        assert_eq!(m, before);
+                fn [<symmetric_plus_skew_recovers_original_ $d d>]() {
+                    let mut rows = [[0.0f64; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            *entry = f64::from(u32::try_from(r * $d + 3 * c).unwrap()) - 3.5;
+                        }
                     }
-                }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
 
-                #[test]
-                fn [<matrix_try_from_rows_rejects_non_finite_ $d d>]() {
-                    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
-                        let mut rows = [[0.0f64; $d]; $d];
-                        rows[$d - 1][$d - 1] = value;
-                        assert_eq!(
-                            Matrix::<$d>::try_from_rows(rows),
-                            Err(LaError::non_finite_input_matrix($d - 1, $d - 1))
-                        );
+                    let sym = a.symmetric_part();
+                    let skew = a.skew_symmetric_part();
+                    assert_eq!(sym.transpose(), sym);
+                    for r in 0..$d {
+                        assert_eq!(skew.rows[r][r].to_bits(), 0.0_f64.to_bits());
+                        for c in 0..$d {
+                            if r != c {
+                                assert_eq!(
+                                    skew.rows[r][c].to_bits(),
+                                    (-skew.rows[c][r]).to_bits()
+                                );
+                            }
+                            // Halves of these small integers are exact, so the
+                            // split reproduces every entry exactly.
+                            assert_eq!(
+                                (sym.rows[r][c] + skew.rows[r][c]).to_bits(),
+                                rows[r][c].to_bits()
+                            );
+                        }
                     }
+                }
+            }
+        };
+    }
+
+    gen_symmetric_skew_tests!(2);
+    gen_symmetric_skew_tests!(3);
+    gen_symmetric_skew_tests!(4);
+    gen_symmetric_skew_tests!(5);
+
+    #[test]
+    fn symmetric_and_skew_parts_do_not_overflow() {
+        let a = Matrix::<2>::try_from_rows([[0.0, f64::MAX], [-f64::MAX, 0.0]]).unwrap();
+        assert_eq!(a.symmetric_part(), Matrix::<2>::zero());
+        assert_eq!(a.skew_symmetric_part(), a);
+
+        let b = Matrix::<2>::try_from_rows([[0.0, f64::MAX], [f64::MAX, 0.0]]).unwrap();
+        assert_eq!(b.symmetric_part(), b);
+        assert_eq!(b.skew_symmetric_part(), Matrix::<2>::zero());
+    }
 
+    // === gershgorin_bounds ===
+
+    macro_rules! gen_gershgorin_bounds_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<gershgorin_bounds_of_diagonal_are_extreme_entries_ $d d>]() {
                     let mut rows = [[0.0f64; $d]; $d];
-                    rows[0][$d - 1] = f64::INFINITY;
-                    rows[$d - 1][0] = f64::NAN;
-                    assert_eq!(
-                        Matrix::<$d>::try_from_rows(rows),
-                        Err(LaError::non_finite_input_matrix(0, $d - 1))
-                    );
+                    for (i, row) in rows.iter_mut().enumerate() {
+                        #[expect(
+                            clippy::cast_precision_loss,
+                            reason = "matrix test indices are at most five and exactly representable as f64"
+                        )]
+                        {
+                            row[i] = i as f64 - 1.0;
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let (lower, upper) = a.gershgorin_bounds().unwrap();
+                    assert_abs_diff_eq!(lower, -1.0, epsilon = 0.0);
+                    assert_abs_diff_eq!(upper, rows[$d - 1][$d - 1], epsilon = 0.0);
                 }
+            }
+        };
+    }
 
-                #[test]
-                fn [<matrix_zero_and_default_are_zero_ $d d>]() {
-                    let z = Matrix::<$d>::zero();
-                    assert_abs_diff_eq!(z.inf_norm().unwrap(), 0.0, epsilon = 0.0);
+    gen_gershgorin_bounds_tests!(2);
+    gen_gershgorin_bounds_tests!(3);
+    gen_gershgorin_bounds_tests!(4);
+    gen_gershgorin_bounds_tests!(5);
 
-                    let d = Matrix::<$d>::default();
-                    assert_abs_diff_eq!(d.inf_norm().unwrap(), 0.0, epsilon = 0.0);
-                }
+    #[test]
+    fn gershgorin_bounds_enclose_spectrum_of_diagonally_dominant_3d() {
+        // Symmetric tridiagonal with eigenvalues 4 - √2, 4, 4 + √2.
+        let a = Matrix::<3>::try_from_rows([[4.0, 1.0, 0.0], [1.0, 4.0, 1.0], [0.0, 1.0, 4.0]])
+            .unwrap();
+        let (lower, upper) = a.gershgorin_bounds().unwrap();
+
+        assert_abs_diff_eq!(lower, 2.0, epsilon = 0.0);
+        assert_abs_diff_eq!(upper, 6.0, epsilon = 0.0);
+        assert!(lower <= 4.0 - 2.0_f64.sqrt());
+        assert!(upper >= 4.0 + 2.0_f64.sqrt());
+        // A positive lower bound certifies this symmetric matrix is SPD.
+        assert!(a.ldlt(DEFAULT_SINGULAR_TOL).is_ok());
+    }
+
+    #[test]
+    fn gershgorin_bounds_handle_empty_and_overflowing_matrices() {
+        assert_eq!(Matrix::<0>::zero().gershgorin_bounds(), Ok((0.0, 0.0)));
+
+        let a = Matrix::<2>::try_from_rows([[1.0, 0.0], [f64::MAX, f64::MAX]]).unwrap();
+        assert_eq!(
+            a.gershgorin_bounds(),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::GershgorinBounds,
+                1
+            ))
+        );
+    }
 
+    // === log_det_spd ===
+
+    macro_rules! gen_log_det_spd_tests {
+        ($d:literal) => {
+            paste! {
                 #[test]
-                fn [<matrix_inf_norm_max_row_sum_ $d d>]() {
+                fn [<log_det_spd_matches_ln_det_for_diagonal_ $d d>]() {
                     let mut rows = [[0.0f64; $d]; $d];
-
-                    // Row 0 has a smaller absolute row sum.
-                    for c in 0..$d {
-                        rows[0][c] = 0.5;
+                    let mut expected = 0.0;
+                    for (i, row) in rows.iter_mut().enumerate() {
+                        #[expect(
+                            clippy::cast_precision_loss,
+                            reason = "matrix test indices are at most five and exactly representable as f64"
+                        )]
+                        let pivot = (i + 2) as f64;
+                        row[i] = pivot;
+                        expected += pivot.ln();
                     }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
 
-                    // The last row has absolute row sum = D.
-                    for c in 0..$d {
-                        rows[$d - 1][c] = -1.0;
-                    }
+                    let log_det = a.log_det_spd(DEFAULT_SINGULAR_TOL).unwrap();
+                    assert_abs_diff_eq!(log_det, expected, epsilon = 1e-12);
+                    assert_abs_diff_eq!(log_det, a.det().unwrap().ln(), epsilon = 1e-12);
+                }
+            }
+        };
+    }
+
+    gen_log_det_spd_tests!(2);
+    gen_log_det_spd_tests!(3);
+    gen_log_det_spd_tests!(4);
+    gen_log_det_spd_tests!(5);
+
+    #[test]
+    fn log_det_spd_matches_ln_det_for_coupled_spd_3d() {
+        let a = Matrix::<3>::try_from_rows([[4.0, 1.0, 0.5], [1.0, 3.0, 0.25], [0.5, 0.25, 2.0]])
+            .unwrap();
+        assert_abs_diff_eq!(
+            a.log_det_spd(DEFAULT_SINGULAR_TOL).unwrap(),
+            a.det().unwrap().ln(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn log_det_spd_is_finite_when_det_overflows() {
+        let a = Matrix::<2>::try_from_rows([[1e200, 0.0], [0.0, 1e200]]).unwrap();
+        assert_matches!(a.det(), Err(LaError::NonFinite { .. }));
+        assert_abs_diff_eq!(
+            a.log_det_spd(DEFAULT_SINGULAR_TOL).unwrap(),
+            2.0 * 1e200_f64.ln(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn log_det_spd_propagates_factorization_errors() {
+        let indefinite = Matrix::<2>::try_from_rows([[1.0, 2.0], [2.0, 1.0]]).unwrap();
+        assert_matches!(
+            indefinite.log_det_spd(DEFAULT_SINGULAR_TOL),
+            Err(LaError::NotPositiveSemidefinite { .. })
+        );
+
+        let asymmetric = Matrix::<2>::try_from_rows([[2.0, 1.0], [0.0, 2.0]]).unwrap();
+        assert_matches!(
+            asymmetric.log_det_spd(DEFAULT_SINGULAR_TOL),
+            Err(LaError::Asymmetric { .. })
+        );
+    }
 
+    // === inv_sqrt_spd ===
+
+    macro_rules! gen_inv_sqrt_spd_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<inv_sqrt_spd_whitens_gram_matrix_ $d d>]() {
+                    // A = MᵀM + I is symmetric positive definite.
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            *entry = f64::from(u32::try_from((2 * r + c) % 5).unwrap()) - 2.0;
+                        }
+                    }
                     let m = Matrix::<$d>::try_from_rows(rows).unwrap();
-                    assert_abs_diff_eq!(m.inf_norm().unwrap(), f64::from($d), epsilon = 0.0);
+                    let a = m
+                        .transpose()
+                        .matmul(&m, ArithmeticOperation::MatrixProduct)
+                        .unwrap()
+                        .add_scaled_identity(1.0)
+                        .unwrap();
+
+                    let w = a.inv_sqrt_spd(DEFAULT_SINGULAR_TOL, 30).unwrap();
+                    assert_eq!(w, w.transpose());
+                    let waw = w
+                        .matmul(&a, ArithmeticOperation::MatrixProduct)
+                        .unwrap()
+                        .matmul(&w, ArithmeticOperation::MatrixProduct)
+                        .unwrap();
+                    for (r, row) in waw.rows.iter().enumerate() {
+                        for (c, &entry) in row.iter().enumerate() {
+                            let expected = if r == c { 1.0 } else { 0.0 };
+                            assert_abs_diff_eq!(entry, expected, epsilon = 1e-12);
+                        }
+                    }
+                    assert!(w.ldlt(DEFAULT_SINGULAR_TOL).is_ok());
                 }
+            }
+        };
+    }
+
+    gen_inv_sqrt_spd_tests!(2);
+    gen_inv_sqrt_spd_tests!(3);
+    gen_inv_sqrt_spd_tests!(4);
+    gen_inv_sqrt_spd_tests!(5);
 
+    #[test]
+    fn inv_sqrt_spd_rejects_non_spd_inputs() {
+        let tol = DEFAULT_SINGULAR_TOL;
+        let asymmetric = Matrix::<2>::try_from_rows([[2.0, 1.0], [0.0, 2.0]]).unwrap();
+        assert_matches!(
+            asymmetric.inv_sqrt_spd(tol, 30),
+            Err(LaError::Asymmetric { .. })
+        );
+        let indefinite = Matrix::<2>::try_from_rows([[1.0, 2.0], [2.0, 1.0]]).unwrap();
+        assert_matches!(
+            indefinite.inv_sqrt_spd(tol, 30),
+            Err(LaError::NotPositiveSemidefinite { .. })
+        );
+        assert_matches!(
+            Matrix::<2>::zero().inv_sqrt_spd(tol, 30),
+            Err(LaError::Singular { .. })
+        );
+    }
+
+    // === apply_spectral ===
+
+    macro_rules! gen_apply_spectral_tests {
+        ($d:literal) => {
+            paste! {
                 #[test]
-                fn [<matrix_inf_norm_reports_first_overflowing_column_ $d d>]() {
-                    let mut rows = [[0.0f64; $d]; $d];
-                    rows[$d - 1][0] = f64::MAX;
-                    rows[$d - 1][1] = f64::MAX;
+                fn [<apply_spectral_exp_matches_taylor_exp_ $d d>]() {
+                    // Symmetric and indefinite: a shifted discrete Laplacian
+                    // with a rank-one coupling.
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            *entry = match r.abs_diff(c) {
+                                0 => f64::from(u32::try_from(r).unwrap()) - 1.0,
+                                1 => -0.5,
+                                _ => 0.25,
+                            };
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let tol = Tolerance::try_new(1e-14).unwrap();
+
+                    let spectral = a.apply_spectral(f64::exp, tol, 30).unwrap();
+                    let taylor = a.exp(20).unwrap();
+                    assert_eq!(spectral, spectral.transpose());
+                    for (s_row, t_row) in spectral.rows.iter().zip(&taylor.rows) {
+                        for (&s, &t) in s_row.iter().zip(t_row) {
+                            assert_relative_eq!(s, t, epsilon = 1e-13, max_relative = 1e-12);
+                        }
+                    }
 
-                    let m = Matrix::<$d>::try_from_rows(rows).unwrap();
-                    assert_eq!(
-                        m.inf_norm(),
-                        Err(LaError::non_finite_computation_matrix(
-                            ArithmeticOperation::MatrixInfinityNorm,
-                            $d - 1,
-                            1,
-                        ))
-                    );
+                    let identity = a.apply_spectral(|l| l, tol, 30).unwrap();
+                    for (i_row, a_row) in identity.rows.iter().zip(&a.rows) {
+                        for (&x, &y) in i_row.iter().zip(a_row) {
+                            assert_abs_diff_eq!(x, y, epsilon = 1e-13);
+                        }
+                    }
                 }
 
                 #[test]
-                fn [<matrix_inf_norm_reports_first_overflowing_row_ $d d>]() {
-                    let mut rows = [[0.0f64; $d]; $d];
-                    rows[0][0] = f64::MAX;
-                    rows[0][$d - 1] = f64::MAX;
-                    rows[$d - 1][0] = f64::MAX;
-                    rows[$d - 1][1] = f64::MAX;
+                fn [<apply_spectral_generalizes_inv_sqrt_spd_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            *entry = if r == c { 4.0 } else { 1.0 / f64::from(u32::try_from(r + c).unwrap()) };
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let tol = Tolerance::try_new(1e-14).unwrap();
 
-                    let m = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let w = a.apply_spectral(|l| 1.0 / l.sqrt(), tol, 30).unwrap();
+                    let expected = a.inv_sqrt_spd(tol, 30).unwrap();
+                    for (w_row, e_row) in w.rows.iter().zip(&expected.rows) {
+                        for (&x, &y) in w_row.iter().zip(e_row) {
+                            assert_abs_diff_eq!(x, y, epsilon = 1e-14);
+                        }
+                    }
                     assert_eq!(
-                        m.inf_norm(),
-                        Err(LaError::non_finite_computation_matrix(
-                            ArithmeticOperation::MatrixInfinityNorm,
-                            0,
-                            $d - 1,
-                        ))
+                        Matrix::<$d>::zero().apply_spectral(f64::exp, tol, 30),
+                        Ok(Matrix::<$d>::identity())
                     );
                 }
+            }
+        };
+    }
+
+    gen_apply_spectral_tests!(2);
+    gen_apply_spectral_tests!(3);
+    gen_apply_spectral_tests!(4);
+    gen_apply_spectral_tests!(5);
+
+    #[test]
+    fn apply_spectral_rejects_asymmetric_input_and_non_finite_values() {
+        let tol = Tolerance::try_new(1e-14).unwrap();
+        let asymmetric = Matrix::<2>::try_from_rows([[2.0, 1.0], [0.0, 2.0]]).unwrap();
+        assert_matches!(
+            asymmetric.apply_spectral(f64::exp, tol, 30),
+            Err(LaError::Asymmetric { .. })
+        );
+
+        // Eigenvalues 3 and −1, sorted by the shifted singular values.
+        let indefinite = Matrix::<2>::try_from_rows([[1.0, 2.0], [2.0, 1.0]]).unwrap();
+        assert_eq!(
+            indefinite.apply_spectral(f64::ln, tol, 30),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::SpectralFunction,
+                1
+            ))
+        );
+        assert_matches!(
+            Matrix::<2>::identity().apply_spectral(|l| l * 1e308 * 10.0, tol, 30),
+            Err(LaError::NonFinite { .. })
+        );
+    }
+
+    // === sym_eigen_sorted ===
 
+    macro_rules! gen_sym_eigen_sorted_tests {
+        ($d:literal) => {
+            paste! {
                 #[test]
-                fn [<matrix_identity_lu_det_solve_ $d d>]() {
-                    let m = Matrix::<$d>::identity();
+                fn [<sym_eigen_sorted_returns_ascending_eigenpairs_ $d d>]() {
+                    // Symmetric and indefinite, as in the apply_spectral tests.
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            *entry = match r.abs_diff(c) {
+                                0 => 1.0 - f64::from(u32::try_from(r).unwrap()),
+                                1 => -0.5,
+                                _ => 0.25,
+                            };
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let tol = Tolerance::try_new(1e-14).unwrap();
+                    let (lambda, v) = a.sym_eigen_sorted(tol, 30).unwrap();
+                    let lambda = lambda.into_array();
 
-                    // Identity has ones on diag and zeros off diag.
-                    for r in 0..$d {
-                        for c in 0..$d {
-                            let expected = if r == c { 1.0 } else { 0.0 };
-                            assert_abs_diff_eq!(m.get(r, c).unwrap(), expected, epsilon = 0.0);
+                    for pair in lambda.windows(2) {
+                        assert!(pair[0] <= pair[1]);
+                    }
+                    assert_abs_diff_eq!(lambda.iter().sum::<f64>(), a.trace().unwrap(), epsilon = 1e-12);
+
+                    let vt = v.transpose();
+                    for (k, column) in vt.rows.iter().enumerate() {
+                        let x = Vector::<$d>::new(*column);
+                        assert_abs_diff_eq!(x.norm2_sq().unwrap(), 1.0, epsilon = 1e-13);
+                        let ax = a.mul_vec(&x).unwrap().into_array();
+                        for (&ax_i, &x_i) in ax.iter().zip(column) {
+                            assert_abs_diff_eq!(ax_i, lambda[k] * x_i, epsilon = 1e-12);
+                        }
+                    }
+                    let gram = vt.matmul(&v, ArithmeticOperation::MatrixProduct).unwrap();
+                    for (r, row) in gram.rows.iter().enumerate() {
+                        for (c, &g) in row.iter().enumerate() {
+                            assert_abs_diff_eq!(g, if r == c { 1.0 } else { 0.0 }, epsilon = 1e-13);
                         }
                     }
+                }
+            }
+        };
+    }
 
-                    // Determinant is 1.
-                    let det = m.det().unwrap();
-                    assert_abs_diff_eq!(det, 1.0, epsilon = 1e-12);
+    gen_sym_eigen_sorted_tests!(2);
+    gen_sym_eigen_sorted_tests!(3);
+    gen_sym_eigen_sorted_tests!(4);
+    gen_sym_eigen_sorted_tests!(5);
 
-                    // LU solve on identity returns the RHS.
-                    let lu = m.lu(DEFAULT_SINGULAR_TOL).unwrap();
+    #[test]
+    fn sym_eigen_sorted_orders_diagonal_entries_and_rejects_asymmetric_input() {
+        let tol = Tolerance::try_new(1e-14).unwrap();
+        let diagonal = Vector::<4>::new([3.0, -2.0, 0.5, -2.0]).to_diagonal();
+        let (lambda, v) = diagonal.sym_eigen_sorted(tol, 30).unwrap();
+        assert_eq!(lambda, Vector::new([-2.0, -2.0, 0.5, 3.0]));
+        // Each eigenvector column is a signed basis vector at its diagonal slot.
+        for (k, slot) in [1, 3, 2, 0].into_iter().enumerate() {
+            assert_abs_diff_eq!(v.rows[slot][k].abs(), 1.0, epsilon = 0.0);
+        }
 
-                    let b_arr = {
-                        let mut arr = [0.0f64; $d];
-                        let values = [1.0f64, 2.0, 3.0, 4.0, 5.0];
-                        for (dst, src) in arr.iter_mut().zip(values.iter()) {
-                            *dst = *src;
-                        }
-                        arr
-                    };
+        let asymmetric = Matrix::<2>::try_from_rows([[2.0, 1.0], [0.0, 2.0]]).unwrap();
+        assert_matches!(
+            asymmetric.sym_eigen_sorted(tol, 30),
+            Err(LaError::Asymmetric { .. })
+        );
+        assert_eq!(
+            Matrix::<0>::zero().sym_eigen_sorted(tol, 30),
+            Ok((Vector::zero(), Matrix::zero()))
+        );
+    }
 
-                    let b = Vector::<$d>::new(b_arr);
-                    let x = lu.solve(b).unwrap().into_array();
+    // === pca ===
 
-                    for (x_i, b_i) in x.iter().zip(b_arr.iter()) {
-                        assert_abs_diff_eq!(*x_i, *b_i, epsilon = 1e-12);
+    macro_rules! gen_pca_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<pca_finds_dominant_direction_ $d d>]() {
+                    // C = 9 u uᵀ + 0.1 I has one dominant direction u / ‖u‖.
+                    let u = Vector::<$d>::new(core::array::from_fn(|i| {
+                        f64::from(u32::try_from(i).unwrap()) - 0.5
+                    }));
+                    let norm_sq = u.norm2_sq().unwrap();
+                    let covariance = Matrix::<$d>::from_outer_sum(&[u], &[9.0])
+                        .unwrap()
+                        .add_scaled_identity(0.1)
+                        .unwrap();
+                    let tol = Tolerance::try_new(1e-14).unwrap();
+                    let (variances, components) = Matrix::pca(&covariance, tol, 30).unwrap();
+                    let variances = variances.into_array();
+
+                    assert_relative_eq!(variances[0], 9.0f64.mul_add(norm_sq, 0.1), max_relative = 1e-13);
+                    for pair in variances.windows(2) {
+                        assert!(pair[0] >= pair[1]);
+                    }
+                    for &rest in &variances[1..] {
+                        assert_abs_diff_eq!(rest, 0.1, epsilon = 1e-12);
                     }
-                }
 
+                    let leading = Vector::<$d>::new(components.transpose().rows[0]);
+                    let alignment = leading.dot(&u).unwrap().abs() / norm_sq.sqrt();
+                    assert_abs_diff_eq!(alignment, 1.0, epsilon = 1e-13);
+
+                    let (ascending, v) = covariance.sym_eigen_sorted(tol, 30).unwrap();
+                    let mut reversed = ascending.into_array();
+                    reversed.reverse();
+                    assert_eq!(variances.map(f64::to_bits), reversed.map(f64::to_bits));
+                    assert_eq!(components.rows[0][0].to_bits(), v.rows[0][$d - 1].to_bits());
+                }
             }
         };
     }
 
-    // Mirror delaunay-style multi-dimension tests.
-    gen_matrix_tests!(2);
-    gen_matrix_tests!(3);
-    gen_matrix_tests!(4);
-    gen_matrix_tests!(5);
+    gen_pca_tests!(2);
+    gen_pca_tests!(3);
+    gen_pca_tests!(4);
+    gen_pca_tests!(5);
 
     #[test]
-    fn matrix_inf_norm_preserves_left_to_right_row_sum_order() {
-        let large = 9_007_199_254_740_992.0;
-        let matrix =
-            Matrix::<4>::try_from_rows([[large, 1.0, 1.0, 1.0], [0.0; 4], [0.0; 4], [0.0; 4]])
-                .unwrap();
+    fn pca_rejects_asymmetric_covariance() {
+        let tol = Tolerance::try_new(1e-14).unwrap();
+        let asymmetric = Matrix::<2>::try_from_rows([[2.0, 1.0], [0.0, 2.0]]).unwrap();
+        assert_matches!(
+            Matrix::pca(&asymmetric, tol, 30),
+            Err(LaError::Asymmetric { .. })
+        );
+    }
 
-        assert_eq!(matrix.inf_norm(), Ok(large));
+    // === weighted_solve ===
+
+    macro_rules! gen_weighted_solve_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<weighted_solve_with_uniform_weights_matches_lu_solve_ $d d>]() {
+                    let mut rows = [[1.0f64; $d]; $d];
+                    let mut b = [0.0f64; $d];
+                    for i in 0..$d {
+                        rows[i][i] = 4.0;
+                        #[expect(
+                            clippy::cast_precision_loss,
+                            reason = "matrix test indices are at most five and exactly representable as f64"
+                        )]
+                        {
+                            b[i] = i as f64 + 1.0;
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let b = Vector::<$d>::new(b);
+                    let expected = a.lu(DEFAULT_SINGULAR_TOL).unwrap().solve(b).unwrap();
+
+                    for weight in [1.0, 0.25, 3.0] {
+                        let x = a
+                            .weighted_solve(Vector::<$d>::new([weight; $d]), b, DEFAULT_SINGULAR_TOL)
+                            .unwrap();
+                        for (&actual, &wanted) in x.as_array().iter().zip(expected.as_array()) {
+                            assert_abs_diff_eq!(actual, wanted, epsilon = 1e-12);
+                        }
+                    }
+                }
+            }
+        };
     }
 
-    // === det_direct tests ===
+    gen_weighted_solve_tests!(2);
+    gen_weighted_solve_tests!(3);
+    gen_weighted_solve_tests!(4);
+    gen_weighted_solve_tests!(5);
 
     #[test]
-    fn det_direct_d0_is_one() {
-        assert_eq!(Matrix::<0>::zero().det_direct(), Ok(Some(1.0)));
+    fn weighted_solve_reports_degenerate_weights_and_overflow() {
+        let a = Matrix::<2>::try_from_rows([[1.0, 0.0], [0.0, 1.0]]).unwrap();
+        let b = Vector::<2>::new([1.0, 1.0]);
+
+        assert_matches!(
+            a.weighted_solve(Vector::<2>::new([1.0, 0.0]), b, DEFAULT_SINGULAR_TOL),
+            Err(LaError::Singular { pivot_col: 1, .. })
+        );
+        assert_matches!(
+            a.weighted_solve(Vector::<2>::new([1.0, -1.0]), b, DEFAULT_SINGULAR_TOL),
+            Err(LaError::NotPositiveSemidefinite { pivot_col: 1, .. })
+        );
+
+        let large = Matrix::<2>::try_from_rows([[1e200, 0.0], [0.0, 1.0]]).unwrap();
+        assert_eq!(
+            large.weighted_solve(Vector::<2>::new([1.0, 1.0]), b, DEFAULT_SINGULAR_TOL),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::WeightedNormalEquations,
+                0,
+                0
+            ))
+        );
     }
 
+    // === solve_equilibrated ===
+
     #[test]
-    fn det_direct_d1_returns_element() {
-        let m = Matrix::<1>::try_from_rows([[42.0]]).unwrap();
-        assert_eq!(m.det_direct(), Ok(Some(42.0)));
+    fn power_of_two_reciprocal_normalizes_magnitudes() {
+        for (max_abs, expected) in [
+            (0.0, 1.0),
+            (1.0, 1.0),
+            (1.5, 1.0),
+            (2.0, 0.5),
+            (0.75, 2.0),
+            (1e8, 2.0_f64.powi(-26)),
+            (f64::MAX, 2.0_f64.powi(-1022)),
+            (f64::MIN_POSITIVE, 2.0_f64.powi(1022)),
+            (5e-324, 2.0_f64.powi(1023)),
+        ] {
+            assert_eq!(
+                power_of_two_reciprocal(max_abs).to_bits(),
+                expected.to_bits()
+            );
+        }
     }
 
     #[test]
-    fn det_direct_d2_known_value() {
-        // [[1,2],[3,4]] → det = 1*4 - 2*3 = -2
-        // black_box prevents compile-time constant folding of the const fn.
-        let m = black_box(Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]]).unwrap());
-        assert_abs_diff_eq!(m.det_direct().unwrap().unwrap(), -2.0, epsilon = 1e-15);
+    fn solve_equilibrated_recovers_accuracy_lost_to_row_scaling() {
+        // [[1e-8, 1], [1, 1]] is well conditioned; scaling row 0 by 1e8 makes
+        // partial pivoting pick the wrong pivot.
+        let a = Matrix::<2>::try_from_rows([[1.0, 1e8], [1.0, 1.0]]).unwrap();
+        let expected: [f64; 2] = [1.0 / 3.0, 2.0 / 3.0];
+        let b = Vector::<2>::new([
+            expected[1].mul_add(1e8, expected[0]),
+            expected[0] + expected[1],
+        ]);
+
+        let naive = a
+            .lu(DEFAULT_SINGULAR_TOL)
+            .unwrap()
+            .solve(b)
+            .unwrap()
+            .into_array();
+        assert!((naive[0] - expected[0]).abs() > 1e-10);
+
+        let x = a
+            .solve_equilibrated(b, DEFAULT_SINGULAR_TOL)
+            .unwrap()
+            .into_array();
+        assert_abs_diff_eq!(x[0], expected[0], epsilon = 1e-15);
+        assert_abs_diff_eq!(x[1], expected[1], epsilon = 1e-15);
     }
 
-    #[test]
-    fn det_direct_d3_known_value() {
-        // Classic 3×3: det = 0
-        let m = black_box(
-            Matrix::<3>::try_from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]])
-                .unwrap(),
-        );
-        assert_abs_diff_eq!(m.det_direct().unwrap().unwrap(), 0.0, epsilon = 1e-12);
+    macro_rules! gen_solve_equilibrated_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<solve_equilibrated_matches_diagonal_solution_ $d d>]() {
+                    let mut rows = [[0.0f64; $d]; $d];
+                    let mut b = [0.0f64; $d];
+                    let mut scale = 1e-6;
+                    for i in 0..$d {
+                        rows[i][i] = scale;
+                        b[i] = 3.0 * scale;
+                        scale *= 1e4;
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+
+                    let x = a
+                        .solve_equilibrated(Vector::<$d>::new(b), DEFAULT_SINGULAR_TOL)
+                        .unwrap();
+                    for &value in x.as_array() {
+                        assert_abs_diff_eq!(value, 3.0, epsilon = 1e-12);
+                    }
+                }
+            }
+        };
     }
 
+    gen_solve_equilibrated_tests!(2);
+    gen_solve_equilibrated_tests!(3);
+    gen_solve_equilibrated_tests!(4);
+    gen_solve_equilibrated_tests!(5);
+
     #[test]
-    fn det_direct_d3_dense_known_value() {
-        // det = 1*(5*8 - 7*6) - 2*(4*8 - 7*2) + 3*(4*6 - 5*2) = 4
-        let m = black_box(
-            Matrix::<3>::try_from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 7.0], [2.0, 6.0, 8.0]])
-                .unwrap(),
+    fn solve_equilibrated_reports_singular_and_overflowing_inputs() {
+        let singular = Matrix::<2>::try_from_rows([[1.0, 2.0], [0.0, 0.0]]).unwrap();
+        assert_matches!(
+            singular.solve_equilibrated(Vector::<2>::new([1.0, 0.0]), DEFAULT_SINGULAR_TOL),
+            Err(LaError::Singular { .. })
         );
-        let direct = m.det_direct().unwrap().unwrap();
-        let paired = m.det_direct_with_errbound().unwrap().unwrap();
 
-        assert_abs_diff_eq!(direct, 4.0, epsilon = 1e-12);
-        assert_eq!(paired.determinant().to_bits(), direct.to_bits());
+        let tiny = Matrix::<2>::try_from_rows([[1e-300, 0.0], [0.0, 1.0]]).unwrap();
+        assert_eq!(
+            tiny.solve_equilibrated(Vector::<2>::new([f64::MAX, 1.0]), DEFAULT_SINGULAR_TOL),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::Equilibration,
+                0
+            ))
+        );
     }
 
-    #[test]
-    fn det_direct_d3_dense_reports_legitimate_overflow() {
-        // The unscaled matrix has determinant 54, so scaling every entry by
-        // 1.6e102 gives a determinant of approximately 2.21e308.
-        let scale = 1.6e102;
-        let m = black_box(
-            Matrix::<3>::try_from_rows([
-                [4.0 * scale, scale, scale],
-                [scale, 4.0 * scale, scale],
-                [scale, scale, 4.0 * scale],
-            ])
-            .unwrap(),
-        );
-        let expected = LaError::non_finite_computation_scalar(ArithmeticOperation::Determinant);
+    // === householder ===
 
-        assert_eq!(m.det_direct(), Err(expected));
-        assert_eq!(m.det(), Err(expected));
+    macro_rules! gen_householder_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<householder_is_symmetric_orthogonal_and_negates_v_ $d d>]() {
+                    let mut v = [0.0f64; $d];
+                    for (i, value) in v.iter_mut().enumerate() {
+                        #[expect(
+                            clippy::cast_precision_loss,
+                            reason = "matrix test indices are at most five and exactly representable as f64"
+                        )]
+                        {
+                            *value = i as f64 - 1.5;
+                        }
+                    }
+                    let h = Matrix::<$d>::householder(Vector::<$d>::new(v)).unwrap();
+
+                    assert_orthogonal(&h, 1e-14);
+                    assert_eq!(h, h.transpose());
+                    for (row, expected) in h.rows.iter().zip(v) {
+                        let image: f64 = row.iter().zip(v).map(|(h_rc, v_c)| h_rc * v_c).sum();
+                        assert_abs_diff_eq!(image, -expected, epsilon = 1e-14);
+                    }
+                    assert_abs_diff_eq!(h.det().unwrap(), -1.0, epsilon = 1e-12);
+                }
+            }
+        };
     }
 
+    gen_householder_tests!(2);
+    gen_householder_tests!(3);
+    gen_householder_tests!(4);
+    gen_householder_tests!(5);
+
     #[test]
-    fn det_errbound_d3_dense_reports_legitimate_overflow() {
-        // The unscaled matrix has determinant 54, so scaling every entry by
-        // 1.6e102 gives a determinant of approximately 2.21e308.
-        let scale = 1.6e102;
-        let m = black_box(
-            Matrix::<3>::try_from_rows([
-                [4.0 * scale, scale, scale],
-                [scale, 4.0 * scale, scale],
-                [scale, scale, 4.0 * scale],
-            ])
-            .unwrap(),
+    fn householder_fixes_orthogonal_vectors_and_is_scale_invariant() {
+        let h = Matrix::<3>::householder(Vector::<3>::new([0.0, 0.0, 2.0])).unwrap();
+        assert_eq!(
+            h,
+            Matrix::<3>::try_from_rows([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]])
+                .unwrap()
         );
-        let expected =
-            LaError::non_finite_computation_scalar(ArithmeticOperation::DeterminantErrorBound);
 
-        assert_eq!(m.det_errbound(), Err(expected));
-        assert_eq!(m.det_direct_with_errbound(), Err(expected));
+        let tiny = Matrix::<2>::householder(Vector::<2>::new([3e-200, 4e-200])).unwrap();
+        let huge = Matrix::<2>::householder(Vector::<2>::new([3e200, 4e200])).unwrap();
+        let unit = Matrix::<2>::householder(Vector::<2>::new([0.6, 0.8])).unwrap();
+        for r in 0..2 {
+            for c in 0..2 {
+                assert_abs_diff_eq!(tiny.rows[r][c], unit.rows[r][c], epsilon = 1e-15);
+                assert_abs_diff_eq!(huge.rows[r][c], unit.rows[r][c], epsilon = 1e-15);
+            }
+        }
     }
 
     #[test]
-    fn det_direct_d3_nonsingular() {
-        // [[2,1,0],[0,3,1],[1,0,2]] → det = 2*(6-0) - 1*(0-1) + 0 = 13
-        let m = black_box(
-            Matrix::<3>::try_from_rows([[2.0, 1.0, 0.0], [0.0, 3.0, 1.0], [1.0, 0.0, 2.0]])
-                .unwrap(),
+    fn householder_rejects_zero_vector() {
+        assert_eq!(
+            Matrix::<3>::householder(Vector::zero()),
+            Err(LaError::zero_vector(3))
         );
-        assert_abs_diff_eq!(m.det_direct().unwrap().unwrap(), 13.0, epsilon = 1e-12);
     }
 
-    #[test]
-    fn det_direct_d3_skips_zero_coefficient_minor_that_would_overflow() {
-        let m = black_box(
-            Matrix::<3>::try_from_rows([
-                [1.0, 0.0, 0.0],
-                [1.0e300, 1.0, 1.0e300],
-                [1.0e300, 0.0, 1.0e300],
-            ])
-            .unwrap(),
-        );
-        assert_eq!(m.det_direct(), Ok(Some(1.0e300)));
+    // === givens ===
+
+    macro_rules! gen_givens_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<givens_is_orthogonal_with_unit_det_ $d d>]() {
+                    for (i, j) in [(0, $d - 1), ($d - 1, 0), (0, 1)] {
+                        let g = Matrix::<$d>::givens(i, j, 0.7).unwrap();
+                        assert_orthogonal(&g, 1e-15);
+                        assert_abs_diff_eq!(g.det().unwrap(), 1.0, epsilon = 1e-14);
+                    }
+                }
+
+                #[test]
+                fn [<givens_validates_plane_indices_ $d d>]() {
+                    assert_eq!(
+                        Matrix::<$d>::givens($d, 0, 0.5),
+                        Err(LaError::index_out_of_bounds($d, 0, $d))
+                    );
+                    assert_eq!(
+                        Matrix::<$d>::givens(0, $d, 0.5),
+                        Err(LaError::index_out_of_bounds(0, $d, $d))
+                    );
+                    assert_eq!(
+                        Matrix::<$d>::givens(1, 1, 0.5),
+                        Err(LaError::repeated_index(1))
+                    );
+                }
+            }
+        };
     }
 
+    gen_givens_tests!(2);
+    gen_givens_tests!(3);
+    gen_givens_tests!(4);
+    gen_givens_tests!(5);
+
     #[test]
-    fn det_direct_d4_known_value() {
-        // Diagonal matrix: det = product of diagonal entries.
-        let mut rows = [[0.0f64; 4]; 4];
-        rows[0][0] = 2.0;
-        rows[1][1] = 3.0;
-        rows[2][2] = 5.0;
-        rows[3][3] = 7.0;
-        let m = black_box(Matrix::<4>::try_from_rows(rows).unwrap());
-        assert_abs_diff_eq!(m.det_direct().unwrap().unwrap(), 210.0, epsilon = 1e-12);
+    fn givens_zeroes_selected_component() {
+        // Rotating (3, 4) in the (0, 1) plane by -atan2(4, 3) gives (5, 0).
+        let theta = -4.0_f64.atan2(3.0);
+        let g = Matrix::<2>::givens(0, 1, theta).unwrap();
+        let x = [3.0, 4.0];
+        let image: [f64; 2] =
+            core::array::from_fn(|r| g.rows[r][0].mul_add(x[0], g.rows[r][1] * x[1]));
+        assert_abs_diff_eq!(image[0], 5.0, epsilon = 1e-14);
+        assert_abs_diff_eq!(image[1], 0.0, epsilon = 1e-14);
     }
 
     #[test]
-    fn det_direct_d4_dense_known_value() {
-        let m = black_box(
-            Matrix::<4>::try_from_rows([
-                [4.0, 1.0, 3.0, 2.0],
-                [1.0, 5.0, 2.0, 1.0],
-                [7.0, 2.0, 6.0, 3.0],
-                [1.0, 8.0, 4.0, 9.0],
-            ])
-            .unwrap(),
-        );
-        let direct = m.det_direct().unwrap().unwrap();
-        let paired = m.det_direct_with_errbound().unwrap().unwrap();
+    fn givens_rejects_non_finite_angle() {
+        for theta in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(
+                Matrix::<3>::givens(0, 1, theta),
+                Err(LaError::non_finite_input_scalar())
+            );
+        }
+    }
 
-        assert_abs_diff_eq!(direct, 112.0, epsilon = 1e-12);
-        assert_eq!(paired.determinant().to_bits(), direct.to_bits());
+    // === permanent ===
+
+    macro_rules! gen_permanent_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<permanent_counts_matchings_of_complete_bipartite_graph_ $d d>]() {
+                    let ones = Matrix::<$d>::try_from_rows([[1.0; $d]; $d]).unwrap();
+                    let factorial: f64 = (1..=$d).map(f64::from).product();
+                    assert_eq!(ones.permanent().unwrap().map(f64::to_bits), Some(factorial.to_bits()));
+                    assert_eq!(Matrix::<$d>::identity().permanent(), Ok(Some(1.0)));
+                    assert_eq!(Matrix::<$d>::zero().permanent(), Ok(Some(0.0)));
+                }
+            }
+        };
     }
 
+    gen_permanent_tests!(2);
+    gen_permanent_tests!(3);
+    gen_permanent_tests!(4);
+    gen_permanent_tests!(5);
+
     #[test]
-    fn det_direct_d4_dense_reports_legitimate_overflow() {
-        // The unscaled matrix has determinant 189, so scaling every entry by
-        // 3.2e76 gives a determinant of approximately 1.98e308.
-        let scale = 3.2e76;
-        let m = black_box(
-            Matrix::<4>::try_from_rows([
-                [4.0 * scale, scale, scale, scale],
-                [scale, 4.0 * scale, scale, scale],
-                [scale, scale, 4.0 * scale, scale],
-                [scale, scale, scale, 4.0 * scale],
-            ])
-            .unwrap(),
-        );
-        let expected = LaError::non_finite_computation_scalar(ArithmeticOperation::Determinant);
+    fn permanent_matches_hand_computed_3x3() {
+        // 1(5·9 + 6·8) + 2(4·9 + 6·7) + 3(4·8 + 5·7) = 93 + 156 + 201.
+        let a = Matrix::<3>::try_from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]])
+            .unwrap();
+        assert_eq!(a.permanent(), Ok(Some(450.0)));
 
-        assert_eq!(m.det_direct(), Err(expected));
-        assert_eq!(m.det(), Err(expected));
+        // Unlike the determinant, row swaps do not change the sign.
+        let swapped =
+            Matrix::<3>::try_from_rows([[4.0, 5.0, 6.0], [1.0, 2.0, 3.0], [7.0, 8.0, 9.0]])
+                .unwrap();
+        assert_eq!(swapped.permanent(), Ok(Some(450.0)));
     }
 
     #[test]
-    fn det_errbound_d4_dense_reports_legitimate_overflow() {
-        // The unscaled matrix has determinant 189, so scaling every entry by
-        // 3.2e76 gives a determinant of approximately 1.98e308.
-        let scale = 3.2e76;
-        let m = black_box(
-            Matrix::<4>::try_from_rows([
-                [4.0 * scale, scale, scale, scale],
-                [scale, 4.0 * scale, scale, scale],
-                [scale, scale, 4.0 * scale, scale],
-                [scale, scale, scale, 4.0 * scale],
-            ])
-            .unwrap(),
+    fn permanent_is_exact_for_all_ones_at_dimension_cap() {
+        // Ryser's terms reach 12^12 here while the permanent is only 12!.
+        let ones = Matrix::<12>::try_from_rows([[1.0; 12]; 12]).unwrap();
+        let factorial: f64 = (1..=12).map(f64::from).product();
+        assert_eq!(
+            ones.permanent().unwrap().map(f64::to_bits),
+            Some(factorial.to_bits())
         );
-        let expected =
-            LaError::non_finite_computation_scalar(ArithmeticOperation::DeterminantErrorBound);
-
-        assert_eq!(m.det_errbound(), Err(expected));
-        assert_eq!(m.det_direct_with_errbound(), Err(expected));
+        assert_eq!(factorial.to_bits(), 479_001_600.0f64.to_bits());
     }
 
     #[test]
-    fn det_direct_d4_skips_zero_coefficient_cofactors_that_would_overflow() {
-        let m = black_box(
-            Matrix::<4>::try_from_rows([
-                [0.0, 0.0, 0.0, 0.0],
-                [0.0, 0.0, 0.0, 0.0],
-                [1.0e300, 0.0, 1.0e300, 1.0e300],
-                [1.0e300, 0.0, 1.0e300, -1.0e300],
-            ])
-            .unwrap(),
+    fn permanent_keeps_small_terms_next_to_large_entries() {
+        // perm = 2^52 + 1, which cancellation between 2^53 + 2 and 2^52 must
+        // not lose.
+        let a = Matrix::<2>::try_from_rows([[4_503_599_627_370_496.0, 1.0], [1.0, 1.0]]).unwrap();
+        assert_eq!(
+            a.permanent().unwrap().map(f64::to_bits),
+            Some(4_503_599_627_370_497.0f64.to_bits())
+        );
+        // 1e20 + 1 rounds to 1e20, the correctly rounded permanent.
+        let b = Matrix::<2>::try_from_rows([[1e20, 1.0], [1.0, 1.0]]).unwrap();
+        assert_eq!(
+            b.permanent().unwrap().map(f64::to_bits),
+            Some((1e20f64 + 1.0).to_bits())
         );
-        assert_eq!(m.det_direct(), Ok(Some(0.0)));
     }
 
     #[test]
-    fn det_direct_d4_sparse_second_row_skips_inactive_overflowing_minors() {
-        let m = black_box(
-            Matrix::<4>::try_from_rows([
-                [1.0e-300, 1.0, 1.0, 1.0],
-                [0.0, 1.0, 0.0, 0.0],
-                [0.0, 1.0e300, 1.0, 1.0e300],
-                [0.0, 1.0e300, 0.0, 1.0e300],
-            ])
-            .unwrap(),
+    fn permanent_handles_dimension_limits_and_overflow() {
+        assert_eq!(Matrix::<0>::zero().permanent(), Ok(Some(1.0)));
+        assert_eq!(
+            Matrix::<1>::try_from_rows([[-3.5]]).unwrap().permanent(),
+            Ok(Some(-3.5))
         );
+        assert_eq!(Matrix::<12>::identity().permanent(), Ok(Some(1.0)));
+        assert_eq!(Matrix::<13>::identity().permanent(), Ok(None));
 
-        assert_eq!(m.det_direct(), Ok(Some(1.0)));
-        assert_eq!(m.det(), Ok(1.0));
+        let large = Matrix::<2>::try_from_rows([[1e200, 1e200], [1e200, 1e200]]).unwrap();
+        assert_eq!(
+            large.permanent(),
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::Permanent
+            ))
+        );
+    }
+
+    // === nearest_orthogonal (polar factor) ===
+
+    fn assert_orthogonal<const D: usize>(q: &Matrix<D>, epsilon: f64) {
+        for i in 0..D {
+            for j in 0..D {
+                let dot: f64 = (0..D).map(|k| q.rows[k][i] * q.rows[k][j]).sum();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert_abs_diff_eq!(dot, expected, epsilon = epsilon);
+            }
+        }
+    }
+
+    macro_rules! gen_nearest_orthogonal_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<nearest_orthogonal_recovers_identity_from_perturbation_ $d d>]() {
+                    let mut rows = Matrix::<$d>::identity().into_rows();
+                    rows[0][$d - 1] = 1e-3;
+                    rows[$d - 1][$d - 1] = 1.002;
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+
+                    let q = a.nearest_orthogonal(Tolerance::try_new(1e-14).unwrap(), 20).unwrap();
+
+                    assert_orthogonal(&q, 1e-13);
+                    assert_abs_diff_eq!(q.det().unwrap(), 1.0, epsilon = 1e-12);
+                }
+
+                #[test]
+                fn [<nearest_orthogonal_returns_orthogonal_input_unchanged_ $d d>]() {
+                    let q = Matrix::<$d>::identity()
+                        .nearest_orthogonal(Tolerance::ZERO, 0)
+                        .unwrap();
+                    assert_eq!(q, Matrix::<$d>::identity());
+                }
+            }
+        };
     }
 
+    gen_nearest_orthogonal_tests!(2);
+    gen_nearest_orthogonal_tests!(3);
+    gen_nearest_orthogonal_tests!(4);
+    gen_nearest_orthogonal_tests!(5);
+
     #[test]
-    fn det_direct_d5_returns_none() {
-        assert_eq!(Matrix::<5>::identity().det_direct(), Ok(None));
+    fn nearest_orthogonal_restores_drifted_rotation_3d() {
+        let (s, c) = 0.7_f64.sin_cos();
+        let rotation = [[c, -s, 0.0], [s, c, 0.0], [0.0, 0.0, 1.0]];
+        let mut drifted = rotation;
+        drifted[0][1] += 2e-5;
+        drifted[2][0] -= 1e-5;
+        drifted[1][1] *= 1.0 + 3e-5;
+        let a = Matrix::<3>::try_from_rows(drifted).unwrap();
+
+        let q = a
+            .nearest_orthogonal(Tolerance::try_new(1e-14).unwrap(), 20)
+            .unwrap();
+
+        assert_orthogonal(&q, 1e-13);
+        for (q_row, r_row) in q.rows.iter().zip(rotation) {
+            for (&actual, expected) in q_row.iter().zip(r_row) {
+                assert_abs_diff_eq!(actual, expected, epsilon = 1e-4);
+            }
+        }
     }
 
     #[test]
-    fn det_direct_d8_returns_none() {
-        assert_eq!(Matrix::<8>::zero().det_direct(), Ok(None));
+    fn nearest_orthogonal_flips_reflection_sign_consistently() {
+        // The polar factor of a matrix with negative determinant is a reflection.
+        let a = Matrix::<2>::try_from_rows([[2.0, 0.1], [0.0, -3.0]]).unwrap();
+        let q = a
+            .nearest_orthogonal(Tolerance::try_new(1e-14).unwrap(), 30)
+            .unwrap();
+        assert_orthogonal(&q, 1e-13);
+        assert_abs_diff_eq!(q.det().unwrap(), -1.0, epsilon = 1e-12);
     }
 
     #[test]
-    fn det_direct_rejects_computed_overflow() {
-        let m = Matrix::<2>::try_from_rows([[1e300, 0.0], [0.0, 1e300]]).unwrap();
-        assert_eq!(
-            m.det_direct(),
-            Err(LaError::non_finite_computation_scalar(
-                ArithmeticOperation::Determinant
-            ))
+    fn nearest_orthogonal_reports_exhausted_iteration_budget() {
+        let a = Matrix::<2>::try_from_rows([[2.0, 0.0], [0.0, 0.5]]).unwrap();
+        let tol = Tolerance::try_new(1e-12).unwrap();
+
+        assert_matches!(
+            a.nearest_orthogonal(tol, 0),
+            Err(LaError::NotConverged { iterations: 0, residual, tolerance })
+                if residual.to_bits() == 3.0f64.to_bits() && tolerance.to_bits() == 1e-12f64.to_bits()
+        );
+        assert_matches!(
+            a.nearest_orthogonal(tol, 1),
+            Err(LaError::NotConverged { iterations: 1, .. })
         );
     }
 
     #[test]
-    fn det_d5_rejects_lu_product_overflow() {
-        let m = Matrix::<5>::try_from_rows([
-            [1.0e100, 0.0, 0.0, 0.0, 0.0],
-            [0.0, 1.0e100, 0.0, 0.0, 0.0],
-            [0.0, 0.0, 1.0e100, 0.0, 0.0],
-            [0.0, 0.0, 0.0, 1.0e100, 0.0],
-            [0.0, 0.0, 0.0, 0.0, 1.0e100],
-        ])
-        .unwrap();
-        assert_eq!(
-            m.det(),
-            Err(LaError::non_finite_computation_step(
-                ArithmeticOperation::Determinant,
-                4
-            ))
+    fn nearest_orthogonal_rejects_singular_input() {
+        let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [2.0, 4.0]]).unwrap();
+        assert_matches!(
+            a.nearest_orthogonal(Tolerance::try_new(1e-12).unwrap(), 20),
+            Err(LaError::Singular { .. })
         );
     }
 
     #[test]
-    fn det_d5_rejects_lu_trailing_update_overflow() {
-        let m = Matrix::<5>::try_from_rows([
-            [1.0, f64::MAX, 0.0, 0.0, 0.0],
-            [-1.0, f64::MAX, 0.0, 0.0, 0.0],
-            [0.0, 0.0, 1.0, 0.0, 0.0],
-            [0.0, 0.0, 0.0, 1.0, 0.0],
-            [0.0, 0.0, 0.0, 0.0, 1.0],
-        ])
-        .unwrap();
-
+    fn nearest_orthogonal_reports_overflowing_defect() {
+        let a = Matrix::<2>::try_from_rows([[f64::MAX, 0.0], [0.0, 1.0]]).unwrap();
         assert_eq!(
-            m.det(),
+            a.nearest_orthogonal(Tolerance::try_new(1e-12).unwrap(), 20),
             Err(LaError::non_finite_computation_matrix(
-                ArithmeticOperation::LuFactorization,
-                1,
-                1
+                ArithmeticOperation::PolarDecomposition,
+                0,
+                0
             ))
         );
     }
 
-    macro_rules! gen_det_direct_agrees_with_lu {
+    // === nearest_correlation ===
+
+    macro_rules! gen_nearest_correlation_tests {
         ($d:literal) => {
             paste! {
                 #[test]
-                #[expect(
-                    clippy::cast_precision_loss,
-                    reason = "r, c, and D are tiny test integers exactly representable as f64"
-                )]
-                fn [<det_direct_agrees_with_lu_ $d d>]() {
-                    // Well-conditioned matrix: diagonally dominant.
-                    let mut rows = [[0.0f64; $d]; $d];
-                    for r in 0..$d {
-                        for c in 0..$d {
-                            rows[r][c] = if r == c {
-                                (r as f64) + f64::from($d) + 1.0
-                            } else {
-                                0.1 / ((r + c + 1) as f64)
-                            };
+                fn [<nearest_correlation_keeps_valid_correlation_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            *entry = 0.5f64.powi(i32::try_from(r.abs_diff(c)).unwrap());
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let tol = Tolerance::try_new(1e-12).unwrap();
+                    let c = a.nearest_correlation(tol, 100).unwrap();
+
+                    assert_eq!(c, c.transpose());
+                    for (got, want) in c.rows.iter().zip(&a.rows) {
+                        for (&x, &y) in got.iter().zip(want) {
+                            assert_abs_diff_eq!(x, y, epsilon = 1e-12);
+                        }
+                    }
+                    assert!(c.is_correlation(tol));
+                }
+
+                #[test]
+                fn [<nearest_correlation_clips_overlarge_equicorrelation_ $d d>]() {
+                    // Off-diagonal 2 gives eigenvalue −1; the nearest
+                    // correlation matrix is the all-ones matrix.
+                    let mut rows = [[2.0; $d]; $d];
+                    for (i, row) in rows.iter_mut().enumerate() {
+                        row[i] = 1.0;
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let c = a
+                        .nearest_correlation(Tolerance::try_new(1e-12).unwrap(), 200)
+                        .unwrap();
+
+                    for (i, row) in c.rows.iter().enumerate() {
+                        assert_eq!(row[i].to_bits(), 1.0f64.to_bits());
+                        for &x in row {
+                            assert_abs_diff_eq!(x, 1.0, epsilon = 1e-10);
                         }
                     }
-                    let m = Matrix::<$d>::try_from_rows(rows).unwrap();
-                    let direct = m.det_direct().unwrap().unwrap();
-                    let lu_det = m.lu(DEFAULT_SINGULAR_TOL).unwrap().det().unwrap();
-                    let eps = lu_det.abs().mul_add(1e-12, 1e-12);
-                    assert_abs_diff_eq!(direct, lu_det, epsilon = eps);
                 }
             }
         };
     }
 
-    gen_det_direct_agrees_with_lu!(1);
-    gen_det_direct_agrees_with_lu!(2);
-    gen_det_direct_agrees_with_lu!(3);
-    gen_det_direct_agrees_with_lu!(4);
+    gen_nearest_correlation_tests!(2);
+    gen_nearest_correlation_tests!(3);
+    gen_nearest_correlation_tests!(4);
+    gen_nearest_correlation_tests!(5);
 
     #[test]
-    fn det_direct_identity_all_dims() {
-        assert_abs_diff_eq!(
-            Matrix::<1>::identity().det_direct().unwrap().unwrap(),
-            1.0,
-            epsilon = 0.0
+    fn nearest_correlation_repairs_higham_example() {
+        // Higham (2002), Section 4: the nearest correlation matrix has
+        // off-diagonal entries 0.7607 and 0.1573.
+        let a = Matrix::<3>::try_from_rows([[1.0, 1.0, 0.0], [1.0, 1.0, 1.0], [0.0, 1.0, 1.0]])
+            .unwrap();
+        let tol = Tolerance::try_new(1e-12).unwrap();
+        let c = a.nearest_correlation(tol, 500).unwrap();
+
+        assert_abs_diff_eq!(c.rows[0][1], 0.7607, epsilon = 1e-4);
+        assert_abs_diff_eq!(c.rows[1][2], 0.7607, epsilon = 1e-4);
+        assert_abs_diff_eq!(c.rows[0][2], 0.1573, epsilon = 1e-4);
+        let (lambda, _) = c.sym_eigen_sorted(tol, 30).unwrap();
+        assert!(lambda.into_array()[0] >= -1e-10);
+    }
+
+    #[test]
+    fn nearest_correlation_budget_does_not_limit_jacobi_sweeps() {
+        // A valid correlation converges in one projection, even though its
+        // eigendecomposition needs several Jacobi sweeps.
+        let a = Matrix::<4>::try_from_rows([
+            [1.0, 0.3, -0.2, 0.1],
+            [0.3, 1.0, 0.4, -0.3],
+            [-0.2, 0.4, 1.0, 0.2],
+            [0.1, -0.3, 0.2, 1.0],
+        ])
+        .unwrap();
+        let tol = Tolerance::try_new(1e-12).unwrap();
+        assert_matches!(
+            a.apply_spectral(|lambda| lambda.max(0.0), tol, 1),
+            Err(LaError::NotConverged { iterations: 1, .. })
         );
-        assert_abs_diff_eq!(
-            Matrix::<2>::identity().det_direct().unwrap().unwrap(),
-            1.0,
-            epsilon = 0.0
+        let c = a.nearest_correlation(tol, 1).unwrap();
+        assert!(c.inf_distance(&a) <= 1e-10);
+    }
+
+    #[test]
+    fn nearest_correlation_reports_errors() {
+        let tol = Tolerance::try_new(1e-12).unwrap();
+        let asymmetric = Matrix::<2>::try_from_rows([[1.0, 0.5], [0.4, 1.0]]).unwrap();
+        assert_matches!(
+            asymmetric.nearest_correlation(tol, 10),
+            Err(LaError::Asymmetric { .. })
         );
-        assert_abs_diff_eq!(
-            Matrix::<3>::identity().det_direct().unwrap().unwrap(),
-            1.0,
-            epsilon = 0.0
+
+        assert_matches!(
+            Matrix::<2>::identity().nearest_correlation(tol, 0),
+            Err(LaError::NotConverged { iterations: 0, residual, .. })
+                if residual == f64::INFINITY
         );
-        assert_abs_diff_eq!(
-            Matrix::<4>::identity().det_direct().unwrap().unwrap(),
-            1.0,
-            epsilon = 0.0
+        let a = Matrix::<3>::try_from_rows([[1.0, 1.0, 0.0], [1.0, 1.0, 1.0], [0.0, 1.0, 1.0]])
+            .unwrap();
+        assert_matches!(
+            a.nearest_correlation(tol, 2),
+            Err(LaError::NotConverged { iterations: 2, .. })
+        );
+
+        let huge = Matrix::<2>::try_from_rows([[f64::MAX, 0.0], [0.0, f64::MAX]]).unwrap();
+        assert_matches!(
+            huge.nearest_correlation(tol, 10),
+            Err(LaError::NonFinite { .. })
         );
     }
 
+    // === svd (one-sided Jacobi) ===
+
+    fn assert_svd_reconstructs<const D: usize>(
+        a: &Matrix<D>,
+        (u, sigma, v): &(Matrix<D>, Vector<D>, Matrix<D>),
+        epsilon: f64,
+    ) {
+        assert_orthogonal(u, epsilon);
+        assert_orthogonal(v, epsilon);
+        let s = sigma.into_array();
+        for pair in s.windows(2) {
+            assert!(pair[0] >= pair[1], "singular values not descending: {s:?}");
+        }
+        for r in 0..D {
+            for c in 0..D {
+                let entry: f64 = (0..D).map(|k| u.rows[r][k] * s[k] * v.rows[c][k]).sum();
+                assert_abs_diff_eq!(entry, a.rows[r][c], epsilon = epsilon);
+            }
+        }
+    }
+
+    macro_rules! gen_svd_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<svd_reconstructs_lehmer_matrix_ $d d>]() {
+                    // Lehmer matrix (min(i, j) + 1) / (max(i, j) + 1): symmetric
+                    // positive definite, so σ are its eigenvalues.
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            let lo = f64::from(u32::try_from(r.min(c)).unwrap()) + 1.0;
+                            let hi = f64::from(u32::try_from(r.max(c)).unwrap()) + 1.0;
+                            *entry = lo / hi;
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+
+                    let svd = a.svd(Tolerance::try_new(1e-14).unwrap(), 30).unwrap();
+                    assert_svd_reconstructs(&a, &svd, 1e-12);
+                    let det: f64 = svd.1.into_array().iter().product();
+                    assert_relative_eq!(det, a.det().unwrap(), max_relative = 1e-12);
+                }
+
+                #[test]
+                fn [<svd_completes_u_for_rank_one_matrix_ $d d>]() {
+                    let a = Matrix::<$d>::try_from_rows([[1.0; $d]; $d]).unwrap();
+
+                    let svd = a.svd(Tolerance::try_new(1e-14).unwrap(), 30).unwrap();
+                    assert_svd_reconstructs(&a, &svd, 1e-12);
+                    let s = svd.1.into_array();
+                    assert_abs_diff_eq!(s[0], f64::from($d), epsilon = 1e-12);
+                    for &rest in &s[1..] {
+                        assert_abs_diff_eq!(rest, 0.0, epsilon = 1e-12);
+                    }
+                }
+
+                #[test]
+                fn [<svd_of_zero_has_identity_factors_ $d d>]() {
+                    let (u, sigma, v) = Matrix::<$d>::zero().svd(Tolerance::ZERO, 0).unwrap();
+                    assert_eq!(u, Matrix::<$d>::identity());
+                    assert_eq!(sigma, Vector::<$d>::zero());
+                    assert_eq!(v, Matrix::<$d>::identity());
+                }
+            }
+        };
+    }
+
+    gen_svd_tests!(2);
+    gen_svd_tests!(3);
+    gen_svd_tests!(4);
+    gen_svd_tests!(5);
+
     #[test]
-    fn det_direct_zero_matrix() {
-        assert_abs_diff_eq!(
-            Matrix::<2>::zero().det_direct().unwrap().unwrap(),
-            0.0,
-            epsilon = 0.0
+    fn svd_of_scalar_moves_sign_into_u() {
+        let a = Matrix::<1>::try_from_rows([[-3.0]]).unwrap();
+        let (u, sigma, v) = a.svd(Tolerance::ZERO, 0).unwrap();
+        assert_eq!(u.into_rows(), [[-1.0]]);
+        assert_eq!(sigma, Vector::<1>::new([3.0]));
+        assert_eq!(v, Matrix::<1>::identity());
+    }
+
+    #[test]
+    fn svd_reports_exhausted_sweeps_and_overflow() {
+        let a = Matrix::<2>::try_from_rows([[3.0, 0.0], [4.0, 5.0]]).unwrap();
+        let tol = Tolerance::try_new(1e-14).unwrap();
+        assert_matches!(
+            a.svd(tol, 0),
+            Err(LaError::NotConverged { iterations: 0, .. })
         );
-        assert_abs_diff_eq!(
-            Matrix::<3>::zero().det_direct().unwrap().unwrap(),
-            0.0,
-            epsilon = 0.0
+
+        // Scaling by the largest entry keeps the Jacobi sweeps finite; only
+        // σ₀ = √2 · f64::MAX itself overflows.
+        let huge = Matrix::<2>::try_from_rows([[1e300, 1e300], [0.0, 1.0]]).unwrap();
+        let (_, sigma, _) = huge.svd(tol, 30).unwrap();
+        assert_relative_eq!(
+            sigma.into_array()[0],
+            2.0f64.sqrt() * 1e300,
+            max_relative = 1e-14
         );
-        assert_abs_diff_eq!(
-            Matrix::<4>::zero().det_direct().unwrap().unwrap(),
-            0.0,
-            epsilon = 0.0
+        let overflowing = Matrix::<2>::try_from_rows([[f64::MAX, f64::MAX], [0.0, 1.0]]).unwrap();
+        assert_eq!(
+            overflowing.svd(tol, 30),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::SingularValueDecomposition,
+                0
+            ))
         );
     }
 
-    macro_rules! gen_det_singular_zero_matrix_tests {
+    // === pseudo_inverse ===
+
+    macro_rules! gen_pseudo_inverse_tests {
         ($d:literal) => {
             paste! {
                 #[test]
-                fn [<det_singular_zero_matrix_returns_zero_ $d d>]() {
-                    assert_abs_diff_eq!(
-                        Matrix::<$d>::zero().det().unwrap(),
-                        0.0,
-                        epsilon = 0.0
-                    );
+                fn [<pseudo_inverse_of_diagonal_inverts_non_zero_entries_ $d d>]() {
+                    // diag(1, 2, …, D − 1, 0): the zero entry stays zero in A⁺.
+                    let mut rows = [[0.0; $d]; $d];
+                    for (i, row) in rows.iter_mut().enumerate().take($d - 1) {
+                        row[i] = f64::from(u32::try_from(i).unwrap()) + 1.0;
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+
+                    let pinv = a.pseudo_inverse(Tolerance::try_new(1e-12).unwrap(), 30).unwrap();
+                    for r in 0..$d {
+                        for c in 0..$d {
+                            let expected = if r == c && r + 1 < $d { rows[r][r].recip() } else { 0.0 };
+                            assert_abs_diff_eq!(pinv.rows[r][c], expected, epsilon = 1e-14);
+                        }
+                    }
+                }
+
+                #[test]
+                fn [<pseudo_inverse_satisfies_penrose_identity_on_rank_one_ $d d>]() {
+                    let a = Matrix::<$d>::try_from_rows([[1.0; $d]; $d]).unwrap();
+                    let pinv = a.pseudo_inverse(Tolerance::try_new(1e-12).unwrap(), 30).unwrap();
+
+                    // A⁺ = A / D², and A A⁺ A = A.
+                    let d2 = f64::from($d * $d);
+                    let round_trip = a
+                        .matmul(&pinv, ArithmeticOperation::MatrixProduct)
+                        .unwrap()
+                        .matmul(&a, ArithmeticOperation::MatrixProduct)
+                        .unwrap();
+                    for r in 0..$d {
+                        for c in 0..$d {
+                            assert_abs_diff_eq!(pinv.rows[r][c], d2.recip(), epsilon = 1e-14);
+                            assert_abs_diff_eq!(round_trip.rows[r][c], 1.0, epsilon = 1e-12);
+                        }
+                    }
                 }
             }
         };
     }
 
-    gen_det_singular_zero_matrix_tests!(2);
-    gen_det_singular_zero_matrix_tests!(3);
-    gen_det_singular_zero_matrix_tests!(4);
+    gen_pseudo_inverse_tests!(2);
+    gen_pseudo_inverse_tests!(3);
+    gen_pseudo_inverse_tests!(4);
+    gen_pseudo_inverse_tests!(5);
 
     #[test]
-    fn det_singular_zero_matrix_d5_preserves_lu_error() {
-        assert_eq!(
-            Matrix::<5>::zero().det(),
-            Err(LaError::singular_numerical(
-                0,
-                FactorizationKind::Lu,
-                0.0,
-                0.0
-            ))
-        );
+    fn pseudo_inverse_gives_minimum_norm_least_squares_solution() {
+        // A = x xᵀ with x = (1, 2, 2): b has a component outside range(A).
+        let x = [1.0, 2.0, 2.0];
+        let rows: [[f64; 3]; 3] = core::array::from_fn(|r| core::array::from_fn(|c| x[r] * x[c]));
+        let a = Matrix::<3>::try_from_rows(rows).unwrap();
+        assert_matches!(a.lu(DEFAULT_SINGULAR_TOL), Err(LaError::Singular { .. }));
+
+        let b = Vector::<3>::new([1.0, 0.0, 3.0]);
+        let pinv = a
+            .pseudo_inverse(Tolerance::try_new(1e-12).unwrap(), 30)
+            .unwrap();
+        let solution = pinv.mul_vec(&b).unwrap().into_array();
+
+        // The least-squares solutions are t x + null(A) with t = (x·b) / ‖x‖⁴;
+        // the minimum-norm one is the multiple of x.
+        let t = 7.0 / 81.0;
+        for (&s, &xi) in solution.iter().zip(&x) {
+            assert_abs_diff_eq!(s, t * xi, epsilon = 1e-14);
+        }
     }
 
     #[test]
-    fn det_d5_does_not_turn_elimination_underflow_into_exact_zero() {
-        let min_subnormal = f64::from_bits(1);
-        let two_pow_800 = f64::from_bits(1823_u64 << 52);
-        let m = Matrix::<5>::try_from_rows([
-            [2.0, min_subnormal, 0.0, 0.0, 0.0],
-            [1.0, 0.0, 0.0, 0.0, 0.0],
-            [0.0, 0.0, two_pow_800, 0.0, 0.0],
-            [0.0, 0.0, 0.0, 1.0, 0.0],
-            [0.0, 0.0, 0.0, 0.0, 1.0],
-        ])
-        .unwrap();
-
-        assert_eq!(
-            m.det(),
-            Err(LaError::singular_numerical(
-                1,
-                FactorizationKind::Lu,
-                0.0,
-                0.0
-            ))
-        );
+    fn pseudo_inverse_of_invertible_matrix_is_inverse() {
+        let a = Matrix::<3>::try_from_rows([[4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]])
+            .unwrap();
+        let pinv = a
+            .pseudo_inverse(Tolerance::try_new(1e-14).unwrap(), 30)
+            .unwrap();
+        let inverse = a.lu(DEFAULT_SINGULAR_TOL).unwrap().inverse().unwrap();
+        for (p_row, i_row) in pinv.rows.iter().zip(&inverse.rows) {
+            for (&p, &i) in p_row.iter().zip(i_row) {
+                assert_abs_diff_eq!(p, i, epsilon = 1e-14);
+            }
+        }
     }
 
-    #[test]
-    fn det_d5_ignores_pivot_tolerance_for_tiny_nonsingular_matrix() {
-        // A small nonzero determinant is still a determinant. `det` must not
-        // flatten the value to zero merely because the default LU tolerance
-        // would reject a pivot this small.
-        let m = Matrix::<5>::try_from_rows([
-            [1e-13, 0.0, 0.0, 0.0, 0.0],
-            [0.0, 1.0, 0.0, 0.0, 0.0],
-            [0.0, 0.0, 1.0, 0.0, 0.0],
-            [0.0, 0.0, 0.0, 1.0, 0.0],
-            [0.0, 0.0, 0.0, 0.0, 1.0],
-        ])
-        .unwrap();
+    // === effective_rank ===
 
-        assert_abs_diff_eq!(m.det().unwrap(), 1e-13, epsilon = 0.0);
-        assert_eq!(
-            m.lu(DEFAULT_SINGULAR_TOL),
-            Err(LaError::singular_numerical(
-                0,
-                FactorizationKind::Lu,
-                1e-13,
-                DEFAULT_SINGULAR_TOL.get()
-            ))
-        );
+    macro_rules! gen_effective_rank_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<effective_rank_drops_tiny_singular_value_ $d d>]() {
+                    // A = H diag(1, 2, …, D − 1, 1e-13) H with a Householder H.
+                    let mut diag = [[0.0; $d]; $d];
+                    for (i, row) in diag.iter_mut().enumerate() {
+                        row[i] = f64::from(u32::try_from(i).unwrap()) + 1.0;
+                    }
+                    diag[$d - 1][$d - 1] = 1e-13;
+                    let mut normal = [1.0; $d];
+                    normal[0] = 2.0;
+                    let h = Matrix::<$d>::householder(Vector::<$d>::new(normal)).unwrap();
+                    let a = h
+                        .matmul(&Matrix::<$d>::try_from_rows(diag).unwrap(), ArithmeticOperation::MatrixProduct)
+                        .unwrap()
+                        .matmul(&h, ArithmeticOperation::MatrixProduct)
+                        .unwrap();
+
+                    let tol = Tolerance::try_new(1e-10).unwrap();
+                    assert_eq!(a.effective_rank(tol, 30), Ok($d - 1));
+                    assert_eq!(Matrix::<$d>::identity().effective_rank(tol, 30), Ok($d));
+                    assert_eq!(Matrix::<$d>::zero().effective_rank(tol, 30), Ok(0));
+                }
+            }
+        };
     }
 
-    #[test]
-    fn det_returns_non_finite_error_for_overflow_with_finite_entries() {
-        // det_direct produces an overflowing f64 (1e300 * 1e300 = ∞) even
-        // though every matrix entry is finite. The entry scan in `det`
-        // falls through and reports a computed determinant overflow rather
-        // than a NaN/∞ input.
-        let m = Matrix::<2>::try_from_rows([[1e300, 0.0], [0.0, 1e300]]).unwrap();
-        assert_eq!(
-            m.det(),
-            Err(LaError::non_finite_computation_scalar(
-                ArithmeticOperation::Determinant
-            ))
-        );
-    }
+    gen_effective_rank_tests!(2);
+    gen_effective_rank_tests!(3);
+    gen_effective_rank_tests!(4);
+    gen_effective_rank_tests!(5);
 
-    // === det_direct const-evaluability tests (D = 2..=5) ===
-    //
-    // Every dimension hits a distinct arm of the `match D { … }` body inside
-    // `det_direct`, so exercising each at compile time is the tightest
-    // const-fn proof available.
+    // === projector ===
 
-    macro_rules! gen_det_direct_const_eval_tests {
+    macro_rules! gen_projector_tests {
         ($d:literal) => {
             paste! {
-                /// `Matrix::<D>::det_direct()` on the identity must const-evaluate
-                /// to `Ok(Some(1.0))` for every closed-form dimension `D ∈ {1, 2, 3, 4}`.
                 #[test]
-                fn [<det_direct_const_eval_ $d d>]() {
-                    const DET: Result<Option<f64>, LaError> = Matrix::<$d>::identity().det_direct();
-                    assert_eq!(DET, Ok(Some(1.0)));
+                fn [<projector_is_symmetric_idempotent_and_fixes_columns_ $d d>]() {
+                    // Diagonally dominant, then the last column replaced by a
+                    // multiple of the first: rank D − 1.
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            *entry = if r == c {
+                                f64::from($d) + 1.0
+                            } else {
+                                f64::from(u32::try_from((r + 2 * c) % 3).unwrap()) - 1.0
+                            };
+                        }
+                        row[$d - 1] = -2.0 * row[0];
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let p = a.projector(Tolerance::try_new(1e-12).unwrap()).unwrap();
+
+                    assert_eq!(p, p.transpose());
+                    let p2 = p.matmul(&p, ArithmeticOperation::MatrixProduct).unwrap();
+                    let pa = p.matmul(&a, ArithmeticOperation::MatrixProduct).unwrap();
+                    for r in 0..$d {
+                        for c in 0..$d {
+                            assert_abs_diff_eq!(p2.rows[r][c], p.rows[r][c], epsilon = 1e-14);
+                            assert_abs_diff_eq!(pa.rows[r][c], a.rows[r][c], epsilon = 1e-13);
+                        }
+                    }
+                    assert_abs_diff_eq!(p.trace().unwrap(), f64::from($d) - 1.0, epsilon = 1e-14);
+
+                    let tol = DEFAULT_SINGULAR_TOL;
+                    let identity = Matrix::<$d>::identity();
+                    assert_eq!(identity.projector(tol), Ok(identity));
+                    assert_eq!(Matrix::<$d>::zero().projector(tol), Ok(Matrix::<$d>::zero()));
                 }
             }
         };
     }
 
-    gen_det_direct_const_eval_tests!(2);
-    gen_det_direct_const_eval_tests!(3);
-    gen_det_direct_const_eval_tests!(4);
+    gen_projector_tests!(2);
+    gen_projector_tests!(3);
+    gen_projector_tests!(4);
+    gen_projector_tests!(5);
 
     #[test]
-    fn det_direct_const_eval_d5_is_none() {
-        // D ≥ 5 has no closed-form arm; `det_direct` returns `Ok(None)`.  Verify
-        // that the wildcard arm is reachable in a `const { … }` context.
-        const DET: Result<Option<f64>, LaError> = Matrix::<5>::identity().det_direct();
-        assert_eq!(DET, Ok(None));
+    fn projector_tolerance_decides_nearly_dependent_columns() {
+        // The second column leaves the first by a relative 1e-9.
+        let a = Matrix::<2>::try_from_rows([[1.0, 1.0], [1.0, 1.0 + 2e-9]]).unwrap();
+
+        let line = a.projector(Tolerance::try_new(1e-6).unwrap()).unwrap();
+        assert_abs_diff_eq!(line.trace().unwrap(), 1.0, epsilon = 1e-15);
+        assert_abs_diff_eq!(line.rows[0][1], 0.5, epsilon = 1e-15);
+
+        let plane = a.projector(Tolerance::try_new(1e-12).unwrap()).unwrap();
+        for (r, row) in plane.rows.iter().enumerate() {
+            for (c, &entry) in row.iter().enumerate() {
+                let expected = if r == c { 1.0 } else { 0.0 };
+                assert_abs_diff_eq!(entry, expected, epsilon = 1e-15);
+            }
+        }
+
+        // Scale does not matter: only the direction of each column is used.
+        let tiny = Matrix::<2>::try_from_rows([[1e-300, 0.0], [1e-300, 0.0]]).unwrap();
+        let p = tiny.projector(DEFAULT_SINGULAR_TOL).unwrap();
+        assert_abs_diff_eq!(p.rows[1][0], 0.5, epsilon = 1e-15);
     }
 
-    // === det_errbound tests (no `exact` feature required) ===
+    macro_rules! gen_subspace_reflection_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<householder_subspace_reflection_is_orthogonal_involution_ $d d>]() {
+                    // Two independent columns span the subspace (all of it for D = 2).
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        let rf = f64::from(u32::try_from(r).unwrap());
+                        row[0] = rf + 1.0;
+                        row[$d - 1] = rf.mul_add(rf, -1.5);
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let r = a.householder_subspace_reflection(DEFAULT_SINGULAR_TOL).unwrap();
 
-    #[test]
-    fn det_errbound_matches_documented_coefficient_scale() {
-        let m2 = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]]).unwrap();
-        let expected_2 = ERR_COEFF_2 * ((1.0_f64 * 4.0).abs() + (2.0_f64 * 3.0).abs());
-        assert_abs_diff_eq!(
-            m2.det_errbound().unwrap().unwrap(),
-            expected_2,
-            epsilon = 0.0
-        );
+                    assert_eq!(r, r.transpose());
+                    assert_orthogonal(&r, 1e-14);
+                    let r2 = r.matmul(&r, ArithmeticOperation::MatrixProduct).unwrap();
+                    let ra = r.matmul(&a, ArithmeticOperation::MatrixProduct).unwrap();
+                    for i in 0..$d {
+                        for j in 0..$d {
+                            let expected = if i == j { 1.0 } else { 0.0 };
+                            assert_abs_diff_eq!(r2.rows[i][j], expected, epsilon = 1e-14);
+                            // Columns of A lie in the subspace and are fixed.
+                            assert_abs_diff_eq!(ra.rows[i][j], a.rows[i][j], epsilon = 1e-13);
+                        }
+                    }
 
-        assert_abs_diff_eq!(
-            Matrix::<3>::identity().det_errbound().unwrap().unwrap(),
-            ERR_COEFF_3,
-            epsilon = 0.0
-        );
-        assert_abs_diff_eq!(
-            Matrix::<4>::identity().det_errbound().unwrap().unwrap(),
-            ERR_COEFF_4,
-            epsilon = 0.0
-        );
+                    // A single column gives the negated Householder reflector.
+                    let mut single = [[0.0; $d]; $d];
+                    for (row, source) in single.iter_mut().zip(&rows) {
+                        row[0] = source[0];
+                    }
+                    let v = Vector::<$d>::new(core::array::from_fn(|i| rows[i][0]));
+                    let h = Matrix::<$d>::householder(v).unwrap();
+                    let reflected = Matrix::<$d>::try_from_rows(single)
+                        .unwrap()
+                        .householder_subspace_reflection(DEFAULT_SINGULAR_TOL)
+                        .unwrap();
+                    for (got, want) in reflected.rows.iter().zip(&h.rows) {
+                        for (&g, &w) in got.iter().zip(want) {
+                            assert_abs_diff_eq!(g, -w, epsilon = 1e-15);
+                        }
+                    }
+                }
+            }
+        };
     }
 
-    #[test]
-    fn det_errbound_d3_skips_zero_coefficient_minor_that_would_overflow() {
-        let m = Matrix::<3>::try_from_rows([
-            [1.0, 0.0, 0.0],
-            [1.0e300, 1.0, 1.0e300],
-            [1.0e300, 0.0, 1.0e300],
-        ])
-        .unwrap();
+    gen_subspace_reflection_tests!(2);
+    gen_subspace_reflection_tests!(3);
+    gen_subspace_reflection_tests!(4);
+    gen_subspace_reflection_tests!(5);
 
-        assert_eq!(m.det_errbound(), Ok(Some(ERR_COEFF_3 * 1.0e300)));
-    }
+    // === to_hessenberg ===
 
-    #[test]
-    fn det_errbound_d4_skips_zero_coefficient_cofactors_that_would_overflow() {
-        let m = Matrix::<4>::try_from_rows([
-            [0.0, 0.0, 0.0, 0.0],
-            [0.0, 0.0, 0.0, 0.0],
-            [1.0e300, 0.0, 1.0e300, 1.0e300],
-            [1.0e300, 0.0, 1.0e300, -1.0e300],
-        ])
-        .unwrap();
+    macro_rules! gen_to_hessenberg_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<to_hessenberg_is_orthogonally_similar_and_hessenberg_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        let rf = f64::from(u32::try_from(r).unwrap());
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            let cf = f64::from(u32::try_from(c).unwrap());
+                            *entry = rf.mul_add(2.0, 1.0) / cf.mul_add(3.0, 1.0) - cf;
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
 
-        assert_eq!(m.det_errbound(), Ok(Some(0.0)));
-    }
+                    let (h, q) = a.to_hessenberg().unwrap();
+                    assert_orthogonal(&q, 1e-14);
+                    for r in 0..$d {
+                        for c in 0..$d {
+                            if r > c + 1 {
+                                assert_eq!(h.get(r, c), Some(0.0));
+                            }
+                            let entry: f64 = (0..$d)
+                                .flat_map(|i| (0..$d).map(move |j| (i, j)))
+                                .map(|(i, j)| q.rows[r][i] * h.rows[i][j] * q.rows[c][j])
+                                .sum();
+                            assert_abs_diff_eq!(entry, a.rows[r][c], epsilon = 1e-13);
+                        }
+                    }
+                    assert_relative_eq!(h.trace().unwrap(), a.trace().unwrap(), max_relative = 1e-14);
+                }
 
-    #[test]
-    fn det_errbound_d5_returns_none() {
-        // D=5 has no fast filter
-        assert_eq!(Matrix::<5>::identity().det_errbound(), Ok(None));
+                #[test]
+                fn [<to_hessenberg_of_symmetric_is_tridiagonal_ $d d>]() {
+                    // Lehmer matrix (min(i, j) + 1) / (max(i, j) + 1).
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            let lo = f64::from(u32::try_from(r.min(c)).unwrap()) + 1.0;
+                            let hi = f64::from(u32::try_from(r.max(c)).unwrap()) + 1.0;
+                            *entry = lo / hi;
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+
+                    let (h, _) = a.to_hessenberg().unwrap();
+                    for r in 0..$d {
+                        for c in (r + 2)..$d {
+                            assert_abs_diff_eq!(h.rows[r][c], 0.0, epsilon = 1e-14);
+                        }
+                    }
+                    assert_relative_eq!(h.det().unwrap(), a.det().unwrap(), max_relative = 1e-12);
+                }
+            }
+        };
     }
 
+    gen_to_hessenberg_tests!(2);
+    gen_to_hessenberg_tests!(3);
+    gen_to_hessenberg_tests!(4);
+    gen_to_hessenberg_tests!(5);
+
     #[test]
-    fn combined_det_bound_wide_exponent_fast_path_matches_tracked_arithmetic() {
-        let threshold = f64::from_bits(1007_u64 << 52); // 2^-16
-        let at_threshold = Matrix::<2>::try_from_rows([[threshold, 0.0], [0.0, 2.0]]).unwrap();
-        assert!(at_threshold.det_bound_inputs_have_wide_exponent_margin());
+    fn to_hessenberg_leaves_small_matrices_unchanged() {
+        let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]]).unwrap();
+        assert_eq!(a.to_hessenberg(), Ok((a, Matrix::identity())));
+        assert_eq!(
+            Matrix::<0>::zero().to_hessenberg(),
+            Ok((Matrix::zero(), Matrix::identity()))
+        );
+    }
 
-        let tracked = at_threshold
-            .det_direct_with_errbound_from_arithmetic(
-                at_threshold
-                    .det_direct_arithmetic::<true>()
-                    .expect("D=2 has direct arithmetic"),
-            )
-            .unwrap();
-        assert_eq!(at_threshold.det_direct_with_errbound().unwrap(), tracked);
+    macro_rules! gen_rayleigh_quotient_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<rayleigh_quotient_of_identities_is_one_ $d d>]() {
+                    let i = Matrix::<$d>::identity();
+                    let mut data = [0.0; $d];
+                    for (k, value) in data.iter_mut().enumerate() {
+                        *value = f64::from(u32::try_from(k).unwrap()) - 1.5;
+                    }
+                    let v = Vector::<$d>::try_new(data).unwrap();
+                    let q = Matrix::rayleigh_quotient(&i, &i, &v, DEFAULT_SINGULAR_TOL).unwrap();
+                    assert_abs_diff_eq!(q, 1.0, epsilon = 1e-15);
+                }
 
-        let just_below = f64::from_bits(threshold.to_bits() - 1);
-        let below_threshold = Matrix::<2>::try_from_rows([[just_below, 0.0], [0.0, 2.0]]).unwrap();
-        assert!(!below_threshold.det_bound_inputs_have_wide_exponent_margin());
-        assert!(!Matrix::<5>::identity().det_bound_inputs_have_wide_exponent_margin());
+                #[test]
+                fn [<mul_vec_matches_columns_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, value) in row.iter_mut().enumerate() {
+                            *value = f64::from(u32::try_from(r * $d + c).unwrap());
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    for c in 0..$d {
+                        let column = a.mul_vec(&Vector::<$d>::basis(c)).unwrap().into_array();
+                        for r in 0..$d {
+                            assert_abs_diff_eq!(column[r], rows[r][c], epsilon = 0.0);
+                        }
+                    }
+                }
+            }
+        };
     }
 
+    gen_rayleigh_quotient_tests!(2);
+    gen_rayleigh_quotient_tests!(3);
+    gen_rayleigh_quotient_tests!(4);
+    gen_rayleigh_quotient_tests!(5);
+
     #[test]
-    fn det_direct_with_errbound_covers_zero_and_one_dimensions() {
-        let empty = Matrix::<0>::zero()
-            .det_direct_with_errbound()
-            .unwrap()
-            .unwrap();
-        assert_abs_diff_eq!(empty.determinant(), 1.0, epsilon = 0.0);
-        assert_abs_diff_eq!(empty.absolute_error_bound(), 0.0, epsilon = 0.0);
+    fn rayleigh_quotient_rejects_small_denominator() {
+        let a = Matrix::<2>::identity();
+        let b = Matrix::<2>::try_from_rows([[1.0, 0.0], [0.0, -1.0]]).unwrap();
+        let v = Vector::<2>::try_new([1.0, 1.0]).unwrap();
+        assert_eq!(
+            Matrix::rayleigh_quotient(&a, &b, &v, DEFAULT_SINGULAR_TOL),
+            Err(LaError::singular_exact(0))
+        );
 
-        let scalar = Matrix::<1>::try_from_rows([[-7.0]])
-            .unwrap()
-            .det_direct_with_errbound()
-            .unwrap()
-            .unwrap();
-        assert_abs_diff_eq!(scalar.determinant(), -7.0, epsilon = 0.0);
-        assert_abs_diff_eq!(scalar.absolute_error_bound(), 0.0, epsilon = 0.0);
+        let b = Matrix::<2>::try_from_rows([[1e-13, 0.0], [0.0, 0.0]]).unwrap();
+        let v = Vector::<2>::try_new([1.0, 0.0]).unwrap();
+        assert_eq!(
+            Matrix::rayleigh_quotient(&a, &b, &v, DEFAULT_SINGULAR_TOL),
+            Err(LaError::singular_numerical(
+                0,
+                FactorizationKind::Lu,
+                1e-13,
+                DEFAULT_SINGULAR_TOL.get()
+            ))
+        );
     }
 
     #[test]
-    fn det_direct_with_errbound_pairs_the_closed_form_values() {
-        let matrix = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 4.0]]).unwrap();
-        let estimate = matrix.det_direct_with_errbound().unwrap().unwrap();
-
-        assert_abs_diff_eq!(
-            estimate.determinant(),
-            matrix.det_direct().unwrap().unwrap(),
-            epsilon = 0.0
-        );
-        assert_abs_diff_eq!(
-            estimate.absolute_error_bound(),
-            ERR_COEFF_2 * (4.0_f64 + 6.0_f64),
-            epsilon = 0.0
+    fn rayleigh_quotient_rejects_zero_vector() {
+        let i = Matrix::<3>::identity();
+        assert_eq!(
+            Matrix::rayleigh_quotient(&i, &i, &Vector::<3>::zero(), Tolerance::ZERO),
+            Err(LaError::zero_vector(3))
         );
     }
 
     #[test]
-    fn det_direct_with_errbound_d5_returns_none() {
-        assert_eq!(Matrix::<5>::identity().det_direct_with_errbound(), Ok(None));
+    fn rayleigh_quotient_reports_overflowing_quotient() {
+        let a = Matrix::<1>::try_from_rows([[1e200]]).unwrap();
+        let b = Matrix::<1>::try_from_rows([[1e-200]]).unwrap();
+        let v = Vector::<1>::try_new([1.0]).unwrap();
+        assert_eq!(
+            Matrix::rayleigh_quotient(&a, &b, &v, Tolerance::ZERO),
+            Err(LaError::non_finite_computation_scalar(
+                ArithmeticOperation::RayleighQuotient
+            ))
+        );
     }
 
     #[test]
-    fn det_errbound_rejects_computed_overflow() {
-        let m = Matrix::<2>::try_from_rows([[1e300, 0.0], [0.0, 1e300]]).unwrap();
+    fn mul_vec_reports_overflowing_entry() {
+        let a = Matrix::<2>::try_from_rows([[1.0, 0.0], [f64::MAX, f64::MAX]]).unwrap();
+        let v = Vector::<2>::try_new([1.0, 1.0]).unwrap();
         assert_eq!(
-            m.det_errbound(),
-            Err(LaError::non_finite_computation_scalar(
-                ArithmeticOperation::DeterminantErrorBound
+            a.mul_vec(&v),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::MatrixVectorProduct,
+                1
             ))
         );
     }
 
-    // === det_errbound const-evaluability tests (D = 2..=5) ===
+    /// Deterministic non-symmetric fixture with entries in `[-2, 2]`.
+    fn sylvester_fixture<const D: usize>(shift: usize) -> Matrix<D> {
+        let mut rows = [[0.0; D]; D];
+        for (r, row) in rows.iter_mut().enumerate() {
+            for (c, value) in row.iter_mut().enumerate() {
+                *value = f64::from(u32::try_from((3 * r + c + shift) % 5).unwrap()) - 2.0;
+            }
+        }
+        Matrix::try_from_rows(rows).unwrap()
+    }
 
-    macro_rules! gen_det_errbound_const_eval_tests {
+    macro_rules! gen_sylvester_tests {
         ($d:literal) => {
             paste! {
-                /// `Matrix::<D>::det_errbound()` on the identity must const-evaluate
-                /// to `Ok(Some(bound))` with `bound > 0` for every closed-form dimension
-                /// `D ∈ {2, 3, 4}`.  Each dimension hits a distinct arm of
-                /// `det_errbound` with a dimension-specific permanent computation.
                 #[test]
-                fn [<det_errbound_const_eval_ $d d>]() {
-                    const BOUND: Result<Option<f64>, LaError> = Matrix::<$d>::identity().det_errbound();
-                    assert!(BOUND.unwrap().unwrap() > 0.0);
+                fn [<solve_sylvester_reconstructs_c_ $d d>]() {
+                    // Shift A and B so every eigenvalue has positive real part,
+                    // keeping λ(A) + μ(B) away from zero.
+                    let shift = 4.0 * f64::from($d);
+                    let a = sylvester_fixture::<$d>(0).add_scaled_identity(shift).unwrap();
+                    let b = sylvester_fixture::<$d>(2).add_scaled_identity(shift).unwrap();
+                    let x = sylvester_fixture::<$d>(1);
+
+                    let ax = a.matmul(&x, ArithmeticOperation::MatrixProduct).unwrap();
+                    let xb = x.matmul(&b, ArithmeticOperation::MatrixProduct).unwrap();
+                    let mut c = [[0.0; $d]; $d];
+                    for r in 0..$d {
+                        for col in 0..$d {
+                            c[r][col] = ax.rows[r][col] + xb.rows[r][col];
+                        }
+                    }
+                    let c = Matrix::<$d>::try_from_rows(c).unwrap();
+
+                    let solved = Matrix::solve_sylvester(&a, &b, &c, DEFAULT_SINGULAR_TOL).unwrap();
+                    let ax = a.matmul(&solved, ArithmeticOperation::MatrixProduct).unwrap();
+                    let xb = solved.matmul(&b, ArithmeticOperation::MatrixProduct).unwrap();
+                    for r in 0..$d {
+                        for col in 0..$d {
+                            assert_abs_diff_eq!(solved.rows[r][col], x.rows[r][col], epsilon = 1e-12);
+                            assert_abs_diff_eq!(
+                                ax.rows[r][col] + xb.rows[r][col],
+                                c.rows[r][col],
+                                epsilon = 1e-12
+                            );
+                        }
+                    }
                 }
             }
         };
     }
 
-    gen_det_errbound_const_eval_tests!(2);
-    gen_det_errbound_const_eval_tests!(3);
-    gen_det_errbound_const_eval_tests!(4);
+    gen_sylvester_tests!(2);
+    gen_sylvester_tests!(3);
+    gen_sylvester_tests!(4);
+    gen_sylvester_tests!(5);
 
     #[test]
-    fn det_errbound_const_eval_d5_is_none() {
-        // D ≥ 5 has no fast-filter bound; `det_errbound` returns `Ok(None)`.
-        const BOUND: Result<Option<f64>, LaError> = Matrix::<5>::identity().det_errbound();
-        assert_eq!(BOUND, Ok(None));
+    fn solve_sylvester_supports_largest_dimension() {
+        let a = Matrix::<8>::identity();
+        let c = Matrix::<8>::try_from_rows([[2.0; 8]; 8]).unwrap();
+        let x = Matrix::solve_sylvester(&a, &a, &c, DEFAULT_SINGULAR_TOL).unwrap();
+        assert_eq!(x, Matrix::try_from_rows([[1.0; 8]; 8]).unwrap());
     }
 
-    // === inf_norm const-evaluability tests (D = 2..=5) ===
+    #[test]
+    fn solve_sylvester_rejects_unsupported_dimension() {
+        let a = Matrix::<9>::identity();
+        assert_eq!(
+            Matrix::solve_sylvester(&a, &a, &a, DEFAULT_SINGULAR_TOL),
+            Err(LaError::unsupported_dimension(9, 8))
+        );
+    }
 
-    macro_rules! gen_inf_norm_const_eval_tests {
+    #[test]
+    fn solve_sylvester_reports_shared_opposite_eigenvalues() {
+        // λ(A) = 1 and μ(B) = -1 make I ⊗ A + Bᵀ ⊗ I singular.
+        let a = Matrix::<2>::identity();
+        let b = Matrix::<2>::identity().add_scaled_identity(-2.0).unwrap();
+        let c = Matrix::<2>::identity();
+        assert_matches!(
+            Matrix::solve_sylvester(&a, &b, &c, DEFAULT_SINGULAR_TOL),
+            Err(LaError::Singular { .. })
+        );
+    }
+
+    #[test]
+    fn solve_sylvester_handles_empty_and_scalar_systems() {
+        let z = Matrix::<0>::zero();
+        assert_eq!(
+            Matrix::solve_sylvester(&z, &z, &z, DEFAULT_SINGULAR_TOL),
+            Ok(z)
+        );
+
+        let a = Matrix::<1>::try_from_rows([[3.0]]).unwrap();
+        let b = Matrix::<1>::try_from_rows([[1.0]]).unwrap();
+        let c = Matrix::<1>::try_from_rows([[8.0]]).unwrap();
+        assert_eq!(
+            Matrix::solve_sylvester(&a, &b, &c, DEFAULT_SINGULAR_TOL),
+            Ok(Matrix::try_from_rows([[2.0]]).unwrap())
+        );
+    }
+
+    #[test]
+    fn solve_sylvester_reports_overflowing_system() {
+        let a = Matrix::<1>::try_from_rows([[f64::MAX]]).unwrap();
+        assert_eq!(
+            Matrix::solve_sylvester(&a, &a, &a, DEFAULT_SINGULAR_TOL),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::SylvesterSystem,
+                0,
+                0
+            ))
+        );
+    }
+
+    #[test]
+    fn solve_lyapunov_matches_known_stable_2x2_solution() {
+        // Companion form of s² + 3s + 2, eigenvalues -1 and -2.
+        let a = Matrix::<2>::try_from_rows([[0.0, 1.0], [-2.0, -3.0]]).unwrap();
+        let q = Matrix::<2>::identity();
+
+        let x = Matrix::solve_lyapunov(&a, &q, DEFAULT_SINGULAR_TOL).unwrap();
+        let expected = [[1.0, -0.5], [-0.5, 0.5]];
+        for r in 0..2 {
+            for c in 0..2 {
+                assert_abs_diff_eq!(x.rows[r][c], expected[r][c], epsilon = 1e-12);
+            }
+        }
+        assert_eq!(x, x.transpose());
+        assert!(x.ldlt(DEFAULT_SINGULAR_TOL).is_ok());
+    }
+
+    macro_rules! gen_lyapunov_tests {
         ($d:literal) => {
             paste! {
-                /// `Matrix::<D>::inf_norm()` on the identity must const-evaluate
-                /// to `1.0` for every `D ≥ 1` — each row has a single `1.0`
-                /// entry, so the max absolute row sum is exactly `1.0`.
                 #[test]
-                fn [<inf_norm_const_eval_ $d d>]() {
-                    const NORM: Result<f64, LaError> = Matrix::<$d>::identity().inf_norm();
-                    assert!((NORM.unwrap() - 1.0).abs() <= 1e-12);
+                fn [<solve_lyapunov_is_symmetric_and_satisfies_equation_ $d d>]() {
+                    let a = sylvester_fixture::<$d>(0)
+                        .add_scaled_identity(-4.0 * f64::from($d))
+                        .unwrap();
+                    let mut q = [[0.0; $d]; $d];
+                    for (r, row) in q.iter_mut().enumerate() {
+                        for (c, value) in row.iter_mut().enumerate() {
+                            *value = if r == c { 2.0 } else { 0.25 };
+                        }
+                    }
+                    let q = Matrix::<$d>::try_from_rows(q).unwrap();
+
+                    let x = Matrix::solve_lyapunov(&a, &q, DEFAULT_SINGULAR_TOL).unwrap();
+                    assert_eq!(x, x.transpose());
+
+                    let ax = a.matmul(&x, ArithmeticOperation::MatrixProduct).unwrap();
+                    let xat = x.matmul(&a.transpose(), ArithmeticOperation::MatrixProduct).unwrap();
+                    for r in 0..$d {
+                        for c in 0..$d {
+                            assert_abs_diff_eq!(
+                                ax.rows[r][c] + xat.rows[r][c],
+                                -q.rows[r][c],
+                                epsilon = 1e-12
+                            );
+                        }
+                    }
                 }
             }
         };
     }
 
-    gen_inf_norm_const_eval_tests!(2);
-    gen_inf_norm_const_eval_tests!(3);
-    gen_inf_norm_const_eval_tests!(4);
-    gen_inf_norm_const_eval_tests!(5);
+    gen_lyapunov_tests!(2);
+    gen_lyapunov_tests!(3);
+    gen_lyapunov_tests!(4);
+    gen_lyapunov_tests!(5);
 
-    // === is_symmetric / first_asymmetry (public LDLT preconditions helpers) ===
+    #[test]
+    fn solve_lyapunov_keeps_asymmetric_q_solution_unsymmetrized() {
+        let a = Matrix::<2>::try_from_rows([[-1.0, 0.0], [0.0, -2.0]]).unwrap();
+        let q = Matrix::<2>::try_from_rows([[0.0, 3.0], [0.0, 0.0]]).unwrap();
+        // X[0][1] (a₀ + a₁) = -Q[0][1], so X[0][1] = 1 and X[1][0] = 0.
+        let x = Matrix::solve_lyapunov(&a, &q, DEFAULT_SINGULAR_TOL).unwrap();
+        assert_abs_diff_eq!(x.rows[0][1], 1.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(x.rows[1][0], 0.0, epsilon = 1e-12);
+    }
 
-    macro_rules! gen_is_symmetric_tests {
+    macro_rules! gen_block_2x2_tests {
         ($d:literal) => {
             paste! {
                 #[test]
-                fn [<is_symmetric_true_for_identity_ $d d>]() {
-                    let m = Matrix::<$d>::identity();
-                    assert!(m.is_symmetric(Tolerance::try_new(1e-12).unwrap()).unwrap());
-                    assert_eq!(m.first_asymmetry(Tolerance::try_new(1e-12).unwrap()).unwrap(), None);
-                }
-
-                #[test]
-                fn [<is_symmetric_true_for_zero_ $d d>]() {
-                    let m = Matrix::<$d>::zero();
-                    assert!(m.is_symmetric(Tolerance::try_new(1e-12).unwrap()).unwrap());
-                    assert_eq!(m.first_asymmetry(Tolerance::try_new(1e-12).unwrap()).unwrap(), None);
-                }
-
-                #[test]
-                fn [<is_symmetric_true_for_constructed_symmetric_ $d d>]() {
-                    // Construct A = M + Mᵀ so A is provably symmetric.
-                    let mut m = [[0.0f64; $d]; $d];
+                fn [<solve_block_2x2_matches_assembled_system_ $d d>]() {
+                    let shift = 4.0 * f64::from($d);
+                    let a = sylvester_fixture::<$d>(0).add_scaled_identity(shift).unwrap();
+                    let b = sylvester_fixture::<$d>(1);
+                    let c = sylvester_fixture::<$d>(2);
+                    // A zero D block gives a saddle-point structure.
+                    let d = Matrix::<$d>::zero().add_scaled_identity(-shift).unwrap();
+                    let f = Vector::<$d>::new(core::array::from_fn(|i| {
+                        f64::from(u32::try_from(i).unwrap()) - 1.0
+                    }));
+                    let g = Vector::<$d>::new([0.5; $d]);
+
+                    let mut assembled = [[0.0; 2 * $d]; 2 * $d];
                     for r in 0..$d {
-                        for c in 0..$d {
-                            #[expect(
-                                clippy::cast_precision_loss,
-                                reason = "matrix test indices are at most five and exactly representable as f64"
-                            )]
-                            {
-                                m[r][c] = (r * $d + c) as f64;
-                            }
+                        for col in 0..$d {
+                            assembled[r][col] = a.rows[r][col];
+                            assembled[r][col + $d] = b.rows[r][col];
+                            assembled[r + $d][col] = c.rows[r][col];
+                            assembled[r + $d][col + $d] = d.rows[r][col];
                         }
                     }
-                    let mut sym = [[0.0f64; $d]; $d];
-                    for r in 0..$d {
-                        for c in 0..$d {
-                            sym[r][c] = m[r][c] + m[c][r];
-                        }
+                    let mut rhs = [0.0; 2 * $d];
+                    rhs[..$d].copy_from_slice(f.as_array());
+                    rhs[$d..].copy_from_slice(g.as_array());
+                    let expected = Matrix::<{ 2 * $d }>::try_from_rows(assembled)
+                        .unwrap()
+                        .lu(DEFAULT_SINGULAR_TOL)
+                        .unwrap()
+                        .solve(Vector::new(rhs))
+                        .unwrap()
+                        .into_array();
+
+                    let (x, y) =
+                        Matrix::solve_block_2x2(&a, &b, &c, &d, f, g, DEFAULT_SINGULAR_TOL).unwrap();
+                    for (&got, &want) in x.as_array().iter().chain(y.as_array()).zip(&expected) {
+                        assert_abs_diff_eq!(got, want, epsilon = 1e-12);
                     }
-                    let a = Matrix::<$d>::try_from_rows(sym).unwrap();
-                    assert!(a.is_symmetric(Tolerance::try_new(1e-12).unwrap()).unwrap());
-                    assert_eq!(a.first_asymmetry(Tolerance::try_new(1e-12).unwrap()).unwrap(), None);
                 }
+            }
+        };
+    }
+
+    gen_block_2x2_tests!(2);
+    gen_block_2x2_tests!(3);
+    gen_block_2x2_tests!(4);
+    gen_block_2x2_tests!(5);
+
+    #[test]
+    fn solve_block_2x2_rejects_singular_blocks_and_overflow() {
+        let identity = Matrix::<2>::identity();
+        let zero = Matrix::<2>::zero();
+        let f = Vector::<2>::new([1.0, 2.0]);
+        let g = Vector::<2>::new([3.0, 4.0]);
+
+        // Singular A, even though [0 I; I 0] is invertible.
+        assert_matches!(
+            Matrix::solve_block_2x2(
+                &zero,
+                &identity,
+                &identity,
+                &zero,
+                f,
+                g,
+                DEFAULT_SINGULAR_TOL
+            ),
+            Err(LaError::Singular { .. })
+        );
+        // S = D − C A⁻¹ B = I − I = 0.
+        assert_matches!(
+            Matrix::solve_block_2x2(
+                &identity,
+                &identity,
+                &identity,
+                &identity,
+                f,
+                g,
+                DEFAULT_SINGULAR_TOL
+            ),
+            Err(LaError::Singular { .. })
+        );
+
+        let huge = Matrix::<2>::try_from_rows([[f64::MAX, 0.0], [0.0, f64::MAX]]).unwrap();
+        let neg_huge = Matrix::<2>::try_from_rows([[-f64::MAX, 0.0], [0.0, -f64::MAX]]).unwrap();
+        assert_eq!(
+            Matrix::solve_block_2x2(
+                &identity,
+                &huge,
+                &huge,
+                &neg_huge,
+                f,
+                g,
+                DEFAULT_SINGULAR_TOL
+            ),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::SchurComplement,
+                0,
+                0
+            ))
+        );
+    }
 
+    macro_rules! gen_schur_complement_tests {
+        ($d:literal, $k:literal, $m:literal) => {
+            paste! {
                 #[test]
-                fn [<is_symmetric_false_for_asymmetric_offdiagonal_ $d d>]() {
-                    // Perturb a single off-diagonal entry so symmetry fails.
-                    let mut rows = [[0.0f64; $d]; $d];
-                    for i in 0..$d {
-                        rows[i][i] = 1.0;
-                    }
-                    rows[0][$d - 1] = 1.0;
-                    rows[$d - 1][0] = -1.0; // breaks symmetry
-                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
-                    assert!(!a.is_symmetric(Tolerance::try_new(1e-12).unwrap()).unwrap());
-                    assert_eq!(
-                        a.first_asymmetry(Tolerance::try_new(1e-12).unwrap()).unwrap(),
-                        Some((0, $d - 1))
+                fn [<schur_complement_matches_det_and_inverse_block_ $d d_ $k k>]() {
+                    let a = Matrix::<$d>::try_from_rows(core::array::from_fn(|r| {
+                        core::array::from_fn(|c| {
+                            if r == c {
+                                f64::from($d + 2)
+                            } else {
+                                f64::from(u32::try_from((r + 3 * c) % 5).unwrap()) / 4.0 - 0.5
+                            }
+                        })
+                    }))
+                    .unwrap();
+                    let s = a.schur_complement::<$k, $m>(DEFAULT_SINGULAR_TOL).unwrap();
+
+                    let a11 = Matrix::<$k>::try_from_rows(core::array::from_fn(|r| {
+                        core::array::from_fn(|c| a.rows[r][c])
+                    }))
+                    .unwrap();
+                    assert_relative_eq!(
+                        a.det().unwrap(),
+                        a11.det().unwrap() * s.det().unwrap(),
+                        max_relative = 1e-12
                     );
-                }
 
+                    let a_inv = a.lu(DEFAULT_SINGULAR_TOL).unwrap().inverse().unwrap();
+                    let s_inv = s.lu(DEFAULT_SINGULAR_TOL).unwrap().inverse().unwrap();
+                    for i in 0..$m {
+                        for j in 0..$m {
+                            assert_abs_diff_eq!(
+                                s_inv.rows[i][j],
+                                a_inv.rows[$k + i][$k + j],
+                                epsilon = 1e-12
+                            );
+                        }
+                    }
+                }
             }
         };
     }
 
-    gen_is_symmetric_tests!(2);
-    gen_is_symmetric_tests!(3);
-    gen_is_symmetric_tests!(4);
-    gen_is_symmetric_tests!(5);
+    gen_schur_complement_tests!(2, 1, 1);
+    gen_schur_complement_tests!(3, 1, 2);
+    gen_schur_complement_tests!(3, 2, 1);
+    gen_schur_complement_tests!(4, 2, 2);
+    gen_schur_complement_tests!(5, 2, 3);
+    gen_schur_complement_tests!(5, 4, 1);
 
-    macro_rules! gen_ldlt_symmetry_proof_tests {
+    #[test]
+    fn schur_complement_of_2x2_blocks_matches_hand_computation() {
+        // A₁₁ = diag(2, 4), A₁₂ = [[2, 0], [0, 4]], A₂₁ = [[1, 1], [0, 2]],
+        // A₂₂ = [[5, 1], [1, 6]]; A₁₁⁻¹ A₁₂ = I, so S = A₂₂ − A₂₁.
+        let a = Matrix::<4>::try_from_rows([
+            [2.0, 0.0, 2.0, 0.0],
+            [0.0, 4.0, 0.0, 4.0],
+            [1.0, 1.0, 5.0, 1.0],
+            [0.0, 2.0, 1.0, 6.0],
+        ])
+        .unwrap();
+        assert_eq!(
+            a.schur_complement::<2, 2>(DEFAULT_SINGULAR_TOL),
+            Ok(Matrix::<2>::try_from_rows([[4.0, 0.0], [1.0, 4.0]]).unwrap())
+        );
+        assert_eq!(a.schur_complement::<0, 4>(DEFAULT_SINGULAR_TOL), Ok(a));
+    }
+
+    #[test]
+    fn schur_complement_rejects_bad_split_singular_block_and_overflow() {
+        let a = Matrix::<3>::try_from_rows([[0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]])
+            .unwrap();
+        assert_eq!(
+            a.schur_complement::<1, 1>(DEFAULT_SINGULAR_TOL),
+            Err(LaError::dimension_mismatch(3, 2))
+        );
+        assert_matches!(
+            a.schur_complement::<1, 2>(DEFAULT_SINGULAR_TOL),
+            Err(LaError::Singular { pivot_col: 0, .. })
+        );
+
+        let huge = Matrix::<2>::try_from_rows([[1.0, f64::MAX], [-f64::MAX, -f64::MAX]]).unwrap();
+        assert_eq!(
+            huge.schur_complement::<1, 1>(DEFAULT_SINGULAR_TOL),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::SchurComplement,
+                0,
+                0
+            ))
+        );
+    }
+
+    macro_rules! gen_circulant_tests {
         ($d:literal) => {
             paste! {
                 #[test]
-                fn [<matrix_ldlt_accepts_exact_symmetric_spd_ $d d>]() {
-                    // This exactly mirrored, strictly diagonally dominant
-                    // tridiagonal matrix is positive definite.
-                    let mut rows = [[0.0_f64; $d]; $d];
-                    for (index, row) in rows.iter_mut().enumerate() {
-                        row[index] = 2.0;
+                fn [<circulant_mul_vec_matches_dense_mul_vec_ $d d>]() {
+                    let mut c = [0.0; $d];
+                    let mut x = [0.0; $d];
+                    for i in 0..$d {
+                        let k = f64::from(u32::try_from(i).unwrap());
+                        c[i] = 0.5f64.mul_add(k, -1.0);
+                        x[i] = k.mul_add(-k, 3.0);
                     }
-                    for index in 1..$d {
-                        rows[index - 1][index] = 0.5;
-                        rows[index][index - 1] = 0.5;
+                    let c = Vector::<$d>::new(c);
+                    let x = Vector::<$d>::new(x);
+
+                    let dense = Matrix::circulant(c);
+                    assert_eq!(Matrix::mul_vec_circulant(&c, &x), dense.mul_vec(&x));
+
+                    // Every row is the previous one rotated right by one.
+                    for i in 1..$d {
+                        for j in 0..$d {
+                            assert_eq!(
+                                dense.rows[i][j].to_bits(),
+                                dense.rows[i - 1][(j + $d - 1) % $d].to_bits()
+                            );
+                        }
                     }
+                }
+            }
+        };
+    }
 
-                    let matrix = Matrix::<$d>::try_from_rows(rows).unwrap();
-                    let symmetric = SymmetricMatrix::try_new(matrix).unwrap();
-                    assert_eq!(symmetric.into_matrix(), matrix);
+    gen_circulant_tests!(2);
+    gen_circulant_tests!(3);
+    gen_circulant_tests!(4);
+    gen_circulant_tests!(5);
 
-                    let ldlt = matrix.ldlt(DEFAULT_SINGULAR_TOL).unwrap();
-                    assert!(ldlt.det().unwrap() > 0.0);
-                }
+    #[test]
+    fn circulant_handles_empty_and_reports_overflow() {
+        assert_eq!(Matrix::<0>::circulant(Vector::zero()), Matrix::zero());
+        assert_eq!(
+            Matrix::mul_vec_circulant(&Vector::<0>::zero(), &Vector::zero()),
+            Ok(Vector::zero())
+        );
+
+        let c = Vector::<2>::new([f64::MAX, 0.0]);
+        let v = Vector::<2>::new([0.0, 2.0]);
+        assert_eq!(
+            Matrix::mul_vec_circulant(&c, &v),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::CirculantProduct,
+                1
+            ))
+        );
+    }
 
+    macro_rules! gen_scale_rows_cols_tests {
+        ($d:literal) => {
+            paste! {
                 #[test]
-                fn [<symmetric_matrix_try_new_rejects_finite_asymmetric_ $d d>]() {
-                    let mut rows = [[0.0f64; $d]; $d];
-                    for (i, row) in rows.iter_mut().enumerate() {
-                        row[i] = 1.0;
+                fn [<scale_rows_and_cols_match_diagonal_products_ $d d>]() {
+                    let a = sylvester_fixture::<$d>(1);
+                    let ones = Vector::<$d>::new([1.0; $d]);
+                    assert_eq!(a.scale_rows(ones), Ok(a));
+                    assert_eq!(a.scale_cols(ones), Ok(a));
+
+                    let mut d = [0.0; $d];
+                    let mut diag = [[0.0; $d]; $d];
+                    for i in 0..$d {
+                        d[i] = f64::from(u32::try_from(i).unwrap()) - 0.5;
+                        diag[i][i] = d[i];
                     }
-                    rows[0][$d - 1] = 1.0;
-                    rows[$d - 1][0] = -1.0;
-
+                    let d = Vector::<$d>::new(d);
+                    let diag = Matrix::<$d>::try_from_rows(diag).unwrap();
                     assert_eq!(
-                        Matrix::<$d>::try_from_rows(rows).and_then(SymmetricMatrix::try_new),
-                        Err(LaError::asymmetric(0, $d - 1, $d, 1.0, -1.0, 0.0))
+                        a.scale_rows(d),
+                        diag.matmul(&a, ArithmeticOperation::MatrixProduct)
+                    );
+                    assert_eq!(
+                        a.scale_cols(d),
+                        a.matmul(&diag, ArithmeticOperation::MatrixProduct)
                     );
                 }
             }
         };
     }
 
-    gen_ldlt_symmetry_proof_tests!(2);
-    gen_ldlt_symmetry_proof_tests!(3);
-    gen_ldlt_symmetry_proof_tests!(4);
-    gen_ldlt_symmetry_proof_tests!(5);
+    gen_scale_rows_cols_tests!(2);
+    gen_scale_rows_cols_tests!(3);
+    gen_scale_rows_cols_tests!(4);
+    gen_scale_rows_cols_tests!(5);
 
     #[test]
-    fn symmetric_matrix_into_matrix_roundtrips_storage_internally() {
-        let a = Matrix::<2>::try_from_rows([[2.0, 1.0], [1.0, 3.0]]).unwrap();
-        let symmetric = SymmetricMatrix::try_new(a).unwrap();
-
-        assert_eq!(symmetric.into_matrix(), a);
+    fn scale_rows_and_cols_report_overflow_coordinates() {
+        let a = Matrix::<2>::try_from_rows([[1.0, 1.0], [1.0, f64::MAX]]).unwrap();
+        let d = Vector::<2>::new([1.0, 2.0]);
+        assert_eq!(
+            a.scale_rows(d),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::DiagonalScaling,
+                1,
+                1
+            ))
+        );
+        assert_eq!(
+            a.scale_cols(d),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::DiagonalScaling,
+                1,
+                1
+            ))
+        );
     }
 
-    #[test]
-    fn matrix_ldlt_accepts_opposite_signed_zero_mirrors() {
-        let matrix = Matrix::<2>::try_from_rows([[2.0, 0.0], [-0.0, 2.0]]).unwrap();
-        let ldlt = matrix.ldlt(DEFAULT_SINGULAR_TOL).unwrap();
+    macro_rules! gen_to_correlation_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<to_correlation_of_diagonal_covariance_is_identity_ $d d>]() {
+                    let variances = Vector::<$d>::new(core::array::from_fn(|i| {
+                        f64::from(u32::try_from(i).unwrap()).mul_add(2.5, 0.5)
+                    }));
+                    let correlation = variances.to_diagonal().to_correlation(DEFAULT_SINGULAR_TOL);
+                    assert_eq!(correlation, Ok(Matrix::<$d>::identity()));
+                }
 
-        assert_eq!(ldlt.det(), Ok(4.0));
+                #[test]
+                fn [<to_correlation_undoes_diagonal_scaling_ $d d>]() {
+                    // A correlation R scaled as S R S has correlation R again.
+                    let mut r_rows = [[0.0; $d]; $d];
+                    for (r, row) in r_rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            *entry = if r == c { 1.0 } else { 0.5f64.powi(i32::try_from(r.abs_diff(c)).unwrap()) };
+                        }
+                    }
+                    let correlation = Matrix::<$d>::try_from_rows(r_rows).unwrap();
+                    let sigma = Vector::<$d>::new(core::array::from_fn(|i| f64::from(1_u32 << i)));
+                    let covariance = correlation.scale_rows(sigma).unwrap().scale_cols(sigma).unwrap();
+
+                    let recovered = covariance.to_correlation(DEFAULT_SINGULAR_TOL).unwrap();
+                    assert_eq!(recovered, recovered.transpose());
+                    for (got, want) in recovered.rows.iter().zip(&correlation.rows) {
+                        for (&x, &y) in got.iter().zip(want) {
+                            assert_abs_diff_eq!(x, y, epsilon = 1e-15);
+                        }
+                    }
+                }
+            }
+        };
     }
 
-    #[test]
-    fn is_symmetric_tolerance_scales_with_inf_norm() {
-        // Off-diagonal entries differ by 1e-6.  With inf_norm ≈ 2e6, the
-        // relative tolerance 1e-12 yields eps ≈ 2e-6, which accepts the gap;
-        // a stricter tol of 1e-15 rejects it.
-        let a = Matrix::<2>::try_from_rows([[1.0e6, 1.0e6 + 1.0e-6], [1.0e6, 1.0e6]]).unwrap();
-        assert!(a.is_symmetric(Tolerance::try_new(1e-12).unwrap()).unwrap());
-        assert!(!a.is_symmetric(Tolerance::try_new(1e-15).unwrap()).unwrap());
-    }
+    gen_to_correlation_tests!(2);
+    gen_to_correlation_tests!(3);
+    gen_to_correlation_tests!(4);
+    gen_to_correlation_tests!(5);
 
     #[test]
-    fn symmetry_epsilon_multiplies_after_row_sum_near_subnormal_boundary() {
-        let min_subnormal = f64::from_bits(1);
-        let mut rows = [[0.0; 5]; 5];
-        let mut col = 0;
-        while col < 4 {
-            rows[0][col] = 0.4;
-            rows[col][0] = 0.4;
-            col += 1;
-        }
-        rows[0][4] = 2.0 * min_subnormal;
-        rows[4][0] = 0.0;
+    fn to_correlation_rejects_invalid_variances_and_reports_overflow() {
+        let tol = Tolerance::try_new(1e-6).unwrap();
+        let negative = Matrix::<2>::try_from_rows([[1.0, 0.0], [0.0, -2.0]]).unwrap();
+        assert_eq!(
+            negative.to_correlation(tol),
+            Err(LaError::not_positive_semidefinite_negative(1, -2.0))
+        );
+        let zero = Matrix::<2>::try_from_rows([[0.0, 0.0], [0.0, 1.0]]).unwrap();
+        assert_eq!(zero.to_correlation(tol), Err(LaError::singular_exact(0)));
+        let tiny = Matrix::<2>::try_from_rows([[1.0, 0.0], [0.0, 1e-7]]).unwrap();
+        assert_eq!(
+            tiny.to_correlation(tol),
+            Err(LaError::singular_numerical(
+                1,
+                FactorizationKind::Diagonal,
+                1e-7,
+                1e-6
+            ))
+        );
+        assert!(tiny.to_correlation(Tolerance::ZERO).is_ok());
 
-        let matrix = Matrix::<5>::try_from_rows(rows).unwrap();
-        let tolerance = Tolerance::try_new(min_subnormal).unwrap();
-        let expected_epsilon = tolerance.get() * matrix.inf_norm().unwrap().max(1.0);
+        let not_psd = Matrix::<2>::try_from_rows([[1e-300, 1e300], [1e300, 1e-300]]).unwrap();
+        assert_eq!(
+            not_psd.to_correlation(Tolerance::ZERO),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::DiagonalScaling,
+                0,
+                1
+            ))
+        );
+    }
 
-        assert_eq!(expected_epsilon.to_bits(), 2);
-        assert_eq!(matrix.first_asymmetry(tolerance), Ok(None));
-        assert_eq!(matrix.is_symmetric(tolerance), Ok(true));
+    macro_rules! gen_reciprocal_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<reciprocal_is_entrywise_involution_on_powers_of_two_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        for (c, entry) in row.iter_mut().enumerate() {
+                            let exponent = i32::try_from((r + 2 * c) % 5).unwrap() - 2;
+                            let sign = if (r + c) % 2 == 0 { 1.0 } else { -1.0 };
+                            *entry = sign * 2.0_f64.powi(exponent);
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let r = a.reciprocal(DEFAULT_SINGULAR_TOL).unwrap();
+                    for (r_row, a_row) in r.rows.iter().zip(&a.rows) {
+                        for (&x, &y) in r_row.iter().zip(a_row) {
+                            assert_abs_diff_eq!(x * y, 1.0, epsilon = 0.0);
+                        }
+                    }
+                    assert_eq!(r.reciprocal(DEFAULT_SINGULAR_TOL), Ok(a));
+                    assert_eq!(
+                        Matrix::<$d>::identity().reciprocal(DEFAULT_SINGULAR_TOL),
+                        Err(LaError::singular_exact(1))
+                    );
+                }
+            }
+        };
     }
 
+    gen_reciprocal_tests!(2);
+    gen_reciprocal_tests!(3);
+    gen_reciprocal_tests!(4);
+    gen_reciprocal_tests!(5);
+
     #[test]
-    fn symmetry_epsilon_scales_terms_when_row_sum_overflows() {
-        let matrix =
-            Matrix::<2>::try_from_rows([[f64::MAX, f64::MAX], [f64::MAX / 2.0, f64::MAX]]).unwrap();
+    fn reciprocal_rejects_small_entries_and_reports_overflow() {
+        let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 1e-13]]).unwrap();
+        assert_eq!(
+            a.reciprocal(DEFAULT_SINGULAR_TOL),
+            Err(LaError::singular_numerical(
+                3,
+                FactorizationKind::Diagonal,
+                1e-13,
+                DEFAULT_SINGULAR_TOL.get()
+            ))
+        );
+        assert!(a.reciprocal(Tolerance::ZERO).is_ok());
 
+        let subnormal = Matrix::<2>::try_from_rows([[1.0, f64::from_bits(1)], [1.0, 1.0]]).unwrap();
         assert_eq!(
-            matrix.inf_norm(),
+            subnormal.reciprocal(Tolerance::ZERO),
             Err(LaError::non_finite_computation_matrix(
-                ArithmeticOperation::MatrixInfinityNorm,
+                ArithmeticOperation::EntrywiseReciprocal,
                 0,
                 1
             ))
         );
         assert_eq!(
-            matrix.first_asymmetry(Tolerance::try_new(0.25).unwrap()),
-            Ok(None)
-        );
-        assert_eq!(
-            matrix.first_asymmetry(Tolerance::try_new(0.125).unwrap()),
-            Ok(Some((0, 1)))
+            Matrix::<0>::zero().reciprocal(Tolerance::ZERO),
+            Ok(Matrix::<0>::zero())
         );
     }
 
+    macro_rules! gen_invert_triangular_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<invert_triangular_stays_triangular_and_inverts_ $d d>]() {
+                    // Upper triangle of interest; the lower triangle holds
+                    // junk that must be ignored.
+                    let mut rows = [[0.0; $d]; $d];
+                    let mut upper = [[0.0; $d]; $d];
+                    for (r, (row, upper_row)) in rows.iter_mut().zip(&mut upper).enumerate() {
+                        for (c, (entry, upper_entry)) in row.iter_mut().zip(upper_row).enumerate() {
+                            let value = f64::from(u32::try_from(r + 2 * c).unwrap()) + 1.0;
+                            *entry = if r > c { -7.0 } else { value };
+                            if r <= c {
+                                *upper_entry = value;
+                            }
+                        }
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let u = Matrix::<$d>::try_from_rows(upper).unwrap();
+
+                    let inv = a.invert_upper_triangular(DEFAULT_SINGULAR_TOL).unwrap();
+                    assert_eq!(inv.lower_bandwidth(), 0);
+                    let product = u.matmul(&inv, ArithmeticOperation::MatrixProduct).unwrap();
+                    assert!(product.inf_distance(&Matrix::<$d>::identity()) <= 1e-12);
+
+                    // The transpose is lower triangular with the same diagonal.
+                    let l = u.transpose();
+                    let inv_l = l.invert_lower_triangular(DEFAULT_SINGULAR_TOL).unwrap();
+                    assert_eq!(inv_l.upper_bandwidth(), 0);
+                    // Substitution sums in a different order, so compare up to rounding.
+                    assert!(inv_l.inf_distance(&inv.transpose()) <= 1e-14);
+                    assert_eq!(
+                        Matrix::<$d>::identity().invert_lower_triangular(Tolerance::ZERO),
+                        Ok(Matrix::<$d>::identity())
+                    );
+                }
+            }
+        };
+    }
+
+    gen_invert_triangular_tests!(2);
+    gen_invert_triangular_tests!(3);
+    gen_invert_triangular_tests!(4);
+    gen_invert_triangular_tests!(5);
+
     #[test]
-    fn first_asymmetry_returns_lexicographically_first_pair() {
-        // Two asymmetric pairs: (0, 2) and (1, 2).  We must get (0, 2) first.
-        let a = Matrix::<3>::try_from_rows([[1.0, 0.0, 2.0], [0.0, 1.0, 3.0], [-2.0, -3.0, 1.0]])
+    fn invert_triangular_rejects_small_pivots_and_reports_overflow() {
+        let zero = Matrix::<3>::try_from_rows([[1.0, 2.0, 3.0], [0.0, 0.0, 1.0], [0.0, 0.0, 0.0]])
             .unwrap();
         assert_eq!(
-            a.first_asymmetry(Tolerance::try_new(1e-12).unwrap())
-                .unwrap(),
-            Some((0, 2))
+            zero.invert_upper_triangular(Tolerance::ZERO),
+            Err(LaError::singular_exact(1))
+        );
+        assert_eq!(
+            zero.transpose().invert_lower_triangular(Tolerance::ZERO),
+            Err(LaError::singular_exact(1))
         );
-    }
-
-    #[test]
-    fn first_asymmetry_strict_tol_survives_row_sum_overflow() {
-        let a = Matrix::<3>::try_from_rows([
-            [1.0, 1.0, 0.0],
-            [2.0, f64::MAX, f64::MAX],
-            [0.0, 0.0, 1.0],
-        ])
-        .unwrap();
 
+        let tiny = Matrix::<2>::try_from_rows([[1.0, 0.0], [0.0, -1e-13]]).unwrap();
+        let expected = Err(LaError::singular_numerical(
+            1,
+            FactorizationKind::Diagonal,
+            1e-13,
+            DEFAULT_SINGULAR_TOL.get(),
+        ));
+        assert_eq!(tiny.invert_upper_triangular(DEFAULT_SINGULAR_TOL), expected);
+        assert_eq!(tiny.invert_lower_triangular(DEFAULT_SINGULAR_TOL), expected);
+        assert!(tiny.invert_upper_triangular(Tolerance::ZERO).is_ok());
+
+        let growth = Matrix::<2>::try_from_rows([[1e-200, 1e200], [0.0, 1e-200]]).unwrap();
         assert_eq!(
-            a.inf_norm(),
+            growth.invert_upper_triangular(Tolerance::ZERO),
             Err(LaError::non_finite_computation_matrix(
-                ArithmeticOperation::MatrixInfinityNorm,
-                1,
-                2
+                ArithmeticOperation::TriangularInverse,
+                0,
+                1
             ))
         );
         assert_eq!(
-            a.first_asymmetry(Tolerance::try_new(0.0).unwrap()).unwrap(),
-            Some((0, 1))
-        );
-        assert!(!a.is_symmetric(Tolerance::try_new(0.0).unwrap()).unwrap());
-    }
-
-    #[test]
-    fn first_asymmetry_rejects_scaled_epsilon_overflow() {
-        let a = Matrix::<2>::try_from_rows([[0.0, 0.0], [2.0, 1.0]]).unwrap();
-        let tol = Tolerance::try_new(f64::MAX).unwrap();
-
-        assert_eq!(
-            a.first_asymmetry(tol),
+            growth.transpose().invert_lower_triangular(Tolerance::ZERO),
             Err(LaError::non_finite_computation_matrix(
-                ArithmeticOperation::SymmetryCheck,
+                ArithmeticOperation::TriangularInverse,
                 1,
                 0
             ))
         );
+        let subnormal = Matrix::<1>::try_from_rows([[f64::from_bits(1)]]).unwrap();
         assert_eq!(
-            a.is_symmetric(tol),
+            subnormal.invert_lower_triangular(Tolerance::ZERO),
             Err(LaError::non_finite_computation_matrix(
-                ArithmeticOperation::SymmetryCheck,
-                1,
+                ArithmeticOperation::TriangularInverse,
+                0,
                 0
             ))
         );
+        assert_eq!(
+            Matrix::<0>::zero().invert_upper_triangular(Tolerance::ZERO),
+            Ok(Matrix::<0>::zero())
+        );
+    }
+
+    macro_rules! gen_power_iteration_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<power_iteration_recovers_largest_diagonal_entry_ $d d>]() {
+                    let mut rows = [[0.0; $d]; $d];
+                    for (i, row) in rows.iter_mut().enumerate() {
+                        row[i] = f64::from(u32::try_from(i).unwrap()) + 1.0;
+                    }
+                    // The dominant entry is negative and sits in the middle.
+                    rows[$d / 2][$d / 2] = -2.0 * f64::from($d);
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+
+                    let tol = Tolerance::try_new(1e-10).unwrap();
+                    let (lambda, v) = a.power_iteration(tol, 500).unwrap();
+                    assert_abs_diff_eq!(lambda, -2.0 * f64::from($d), epsilon = 1e-9);
+                    let v = v.into_array();
+                    assert_abs_diff_eq!(v[$d / 2].abs(), 1.0, epsilon = 1e-9);
+                }
+            }
+        };
+    }
+
+    gen_power_iteration_tests!(2);
+    gen_power_iteration_tests!(3);
+    gen_power_iteration_tests!(4);
+    gen_power_iteration_tests!(5);
+
+    #[test]
+    fn power_iteration_reports_non_convergence() {
+        // Eigenvalues ±1 have equal magnitude, so the iterates oscillate.
+        let a = Matrix::<2>::try_from_rows([[1.0, 0.0], [0.0, -1.0]]).unwrap();
+        let tol = Tolerance::try_new(1e-12).unwrap();
+        assert_matches!(
+            a.power_iteration(tol, 10),
+            Err(LaError::NotConverged { iterations: 10, .. })
+        );
     }
 
     #[test]
-    fn first_asymmetry_flags_overflowed_finite_difference() {
-        let a = Matrix::<2>::try_from_rows([[1.0, f64::MAX], [-f64::MAX, 1.0]]).unwrap();
+    fn power_iteration_handles_null_and_empty_inputs() {
+        let tol = Tolerance::try_new(1e-12).unwrap();
+        let (lambda, _) = Matrix::<3>::zero().power_iteration(tol, 0).unwrap();
+        assert_eq!(lambda.to_bits(), 0.0f64.to_bits());
+
+        let (lambda, v) = Matrix::<0>::zero().power_iteration(tol, 0).unwrap();
+        assert_eq!(lambda.to_bits(), 0.0f64.to_bits());
+        assert_eq!(v, Vector::zero());
+    }
+
+    macro_rules! gen_eigen_near_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<eigen_near_targets_interior_diagonal_entry_ $d d>]() {
+                    // Diagonal entries 1, 2, …, D; target the one at D / 2.
+                    let mut rows = [[0.0; $d]; $d];
+                    for (i, row) in rows.iter_mut().enumerate() {
+                        row[i] = f64::from(u32::try_from(i).unwrap()) + 1.0;
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+                    let target = f64::from($d / 2) + 1.0;
+
+                    let tol = Tolerance::try_new(1e-12).unwrap();
+                    let (lambda, v) = a.eigen_near(target + 0.1, tol, 100).unwrap();
+                    assert_abs_diff_eq!(lambda, target, epsilon = 1e-12);
+                    assert_abs_diff_eq!(v.into_array()[$d / 2].abs(), 1.0, epsilon = 1e-12);
+                }
+            }
+        };
+    }
+
+    gen_eigen_near_tests!(2);
+    gen_eigen_near_tests!(3);
+    gen_eigen_near_tests!(4);
+    gen_eigen_near_tests!(5);
+
+    #[test]
+    fn eigen_near_reports_invalid_shift_and_non_convergence() {
+        let a = Matrix::<2>::try_from_rows([[1.0, 0.0], [0.0, 3.0]]).unwrap();
+        let tol = Tolerance::try_new(1e-12).unwrap();
         assert_eq!(
-            a.first_asymmetry(Tolerance::try_new(1e-12).unwrap())
-                .unwrap(),
-            Some((0, 1))
+            a.eigen_near(f64::NAN, tol, 10),
+            Err(LaError::non_finite_input_scalar())
         );
-        assert!(!a.is_symmetric(Tolerance::try_new(1e-12).unwrap()).unwrap());
+        assert_matches!(a.eigen_near(3.0, tol, 10), Err(LaError::Singular { .. }));
+        // σ = 2 is equidistant from both eigenvalues, so no mode dominates.
+        assert_matches!(
+            a.eigen_near(2.0, tol, 10),
+            Err(LaError::NotConverged { iterations: 10, .. })
+        );
+    }
+
+    #[test]
+    fn spectral_radius_estimate_matches_known_eigenvalue_within_gershgorin() {
+        // Symmetric tridiag(1, 2, 1) in 4D has eigenvalues 2 + 2cos(kπ/5).
+        let a = Matrix::<4>::try_from_rows([
+            [2.0, 1.0, 0.0, 0.0],
+            [1.0, 2.0, 1.0, 0.0],
+            [0.0, 1.0, 2.0, 1.0],
+            [0.0, 0.0, 1.0, 2.0],
+        ])
+        .unwrap();
+        let expected = 2.0f64.mul_add((core::f64::consts::PI / 5.0).cos(), 2.0);
+
+        let tol = Tolerance::try_new(1e-10).unwrap();
+        let rho = a.spectral_radius_estimate(tol, 1000).unwrap();
+        assert_abs_diff_eq!(rho, expected, epsilon = 1e-9);
+
+        let (lower, upper) = a.gershgorin_bounds().unwrap();
+        assert!(rho <= lower.abs().max(upper.abs()));
+        assert!(rho <= a.inf_norm().unwrap());
+    }
+
+    #[test]
+    fn spectral_radius_estimate_rejects_rotation_spectrum() {
+        // A quarter-turn rotation has eigenvalues ±i, both of modulus 1.
+        let a = Matrix::<2>::try_from_rows([[0.0, -1.0], [1.0, 0.0]]).unwrap();
+        let tol = Tolerance::try_new(1e-12).unwrap();
+        assert_matches!(
+            a.spectral_radius_estimate(tol, 20),
+            Err(LaError::NotConverged { iterations: 20, .. })
+        );
+    }
+
+    // === spectral_norm ===
+
+    macro_rules! gen_spectral_norm_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<spectral_norm_of_diagonal_is_largest_abs_entry_ $d d>]() {
+                    // Diagonal entries 1, -2, 3, …, ±D.
+                    let mut rows = [[0.0; $d]; $d];
+                    for (i, row) in rows.iter_mut().enumerate() {
+                        let magnitude = f64::from(u32::try_from(i).unwrap()) + 1.0;
+                        row[i] = if i % 2 == 0 { magnitude } else { -magnitude };
+                    }
+                    let a = Matrix::<$d>::try_from_rows(rows).unwrap();
+
+                    let tol = Tolerance::try_new(1e-12).unwrap();
+                    let sigma = a.spectral_norm(tol, 500).unwrap();
+                    assert_abs_diff_eq!(sigma, f64::from($d), epsilon = 1e-12);
+                    assert!(sigma <= a.inf_norm().unwrap());
+                }
+
+                #[test]
+                fn [<spectral_norm_of_zero_is_zero_ $d d>]() {
+                    let tol = Tolerance::try_new(1e-12).unwrap();
+                    assert_eq!(Matrix::<$d>::zero().spectral_norm(tol, 10), Ok(0.0));
+                }
+            }
+        };
+    }
+
+    gen_spectral_norm_tests!(2);
+    gen_spectral_norm_tests!(3);
+    gen_spectral_norm_tests!(4);
+    gen_spectral_norm_tests!(5);
+
+    #[test]
+    fn spectral_norm_of_shear_is_golden_ratio_and_rejects_overflow() {
+        // σ_max([[1, 1], [0, 1]]) = (1 + √5) / 2.
+        let a = Matrix::<2>::try_from_rows([[1.0, 1.0], [0.0, 1.0]]).unwrap();
+        let tol = Tolerance::try_new(1e-12).unwrap();
+        let sigma = a.spectral_norm(tol, 200).unwrap();
+        assert_abs_diff_eq!(sigma, f64::midpoint(1.0, 5.0f64.sqrt()), epsilon = 1e-12);
+
+        let huge = Matrix::<2>::try_from_rows([[1e200, 0.0], [0.0, 1.0]]).unwrap();
+        assert_matches!(huge.spectral_norm(tol, 10), Err(LaError::NonFinite { .. }));
     }
 }
@@ -0,0 +1,397 @@
+#![forbid(unsafe_code)]
+
+//! Random matrix constructors for tests, benchmarks, and Monte Carlo
+//! experiments.
+//!
+//! This module is only compiled when the `"rand"` Cargo feature is enabled.
+//! Entries are drawn independently from the uniform distribution on a
+//! half-open range with [`rand::distr::Uniform`]; the caller supplies the
+//! generator, so seeded generators give reproducible matrices and vectors.
+//! Unit vectors normalize standard Gaussian samples from Marsaglia's polar
+//! method, whose rotation invariance makes the direction uniform on the
+//! sphere.
+
+use core::hint::cold_path;
+use core::ops::Range;
+
+use rand::Rng;
+use rand::distr::Uniform;
+
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::{ArithmeticOperation, LaError};
+
+/// Validate a half-open sampling range as a uniform distribution.
+fn uniform(range: Range<f64>) -> Result<Uniform<f64>, LaError> {
+    let Range { start, end } = range;
+    if !start.is_finite() || !end.is_finite() {
+        cold_path();
+        return Err(LaError::non_finite_input_scalar());
+    }
+    Uniform::new(start, end).map_err(|_| {
+        cold_path();
+        LaError::invalid_range(start, end)
+    })
+}
+
+/// Two independent standard normal samples by Marsaglia's polar method.
+///
+/// A point `(u, v)` drawn uniformly from the square `[-1, 1)²` is accepted
+/// when `0 < s = u² + v² < 1`; then `(u, v) · √(−2 ln s / s)` is a pair of
+/// independent `N(0, 1)` samples. Acceptance has probability `π/4`.
+fn standard_normal_pair<R: Rng + ?Sized>(rng: &mut R) -> (f64, f64) {
+    loop {
+        let u = 2.0_f64.mul_add(rng.random::<f64>(), -1.0);
+        let v = 2.0_f64.mul_add(rng.random::<f64>(), -1.0);
+        let s = u.mul_add(u, v * v);
+        if s > 0.0 && s < 1.0 {
+            let factor = (-2.0 * s.ln() / s).sqrt();
+            return (u * factor, v * factor);
+        }
+    }
+}
+
+impl<const D: usize> Matrix<D> {
+    /// Matrix with entries drawn independently and uniformly from `range`.
+    ///
+    /// Entries are sampled in row-major order, so a generator in a given
+    /// state always produces the same matrix. Every sample lies in
+    /// `range.start..range.end`, so the result is finite.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let mut rng = SmallRng::seed_from_u64(7);
+    /// let a = Matrix::<3>::random(&mut rng, -1.0..1.0)?;
+    /// assert!(a.into_rows().iter().flatten().all(|x| (-1.0..1.0).contains(x)));
+    ///
+    /// let mut again = SmallRng::seed_from_u64(7);
+    /// assert_eq!(Matrix::<3>::random(&mut again, -1.0..1.0)?, a);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] with scalar input location if either
+    /// end of `range` is NaN or infinite.
+    /// Returns [`LaError::InvalidRange`] if `range` is empty or its width
+    /// `end - start` overflows.
+    #[inline]
+    pub fn random<R: Rng + ?Sized>(rng: &mut R, range: Range<f64>) -> Result<Self, LaError> {
+        let uniform = uniform(range)?;
+        let mut rows = [[0.0; D]; D];
+        for row in &mut rows {
+            for value in row {
+                *value = rng.sample(uniform);
+            }
+        }
+        // Uniform samples lie inside the finite range.
+        Ok(Self::from_rows_unchecked(rows))
+    }
+
+    /// Random symmetric positive-definite matrix `MᵀM + εI`.
+    ///
+    /// `M` is drawn with [`random`](Self::random), and its Gram matrix `MᵀM`
+    /// is positive semidefinite; adding `ε = eps` to the diagonal lifts every
+    /// eigenvalue to at least `ε`. The Gram products are accumulated with
+    /// [`f64::mul_add`], which is commutative, so the result is exactly
+    /// symmetric and can be passed to [`ldlt`](Self::ldlt) directly. Pass an
+    /// `eps` above the factorization tolerance to guarantee that `LDLᵀ` accepts
+    /// every pivot. With `eps = 0` the result is `MᵀM` itself, which is
+    /// singular whenever `M` is and can fail `LDLᵀ` when `M` is ill-conditioned;
+    /// a negative `eps` can make it indefinite.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let mut rng = SmallRng::seed_from_u64(7);
+    /// let a = Matrix::<4>::random_spd(&mut rng, -1.0..1.0, 1e-3)?;
+    ///
+    /// let ldlt = a.ldlt(DEFAULT_SINGULAR_TOL)?;
+    /// assert!(ldlt.det()? > 0.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] with scalar input location if `eps` is
+    /// NaN or infinite, checked before any sample is drawn.
+    /// Returns [`LaError::NonFinite`] or [`LaError::InvalidRange`] for an
+    /// invalid `range`, as in [`random`](Self::random).
+    /// Returns [`LaError::NonFinite`] if forming `MᵀM` or shifting its
+    /// diagonal overflows.
+    #[inline]
+    pub fn random_spd<R: Rng + ?Sized>(
+        rng: &mut R,
+        range: Range<f64>,
+        eps: f64,
+    ) -> Result<Self, LaError> {
+        if !eps.is_finite() {
+            cold_path();
+            return Err(LaError::non_finite_input_scalar());
+        }
+        let m = Self::random(rng, range)?;
+        m.transpose()
+            .matmul(&m, ArithmeticOperation::MatrixProduct)?
+            .add_scaled_identity(eps)
+    }
+}
+
+impl<const D: usize> Vector<D> {
+    /// Vector with components drawn independently and uniformly from `range`.
+    ///
+    /// Components are sampled in index order, so a generator in a given
+    /// state always produces the same vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let mut rng = SmallRng::seed_from_u64(7);
+    /// let v = Vector::<3>::random(&mut rng, 0.0..10.0)?;
+    /// assert!(v.as_array().iter().all(|x| (0.0..10.0).contains(x)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] with scalar input location if either
+    /// end of `range` is NaN or infinite.
+    /// Returns [`LaError::InvalidRange`] if `range` is empty or its width
+    /// `end - start` overflows.
+    #[inline]
+    pub fn random<R: Rng + ?Sized>(rng: &mut R, range: Range<f64>) -> Result<Self, LaError> {
+        let uniform = uniform(range)?;
+        // Uniform samples lie inside the finite range.
+        Self::try_new(core::array::from_fn(|_| rng.sample(uniform)))
+    }
+
+    /// Unit vector drawn uniformly from the sphere `‖v‖₂ = 1`.
+    ///
+    /// The components start as independent standard Gaussian samples; their
+    /// joint density depends only on the norm, so the normalized direction is
+    /// uniform on the sphere in every dimension. (Sampling the cube instead
+    /// would bias directions toward its corners.) The result is normalized
+    /// with [`normalize`](Self::normalize), so its norm is one up to rounding.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let mut rng = SmallRng::seed_from_u64(7);
+    /// let direction = Vector::<3>::random_unit(&mut rng)?;
+    /// assert!(direction.is_unit(Tolerance::try_new(1e-12)?));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::ZeroVector`] for `Vector<0>`, whose sphere is
+    /// empty.
+    #[inline]
+    pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Result<Self, LaError> {
+        if D == 0 {
+            cold_path();
+            return Err(LaError::zero_vector(D));
+        }
+        loop {
+            let mut data = [0.0; D];
+            for pair in data.chunks_mut(2) {
+                let (z0, z1) = standard_normal_pair(rng);
+                pair[0] = z0;
+                if let Some(second) = pair.get_mut(1) {
+                    *second = z1;
+                }
+            }
+            // Polar-method samples are finite; an all-zero draw is redrawn.
+            if let Ok(unit) = Self::try_new(data)?.normalize() {
+                return Ok(unit);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use pastey::paste;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    use super::*;
+    use crate::DEFAULT_SINGULAR_TOL;
+
+    macro_rules! gen_random_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<random_samples_range_reproducibly_ $d d>]() {
+                    let mut rng = SmallRng::seed_from_u64($d);
+                    let a = Matrix::<$d>::random(&mut rng, 2.0..3.0).unwrap();
+                    let b = Matrix::<$d>::random(&mut rng, 2.0..3.0).unwrap();
+                    assert_ne!(a, b);
+                    for &value in a.as_rows().iter().flatten() {
+                        assert!((2.0..3.0).contains(&value));
+                    }
+
+                    let mut rng = SmallRng::seed_from_u64($d);
+                    assert_eq!(Matrix::<$d>::random(&mut rng, 2.0..3.0), Ok(a));
+                }
+
+                #[test]
+                fn [<random_spd_passes_ldlt_ $d d>]() {
+                    let mut rng = SmallRng::seed_from_u64($d);
+                    for _ in 0..32 {
+                        let a = Matrix::<$d>::random_spd(&mut rng, -1.0..1.0, 1e-6).unwrap();
+                        let rows = a.as_rows();
+                        for r in 0..$d {
+                            for c in 0..$d {
+                                assert_eq!(rows[r][c].to_bits(), rows[c][r].to_bits());
+                            }
+                        }
+                        let ldlt = a.ldlt(DEFAULT_SINGULAR_TOL).unwrap();
+                        assert!(ldlt.det().unwrap() > 0.0);
+                        assert!(a.log_det_spd(DEFAULT_SINGULAR_TOL).is_ok());
+                    }
+                }
+            }
+        };
+    }
+
+    gen_random_tests!(2);
+    gen_random_tests!(3);
+    gen_random_tests!(4);
+    gen_random_tests!(5);
+
+    macro_rules! gen_vector_random_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<vector_random_samples_range_reproducibly_ $d d>]() {
+                    let mut rng = SmallRng::seed_from_u64($d);
+                    let v = Vector::<$d>::random(&mut rng, -3.0..-2.0).unwrap();
+                    assert!(v.as_array().iter().all(|x| (-3.0..-2.0).contains(x)));
+
+                    let mut rng = SmallRng::seed_from_u64($d);
+                    assert_eq!(Vector::<$d>::random(&mut rng, -3.0..-2.0), Ok(v));
+                }
+
+                #[test]
+                fn [<random_unit_has_unit_norm_and_isotropic_moments_ $d d>]() {
+                    const SAMPLES: u32 = 4096;
+                    let mut rng = SmallRng::seed_from_u64($d);
+                    let mut mean = [0.0_f64; $d];
+                    let mut second_moment = [0.0_f64; $d];
+                    for _ in 0..SAMPLES {
+                        let v = Vector::<$d>::random_unit(&mut rng).unwrap();
+                        assert_abs_diff_eq!(v.norm2_sq().unwrap(), 1.0, epsilon = 1e-14);
+                        for (i, &x) in v.as_array().iter().enumerate() {
+                            mean[i] += x;
+                            second_moment[i] = x.mul_add(x, second_moment[i]);
+                        }
+                    }
+                    // A uniform direction has E[xᵢ] = 0 and E[xᵢ²] = 1/D; the bounds
+                    // are several standard errors wide for this sample size.
+                    let n = f64::from(SAMPLES);
+                    for i in 0..$d {
+                        assert_abs_diff_eq!(mean[i] / n, 0.0, epsilon = 0.06);
+                        assert_abs_diff_eq!(second_moment[i] / n, 1.0 / f64::from($d), epsilon = 0.03);
+                    }
+                }
+            }
+        };
+    }
+
+    gen_vector_random_tests!(2);
+    gen_vector_random_tests!(3);
+    gen_vector_random_tests!(4);
+    gen_vector_random_tests!(5);
+
+    #[test]
+    fn vector_random_rejects_invalid_ranges_and_random_unit_rejects_d0() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        assert_eq!(
+            Vector::<2>::random(&mut rng, 2.0..1.0),
+            Err(LaError::invalid_range(2.0, 1.0))
+        );
+        assert_eq!(
+            Vector::<2>::random(&mut rng, f64::NEG_INFINITY..1.0),
+            Err(LaError::non_finite_input_scalar())
+        );
+        assert_eq!(
+            Vector::<0>::random_unit(&mut rng),
+            Err(LaError::zero_vector(0))
+        );
+        let single = Vector::<1>::random_unit(&mut rng).unwrap();
+        assert_abs_diff_eq!(single.as_array()[0].abs(), 1.0, epsilon = 0.0);
+    }
+
+    #[test]
+    fn random_rejects_invalid_ranges() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        assert_eq!(
+            Matrix::<2>::random(&mut rng, 1.0..1.0),
+            Err(LaError::invalid_range(1.0, 1.0))
+        );
+        assert_eq!(
+            Matrix::<2>::random(&mut rng, 1.0..-1.0),
+            Err(LaError::invalid_range(1.0, -1.0))
+        );
+        assert_eq!(
+            Matrix::<2>::random(&mut rng, -f64::MAX..f64::MAX),
+            Err(LaError::invalid_range(-f64::MAX, f64::MAX))
+        );
+        assert_eq!(
+            Matrix::<2>::random(&mut rng, 0.0..f64::INFINITY),
+            Err(LaError::non_finite_input_scalar())
+        );
+        assert_eq!(
+            Matrix::<2>::random(&mut rng, f64::NAN..1.0),
+            Err(LaError::non_finite_input_scalar())
+        );
+        assert_eq!(
+            Matrix::<0>::random(&mut rng, 1.0..1.0),
+            Err(LaError::invalid_range(1.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn random_spd_rejects_non_finite_shift_before_sampling() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let untouched = rng.clone();
+        for eps in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(
+                Matrix::<2>::random_spd(&mut rng, -1.0..1.0, eps),
+                Err(LaError::non_finite_input_scalar())
+            );
+        }
+        assert_eq!(rng, untouched);
+    }
+
+    #[test]
+    fn random_spd_reports_gram_overflow() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        assert_eq!(
+            Matrix::<2>::random_spd(&mut rng, 1e200..1e201, 0.0),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::MatrixProduct,
+                0,
+                0
+            ))
+        );
+    }
+}
@@ -149,6 +149,27 @@ impl ScaledProduct {
         }
     }
 
+    /// Split the accumulated product into a signed mantissa with magnitude in
+    /// `[1, 2)` and a base-2 exponent, without leaving the exponent range.
+    ///
+    /// An exact zero yields a signed zero mantissa and exponent `0`. Callers
+    /// must only pass finite factors.
+    #[inline]
+    pub(crate) const fn finish_scaled(mut self) -> (f64, i128) {
+        debug_assert!(!self.non_finite, "scaled products require finite factors");
+        let sign = if self.negative { SIGN_MASK } else { 0 };
+        if self.zero {
+            return (f64::from_bits(sign), 0);
+        }
+        if let Some(pending) = self.pending_factor {
+            self.absorb_factor(pending);
+        }
+        (
+            f64::from_bits(sign | self.mantissa.to_bits()),
+            self.exponent,
+        )
+    }
+
     /// Round the accumulated product to binary64.
     ///
     /// Returns `None` only when the accumulated result rounds outside the
@@ -296,6 +317,24 @@ mod tests {
         assert_eq!(overflow.finish(), None);
     }
 
+    #[test]
+    fn scaled_finish_keeps_exponents_beyond_binary64_range() {
+        let mut overflow = ScaledProduct::new(true);
+        overflow.multiply(TWO_POS_800);
+        overflow.multiply(-1.5 * TWO_POS_800);
+        assert_eq!(overflow.finish_scaled(), (1.5, 1600));
+
+        let mut underflow = ScaledProduct::new(false);
+        underflow.multiply(TWO_NEG_800);
+        underflow.multiply(f64::from_bits(1)); // 2^-1074
+        assert_eq!(underflow.finish_scaled(), (1.0, -1874));
+
+        let mut zero = ScaledProduct::new(true);
+        zero.multiply(0.0);
+        assert_eq!(zero.finish_scaled().0.to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(ScaledProduct::new(false).finish_scaled(), (1.0, 0));
+    }
+
     #[test]
     fn final_subnormal_product_is_rounded_once_in_const_evaluation() {
         const POSITIVE: Option<f64> = {
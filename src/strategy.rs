@@ -0,0 +1,91 @@
+#![forbid(unsafe_code)]
+
+//! [`proptest`] strategies for property-testing code that consumes la-stack
+//! types.
+//!
+//! This module is only compiled when the `"proptest"` Cargo feature is
+//! enabled. Each strategy draws entries from a caller-supplied `f64`
+//! strategy, so the value range, and therefore the conditioning of the
+//! generated matrices, stays under the caller's control. Draws that would
+//! break the finite-storage invariant are rejected with
+//! [`prop_filter_map`](Strategy::prop_filter_map) rather than stored, so
+//! an entry strategy that rarely produces NaN or infinity only costs
+//! rejected cases.
+
+use proptest::array;
+use proptest::strategy::Strategy;
+
+use crate::ArithmeticOperation;
+use crate::matrix::Matrix;
+
+/// Strategy for `D×D` matrices with entries drawn independently from `entry`.
+///
+/// Rows are generated in order and shrink entry by entry, following
+/// `entry`'s own shrinking. Draws containing a NaN or infinite entry are
+/// rejected.
+///
+/// # Examples
+/// ```
+/// use la_stack::prelude::*;
+/// use la_stack::strategy::matrix_strategy;
+/// use proptest::test_runner::TestRunner;
+///
+/// let mut runner = TestRunner::default();
+/// runner
+///     .run(&matrix_strategy::<3>(-10.0..10.0), |a| {
+///         assert!(a.inf_norm().unwrap() < 30.0);
+///         Ok(())
+///     })
+///     .unwrap();
+/// ```
+#[inline]
+pub fn matrix_strategy<const D: usize>(
+    entry: impl Strategy<Value = f64>,
+) -> impl Strategy<Value = Matrix<D>> {
+    array::uniform::<_, D>(array::uniform::<_, D>(entry))
+        .prop_filter_map("matrix entries must be finite", |rows| {
+            Matrix::try_from_rows(rows).ok()
+        })
+}
+
+/// Strategy for symmetric positive-definite matrices `MᵀM + I`.
+///
+/// `M` is drawn with [`matrix_strategy`]. Its Gram matrix `MᵀM` is positive
+/// semidefinite, so adding the identity puts every eigenvalue at or above
+/// one. The matrix is formed as in
+#[cfg_attr(
+    feature = "rand",
+    doc = "[`Matrix::random_spd`](crate::Matrix::random_spd),"
+)]
+#[cfg_attr(not(feature = "rand"), doc = "`Matrix::random_spd` (`rand` feature),")]
+/// so it is exactly symmetric and can be passed to [`Matrix::ldlt`]
+/// directly. Rounding in `MᵀM` is about `D · ε · max|m|²`, so for entries up
+/// to about `1e6` in magnitude it cannot erode that margin and `LDLᵀ`
+/// succeeds at [`DEFAULT_SINGULAR_TOL`](crate::DEFAULT_SINGULAR_TOL).
+/// Draws whose Gram matrix overflows are rejected.
+///
+/// # Examples
+/// ```
+/// use la_stack::prelude::*;
+/// use la_stack::strategy::spd_matrix_strategy;
+/// use proptest::test_runner::TestRunner;
+///
+/// let mut runner = TestRunner::default();
+/// runner
+///     .run(&spd_matrix_strategy::<4>(-5.0..5.0), |a| {
+///         assert!(a.ldlt(DEFAULT_SINGULAR_TOL).unwrap().det().unwrap() >= 1.0);
+///         Ok(())
+///     })
+///     .unwrap();
+/// ```
+#[inline]
+pub fn spd_matrix_strategy<const D: usize>(
+    entry: impl Strategy<Value = f64>,
+) -> impl Strategy<Value = Matrix<D>> {
+    matrix_strategy::<D>(entry).prop_filter_map("Gram matrix must be finite", |m| {
+        m.transpose()
+            .matmul(&m, ArithmeticOperation::MatrixProduct)
+            .and_then(|gram| gram.add_scaled_identity(1.0))
+            .ok()
+    })
+}
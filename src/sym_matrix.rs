@@ -0,0 +1,399 @@
+#![forbid(unsafe_code)]
+
+//! Packed storage for symmetric matrices.
+//!
+//! [`SymMatrix`] keeps only the lower triangle of a symmetric `D×D` matrix, in
+//! row-major order, so a Gram or covariance matrix occupies `D·(D+1)/2` entries
+//! instead of `D²`. The packed length `N` must equal `D·(D+1)/2`, checked at
+//! compile time.
+
+use core::hint::cold_path;
+
+use crate::ldlt::Ldlt;
+use crate::matrix::{Matrix, SymmetricMatrix};
+use crate::{LaError, Tolerance};
+
+/// Finite symmetric `D×D` matrix stored as its packed lower triangle.
+///
+/// Entry `(r, c)` with `r >= c` lives at packed index `r·(r+1)/2 + c`; the
+/// upper triangle is never stored, so every value is exactly symmetric by
+/// construction. `N` must equal `D·(D+1)/2`; any other choice is rejected when
+/// the type is used:
+///
+/// ```compile_fail
+/// use la_stack::prelude::*;
+///
+/// let _ = SymMatrix::<3, 5>::zero();
+/// ```
+///
+/// Like [`Matrix`], every stored entry is finite.
+#[must_use]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SymMatrix<const D: usize, const N: usize> {
+    packed: [f64; N],
+}
+
+impl<const D: usize, const N: usize> SymMatrix<D, N> {
+    /// Compile-time proof that `N` is the packed lower-triangle length for `D`.
+    const PACKED_LEN_MATCHES: () = assert!(
+        N == D * (D + 1) / 2,
+        "SymMatrix<D, N> requires N == D * (D + 1) / 2"
+    );
+
+    /// Packed index of lower-triangle entry `(row, col)` with `row >= col`.
+    #[inline]
+    const fn packed_index(row: usize, col: usize) -> usize {
+        row * (row + 1) / 2 + col
+    }
+
+    /// All-zeros symmetric matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// let z = SymMatrix::<2, 3>::zero();
+    /// assert_eq!(z.get(1, 0), Some(0.0));
+    /// ```
+    #[inline]
+    pub const fn zero() -> Self {
+        let () = Self::PACKED_LEN_MATCHES;
+        Self { packed: [0.0; N] }
+    }
+
+    /// Construct from the packed lower triangle in row-major order.
+    ///
+    /// For `D = 3` the layout is `[a00, a10, a11, a20, a21, a22]`.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let s = SymMatrix::<2, 3>::try_from_packed([4.0, 2.0, 3.0])?;
+    /// assert_eq!(s.get(0, 1), Some(2.0));
+    /// assert_eq!(s.get(1, 0), Some(2.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] with the lower-triangle coordinates of the
+    /// first NaN or infinite entry in packed order.
+    #[inline]
+    pub const fn try_from_packed(packed: [f64; N]) -> Result<Self, LaError> {
+        let () = Self::PACKED_LEN_MATCHES;
+        let mut row = 0;
+        while row < D {
+            let mut col = 0;
+            while col <= row {
+                if !packed[Self::packed_index(row, col)].is_finite() {
+                    cold_path();
+                    return Err(LaError::non_finite_input_matrix(row, col));
+                }
+                col += 1;
+            }
+            row += 1;
+        }
+        Ok(Self { packed })
+    }
+
+    /// Pack an exactly symmetric dense matrix.
+    ///
+    /// Symmetry is checked with the same exact mirrored-entry predicate as
+    /// [`Matrix::ldlt`].
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<2>::try_from_rows([[4.0, 2.0], [2.0, 3.0]])?;
+    /// let s = SymMatrix::<2, 3>::try_from_matrix(a)?;
+    /// assert_eq!(s.as_packed(), &[4.0, 2.0, 3.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::Asymmetric`] with `allowed_abs_diff == 0.0` for the
+    /// first mirrored pair that is not exactly equal.
+    #[inline]
+    pub fn try_from_matrix(a: Matrix<D>) -> Result<Self, LaError> {
+        let rows = SymmetricMatrix::try_new(a)?.into_matrix().into_rows();
+        let mut s = Self::zero();
+        for (row, values) in rows.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate().take(row + 1) {
+                s.packed[Self::packed_index(row, col)] = value;
+            }
+        }
+        Ok(s)
+    }
+
+    /// Borrow the packed lower triangle in row-major order.
+    #[inline]
+    #[must_use]
+    pub const fn as_packed(&self) -> &[f64; N] {
+        &self.packed
+    }
+
+    /// Get an element with bounds checking.
+    ///
+    /// `get(r, c)` and `get(c, r)` read the same stored entry.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let s = SymMatrix::<2, 3>::try_from_packed([4.0, 2.0, 3.0])?;
+    /// assert_eq!(s.get(0, 1), Some(2.0));
+    /// assert_eq!(s.get(2, 0), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn get(&self, row: usize, col: usize) -> Option<f64> {
+        if row >= D || col >= D {
+            return None;
+        }
+        let (row, col) = if row >= col { (row, col) } else { (col, row) };
+        Some(self.packed[Self::packed_index(row, col)])
+    }
+
+    /// Set a finite element and its mirror with bounds checking.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let mut s = SymMatrix::<2, 3>::zero();
+    /// s.set(0, 1, 2.5)?;
+    /// assert_eq!(s.get(1, 0), Some(2.5));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::IndexOutOfBounds`] when either index is not `< D`.
+    /// Returns [`LaError::NonFinite`] when `value` is NaN or infinity.
+    #[inline]
+    pub const fn set(&mut self, row: usize, col: usize, value: f64) -> Result<(), LaError> {
+        if row >= D || col >= D {
+            return Err(LaError::index_out_of_bounds(row, col, D));
+        }
+        if !value.is_finite() {
+            return Err(LaError::non_finite_input_matrix(row, col));
+        }
+        let (row, col) = if row >= col { (row, col) } else { (col, row) };
+        self.packed[Self::packed_index(row, col)] = value;
+        Ok(())
+    }
+
+    /// Expand into a dense [`Matrix`] with both triangles filled.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let s = SymMatrix::<2, 3>::try_from_packed([4.0, 2.0, 3.0])?;
+    /// let a = Matrix::<2>::try_from_rows([[4.0, 2.0], [2.0, 3.0]])?;
+    /// assert_eq!(s.to_matrix(), a);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn to_matrix(&self) -> Matrix<D> {
+        let mut rows = [[0.0; D]; D];
+        let mut row = 0;
+        while row < D {
+            let mut col = 0;
+            while col <= row {
+                let value = self.packed[Self::packed_index(row, col)];
+                rows[row][col] = value;
+                rows[col][row] = value;
+                col += 1;
+            }
+            row += 1;
+        }
+        // Every entry is copied from finite packed storage.
+        Matrix::from_rows_unchecked(rows)
+    }
+
+    /// Compute an LDLT factorization (`A = L D Lᵀ`) from packed storage.
+    ///
+    /// Packed storage is symmetric by construction, so unlike
+    /// [`Matrix::ldlt`] no mirrored-entry scan is needed. The factorization
+    /// itself is the same one [`Matrix::ldlt`] runs and produces bit-identical
+    /// factors for the expanded matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let s = SymMatrix::<2, 3>::try_from_packed([4.0, 2.0, 3.0])?;
+    /// let ldlt = s.ldlt(DEFAULT_SINGULAR_TOL)?;
+    /// assert!((ldlt.det()? - 8.0).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the same errors as [`Matrix::ldlt`] except
+    /// [`LaError::Asymmetric`]: [`LaError::NotPositiveSemidefinite`],
+    /// [`LaError::Singular`], or [`LaError::NonFinite`].
+    #[inline]
+    pub fn ldlt(&self, tol: Tolerance) -> Result<Ldlt<D>, LaError> {
+        // Both triangles of the expansion come from one packed value.
+        Ldlt::factor_symmetric(SymmetricMatrix::new_unchecked(self.to_matrix()), tol)
+    }
+}
+
+impl<const D: usize, const N: usize> Default for SymMatrix<D, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DEFAULT_SINGULAR_TOL, Vector};
+
+    use core::assert_matches;
+    use pastey::paste;
+
+    /// Dense SPD fixture `A = B Bᵀ + D·I` with a deterministic `B`.
+    fn spd_rows<const D: usize>() -> [[f64; D]; D] {
+        let mut rows = [[0.0; D]; D];
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                let mut sum = 0.0;
+                for k in 0..D {
+                    let left = f64::from(u32::try_from((i + 2 * k) % 5).unwrap()) - 2.0;
+                    let right = f64::from(u32::try_from((j + 2 * k) % 5).unwrap()) - 2.0;
+                    sum = left.mul_add(right, sum);
+                }
+                *value = sum;
+            }
+            row[i] += f64::from(u32::try_from(D).unwrap());
+        }
+        rows
+    }
+
+    macro_rules! gen_sym_matrix_tests {
+        ($d:literal, $n:literal) => {
+            paste! {
+                #[test]
+                fn [<packed_roundtrips_dense_ $d d>]() {
+                    let dense = Matrix::<$d>::try_from_rows(spd_rows::<$d>()).unwrap();
+                    let packed = SymMatrix::<$d, $n>::try_from_matrix(dense).unwrap();
+                    assert_eq!(packed.to_matrix(), dense);
+                    for r in 0..$d {
+                        for c in 0..$d {
+                            assert_eq!(packed.get(r, c), dense.get(r, c));
+                        }
+                    }
+                    assert_eq!(packed.get($d, 0), None);
+                    assert_eq!(packed.get(0, $d), None);
+                }
+
+                #[test]
+                fn [<packed_ldlt_matches_dense_ldlt_ $d d>]() {
+                    let dense = Matrix::<$d>::try_from_rows(spd_rows::<$d>()).unwrap();
+                    let packed = SymMatrix::<$d, $n>::try_from_matrix(dense).unwrap();
+
+                    let from_packed = packed.ldlt(DEFAULT_SINGULAR_TOL).unwrap();
+                    let from_dense = dense.ldlt(DEFAULT_SINGULAR_TOL).unwrap();
+                    assert_eq!(from_packed, from_dense);
+
+                    let mut b = [0.0; $d];
+                    for (i, value) in b.iter_mut().enumerate() {
+                        *value = f64::from(u32::try_from(i).unwrap()) + 1.0;
+                    }
+                    let b = Vector::<$d>::try_new(b).unwrap();
+                    assert_eq!(from_packed.solve(b), from_dense.solve(b));
+                    assert_eq!(
+                        from_packed.det().unwrap().to_bits(),
+                        from_dense.det().unwrap().to_bits()
+                    );
+                }
+
+                #[test]
+                fn [<set_writes_both_mirrors_ $d d>]() {
+                    let mut s = SymMatrix::<$d, $n>::zero();
+                    s.set(0, $d - 1, 7.0).unwrap();
+                    assert_eq!(s.get($d - 1, 0), Some(7.0));
+                    assert_eq!(s.get(0, $d - 1), Some(7.0));
+                    assert_eq!(
+                        s.set($d, 0, 1.0),
+                        Err(LaError::index_out_of_bounds($d, 0, $d))
+                    );
+                }
+            }
+        };
+    }
+
+    gen_sym_matrix_tests!(2, 3);
+    gen_sym_matrix_tests!(3, 6);
+    gen_sym_matrix_tests!(4, 10);
+    gen_sym_matrix_tests!(5, 15);
+
+    #[test]
+    fn packed_layout_is_row_major_lower_triangle() {
+        let s = SymMatrix::<3, 6>::try_from_packed([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let expected =
+            Matrix::<3>::try_from_rows([[1.0, 2.0, 4.0], [2.0, 3.0, 5.0], [4.0, 5.0, 6.0]])
+                .unwrap();
+        assert_eq!(s.to_matrix(), expected);
+    }
+
+    #[test]
+    fn try_from_packed_reports_lower_triangle_coordinates() {
+        assert_eq!(
+            SymMatrix::<3, 6>::try_from_packed([1.0, 0.0, 1.0, 0.0, f64::NAN, 1.0]),
+            Err(LaError::non_finite_input_matrix(2, 1))
+        );
+    }
+
+    #[test]
+    fn set_rejects_non_finite_value() {
+        let mut s = SymMatrix::<2, 3>::zero();
+        assert_eq!(
+            s.set(0, 1, f64::INFINITY),
+            Err(LaError::non_finite_input_matrix(0, 1))
+        );
+        assert_eq!(s, SymMatrix::zero());
+    }
+
+    #[test]
+    fn try_from_matrix_rejects_asymmetric_input() {
+        let a = Matrix::<2>::try_from_rows([[1.0, 2.0], [3.0, 1.0]]).unwrap();
+        assert_matches!(
+            SymMatrix::<2, 3>::try_from_matrix(a),
+            Err(LaError::Asymmetric { .. })
+        );
+    }
+
+    #[test]
+    fn ldlt_reports_same_failure_as_dense_path() {
+        let s = SymMatrix::<2, 3>::try_from_packed([1.0, 2.0, 1.0]).unwrap();
+        assert_eq!(
+            s.ldlt(DEFAULT_SINGULAR_TOL),
+            s.to_matrix().ldlt(DEFAULT_SINGULAR_TOL)
+        );
+        assert!(s.ldlt(DEFAULT_SINGULAR_TOL).is_err());
+    }
+
+    #[test]
+    fn zero_dimension_is_empty() {
+        let s = SymMatrix::<0, 0>::zero();
+        assert!(s.as_packed().is_empty());
+        assert_eq!(s.ldlt(DEFAULT_SINGULAR_TOL).unwrap().det(), Ok(1.0));
+    }
+}
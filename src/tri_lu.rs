@@ -0,0 +1,359 @@
+#![forbid(unsafe_code)]
+
+//! Tridiagonal LU factorization (Thomas algorithm) and solves.
+//!
+//! A tridiagonal matrix has LU factors that are bidiagonal, so eliminating
+//! without pivoting costs `O(D)` operations and stores three length-`D` bands
+//! instead of a dense `D×D` array. Without pivoting the factorization is stable
+//! for diagonally dominant and symmetric positive-definite inputs, such as the
+//! second-difference matrices of 1D PDE discretizations; other inputs may
+//! need [`crate::Lu`]. See `REFERENCES.md` \[11-12\].
+
+use core::hint::cold_path;
+
+use crate::matrix::Matrix;
+use crate::scaled_product::{RangeCheckedProduct, ScaledProduct, range_checked_product};
+use crate::vector::Vector;
+use crate::{ArithmeticOperation, FactorizationKind, LaError, Tolerance};
+
+/// Bandwidth accepted by [`Matrix::lu_tridiagonal`].
+const TRIDIAGONAL_BANDWIDTH: usize = 1;
+
+/// LU factorization (`A = L U`) of a tridiagonal matrix without pivoting.
+///
+/// `L` is unit lower bidiagonal and `U` is upper bidiagonal whose
+/// superdiagonal equals that of `A`. `TriLu<0>` represents the empty
+/// factorization: its determinant is `1.0` and solving against [`Vector<0>`]
+/// returns [`Vector<0>`]. No certified error bound is provided.
+///
+/// # Storage
+/// Three inline length-`D` bands:
+/// - `lower[i]` is the multiplier `L[i][i-1]`; `lower[0]` is unused.
+/// - `diag[i]` is the pivot `U[i][i]`.
+/// - `upper[i]` is `U[i][i+1] = A[i][i+1]`; `upper[D-1]` is unused.
+#[must_use]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TriLu<const D: usize> {
+    lower: [f64; D],
+    diag: [f64; D],
+    upper: [f64; D],
+}
+
+impl<const D: usize> TriLu<D> {
+    /// Factor a finite tridiagonal matrix for
+    /// [`Matrix::lu_tridiagonal`](crate::Matrix::lu_tridiagonal).
+    ///
+    /// # Errors
+    /// Returns [`LaError::OutsideBand`] for the first non-zero entry with
+    /// `|row - col| > 1` in row-major order, [`LaError::Singular`] when a pivot
+    /// magnitude is at or below `tol`, and [`LaError::NonFinite`] when a
+    /// multiplier or pivot overflows.
+    pub(crate) fn factor(a: &Matrix<D>, tol: Tolerance) -> Result<Self, LaError> {
+        let rows = a.as_rows();
+        for (r, row) in rows.iter().enumerate() {
+            for (c, &value) in row.iter().enumerate() {
+                if r.abs_diff(c) > TRIDIAGONAL_BANDWIDTH && value != 0.0 {
+                    cold_path();
+                    return Err(LaError::outside_band(r, c, D, value, TRIDIAGONAL_BANDWIDTH));
+                }
+            }
+        }
+
+        let tolerance = tol.get();
+        let mut lower = [0.0; D];
+        let mut diag = [0.0; D];
+        let mut upper = [0.0; D];
+        for i in 0..D {
+            let mut pivot = rows[i][i];
+            if i > 0 {
+                let l = rows[i][i - 1] / diag[i - 1];
+                if !l.is_finite() {
+                    cold_path();
+                    return Err(LaError::non_finite_computation_matrix(
+                        ArithmeticOperation::TridiagonalFactorization,
+                        i,
+                        i - 1,
+                    ));
+                }
+                lower[i] = l;
+                pivot = (-l).mul_add(upper[i - 1], pivot);
+            }
+            if !pivot.is_finite() {
+                cold_path();
+                return Err(LaError::non_finite_computation_matrix(
+                    ArithmeticOperation::TridiagonalFactorization,
+                    i,
+                    i,
+                ));
+            }
+            if pivot.abs() <= tolerance {
+                cold_path();
+                return Err(LaError::singular_numerical(
+                    i,
+                    FactorizationKind::TridiagonalLu,
+                    pivot.abs(),
+                    tolerance,
+                ));
+            }
+            diag[i] = pivot;
+            if i + 1 < D {
+                upper[i] = rows[i][i + 1];
+            }
+        }
+
+        Ok(Self { lower, diag, upper })
+    }
+
+    /// Solve `A x = b` using this factorization in `O(D)` operations.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<3>::try_from_rows([
+    ///     [2.0, -1.0, 0.0],
+    ///     [-1.0, 2.0, -1.0],
+    ///     [0.0, -1.0, 2.0],
+    /// ])?;
+    /// let tri = a.lu_tridiagonal(DEFAULT_SINGULAR_TOL)?;
+    ///
+    /// let x = tri.solve(Vector::<3>::try_new([1.0, 0.0, 1.0])?)?.into_array();
+    /// for x_i in x {
+    ///     assert!((x_i - 1.0).abs() <= 1e-12);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] with the step index when forward or back
+    /// substitution overflows.
+    #[inline]
+    pub fn solve(&self, b: Vector<D>) -> Result<Vector<D>, LaError> {
+        let mut x = b.into_array();
+
+        for i in 1..D {
+            let y = (-self.lower[i]).mul_add(x[i - 1], x[i]);
+            if !y.is_finite() {
+                cold_path();
+                return Err(LaError::non_finite_computation_step(
+                    ArithmeticOperation::TridiagonalSolve,
+                    i,
+                ));
+            }
+            x[i] = y;
+        }
+
+        for i in (0..D).rev() {
+            let mut numerator = x[i];
+            if i + 1 < D {
+                numerator = (-self.upper[i]).mul_add(x[i + 1], numerator);
+            }
+            let quotient = numerator / self.diag[i];
+            if !quotient.is_finite() {
+                cold_path();
+                return Err(LaError::non_finite_computation_step(
+                    ArithmeticOperation::TridiagonalSolve,
+                    i,
+                ));
+            }
+            x[i] = quotient;
+        }
+
+        Vector::from_computation(x, ArithmeticOperation::TridiagonalSolve)
+    }
+
+    /// Determinant of the original matrix, the product of the pivots.
+    ///
+    /// Pivots are multiplied with the same range-checked accumulation as
+    /// [`Lu::det`](crate::Lu::det), falling back to power-of-two scaling before
+    /// a premature overflow or underflow can affect the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Matrix::<3>::try_from_rows([
+    ///     [2.0, -1.0, 0.0],
+    ///     [-1.0, 2.0, -1.0],
+    ///     [0.0, -1.0, 2.0],
+    /// ])?;
+    /// let det = a.lu_tridiagonal(DEFAULT_SINGULAR_TOL)?.det()?;
+    /// assert!((det - 4.0).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] if the determinant cannot be represented
+    /// as a finite `f64`.
+    #[inline]
+    pub const fn det(&self) -> Result<f64, LaError> {
+        let mut det = 1.0;
+        let mut i = 0;
+        while i < D {
+            match range_checked_product(det, self.diag[i]) {
+                RangeCheckedProduct::Safe(next) => det = next,
+                RangeCheckedProduct::NeedsScaling => {
+                    cold_path();
+                    return self.scaled_det();
+                }
+            }
+            i += 1;
+        }
+        Ok(det)
+    }
+
+    /// Recompute the determinant with scaled pivot accumulation.
+    const fn scaled_det(&self) -> Result<f64, LaError> {
+        let mut product = ScaledProduct::new(false);
+        let mut i = 0;
+        while i < D {
+            product.multiply(self.diag[i]);
+            i += 1;
+        }
+
+        if let Some(det) = product.finish() {
+            Ok(det)
+        } else {
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::Determinant,
+                D.saturating_sub(1),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_SINGULAR_TOL;
+
+    use approx::{assert_abs_diff_eq, assert_relative_eq};
+    use core::assert_matches;
+    use pastey::paste;
+
+    macro_rules! gen_tri_lu_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<tri_lu_matches_dense_lu_on_second_difference_ $d d>]() {
+                    let a = Matrix::<$d>::second_difference();
+                    let tri = a.lu_tridiagonal(DEFAULT_SINGULAR_TOL).unwrap();
+                    let lu = a.lu(DEFAULT_SINGULAR_TOL).unwrap();
+
+                    // det(tridiag(-1, 2, -1)) = D + 1.
+                    assert_abs_diff_eq!(tri.det().unwrap(), f64::from($d + 1), epsilon = 1e-12);
+
+                    let mut b = [0.0; $d];
+                    for (i, value) in b.iter_mut().enumerate() {
+                        *value = f64::from(u32::try_from(i).unwrap()) - 1.0;
+                    }
+                    let b = Vector::<$d>::try_new(b).unwrap();
+                    let x_tri = tri.solve(b).unwrap().into_array();
+                    let x_lu = lu.solve(b).unwrap().into_array();
+                    for (t, l) in x_tri.into_iter().zip(x_lu) {
+                        assert_abs_diff_eq!(t, l, epsilon = 1e-12);
+                    }
+                }
+            }
+        };
+    }
+
+    gen_tri_lu_tests!(2);
+    gen_tri_lu_tests!(3);
+    gen_tri_lu_tests!(4);
+    gen_tri_lu_tests!(5);
+
+    #[test]
+    fn tri_lu_empty_and_scalar_systems() {
+        let empty = Matrix::<0>::zero()
+            .lu_tridiagonal(DEFAULT_SINGULAR_TOL)
+            .unwrap();
+        assert_eq!(empty.det(), Ok(1.0));
+        assert_eq!(empty.solve(Vector::zero()), Ok(Vector::zero()));
+
+        let scalar = Matrix::<1>::try_from_rows([[4.0]])
+            .unwrap()
+            .lu_tridiagonal(DEFAULT_SINGULAR_TOL)
+            .unwrap();
+        assert_eq!(scalar.det(), Ok(4.0));
+        assert_eq!(
+            scalar.solve(Vector::try_new([2.0]).unwrap()),
+            Ok(Vector::try_new([0.5]).unwrap())
+        );
+    }
+
+    #[test]
+    fn tri_lu_rejects_entries_outside_band() {
+        let a = Matrix::<3>::try_from_rows([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.5, -1.0, 2.0]])
+            .unwrap();
+        assert_eq!(
+            a.lu_tridiagonal(DEFAULT_SINGULAR_TOL),
+            Err(LaError::outside_band(2, 0, 3, 0.5, 1))
+        );
+
+        let signed_zero =
+            Matrix::<3>::try_from_rows([[2.0, -1.0, -0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]])
+                .unwrap();
+        assert!(signed_zero.lu_tridiagonal(DEFAULT_SINGULAR_TOL).is_ok());
+    }
+
+    #[test]
+    fn tri_lu_reports_small_pivot_without_pivoting() {
+        // Dense LU pivots past the zero diagonal; the Thomas algorithm cannot.
+        let a = Matrix::<2>::try_from_rows([[0.0, 1.0], [1.0, 0.0]]).unwrap();
+        assert!(a.lu(DEFAULT_SINGULAR_TOL).is_ok());
+        assert_eq!(
+            a.lu_tridiagonal(DEFAULT_SINGULAR_TOL),
+            Err(LaError::singular_numerical(
+                0,
+                FactorizationKind::TridiagonalLu,
+                0.0,
+                DEFAULT_SINGULAR_TOL.get()
+            ))
+        );
+
+        let b = Matrix::<2>::try_from_rows([[1.0, 1.0], [1.0, 1.0]]).unwrap();
+        assert_matches!(
+            b.lu_tridiagonal(DEFAULT_SINGULAR_TOL),
+            Err(LaError::Singular { pivot_col: 1, .. })
+        );
+    }
+
+    #[test]
+    fn tri_lu_reports_overflowing_factorization_and_solve() {
+        let a = Matrix::<2>::try_from_rows([[1e-300, 1.0], [1e300, 1.0]]).unwrap();
+        assert_eq!(
+            a.lu_tridiagonal(Tolerance::ZERO),
+            Err(LaError::non_finite_computation_matrix(
+                ArithmeticOperation::TridiagonalFactorization,
+                1,
+                0
+            ))
+        );
+
+        let tri = Matrix::<2>::try_from_rows([[1e-300, 0.0], [0.0, 1.0]])
+            .unwrap()
+            .lu_tridiagonal(Tolerance::ZERO)
+            .unwrap();
+        assert_eq!(
+            tri.solve(Vector::try_new([1e10, 0.0]).unwrap()),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::TridiagonalSolve,
+                0
+            ))
+        );
+    }
+
+    #[test]
+    fn tri_lu_det_scales_extreme_pivots() {
+        let a =
+            Matrix::<3>::try_from_rows([[1e200, 0.0, 0.0], [0.0, 1e200, 0.0], [0.0, 0.0, 1e-300]])
+                .unwrap();
+        let det = a.lu_tridiagonal(Tolerance::ZERO).unwrap().det().unwrap();
+        assert_relative_eq!(det, 1e100, max_relative = 1e-12);
+    }
+}
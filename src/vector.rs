@@ -4,7 +4,8 @@
 
 use core::hint::cold_path;
 
-use crate::{ArithmeticOperation, LaError};
+use crate::matrix::Matrix;
+use crate::{ArithmeticOperation, FactorizationKind, LaError, Tolerance};
 
 /// Finite fixed-size vector of length `D`, stored inline.
 ///
@@ -69,6 +70,52 @@ impl<const D: usize> Vector<D> {
         }
     }
 
+    /// Try to create a finite vector from exactly `D` iterator items.
+    ///
+    /// Useful for filtered or mapped data that is not already an array. Extra
+    /// items are an error rather than silently dropped: the rest of the
+    /// iterator is drained to report its full length, so it must be finite.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let v = Vector::<3>::try_from_iter((1..=3).map(f64::from))?;
+    /// assert_eq!(v.into_array(), [1.0, 2.0, 3.0]);
+    ///
+    /// assert!(matches!(
+    ///     Vector::<3>::try_from_iter([1.0, 2.0]),
+    ///     Err(LaError::DimensionMismatch { expected: 3, actual: 2, .. })
+    /// ));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::DimensionMismatch`] if the iterator yields fewer or
+    /// more than `D` items.
+    /// Returns [`LaError::NonFinite`] with the first offending entry index when
+    /// an item is NaN or infinite.
+    #[inline]
+    pub fn try_from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Result<Self, LaError> {
+        let mut iter = iter.into_iter();
+        let mut data = [0.0; D];
+        for (filled, slot) in data.iter_mut().enumerate() {
+            let Some(value) = iter.next() else {
+                cold_path();
+                return Err(LaError::dimension_mismatch(D, filled));
+            };
+            *slot = value;
+        }
+        let extra = iter.count();
+        if extra > 0 {
+            cold_path();
+            return Err(LaError::dimension_mismatch(D, D + extra));
+        }
+        Self::try_new(data)
+    }
+
     /// Finalize vector storage produced by an arithmetic operation.
     ///
     /// Keeping this validation in the type that owns the finite-storage
@@ -115,6 +162,16 @@ impl<const D: usize> Vector<D> {
         Self { data: [0.0; D] }
     }
 
+    /// Standard basis vector `e_index`; all zeros when `index >= D`.
+    #[inline]
+    pub(crate) const fn basis(index: usize) -> Self {
+        let mut data = [0.0; D];
+        if index < D {
+            data[index] = 1.0;
+        }
+        Self { data }
+    }
+
     /// Borrow the finite backing array.
     ///
     /// # Examples
@@ -152,6 +209,33 @@ impl<const D: usize> Vector<D> {
         self.data
     }
 
+    /// Diagonal matrix `diag(v)` with the components on the diagonal.
+    ///
+    /// Off-diagonal entries are `+0.0`. The components are already finite, so
+    /// no check is needed.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let d = Vector::<2>::try_new([3.0, -1.0])?.to_diagonal();
+    /// assert_eq!(d, Matrix::<2>::try_from_rows([[3.0, 0.0], [0.0, -1.0]])?);
+    /// assert_eq!(d.trace()?, 2.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn to_diagonal(self) -> Matrix<D> {
+        let mut rows = [[0.0; D]; D];
+        let mut i = 0;
+        while i < D {
+            rows[i][i] = self.data[i];
+            i += 1;
+        }
+        Matrix::from_rows_unchecked(rows)
+    }
+
     /// Dot product.
     ///
     /// Terms are accumulated in `f64` using [`f64::mul_add`] at each index.
@@ -180,6 +264,37 @@ impl<const D: usize> Vector<D> {
         self.dot_with_operation(other, ArithmeticOperation::VectorDotProduct)
     }
 
+    /// Dot product of `self` with each vector of a fixed-size batch.
+    ///
+    /// Element `i` of the result is exactly `self.dot(&others[i])`, including
+    /// its overflow error. Projecting one point against many plane normals is
+    /// the typical use; the batch size is a compile-time constant, so the
+    /// results are returned on the stack.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let p = Vector::<2>::try_new([3.0, 4.0])?;
+    /// let normals = [
+    ///     Vector::<2>::try_new([1.0, 0.0])?,
+    ///     Vector::<2>::try_new([0.0, 1.0])?,
+    ///     Vector::<2>::try_new([0.6, 0.8])?,
+    /// ];
+    /// let [x, y, along] = p.dot_batch(&normals);
+    ///
+    /// assert_eq!(x?, 3.0);
+    /// assert_eq!(y?, 4.0);
+    /// assert!((along? - 5.0).abs() <= 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn dot_batch<const N: usize>(&self, others: &[Self; N]) -> [Result<f64, LaError>; N] {
+        others.each_ref().map(|other| self.dot(other))
+    }
+
     /// Accumulate a dot product while retaining the public operation that owns it.
     const fn dot_with_operation(
         &self,
@@ -229,6 +344,292 @@ impl<const D: usize> Vector<D> {
         LaError::non_finite_computation_step(operation, last)
     }
 
+    /// Dot product under a diagonal metric: `Σ wᵢ aᵢ bᵢ`.
+    ///
+    /// This is `aᵀ W b` for `W = diag(weights)`, the building block for
+    /// weighted norms and diagonal Mahalanobis distances. Each term is
+    /// accumulated as `(wᵢ aᵢ)·bᵢ` with [`mul_add`](f64::mul_add); no certified
+    /// rounding bound is provided. With unit weights the result equals
+    /// [`dot`](Self::dot).
+    ///
+    /// The weights are not validated: non-negative weights give a positive
+    /// semidefinite form, but any negative weight makes the form indefinite,
+    /// so `a.weighted_dot(&a, &w)` can be negative and is then not a squared
+    /// norm.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Vector::<3>::try_new([1.0, 2.0, 3.0])?;
+    /// let b = Vector::<3>::try_new([4.0, 5.0, 6.0])?;
+    /// let w = Vector::<3>::try_new([1.0, 0.5, 0.0])?;
+    /// assert_eq!(a.weighted_dot(&b, &w)?, 9.0);
+    ///
+    /// // A negative weight makes the form indefinite.
+    /// let minkowski = Vector::<3>::try_new([-1.0, 1.0, 1.0])?;
+    /// assert_eq!(a.weighted_dot(&a, &minkowski)?, 12.0);
+    /// let x = Vector::<3>::try_new([2.0, 1.0, 0.0])?;
+    /// assert_eq!(x.weighted_dot(&x, &minkowski)?, -3.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] at the first index whose weighted term or
+    /// running sum overflows.
+    #[inline]
+    pub const fn weighted_dot(&self, other: &Self, weights: &Self) -> Result<f64, LaError> {
+        let mut acc = 0.0;
+        let mut i = 0;
+        while i < D {
+            acc = (weights.data[i] * self.data[i]).mul_add(other.data[i], acc);
+            if !acc.is_finite() {
+                cold_path();
+                return Err(LaError::non_finite_computation_step(
+                    ArithmeticOperation::WeightedDotProduct,
+                    i,
+                ));
+            }
+            i += 1;
+        }
+        Ok(acc)
+    }
+
+    /// Component-wise (Hadamard) product `(aᵢ bᵢ)ᵢ`.
+    ///
+    /// Scales each axis by its own factor; unlike [`dot`](Self::dot), nothing
+    /// is summed. Each product is correctly rounded.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let v = Vector::<3>::try_new([1.0, 2.0, 3.0])?;
+    /// let factors = Vector::<3>::try_new([2.0, 0.5, -1.0])?;
+    /// assert_eq!(v.component_mul(&factors)?.into_array(), [2.0, 1.0, -3.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] at the first index whose product
+    /// overflows.
+    #[inline]
+    pub const fn component_mul(&self, other: &Self) -> Result<Self, LaError> {
+        let mut data = [0.0; D];
+        let mut i = 0;
+        while i < D {
+            data[i] = self.data[i] * other.data[i];
+            i += 1;
+        }
+        Self::from_computation(data, ArithmeticOperation::ComponentProduct)
+    }
+
+    /// Component-wise quotient `(aᵢ / bᵢ)ᵢ`.
+    ///
+    /// This solves the diagonal system `diag(other) x = self`, so each divisor
+    /// is treated as a pivot: one with `|bᵢ| ≤ tol` is rejected rather than
+    /// producing a huge or infinite component. Each quotient is correctly
+    /// rounded.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let v = Vector::<3>::try_new([2.0, 1.0, -3.0])?;
+    /// let factors = Vector::<3>::try_new([2.0, 0.5, -1.0])?;
+    /// let tol = DEFAULT_SINGULAR_TOL;
+    /// assert_eq!(v.component_div(&factors, tol)?.into_array(), [1.0, 2.0, 3.0]);
+    ///
+    /// let with_zero = Vector::<3>::try_new([2.0, 0.0, -1.0])?;
+    /// assert!(matches!(
+    ///     v.component_div(&with_zero, tol),
+    ///     Err(LaError::Singular { pivot_col: 1, .. })
+    /// ));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::Singular`] for the first divisor that is exactly
+    /// zero ([`SingularityReason::Exact`](crate::SingularityReason::Exact)) or
+    /// has `|bᵢ| ≤ tol`
+    /// ([`SingularityReason::Numerical`](crate::SingularityReason::Numerical)
+    /// with [`FactorizationKind::Diagonal`]).
+    /// Returns [`LaError::NonFinite`] at the first index whose quotient
+    /// overflows.
+    #[inline]
+    pub const fn component_div(&self, other: &Self, tol: Tolerance) -> Result<Self, LaError> {
+        let mut data = [0.0; D];
+        let mut i = 0;
+        while i < D {
+            let divisor = other.data[i];
+            if divisor == 0.0 {
+                cold_path();
+                return Err(LaError::singular_exact(i));
+            }
+            if divisor.abs() <= tol.get() {
+                cold_path();
+                return Err(LaError::singular_numerical(
+                    i,
+                    FactorizationKind::Diagonal,
+                    divisor.abs(),
+                    tol.get(),
+                ));
+            }
+            data[i] = self.data[i] / divisor;
+            i += 1;
+        }
+        Self::from_computation(data, ArithmeticOperation::ComponentQuotient)
+    }
+
+    /// Component-wise standardization `((xᵢ − μᵢ) / σᵢ)ᵢ`.
+    ///
+    /// Each feature is centered by `mean` and scaled by `std_dev`, the usual
+    /// z-score preprocessing. As in [`component_div`](Self::component_div),
+    /// each standard deviation is a pivot: one with `|σᵢ| ≤ tol` is rejected,
+    /// since a feature with (near) zero variance carries no scale. Each
+    /// component is one rounded subtraction followed by one rounded division.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let x = Vector::<3>::try_new([12.0, 0.5, -4.0])?;
+    /// let mean = Vector::<3>::try_new([10.0, 0.0, -1.0])?;
+    /// let std_dev = Vector::<3>::try_new([2.0, 0.25, 3.0])?;
+    /// let z = x.standardize(&mean, &std_dev, DEFAULT_SINGULAR_TOL)?;
+    /// assert_eq!(z.into_array(), [1.0, 2.0, -1.0]);
+    ///
+    /// let constant = Vector::<3>::try_new([2.0, 0.0, 3.0])?;
+    /// assert!(matches!(
+    ///     x.standardize(&mean, &constant, DEFAULT_SINGULAR_TOL),
+    ///     Err(LaError::Singular { pivot_col: 1, .. })
+    /// ));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::Singular`] for the first standard deviation that is
+    /// exactly zero ([`SingularityReason::Exact`](crate::SingularityReason::Exact))
+    /// or has `|σᵢ| ≤ tol`
+    /// ([`SingularityReason::Numerical`](crate::SingularityReason::Numerical)
+    /// with [`FactorizationKind::Diagonal`]).
+    /// Returns [`LaError::NonFinite`] at the first index whose difference or
+    /// quotient overflows.
+    #[inline]
+    pub const fn standardize(
+        &self,
+        mean: &Self,
+        std_dev: &Self,
+        tol: Tolerance,
+    ) -> Result<Self, LaError> {
+        let mut data = [0.0; D];
+        let mut i = 0;
+        while i < D {
+            let sigma = std_dev.data[i];
+            if sigma == 0.0 {
+                cold_path();
+                return Err(LaError::singular_exact(i));
+            }
+            if sigma.abs() <= tol.get() {
+                cold_path();
+                return Err(LaError::singular_numerical(
+                    i,
+                    FactorizationKind::Diagonal,
+                    sigma.abs(),
+                    tol.get(),
+                ));
+            }
+            data[i] = (self.data[i] - mean.data[i]) / sigma;
+            i += 1;
+        }
+        Self::from_computation(data, ArithmeticOperation::Standardization)
+    }
+
+    /// Component-wise square root `(√aᵢ)ᵢ`.
+    ///
+    /// Each root is correctly rounded. A negative component has no real square
+    /// root: [`f64::sqrt`] returns NaN for it, which a `Vector` cannot store,
+    /// so the first such index is reported as an error instead. `-0.0` is not
+    /// negative and maps to `-0.0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let v = Vector::<3>::try_new([4.0, 0.25, 0.0])?;
+    /// assert_eq!(v.sqrt()?.into_array(), [2.0, 0.5, 0.0]);
+    ///
+    /// let negative = Vector::<3>::try_new([4.0, -1.0, 0.0])?;
+    /// assert_eq!(
+    ///     negative.sqrt(),
+    ///     Err(LaError::non_finite_computation_step(
+    ///         ArithmeticOperation::ComponentSquareRoot,
+    ///         1
+    ///     ))
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] at the first index whose component is
+    /// negative, since its square root is NaN.
+    #[inline]
+    pub fn sqrt(&self) -> Result<Self, LaError> {
+        Self::from_computation(
+            self.data.map(f64::sqrt),
+            ArithmeticOperation::ComponentSquareRoot,
+        )
+    }
+
+    /// Component-wise exponential `(exp aᵢ)ᵢ`.
+    ///
+    /// Components below about `-745` underflow to `0.0`; components above
+    /// about `709.78` overflow and are reported as an error.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let v = Vector::<3>::try_new([0.0, 1.0, -1000.0])?;
+    /// let e = v.exp()?;
+    /// assert_eq!(e.as_array()[0], 1.0);
+    /// assert!((e.as_array()[1] - core::f64::consts::E).abs() <= 1e-15);
+    /// assert_eq!(e.as_array()[2], 0.0);
+    ///
+    /// let large = Vector::<2>::try_new([1.0, 710.0])?;
+    /// assert_eq!(
+    ///     large.exp(),
+    ///     Err(LaError::non_finite_computation_step(
+    ///         ArithmeticOperation::ComponentExponential,
+    ///         1
+    ///     ))
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] at the first index whose exponential
+    /// overflows.
+    #[inline]
+    pub fn exp(&self) -> Result<Self, LaError> {
+        Self::from_computation(
+            self.data.map(f64::exp),
+            ArithmeticOperation::ComponentExponential,
+        )
+    }
+
     /// Squared Euclidean norm.
     ///
     /// This is computed as `dot(self, self)`, so `norm2_sq` has the same
@@ -256,334 +657,1859 @@ impl<const D: usize> Vector<D> {
     pub const fn norm2_sq(&self) -> Result<f64, LaError> {
         self.dot_with_operation(self, ArithmeticOperation::VectorSquaredNorm)
     }
-}
 
-impl<const D: usize> Default for Vector<D> {
+    /// Unit vector `v / ‖v‖₂` in the direction of `v`.
+    ///
+    /// Entries are first divided by the largest magnitude, so the squared norm
+    /// of the rescaled vector lies in `[1, D]` and neither overflows nor
+    /// underflows for any finite input. The result has Euclidean norm 1 up to
+    /// rounding.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let v = Vector::<2>::try_new([3e300, -4e300])?;
+    /// let [x, y] = v.normalize()?.into_array();
+    /// assert!((x - 0.6).abs() <= 1e-15);
+    /// assert!((y + 0.8).abs() <= 1e-15);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::ZeroVector`] if every entry is zero.
     #[inline]
-    fn default() -> Self {
-        Self::zero()
+    pub fn normalize(&self) -> Result<Self, LaError> {
+        let (max_abs, norm) = self.scaled_norm2();
+        if max_abs == 0.0 {
+            cold_path();
+            return Err(LaError::zero_vector(D));
+        }
+
+        // Each rescaled entry has magnitude at most 1 and `norm >= 1`.
+        Ok(Self {
+            data: self.data.map(|x| x / max_abs / norm),
+        })
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use core::hint::black_box;
+    /// Whether `v` has unit Euclidean norm, `|‖v‖₂² − 1| ≤ tol`.
+    ///
+    /// Comparing the squared norm avoids a square root; near 1 the two
+    /// deviations differ by a factor of about 2. This is the natural
+    /// precondition check for reflection normals and rotation axes. A squared
+    /// norm that overflows is far from 1 and reports `false`.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let tol = Tolerance::try_new(1e-12)?;
+    /// assert!(Vector::<2>::try_new([0.6, -0.8])?.is_unit(tol));
+    /// assert!(!Vector::<2>::try_new([3.0, 4.0])?.is_unit(tol));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_unit(&self, tol: Tolerance) -> bool {
+        match self.norm2_sq() {
+            Ok(norm2_sq) => (norm2_sq - 1.0).abs() <= tol.get(),
+            Err(_) => false,
+        }
+    }
 
-    use approx::assert_abs_diff_eq;
-    use pastey::paste;
+    /// Split the Euclidean norm as `‖v‖₂ = max_abs · ‖v / max_abs‖₂`.
+    ///
+    /// Returns `(max_abs, ‖v / max_abs‖₂)`; the second factor lies in
+    /// `[1, √D]` and cannot overflow or underflow. The zero vector returns
+    /// `(0.0, 0.0)`.
+    pub(crate) fn scaled_norm2(&self) -> (f64, f64) {
+        let max_abs = self.data.iter().fold(0.0_f64, |max, x| max.max(x.abs()));
+        if max_abs == 0.0 {
+            return (0.0, 0.0);
+        }
+        let norm = self
+            .data
+            .iter()
+            .fold(0.0_f64, |acc, &x| {
+                let scaled = x / max_abs;
+                scaled.mul_add(scaled, acc)
+            })
+            .sqrt();
+        (max_abs, norm)
+    }
 
-    use super::*;
+    /// Compact Householder reflector `(v, β)` with `(I − β v vᵀ) x = ‖x‖₂ e₀`.
+    ///
+    /// This is the representation Householder QR stores in place of the full
+    /// reflection matrix: `v[0] = 1`, so only `v[1..]` and `β` need to be kept,
+    /// and applying the reflector costs `O(D)` per vector. The first entry of
+    /// `v` is computed without cancellation for either sign of `x[0]`
+    /// (`REFERENCES.md` \[12\], Algorithm 5.1.1), and `x` is first divided by
+    /// its largest magnitude, which leaves `(v, β)` unchanged but keeps every
+    /// intermediate finite. `β` lies in `[0, 2]`; it is 0 when `x` is already a
+    /// non-negative multiple of `e₀`, including the zero vector. For `D = 0`
+    /// the result is the empty vector with `β = 0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let x = Vector::<3>::try_new([-2.0, 1.0, 2.0])?;
+    /// let (v, beta) = x.householder_vector();
+    ///
+    /// // x − β (v · x) v = ‖x‖₂ e₀ = (3, 0, 0).
+    /// let coefficient = beta * v.dot(&x)?;
+    /// let [r0, r1, r2] = core::array::from_fn(|i| {
+    ///     (-coefficient).mul_add(v.as_array()[i], x.as_array()[i])
+    /// });
+    /// assert!((r0 - 3.0).abs() <= 1e-15);
+    /// assert!(r1.abs() <= 1e-15 && r2.abs() <= 1e-15);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn householder_vector(&self) -> (Self, f64) {
+        let max_abs = self.data.iter().fold(0.0_f64, |max, x| max.max(x.abs()));
+        if max_abs == 0.0 {
+            return (Self::basis(0), 0.0);
+        }
 
-    macro_rules! gen_vector_tests {
-        ($d:literal) => {
-            paste! {
-                #[test]
-                fn [<vector_new_as_array_into_array_ $d d>]() {
-                    let arr = {
-                        let mut arr = [0.0f64; $d];
-                        let values = [1.0f64, 2.0, 3.0, 4.0, 5.0];
-                        for (dst, src) in arr.iter_mut().zip(values.iter()) {
-                            *dst = *src;
-                        }
-                        arr
-                    };
+        let mut v = self.data.map(|x| x / max_abs);
+        let head = v[0];
+        let tail_sq = v[1..].iter().fold(0.0_f64, |acc, &x| x.mul_add(x, acc));
+        v[0] = 1.0;
+        if tail_sq == 0.0 {
+            // Already a multiple of e₀: keep it, or reflect e₀ to flip its sign.
+            let beta = if head >= 0.0 { 0.0 } else { 2.0 };
+            return (Self::basis(0), beta);
+        }
 
-                    let v = Vector::<$d>::new(arr);
+        let norm = head.mul_add(head, tail_sq).sqrt();
+        // `head − norm` cancels for positive `head`; use the equivalent
+        // `−tail_sq / (head + norm)` there.
+        let v0 = if head <= 0.0 {
+            head - norm
+        } else {
+            -tail_sq / (head + norm)
+        };
+        let beta = 2.0 * v0 * v0 / v0.mul_add(v0, tail_sq);
+        for entry in &mut v[1..] {
+            *entry /= v0;
+        }
+        (Self { data: v }, beta)
+    }
 
-                    for i in 0..$d {
-                        assert_abs_diff_eq!(v.as_array()[i], arr[i], epsilon = 0.0);
+    /// Index of the largest component, or `None` for `Vector<0>`.
+    ///
+    /// Ties resolve to the first occurrence. Components are finite by
+    /// construction, so no NaN ordering rule is needed; `-0.0` and `0.0`
+    /// compare equal and therefore also resolve to the first occurrence.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let v = Vector::<4>::try_new([1.0, 3.0, -2.0, 3.0])?;
+    /// assert_eq!(v.argmax(), Some(1));
+    /// assert_eq!(Vector::<0>::zero().argmax(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn argmax(&self) -> Option<usize> {
+        if D == 0 {
+            return None;
+        }
+        let mut best = 0;
+        let mut i = 1;
+        while i < D {
+            if self.data[i] > self.data[best] {
+                best = i;
+            }
+            i += 1;
+        }
+        Some(best)
+    }
+
+    /// Index of the smallest component, or `None` for `Vector<0>`.
+    ///
+    /// Ties resolve to the first occurrence, with the same finite-component
+    /// and signed-zero semantics as [`argmax`](Self::argmax).
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let v = Vector::<4>::try_new([1.0, -2.0, 3.0, -2.0])?;
+    /// assert_eq!(v.argmin(), Some(1));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn argmin(&self) -> Option<usize> {
+        if D == 0 {
+            return None;
+        }
+        let mut best = 0;
+        let mut i = 1;
+        while i < D {
+            if self.data[i] < self.data[best] {
+                best = i;
+            }
+            i += 1;
+        }
+        Some(best)
+    }
+
+    /// Reorder components so that entry `i` of the result is `v[perm[i]]`.
+    ///
+    /// Returns `None` unless `perm` lists every index in `0..D` exactly once.
+    /// Components are moved, not recomputed, so the result is exact.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// // Swap y and z, e.g. to convert between y-up and z-up coordinates.
+    /// let v = Vector::<3>::try_new([1.0, 2.0, 3.0])?;
+    /// assert_eq!(v.permute(&[0, 2, 1]).map(Vector::into_array), Some([1.0, 3.0, 2.0]));
+    /// assert_eq!(v.permute(&[0, 1, 1]), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn permute(&self, perm: &[usize; D]) -> Option<Self> {
+        let mut seen = [false; D];
+        let mut out = [0.0; D];
+        let mut i = 0;
+        while i < D {
+            let src = perm[i];
+            if src >= D || seen[src] {
+                return None;
+            }
+            seen[src] = true;
+            out[i] = self.data[src];
+            i += 1;
+        }
+        Some(Self { data: out })
+    }
+
+    /// Running sum: entry `i` is `v[0] + v[1] + … + v[i]`.
+    ///
+    /// Sums are accumulated left to right with ordinary `f64` addition; no
+    /// compensated summation or certified rounding bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let v = Vector::<4>::try_new([1.0, 2.0, 3.0, 4.0])?;
+    /// assert_eq!(v.cumsum()?.into_array(), [1.0, 3.0, 6.0, 10.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] at the first index whose running sum
+    /// overflows.
+    #[inline]
+    pub const fn cumsum(&self) -> Result<Self, LaError> {
+        let mut out = [0.0; D];
+        let mut acc = 0.0;
+        let mut i = 0;
+        while i < D {
+            acc += self.data[i];
+            if !acc.is_finite() {
+                cold_path();
+                return Err(LaError::non_finite_computation_step(
+                    ArithmeticOperation::CumulativeSum,
+                    i,
+                ));
+            }
+            out[i] = acc;
+            i += 1;
+        }
+        Ok(Self { data: out })
+    }
+
+    /// Consecutive differences: entry `i` is `v[i + 1] - v[i]`.
+    ///
+    /// A vector of length `D` has only `D - 1` differences, but stable Rust
+    /// cannot yet name `Vector<{ D - 1 }>` for a generic `D`. The result
+    /// therefore keeps length `D` with its last entry set to `0.0`;
+    /// `Vector<0>` and `Vector<1>` return all zeros.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let v = Vector::<4>::try_new([1.0, 3.0, 6.0, 10.0])?;
+    /// // Three differences, then the zero padding entry.
+    /// assert_eq!(v.diff()?.into_array(), [2.0, 3.0, 4.0, 0.0]);
+    ///
+    /// // `diff` undoes `cumsum` apart from the first entry.
+    /// let w = Vector::<4>::try_new([5.0, -1.0, 2.0, 7.0])?;
+    /// assert_eq!(w.cumsum()?.diff()?.into_array(), [-1.0, 2.0, 7.0, 0.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] at the first index whose difference
+    /// overflows.
+    #[inline]
+    pub const fn diff(&self) -> Result<Self, LaError> {
+        let mut out = [0.0; D];
+        let mut i = 0;
+        while i + 1 < D {
+            let delta = self.data[i + 1] - self.data[i];
+            if !delta.is_finite() {
+                cold_path();
+                return Err(LaError::non_finite_computation_step(
+                    ArithmeticOperation::VectorDifference,
+                    i,
+                ));
+            }
+            out[i] = delta;
+            i += 1;
+        }
+        Ok(Self { data: out })
+    }
+
+    /// Midpoint `(a + b) / 2` of two points.
+    ///
+    /// Each component is evaluated as `0.5·a + 0.5·b` with one fused
+    /// multiply-add, so it is the correctly rounded average and cannot
+    /// overflow even when `a + b` would; halving a subnormal component may
+    /// round.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Vector::<2>::try_new([1.0, -2.0])?;
+    /// let b = Vector::<2>::try_new([3.0, 6.0])?;
+    /// assert_eq!(a.midpoint(b).into_array(), [2.0, 2.0]);
+    ///
+    /// let huge = Vector::<1>::try_new([f64::MAX])?;
+    /// assert_eq!(huge.midpoint(huge), huge);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn midpoint(self, other: Self) -> Self {
+        let mut out = self.data;
+        let mut i = 0;
+        while i < D {
+            out[i] = 0.5_f64.mul_add(out[i], 0.5 * other.data[i]);
+            i += 1;
+        }
+        Self { data: out }
+    }
+
+    /// Componentwise maximum of two vectors.
+    ///
+    /// Folding this over a point set gives the upper corner of its
+    /// axis-aligned bounding box; [`min`](Self::min) gives the lower corner.
+    /// Both inputs are finite, so no NaN can arise and the result is always
+    /// one of the two components. Of `0.0` and `-0.0` either may be returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let a = Vector::<3>::try_new([1.0, -2.0, 5.0])?;
+    /// let b = Vector::<3>::try_new([3.0, -4.0, 5.0])?;
+    /// assert_eq!(a.max(b).into_array(), [3.0, -2.0, 5.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn max(self, other: Self) -> Self {
+        let mut out = self.data;
+        let mut i = 0;
+        while i < D {
+            out[i] = out[i].max(other.data[i]);
+            i += 1;
+        }
+        Self { data: out }
+    }
+
+    /// Componentwise minimum of two vectors.
+    ///
+    /// The counterpart of [`max`](Self::max); folding it over a point set
+    /// gives the lower corner of its axis-aligned bounding box.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let points = [
+    ///     Vector::<2>::try_new([1.0, 4.0])?,
+    ///     Vector::<2>::try_new([-3.0, 2.0])?,
+    ///     Vector::<2>::try_new([2.0, -1.0])?,
+    /// ];
+    /// let lower = points.iter().fold(points[0], |lo, &p| lo.min(p));
+    /// let upper = points.iter().fold(points[0], |hi, &p| hi.max(p));
+    /// assert_eq!(lower.into_array(), [-3.0, -1.0]);
+    /// assert_eq!(upper.into_array(), [2.0, 4.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn min(self, other: Self) -> Self {
+        let mut out = self.data;
+        let mut i = 0;
+        while i < D {
+            out[i] = out[i].min(other.data[i]);
+            i += 1;
+        }
+        Self { data: out }
+    }
+
+    /// Centroid (arithmetic mean) of `N` points.
+    ///
+    /// Every point is divided by `N` before it is added, so the running sum
+    /// stays within the range of the inputs. For simplex vertices this is the
+    /// barycenter. Sums are accumulated in input order with ordinary `f64`
+    /// arithmetic; no certified error bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let triangle = [
+    ///     Vector::<2>::try_new([0.0, 0.0])?,
+    ///     Vector::<2>::try_new([3.0, 0.0])?,
+    ///     Vector::<2>::try_new([0.0, 6.0])?,
+    /// ];
+    /// assert_eq!(Vector::centroid(&triangle)?.into_array(), [1.0, 2.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::DimensionMismatch`] with `expected: 1` and
+    /// `actual: 0` for an empty point set, which has no centroid.
+    /// Returns [`LaError::NonFinite`] if a sum rounds past `f64::MAX`.
+    #[inline]
+    pub fn centroid<const N: usize>(points: &[Self; N]) -> Result<Self, LaError> {
+        if N == 0 {
+            cold_path();
+            return Err(LaError::dimension_mismatch(1, 0));
+        }
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "point counts are far below 2^53 and convert exactly"
+        )]
+        let count = N as f64;
+
+        let mut out = [0.0; D];
+        for point in points {
+            for (acc, &x) in out.iter_mut().zip(&point.data) {
+                *acc += x / count;
+            }
+        }
+        Self::from_computation(out, ArithmeticOperation::Centroid)
+    }
+
+    /// Generalized cross product of `D − 1` vectors in `D` dimensions.
+    ///
+    /// Component `i` is the cofactor `det([v₁; …; v_{D−1}; eᵢ])`, so the result
+    /// `c` satisfies `c · x = det([v₁; …; v_{D−1}; x])` for every `x`. It is
+    /// orthogonal to each input, its length is the `(D − 1)`-volume of the
+    /// parallelotope they span, and it is zero exactly when they are linearly
+    /// dependent. For `D = 3` this is `v₁ × v₂`, and for `D = 2` it turns the
+    /// single input a quarter turn counter-clockwise; for simplices it gives
+    /// the facet normal from the edge vectors.
+    ///
+    /// `N` must equal `D − 1`, checked at run time as in
+    /// [`Matrix::simplex_volume`](crate::Matrix::simplex_volume). Each
+    /// cofactor is evaluated with [`Matrix::det`](crate::Matrix::det); an LU
+    /// fallback that reports dependent rows as singular yields a zero
+    /// component. No certified error bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::assert_matches;
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let x = Vector::<3>::try_new([1.0, 0.0, 0.0])?;
+    /// let y = Vector::<3>::try_new([0.0, 1.0, 0.0])?;
+    /// let z = Vector::generalized_cross(&[x, y])?;
+    /// assert_eq!(z.into_array(), [0.0, 0.0, 1.0]);
+    ///
+    /// // Exactly D − 1 inputs are required.
+    /// assert_matches!(
+    ///     Vector::generalized_cross(&[x]),
+    ///     Err(LaError::DimensionMismatch { expected: 3, actual: 2, .. })
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::DimensionMismatch`] with `expected: D` and
+    /// `actual: N + 1`, the number of cofactor matrix rows, unless
+    /// `N + 1 == D`. Returns [`LaError::NonFinite`] if a cofactor overflows.
+    #[inline]
+    pub fn generalized_cross<const N: usize>(vectors: &[Self; N]) -> Result<Self, LaError> {
+        if N + 1 != D {
+            cold_path();
+            return Err(LaError::dimension_mismatch(D, N + 1));
+        }
+        let mut rows = [[0.0; D]; D];
+        for (row, v) in rows.iter_mut().zip(vectors) {
+            *row = v.data;
+        }
+
+        let mut out = [0.0; D];
+        for (i, component) in out.iter_mut().enumerate() {
+            rows[D - 1] = Self::basis(i).data;
+            *component = match Matrix::from_rows_unchecked(rows).det() {
+                Ok(cofactor) => cofactor,
+                Err(LaError::Singular { .. }) => 0.0,
+                Err(err) => return Err(err),
+            };
+        }
+        Ok(Self { data: out })
+    }
+}
+
+impl Vector<2> {
+    /// Rotate counter-clockwise by `theta` radians.
+    ///
+    /// Applies `[[cos θ, −sin θ], [sin θ, cos θ]]` directly without building a
+    /// matrix. The sine and cosine are rounded, so the length is preserved
+    /// only to within a few ulps.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let v = Vector::<2>::try_new([1.0, 0.0])?;
+    /// let [x, y] = v.rotate(core::f64::consts::FRAC_PI_2)?.into_array();
+    ///
+    /// assert!(x.abs() <= 1e-15);
+    /// assert!((y - 1.0).abs() <= 1e-15);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::NonFinite`] with scalar input location if `theta` is
+    /// NaN or infinite, or with the component index if a rotated component of a
+    /// vector near `f64::MAX` in length overflows.
+    #[inline]
+    pub fn rotate(&self, theta: f64) -> Result<Self, LaError> {
+        if !theta.is_finite() {
+            cold_path();
+            return Err(LaError::non_finite_input_scalar());
+        }
+        let (sin, cos) = theta.sin_cos();
+        let [x, y] = self.data;
+        Self::from_computation(
+            [cos.mul_add(x, -(sin * y)), sin.mul_add(x, cos * y)],
+            ArithmeticOperation::VectorRotation,
+        )
+    }
+}
+
+impl Vector<3> {
+    /// Rotate by `theta` radians about `axis`, following the right-hand rule.
+    ///
+    /// Uses Rodrigues' rotation formula
+    /// `v cos θ + (k × v) sin θ + k (k · v)(1 − cos θ)` with the unit axis
+    /// `k = axis / ‖axis‖`, so `axis` need not be normalized. The axis is
+    /// divided by its largest magnitude before normalizing, so its length
+    /// cannot overflow or underflow. Results are rounded; no certified error
+    /// bound is provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use la_stack::prelude::*;
+    ///
+    /// # fn main() -> Result<(), LaError> {
+    /// let v = Vector::<3>::try_new([1.0, 0.0, 0.0])?;
+    /// let z = Vector::<3>::try_new([0.0, 0.0, 2.0])?;
+    /// let [x, y, w] = v.rotate_around(z, core::f64::consts::FRAC_PI_2)?.into_array();
+    ///
+    /// assert!(x.abs() <= 1e-15);
+    /// assert!((y - 1.0).abs() <= 1e-15);
+    /// assert_eq!(w, 0.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`LaError::ZeroVector`] if every entry of `axis` is zero.
+    /// Returns [`LaError::NonFinite`] with scalar input location if `theta` is
+    /// NaN or infinite, or with the component index if a rotated component
+    /// overflows.
+    #[inline]
+    pub fn rotate_around(&self, axis: Self, theta: f64) -> Result<Self, LaError> {
+        if !theta.is_finite() {
+            cold_path();
+            return Err(LaError::non_finite_input_scalar());
+        }
+        let [kx, ky, kz] = axis.normalize()?.data;
+        let [vx, vy, vz] = self.data;
+
+        let (sin, cos) = theta.sin_cos();
+        let cross = [
+            ky.mul_add(vz, -(kz * vy)),
+            kz.mul_add(vx, -(kx * vz)),
+            kx.mul_add(vy, -(ky * vx)),
+        ];
+        let along = kz.mul_add(vz, kx.mul_add(vx, ky * vy)) * (1.0 - cos);
+        let mut rotated = [0.0; 3];
+        for (((out, v), c), k) in rotated
+            .iter_mut()
+            .zip(self.data)
+            .zip(cross)
+            .zip([kx, ky, kz])
+        {
+            *out = k.mul_add(along, sin.mul_add(c, cos * v));
+        }
+        Self::from_computation(rotated, ArithmeticOperation::VectorRotation)
+    }
+}
+
+impl<const D: usize> Default for Vector<D> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::assert_matches;
+    use core::hint::black_box;
+
+    use approx::assert_abs_diff_eq;
+    use pastey::paste;
+
+    use super::*;
+    use crate::DEFAULT_SINGULAR_TOL;
+
+    macro_rules! gen_vector_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<vector_new_as_array_into_array_ $d d>]() {
+                    let arr = {
+                        let mut arr = [0.0f64; $d];
+                        let values = [1.0f64, 2.0, 3.0, 4.0, 5.0];
+                        for (dst, src) in arr.iter_mut().zip(values.iter()) {
+                            *dst = *src;
+                        }
+                        arr
+                    };
+
+                    let v = Vector::<$d>::new(arr);
+
+                    for i in 0..$d {
+                        assert_abs_diff_eq!(v.as_array()[i], arr[i], epsilon = 0.0);
+                    }
+
+                    let out = v.into_array();
+                    for i in 0..$d {
+                        assert_abs_diff_eq!(out[i], arr[i], epsilon = 0.0);
+                    }
+                }
+
+                #[test]
+                fn [<vector_zero_as_array_into_array_default_ $d d>]() {
+                    let z = Vector::<$d>::zero();
+                    for &x in z.as_array() {
+                        assert_abs_diff_eq!(x, 0.0, epsilon = 0.0);
+                    }
+                    for x in z.into_array() {
+                        assert_abs_diff_eq!(x, 0.0, epsilon = 0.0);
+                    }
+
+                    let d = Vector::<$d>::default();
+                    for x in d.into_array() {
+                        assert_abs_diff_eq!(x, 0.0, epsilon = 0.0);
+                    }
+                }
+
+                #[test]
+                fn [<vector_dot_and_norm2_sq_ $d d>]() {
+                    // Use black_box to avoid constant-folding/inlining eliminating the actual dot loop,
+                    // which can make coverage tools report the mul_add line as uncovered.
+
+                    let a_arr = {
+                        let mut arr = [0.0f64; $d];
+                        let values = [1.0f64, 2.0, 3.0, 4.0, 5.0];
+                        for (dst, src) in arr.iter_mut().zip(values.iter()) {
+                            *dst = black_box(*src);
+                        }
+                        arr
+                    };
+                    let b_arr = {
+                        let mut arr = [0.0f64; $d];
+                        let values = [-2.0f64, 0.5, 4.0, -1.0, 2.0];
+                        for (dst, src) in arr.iter_mut().zip(values.iter()) {
+                            *dst = black_box(*src);
+                        }
+                        arr
+                    };
+
+                    let expected_dot = {
+                        let mut acc = 0.0;
+                        let mut i = 0;
+                        while i < $d {
+                            acc = a_arr[i].mul_add(b_arr[i], acc);
+                            i += 1;
+                        }
+                        acc
+                    };
+                    let expected_norm2_sq = {
+                        let mut acc = 0.0;
+                        let mut i = 0;
+                        while i < $d {
+                            acc = a_arr[i].mul_add(a_arr[i], acc);
+                            i += 1;
+                        }
+                        acc
+                    };
+
+                    let a = Vector::<$d>::new(black_box(a_arr));
+                    let b = Vector::<$d>::new(black_box(b_arr));
+
+                    // Call via (black_boxed) fn pointers to discourage inlining, improving line-level coverage
+                    // attribution for the loop body.
+                    let dot_fn: fn(&Vector<$d>, &Vector<$d>) -> Result<f64, LaError> =
+                        black_box(Vector::<$d>::dot);
+                    let norm2_sq_fn: fn(&Vector<$d>) -> Result<f64, LaError> =
+                        black_box(Vector::<$d>::norm2_sq);
+
+                    assert_abs_diff_eq!(
+                        dot_fn(black_box(&a), black_box(&b)).unwrap(),
+                        expected_dot,
+                        epsilon = 1e-14
+                    );
+                    assert_abs_diff_eq!(
+                        norm2_sq_fn(black_box(&a)).unwrap(),
+                        expected_norm2_sq,
+                        epsilon = 1e-14
+                    );
+                }
+
+                #[test]
+                fn [<vector_try_new_rejects_non_finite_ $d d>]() {
+                    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+                        let mut data = [1.0f64; $d];
+                        data[$d - 1] = value;
+                        assert_eq!(
+                            Vector::<$d>::try_new(data),
+                            Err(LaError::non_finite_input_vector($d - 1))
+                        );
+                    }
+
+                    let mut data = [1.0f64; $d];
+                    data[0] = f64::INFINITY;
+                    data[$d - 1] = f64::NAN;
+                    assert_eq!(
+                        Vector::<$d>::try_new(data),
+                        Err(LaError::non_finite_input_vector(0))
+                    );
+                }
+
+                #[test]
+                fn [<vector_try_from_iter_requires_exact_length_ $d d>]() {
+                    let values = (1..=$d).map(|i| f64::from(u32::try_from(i).unwrap()));
+                    let expected: [f64; $d] = core::array::from_fn(|i| {
+                        f64::from(u32::try_from(i + 1).unwrap())
+                    });
+                    assert_eq!(
+                        Vector::<$d>::try_from_iter(values.clone()),
+                        Ok(Vector::new(expected))
+                    );
+                    assert_eq!(
+                        Vector::<$d>::try_from_iter(values.clone().take($d - 1)),
+                        Err(LaError::dimension_mismatch($d, $d - 1))
+                    );
+                    assert_eq!(
+                        Vector::<$d>::try_from_iter(values.chain([0.0, 0.0])),
+                        Err(LaError::dimension_mismatch($d, $d + 2))
+                    );
+                    assert_eq!(
+                        Vector::<$d>::try_from_iter(core::iter::repeat_n(f64::NAN, $d)),
+                        Err(LaError::non_finite_input_vector(0))
+                    );
+                }
+
+                #[test]
+                fn [<vector_from_computation_preserves_failure_provenance_ $d d>]() {
+                    let mut data = [1.0f64; $d];
+                    data[$d - 1] = f64::INFINITY;
+
+                    assert_eq!(
+                        Vector::<$d>::from_computation(
+                            data,
+                            ArithmeticOperation::LuSolve,
+                        ),
+                        Err(LaError::non_finite_computation_step(
+                            ArithmeticOperation::LuSolve,
+                            $d - 1,
+                        ))
+                    );
+                }
+
+                #[test]
+                fn [<vector_dot_and_norm2_sq_reject_overflow_ $d d>]() {
+                    let mut a_arr = [1.0f64; $d];
+                    a_arr[0] = f64::MAX;
+                    let a = Vector::<$d>::new(a_arr);
+
+                    let mut b_arr = [1.0f64; $d];
+                    b_arr[0] = 2.0;
+                    let b = Vector::<$d>::new(b_arr);
+
+                    assert_eq!(
+                        a.dot(&b),
+                        Err(LaError::non_finite_computation_step(
+                            ArithmeticOperation::VectorDotProduct,
+                            0,
+                        ))
+                    );
+                    assert_eq!(
+                        a.norm2_sq(),
+                        Err(LaError::non_finite_computation_step(
+                            ArithmeticOperation::VectorSquaredNorm,
+                            0,
+                        ))
+                    );
+                }
+
+            }
+        };
+    }
+
+    // Mirror delaunay-style multi-dimension tests.
+    gen_vector_tests!(1);
+    gen_vector_tests!(2);
+    gen_vector_tests!(3);
+    gen_vector_tests!(4);
+    gen_vector_tests!(5);
+
+    macro_rules! gen_vector_replay_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<vector_dot_and_norm2_sq_report_last_overflowing_step_ $d d>]() {
+                    let mut dot_lhs = [1.0f64; $d];
+                    dot_lhs[$d - 1] = f64::MAX;
+                    let mut dot_rhs = [1.0f64; $d];
+                    dot_rhs[$d - 1] = 2.0;
+                    let dot_lhs = Vector::<$d>::new(dot_lhs);
+                    let dot_rhs = Vector::<$d>::new(dot_rhs);
+
+                    assert_eq!(
+                        dot_lhs.dot(&dot_rhs),
+                        Err(LaError::non_finite_computation_step(
+                            ArithmeticOperation::VectorDotProduct,
+                            $d - 1,
+                        ))
+                    );
+
+                    let mut norm_data = [1.0f64; $d];
+                    norm_data[$d - 1] = f64::MAX;
+                    let vector = Vector::<$d>::new(norm_data);
+
+                    assert_eq!(
+                        vector.norm2_sq(),
+                        Err(LaError::non_finite_computation_step(
+                            ArithmeticOperation::VectorSquaredNorm,
+                            $d - 1,
+                        ))
+                    );
+                }
+            }
+        };
+    }
+
+    gen_vector_replay_tests!(2);
+    gen_vector_replay_tests!(3);
+    gen_vector_replay_tests!(4);
+    gen_vector_replay_tests!(5);
+
+    macro_rules! gen_vector_const_eval_tests {
+        ($d:literal, $dot:literal, $norm2_sq:literal) => {
+            paste! {
+                #[test]
+                fn [<vector_dot_and_norm2_sq_const_eval_ $d d>]() {
+                    const DOT: Result<f64, LaError> = Vector::<$d>::new([1.0; $d])
+                        .dot(&Vector::<$d>::new([2.0; $d]));
+                    const NORM2_SQ: Result<f64, LaError> =
+                        Vector::<$d>::new([1.0; $d]).norm2_sq();
+
+                    assert_eq!(DOT, Ok($dot));
+                    assert_eq!(NORM2_SQ, Ok($norm2_sq));
+                }
+            }
+        };
+    }
+
+    gen_vector_const_eval_tests!(2, 4.0, 2.0);
+    gen_vector_const_eval_tests!(3, 6.0, 3.0);
+    gen_vector_const_eval_tests!(4, 8.0, 4.0);
+    gen_vector_const_eval_tests!(5, 10.0, 5.0);
+
+    #[test]
+    fn vector_dot_and_norm2_sq_overflow_const_eval() {
+        const DOT: Result<f64, LaError> =
+            Vector::<2>::new([f64::MAX; 2]).dot(&Vector::<2>::new([1.0; 2]));
+        const NORM2_SQ: Result<f64, LaError> = Vector::<2>::new([f64::MAX; 2]).norm2_sq();
+
+        assert_eq!(
+            DOT,
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::VectorDotProduct,
+                1,
+            ))
+        );
+        assert_eq!(
+            NORM2_SQ,
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::VectorSquaredNorm,
+                0,
+            ))
+        );
+    }
+
+    #[test]
+    fn vector_dot_and_norm2_sq_preserve_fma_and_left_to_right_order() {
+        let dot_large = 9_007_199_254_740_992.0;
+        let dot_lhs = Vector::<4>::new([dot_large, 1.0, 1.0, 1.0]);
+        let dot_rhs = Vector::<4>::new([1.0; 4]);
+        assert_eq!(dot_lhs.dot(&dot_rhs), Ok(dot_large));
+
+        let fused_lhs = Vector::<2>::new([f64::MAX, f64::MAX]);
+        let fused_rhs = Vector::<2>::new([-1.0, 2.0]);
+        assert_eq!(fused_lhs.dot(&fused_rhs), Ok(f64::MAX));
+
+        let norm_large = 134_217_728.0;
+        let vector = Vector::<4>::new([norm_large, 1.0, 1.0, 1.0]);
+        assert_eq!(vector.norm2_sq(), Ok(norm_large * norm_large));
+    }
+
+    #[test]
+    fn vector_dot_and_norm2_sq_report_first_middle_overflowing_step() {
+        let dot_lhs = Vector::<3>::new([f64::MAX, f64::MAX, 1.0]);
+        let dot_rhs = Vector::<3>::new([1.0; 3]);
+        assert_eq!(
+            dot_lhs.dot(&dot_rhs),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::VectorDotProduct,
+                1,
+            ))
+        );
+
+        let norm_large = 1.0e154;
+        let vector = Vector::<3>::new([norm_large, norm_large, 1.0]);
+        assert_eq!(
+            vector.norm2_sq(),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::VectorSquaredNorm,
+                1,
+            ))
+        );
+    }
+
+    #[test]
+    fn zero_dimension_vector_has_zero_dot_and_norm() {
+        let vector = Vector::<0>::try_new([]).unwrap();
+
+        assert!(vector.as_array().is_empty());
+        assert!(vector.into_array().is_empty());
+        assert_eq!(vector.dot(&Vector::zero()), Ok(0.0));
+        assert_eq!(vector.norm2_sq(), Ok(0.0));
+    }
+
+    macro_rules! gen_to_diagonal_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<to_diagonal_places_components_on_diagonal_ $d d>]() {
+                    let v = Vector::<$d>::new(core::array::from_fn(|i| {
+                        f64::from(u32::try_from(i).unwrap()) - 1.5
+                    }));
+                    let d = v.to_diagonal();
+                    for r in 0..$d {
+                        for c in 0..$d {
+                            let expected = if r == c { v.data[r] } else { 0.0 };
+                            assert_eq!(d.get(r, c).unwrap().to_bits(), expected.to_bits());
+                        }
                     }
+                    assert_eq!(Vector::<$d>::new([1.0; $d]).to_diagonal(), Matrix::identity());
+                    assert_eq!(d.mul_vec(&Vector::new([1.0; $d])), Ok(v));
+                }
+            }
+        };
+    }
 
-                    let out = v.into_array();
-                    for i in 0..$d {
-                        assert_abs_diff_eq!(out[i], arr[i], epsilon = 0.0);
+    gen_to_diagonal_tests!(2);
+    gen_to_diagonal_tests!(3);
+    gen_to_diagonal_tests!(4);
+    gen_to_diagonal_tests!(5);
+
+    macro_rules! gen_argmax_argmin_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<argmax_argmin_find_extremes_and_first_tie_ $d d>]() {
+                    let mut arr = [0.0f64; $d];
+                    arr[$d - 1] = 4.0;
+                    arr[$d - 2] = -4.0;
+                    let v = Vector::<$d>::new(arr);
+                    assert_eq!(v.argmax(), Some($d - 1));
+                    assert_eq!(v.argmin(), Some($d - 2));
+
+                    let constant = Vector::<$d>::new([7.0; $d]);
+                    assert_eq!(constant.argmax(), Some(0));
+                    assert_eq!(constant.argmin(), Some(0));
+                }
+            }
+        };
+    }
+
+    gen_argmax_argmin_tests!(2);
+    gen_argmax_argmin_tests!(3);
+    gen_argmax_argmin_tests!(4);
+    gen_argmax_argmin_tests!(5);
+
+    macro_rules! gen_midpoint_centroid_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<centroid_of_symmetric_point_set_is_origin_ $d d>]() {
+                    // ±2e_i for every axis, plus ±(1, 2, …, D).
+                    let mut points = [Vector::<$d>::zero(); 2 * $d + 2];
+                    for (i, pair) in points.chunks_exact_mut(2).take($d).enumerate() {
+                        let mut axis = [0.0; $d];
+                        axis[i] = 2.0;
+                        pair[0] = Vector::new(axis);
+                        pair[1] = Vector::new(axis.map(|x| -x));
+                    }
+                    let ramp: [f64; $d] = core::array::from_fn(|i| f64::from(u32::try_from(i).unwrap()) + 1.0);
+                    points[2 * $d] = Vector::new(ramp);
+                    points[2 * $d + 1] = Vector::new(ramp.map(|x| -x));
+
+                    assert_eq!(Vector::centroid(&points), Ok(Vector::<$d>::zero()));
+                    for pair in points.chunks_exact(2) {
+                        assert_eq!(pair[0].midpoint(pair[1]), Vector::<$d>::zero());
                     }
                 }
 
                 #[test]
-                fn [<vector_zero_as_array_into_array_default_ $d d>]() {
-                    let z = Vector::<$d>::zero();
-                    for &x in z.as_array() {
-                        assert_abs_diff_eq!(x, 0.0, epsilon = 0.0);
+                fn [<centroid_of_two_points_is_midpoint_ $d d>]() {
+                    let a = Vector::<$d>::new(core::array::from_fn(|i| f64::from(u32::try_from(i).unwrap())));
+                    let b = Vector::<$d>::new([4.0; $d]);
+                    assert_eq!(Vector::centroid(&[a, b]), Ok(a.midpoint(b)));
+                    assert_eq!(Vector::centroid(&[a]), Ok(a));
+                    assert_eq!(a.midpoint(a), a);
+                }
+            }
+        };
+    }
+
+    gen_midpoint_centroid_tests!(2);
+    gen_midpoint_centroid_tests!(3);
+    gen_midpoint_centroid_tests!(4);
+    gen_midpoint_centroid_tests!(5);
+
+    #[test]
+    fn midpoint_and_centroid_avoid_intermediate_overflow() {
+        const MAX: Vector<2> = Vector::<2>::new([f64::MAX, -f64::MAX]);
+        const MIDPOINT: Vector<2> = MAX.midpoint(MAX);
+        let max = MAX;
+        assert_eq!(MIDPOINT, max);
+        assert_eq!(Vector::centroid(&[max; 4]), Ok(max));
+        // f64::MAX / 3 rounds up, so three copies sum past the range.
+        assert_eq!(
+            Vector::centroid(&[max; 3]),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::Centroid,
+                0
+            ))
+        );
+        assert_eq!(
+            Vector::<2>::centroid::<0>(&[]),
+            Err(LaError::dimension_mismatch(1, 0))
+        );
+    }
+
+    macro_rules! gen_max_min_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<max_min_fold_to_bounding_box_ $d d>]() {
+                    // Point k has coordinate i equal to ((k + 3 i) mod 7) − 3.
+                    let points: [Vector<$d>; 6] = core::array::from_fn(|k| {
+                        Vector::new(core::array::from_fn(|i| {
+                            f64::from(u32::try_from((k + 3 * i) % 7).unwrap()) - 3.0
+                        }))
+                    });
+                    let lower = points.iter().fold(points[0], |lo, &p| lo.min(p));
+                    let upper = points.iter().fold(points[0], |hi, &p| hi.max(p));
+
+                    for point in &points {
+                        assert_eq!(lower.min(*point), lower);
+                        assert_eq!(upper.max(*point), upper);
                     }
-                    for x in z.into_array() {
-                        assert_abs_diff_eq!(x, 0.0, epsilon = 0.0);
+                    for (i, (&lo, &hi)) in lower.data.iter().zip(&upper.data).enumerate() {
+                        let coords = points.map(|p| p.data[i]);
+                        assert!(coords.contains(&lo) && coords.contains(&hi));
+                        assert!(coords.iter().all(|&x| lo <= x && x <= hi));
                     }
 
-                    let d = Vector::<$d>::default();
-                    for x in d.into_array() {
-                        assert_abs_diff_eq!(x, 0.0, epsilon = 0.0);
+                    let a = points[1];
+                    let b = points[4];
+                    assert_eq!(a.max(b), b.max(a));
+                    assert_eq!(a.min(b), b.min(a));
+                    assert_eq!(a.max(a), a);
+                }
+            }
+        };
+    }
+
+    gen_max_min_tests!(2);
+    gen_max_min_tests!(3);
+    gen_max_min_tests!(4);
+    gen_max_min_tests!(5);
+
+    #[test]
+    fn max_min_handle_extreme_values() {
+        let a = Vector::<3>::new([f64::MAX, -f64::MAX, 1.0]);
+        let b = Vector::<3>::new([-f64::MAX, f64::MAX, f64::from_bits(1)]);
+        assert_eq!(a.max(b), Vector::new([f64::MAX, f64::MAX, 1.0]));
+        assert_eq!(
+            a.min(b),
+            Vector::new([-f64::MAX, -f64::MAX, f64::from_bits(1)])
+        );
+        assert_eq!(Vector::<0>::zero().max(Vector::zero()), Vector::<0>::zero());
+    }
+
+    macro_rules! gen_generalized_cross_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<generalized_cross_is_orthogonal_and_matches_determinant_ $d d>]() {
+                    let vectors: [Vector<$d>; $d - 1] = core::array::from_fn(|k| {
+                        Vector::new(core::array::from_fn(|i| {
+                            let base = if i == k { 3.0 } else { 0.0 };
+                            base + f64::from(u32::try_from((k + 2 * i) % 5).unwrap()) - 2.0
+                        }))
+                    });
+                    let c = Vector::generalized_cross(&vectors).unwrap();
+                    for v in &vectors {
+                        assert_abs_diff_eq!(c.dot(v).unwrap(), 0.0, epsilon = 1e-12);
+                    }
+
+                    let mut rows = [[0.0; $d]; $d];
+                    for (row, v) in rows.iter_mut().zip(&vectors) {
+                        *row = v.into_array();
                     }
+                    rows[$d - 1] = c.into_array();
+                    let det = Matrix::<$d>::try_from_rows(rows).unwrap().det().unwrap();
+                    assert!(det > 0.0);
+                    assert_abs_diff_eq!(det, c.norm2_sq().unwrap(), epsilon = 1e-9 * det);
+
+                    let mut dependent = vectors;
+                    dependent[$d - 2] = Vector::zero();
+                    assert_eq!(
+                        Vector::generalized_cross(&dependent),
+                        Ok(Vector::<$d>::zero())
+                    );
+                }
+            }
+        };
+    }
+
+    gen_generalized_cross_tests!(2);
+    gen_generalized_cross_tests!(3);
+    gen_generalized_cross_tests!(4);
+    gen_generalized_cross_tests!(5);
+
+    #[test]
+    fn generalized_cross_matches_3d_cross_product() {
+        let pairs = [
+            ([1.0_f64, 2.0, 3.0], [-4.0, 0.5, 2.0]),
+            ([0.0, 1.0, 0.0], [1.0, 0.0, 0.0]),
+            ([2.5, -1.0, 7.0], [3.0, 3.0, -0.25]),
+        ];
+        for ([ax, ay, az], [bx, by, bz]) in pairs {
+            let expected = [
+                ay.mul_add(bz, -(az * by)),
+                az.mul_add(bx, -(ax * bz)),
+                ax.mul_add(by, -(ay * bx)),
+            ];
+            let a = Vector::<3>::new([ax, ay, az]);
+            let b = Vector::<3>::new([bx, by, bz]);
+            let c = Vector::generalized_cross(&[a, b]).unwrap().into_array();
+            for (got, want) in c.into_iter().zip(expected) {
+                assert_abs_diff_eq!(got, want, epsilon = 1e-13);
+            }
+        }
+
+        let a = Vector::<3>::new([1.0, -2.0, 0.5]);
+        assert_eq!(Vector::generalized_cross(&[a, a]), Ok(Vector::zero()));
+
+        let quarter_turn = Vector::generalized_cross(&[Vector::<2>::new([3.0, 4.0])]);
+        assert_eq!(quarter_turn, Ok(Vector::new([-4.0, 3.0])));
+    }
+
+    #[test]
+    fn generalized_cross_rejects_wrong_count_and_overflow() {
+        let v = Vector::<3>::new([1.0, 0.0, 0.0]);
+        assert_eq!(
+            Vector::generalized_cross(&[v]),
+            Err(LaError::dimension_mismatch(3, 2))
+        );
+        assert_eq!(
+            Vector::generalized_cross(&[v, v, v]),
+            Err(LaError::dimension_mismatch(3, 4))
+        );
+        assert_eq!(
+            Vector::<0>::generalized_cross::<0>(&[]),
+            Err(LaError::dimension_mismatch(0, 1))
+        );
+
+        let big = Vector::<3>::new([0.0, f64::MAX, 0.0]);
+        let other = Vector::<3>::new([0.0, 0.0, f64::MAX]);
+        assert_matches!(
+            Vector::generalized_cross(&[big, other]),
+            Err(LaError::NonFinite { .. })
+        );
+    }
+
+    macro_rules! gen_permute_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<permute_swaps_and_reverses_components_ $d d>]() {
+                    let v = Vector::<$d>::new(core::array::from_fn(|i| {
+                        f64::from(u32::try_from(i).unwrap()) + 1.0
+                    }));
+                    let identity: [usize; $d] = core::array::from_fn(|i| i);
+                    assert_eq!(v.permute(&identity), Some(v));
+
+                    let mut swap = identity;
+                    swap.swap(0, $d - 1);
+                    let mut expected = *v.as_array();
+                    expected.swap(0, $d - 1);
+                    assert_eq!(v.permute(&swap), Some(Vector::<$d>::new(expected)));
+
+                    let reverse: [usize; $d] = core::array::from_fn(|i| $d - 1 - i);
+                    let reversed = v.permute(&reverse).unwrap();
+                    assert_eq!(reversed.argmax(), Some(0));
+                    assert_eq!(reversed.permute(&reverse), Some(v));
                 }
 
                 #[test]
-                fn [<vector_dot_and_norm2_sq_ $d d>]() {
-                    // Use black_box to avoid constant-folding/inlining eliminating the actual dot loop,
-                    // which can make coverage tools report the mul_add line as uncovered.
+                fn [<permute_rejects_duplicate_and_out_of_range_indices_ $d d>]() {
+                    let v = Vector::<$d>::new([1.0; $d]);
+                    let mut duplicate: [usize; $d] = core::array::from_fn(|i| i);
+                    duplicate[$d - 1] = 0;
+                    assert_eq!(v.permute(&duplicate), None);
 
-                    let a_arr = {
-                        let mut arr = [0.0f64; $d];
-                        let values = [1.0f64, 2.0, 3.0, 4.0, 5.0];
-                        for (dst, src) in arr.iter_mut().zip(values.iter()) {
-                            *dst = black_box(*src);
-                        }
-                        arr
-                    };
-                    let b_arr = {
-                        let mut arr = [0.0f64; $d];
-                        let values = [-2.0f64, 0.5, 4.0, -1.0, 2.0];
-                        for (dst, src) in arr.iter_mut().zip(values.iter()) {
-                            *dst = black_box(*src);
-                        }
-                        arr
-                    };
+                    let mut out_of_range: [usize; $d] = core::array::from_fn(|i| i);
+                    out_of_range[0] = $d;
+                    assert_eq!(v.permute(&out_of_range), None);
+                }
+            }
+        };
+    }
 
-                    let expected_dot = {
-                        let mut acc = 0.0;
-                        let mut i = 0;
-                        while i < $d {
-                            acc = a_arr[i].mul_add(b_arr[i], acc);
-                            i += 1;
-                        }
-                        acc
-                    };
-                    let expected_norm2_sq = {
-                        let mut acc = 0.0;
-                        let mut i = 0;
-                        while i < $d {
-                            acc = a_arr[i].mul_add(a_arr[i], acc);
-                            i += 1;
-                        }
-                        acc
-                    };
+    gen_permute_tests!(2);
+    gen_permute_tests!(3);
+    gen_permute_tests!(4);
+    gen_permute_tests!(5);
 
-                    let a = Vector::<$d>::new(black_box(a_arr));
-                    let b = Vector::<$d>::new(black_box(b_arr));
+    #[test]
+    fn argmax_argmin_treat_signed_zeros_as_ties() {
+        let v = Vector::<2>::new([-0.0, 0.0]);
+        assert_eq!(v.argmax(), Some(0));
+        assert_eq!(v.argmin(), Some(0));
+        assert_eq!(Vector::<0>::zero().argmin(), None);
+    }
 
-                    // Call via (black_boxed) fn pointers to discourage inlining, improving line-level coverage
-                    // attribution for the loop body.
-                    let dot_fn: fn(&Vector<$d>, &Vector<$d>) -> Result<f64, LaError> =
-                        black_box(Vector::<$d>::dot);
-                    let norm2_sq_fn: fn(&Vector<$d>) -> Result<f64, LaError> =
-                        black_box(Vector::<$d>::norm2_sq);
+    #[test]
+    fn rotate_2d_quarter_turn_maps_x_to_y() {
+        let [x, y] = Vector::<2>::new([1.0, 0.0])
+            .rotate(core::f64::consts::FRAC_PI_2)
+            .unwrap()
+            .into_array();
+        assert_abs_diff_eq!(x, 0.0, epsilon = 1e-15);
+        assert_abs_diff_eq!(y, 1.0, epsilon = 1e-15);
 
-                    assert_abs_diff_eq!(
-                        dot_fn(black_box(&a), black_box(&b)).unwrap(),
-                        expected_dot,
-                        epsilon = 1e-14
-                    );
-                    assert_abs_diff_eq!(
-                        norm2_sq_fn(black_box(&a)).unwrap(),
-                        expected_norm2_sq,
-                        epsilon = 1e-14
-                    );
+        let v = Vector::<2>::new([3.0, -4.0]);
+        assert_eq!(v.rotate(0.0), Ok(v));
+        let back = v.rotate(0.9).unwrap().rotate(-0.9).unwrap().into_array();
+        assert_abs_diff_eq!(back[0], 3.0, epsilon = 1e-14);
+        assert_abs_diff_eq!(back[1], -4.0, epsilon = 1e-14);
+    }
+
+    #[test]
+    fn rotate_around_z_matches_2d_rotation_in_xy_plane() {
+        let z_axis = Vector::<3>::new([0.0, 0.0, 5.0]);
+        for theta in [0.3, -1.2, 2.5] {
+            let planar = Vector::<2>::new([2.0, -1.0])
+                .rotate(theta)
+                .unwrap()
+                .into_array();
+            let spatial = Vector::<3>::new([2.0, -1.0, 0.75])
+                .rotate_around(z_axis, theta)
+                .unwrap()
+                .into_array();
+            assert_abs_diff_eq!(spatial[0], planar[0], epsilon = 1e-14);
+            assert_abs_diff_eq!(spatial[1], planar[1], epsilon = 1e-14);
+            assert_abs_diff_eq!(spatial[2], 0.75, epsilon = 1e-14);
+        }
+    }
+
+    #[test]
+    fn rotate_around_preserves_length_and_fixes_axis() {
+        let axis = Vector::<3>::new([1e-200, 2e-200, -2e-200]);
+        let v = Vector::<3>::new([0.5, -1.5, 2.0]);
+        let rotated = v.rotate_around(axis, 1.1).unwrap();
+        assert_abs_diff_eq!(
+            rotated.norm2_sq().unwrap(),
+            v.norm2_sq().unwrap(),
+            epsilon = 1e-13
+        );
+
+        let on_axis = Vector::<3>::new([1.0, 2.0, -2.0]);
+        let fixed = on_axis.rotate_around(axis, 1.1).unwrap().into_array();
+        for (actual, expected) in fixed.into_iter().zip([1.0, 2.0, -2.0]) {
+            assert_abs_diff_eq!(actual, expected, epsilon = 1e-14);
+        }
+    }
+
+    #[test]
+    fn rotations_reject_invalid_inputs_and_overflow() {
+        let v3 = Vector::<3>::new([1.0, 0.0, 0.0]);
+        assert_eq!(
+            v3.rotate_around(Vector::zero(), 1.0),
+            Err(LaError::zero_vector(3))
+        );
+        for theta in [f64::NAN, f64::INFINITY] {
+            assert_eq!(
+                v3.rotate_around(Vector::<3>::new([0.0, 0.0, 1.0]), theta),
+                Err(LaError::non_finite_input_scalar())
+            );
+            assert_eq!(
+                Vector::<2>::new([1.0, 0.0]).rotate(theta),
+                Err(LaError::non_finite_input_scalar())
+            );
+        }
+
+        assert_eq!(
+            Vector::<2>::new([f64::MAX, f64::MAX]).rotate(core::f64::consts::FRAC_PI_4),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::VectorRotation,
+                1
+            ))
+        );
+    }
+
+    macro_rules! gen_cumsum_diff_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<cumsum_of_ones_counts_and_diff_recovers_ones_ $d d>]() {
+                    let sums = Vector::<$d>::new([1.0; $d]).cumsum().unwrap();
+                    let mut expected = [0.0; $d];
+                    for (i, value) in expected.iter_mut().enumerate() {
+                        *value = f64::from(u32::try_from(i + 1).unwrap());
+                    }
+                    assert_eq!(sums, Vector::new(expected));
+
+                    let mut ones = [1.0; $d];
+                    ones[$d - 1] = 0.0;
+                    assert_eq!(sums.diff(), Ok(Vector::new(ones)));
                 }
+            }
+        };
+    }
+
+    gen_cumsum_diff_tests!(2);
+    gen_cumsum_diff_tests!(3);
+    gen_cumsum_diff_tests!(4);
+    gen_cumsum_diff_tests!(5);
+
+    #[test]
+    fn cumsum_and_diff_handle_short_vectors() {
+        assert_eq!(Vector::<0>::zero().cumsum(), Ok(Vector::zero()));
+        assert_eq!(Vector::<0>::zero().diff(), Ok(Vector::zero()));
+        assert_eq!(Vector::<1>::new([3.0]).cumsum(), Ok(Vector::new([3.0])));
+        assert_eq!(Vector::<1>::new([3.0]).diff(), Ok(Vector::zero()));
+    }
+
+    #[test]
+    fn cumsum_and_diff_report_overflow_index() {
+        assert_eq!(
+            Vector::<3>::new([1.0, f64::MAX, f64::MAX]).cumsum(),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::CumulativeSum,
+                2
+            ))
+        );
+        assert_eq!(
+            Vector::<3>::new([0.0, f64::MAX, -f64::MAX]).diff(),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::VectorDifference,
+                1
+            ))
+        );
+    }
 
+    macro_rules! gen_weighted_dot_tests {
+        ($d:literal) => {
+            paste! {
                 #[test]
-                fn [<vector_try_new_rejects_non_finite_ $d d>]() {
-                    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
-                        let mut data = [1.0f64; $d];
-                        data[$d - 1] = value;
-                        assert_eq!(
-                            Vector::<$d>::try_new(data),
-                            Err(LaError::non_finite_input_vector($d - 1))
-                        );
+                fn [<weighted_dot_with_unit_weights_matches_dot_ $d d>]() {
+                    let mut a = [0.0; $d];
+                    let mut b = [0.0; $d];
+                    for i in 0..$d {
+                        let k = f64::from(u32::try_from(i).unwrap());
+                        a[i] = k + 0.25;
+                        b[i] = 1.5 - k;
                     }
-
-                    let mut data = [1.0f64; $d];
-                    data[0] = f64::INFINITY;
-                    data[$d - 1] = f64::NAN;
+                    let a = Vector::<$d>::new(a);
+                    let b = Vector::<$d>::new(b);
+                    let ones = Vector::<$d>::new([1.0; $d]);
                     assert_eq!(
-                        Vector::<$d>::try_new(data),
-                        Err(LaError::non_finite_input_vector(0))
+                        a.weighted_dot(&b, &ones).unwrap().to_bits(),
+                        a.dot(&b).unwrap().to_bits()
                     );
+                    assert_eq!(a.weighted_dot(&b, &Vector::zero()), Ok(0.0));
                 }
+            }
+        };
+    }
 
-                #[test]
-                fn [<vector_from_computation_preserves_failure_provenance_ $d d>]() {
-                    let mut data = [1.0f64; $d];
-                    data[$d - 1] = f64::INFINITY;
+    gen_weighted_dot_tests!(2);
+    gen_weighted_dot_tests!(3);
+    gen_weighted_dot_tests!(4);
+    gen_weighted_dot_tests!(5);
 
-                    assert_eq!(
-                        Vector::<$d>::from_computation(
-                            data,
-                            ArithmeticOperation::LuSolve,
-                        ),
-                        Err(LaError::non_finite_computation_step(
-                            ArithmeticOperation::LuSolve,
-                            $d - 1,
-                        ))
-                    );
-                }
+    #[test]
+    fn weighted_dot_reports_overflow_index() {
+        let a = Vector::<3>::new([1.0, f64::MAX, 1.0]);
+        let w = Vector::<3>::new([1.0, 4.0, 1.0]);
+        assert_eq!(
+            a.weighted_dot(&Vector::new([1.0; 3]), &w),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::WeightedDotProduct,
+                1
+            ))
+        );
+    }
 
+    macro_rules! gen_normalize_tests {
+        ($d:literal) => {
+            paste! {
                 #[test]
-                fn [<vector_dot_and_norm2_sq_reject_overflow_ $d d>]() {
-                    let mut a_arr = [1.0f64; $d];
-                    a_arr[0] = f64::MAX;
-                    let a = Vector::<$d>::new(a_arr);
+                fn [<normalize_yields_unit_vector_at_extreme_scales_ $d d>]() {
+                    for scale in [1e-300, 1.0, 1e300] {
+                        let mut data = [0.0; $d];
+                        for (i, value) in data.iter_mut().enumerate() {
+                            *value = scale * (f64::from(u32::try_from(i).unwrap()) - 0.5);
+                        }
+                        let unit = Vector::<$d>::new(data).normalize().unwrap();
+                        assert_abs_diff_eq!(unit.norm2_sq().unwrap(), 1.0, epsilon = 1e-15);
+                    }
+                    assert_eq!(Vector::<$d>::zero().normalize(), Err(LaError::zero_vector($d)));
+                }
+            }
+        };
+    }
 
-                    let mut b_arr = [1.0f64; $d];
-                    b_arr[0] = 2.0;
-                    let b = Vector::<$d>::new(b_arr);
+    gen_normalize_tests!(2);
+    gen_normalize_tests!(3);
+    gen_normalize_tests!(4);
+    gen_normalize_tests!(5);
 
-                    assert_eq!(
-                        a.dot(&b),
-                        Err(LaError::non_finite_computation_step(
-                            ArithmeticOperation::VectorDotProduct,
-                            0,
-                        ))
-                    );
-                    assert_eq!(
-                        a.norm2_sq(),
-                        Err(LaError::non_finite_computation_step(
-                            ArithmeticOperation::VectorSquaredNorm,
-                            0,
-                        ))
-                    );
+    macro_rules! gen_is_unit_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<is_unit_accepts_basis_and_normalized_vectors_ $d d>]() {
+                    let tol = Tolerance::try_new(1e-12).unwrap();
+                    for i in 0..$d {
+                        assert!(Vector::<$d>::basis(i).is_unit(Tolerance::ZERO));
+                    }
+                    let v = Vector::<$d>::new([0.5; $d]);
+                    assert_eq!(v.is_unit(tol), $d == 4);
+                    assert!(v.normalize().unwrap().is_unit(tol));
+                    assert!(!Vector::<$d>::zero().is_unit(tol));
                 }
-
             }
         };
     }
 
-    // Mirror delaunay-style multi-dimension tests.
-    gen_vector_tests!(1);
-    gen_vector_tests!(2);
-    gen_vector_tests!(3);
-    gen_vector_tests!(4);
-    gen_vector_tests!(5);
+    gen_is_unit_tests!(2);
+    gen_is_unit_tests!(3);
+    gen_is_unit_tests!(4);
+    gen_is_unit_tests!(5);
 
-    macro_rules! gen_vector_replay_tests {
+    #[test]
+    fn is_unit_respects_tolerance_and_overflow() {
+        let v = Vector::<2>::new([1.0, 1e-4]); // ‖v‖² = 1 + 1e-8
+        assert!(!v.is_unit(Tolerance::try_new(1e-9).unwrap()));
+        assert!(v.is_unit(Tolerance::try_new(1e-7).unwrap()));
+        assert!(!Vector::<2>::new([f64::MAX, 1.0]).is_unit(Tolerance::try_new(1e300).unwrap()));
+    }
+
+    macro_rules! gen_dot_batch_tests {
         ($d:literal) => {
             paste! {
                 #[test]
-                fn [<vector_dot_and_norm2_sq_report_last_overflowing_step_ $d d>]() {
-                    let mut dot_lhs = [1.0f64; $d];
-                    dot_lhs[$d - 1] = f64::MAX;
-                    let mut dot_rhs = [1.0f64; $d];
-                    dot_rhs[$d - 1] = 2.0;
-                    let dot_lhs = Vector::<$d>::new(dot_lhs);
-                    let dot_rhs = Vector::<$d>::new(dot_rhs);
+                fn [<dot_batch_matches_individual_dot_ $d d>]() {
+                    let a = Vector::<$d>::new(core::array::from_fn(|i| {
+                        f64::from(u32::try_from(i).unwrap()) - 0.75
+                    }));
+                    let others: [Vector<$d>; 3] = core::array::from_fn(|k| {
+                        Vector::new(core::array::from_fn(|i| {
+                            f64::from(u32::try_from(i * k + 1).unwrap()) * 0.5
+                        }))
+                    });
+                    for (other, result) in others.iter().zip(a.dot_batch(&others)) {
+                        assert_eq!(result, a.dot(other));
+                    }
+                    assert!(a.dot_batch::<0>(&[]).is_empty());
+                }
+            }
+        };
+    }
 
-                    assert_eq!(
-                        dot_lhs.dot(&dot_rhs),
-                        Err(LaError::non_finite_computation_step(
-                            ArithmeticOperation::VectorDotProduct,
-                            $d - 1,
-                        ))
-                    );
+    gen_dot_batch_tests!(2);
+    gen_dot_batch_tests!(3);
+    gen_dot_batch_tests!(4);
+    gen_dot_batch_tests!(5);
 
-                    let mut norm_data = [1.0f64; $d];
-                    norm_data[$d - 1] = f64::MAX;
-                    let vector = Vector::<$d>::new(norm_data);
+    #[test]
+    fn dot_batch_isolates_overflow() {
+        let a = Vector::<2>::new([f64::MAX, 1.0]);
+        let others = [Vector::new([1.0, 1.0]), Vector::new([2.0, 0.0])];
+        assert_eq!(
+            a.dot_batch(&others),
+            [
+                Ok(f64::MAX + 1.0),
+                Err(LaError::non_finite_computation_step(
+                    ArithmeticOperation::VectorDotProduct,
+                    0
+                ))
+            ]
+        );
+    }
+
+    macro_rules! gen_component_mul_div_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<component_div_inverts_component_mul_ $d d>]() {
+                    let a = Vector::<$d>::new(core::array::from_fn(|i| {
+                        f64::from(u32::try_from(i).unwrap()) - 1.5
+                    }));
+                    let factors = Vector::<$d>::new(core::array::from_fn(|i| {
+                        // Powers of two keep every product and quotient exact.
+                        f64::from(1_u32 << i) * if i % 2 == 0 { 1.0 } else { -0.25 }
+                    }));
 
+                    let product = a.component_mul(&factors).unwrap();
+                    for i in 0..$d {
+                        assert_eq!(
+                            product.data[i].to_bits(),
+                            (a.data[i] * factors.data[i]).to_bits()
+                        );
+                    }
+                    assert_eq!(product.component_div(&factors, DEFAULT_SINGULAR_TOL), Ok(a));
                     assert_eq!(
-                        vector.norm2_sq(),
-                        Err(LaError::non_finite_computation_step(
-                            ArithmeticOperation::VectorSquaredNorm,
-                            $d - 1,
-                        ))
+                        a.component_mul(&Vector::new([1.0; $d])),
+                        Ok(a)
                     );
                 }
             }
         };
     }
 
-    gen_vector_replay_tests!(2);
-    gen_vector_replay_tests!(3);
-    gen_vector_replay_tests!(4);
-    gen_vector_replay_tests!(5);
+    gen_component_mul_div_tests!(2);
+    gen_component_mul_div_tests!(3);
+    gen_component_mul_div_tests!(4);
+    gen_component_mul_div_tests!(5);
 
-    macro_rules! gen_vector_const_eval_tests {
-        ($d:literal, $dot:literal, $norm2_sq:literal) => {
+    #[test]
+    fn component_div_rejects_zero_and_tiny_divisors() {
+        let a = Vector::<3>::new([1.0, 2.0, 3.0]);
+        assert_eq!(
+            a.component_div(&Vector::new([1.0, 0.0, -0.0]), Tolerance::ZERO),
+            Err(LaError::singular_exact(1))
+        );
+        assert_eq!(
+            a.component_div(&Vector::new([1.0, 2.0, -0.0]), DEFAULT_SINGULAR_TOL),
+            Err(LaError::singular_exact(2))
+        );
+        let tol = Tolerance::try_new(1e-6).unwrap();
+        assert_eq!(
+            a.component_div(&Vector::new([1.0, -1e-7, 1.0]), tol),
+            Err(LaError::singular_numerical(
+                1,
+                FactorizationKind::Diagonal,
+                1e-7,
+                1e-6
+            ))
+        );
+        assert!(
+            a.component_div(&Vector::new([1.0, -1e-7, 1.0]), Tolerance::ZERO)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn component_mul_and_div_report_overflow_index() {
+        let a = Vector::<2>::new([1.0, f64::MAX]);
+        assert_eq!(
+            a.component_mul(&Vector::new([2.0, 2.0])),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::ComponentProduct,
+                1
+            ))
+        );
+        assert_eq!(
+            a.component_div(&Vector::new([0.5, 0.5]), Tolerance::ZERO),
+            Err(LaError::non_finite_computation_step(
+                ArithmeticOperation::ComponentQuotient,
+                1
+            ))
+        );
+    }
+
+    macro_rules! gen_standardize_tests {
+        ($d:literal) => {
             paste! {
                 #[test]
-                fn [<vector_dot_and_norm2_sq_const_eval_ $d d>]() {
-                    const DOT: Result<f64, LaError> = Vector::<$d>::new([1.0; $d])
-                        .dot(&Vector::<$d>::new([2.0; $d]));
-                    const NORM2_SQ: Result<f64, LaError> =
-                        Vector::<$d>::new([1.0; $d]).norm2_sq();
-
-                    assert_eq!(DOT, Ok($dot));
-                    assert_eq!(NORM2_SQ, Ok($norm2_sq));
+                fn [<standardize_matches_centered_component_div_ $d d>]() {
+                    let x = Vector::<$d>::new(core::array::from_fn(|i| {
+                        f64::from(u32::try_from(i).unwrap()).mul_add(3.0, 1.0)
+                    }));
+                    let mean = Vector::<$d>::new([1.0; $d]);
+                    let std_dev = Vector::<$d>::new(core::array::from_fn(|i| {
+                        if i % 2 == 0 { 3.0 } else { -1.5 }
+                    }));
+                    let z = x.standardize(&mean, &std_dev, DEFAULT_SINGULAR_TOL).unwrap();
+                    for i in 0..$d {
+                        let k = f64::from(u32::try_from(i).unwrap());
+                        let expected = if i % 2 == 0 { k } else { -2.0 * k };
+                        assert_eq!(z.data[i].to_bits(), expected.to_bits());
+                    }
+                    assert_eq!(
+                        mean.standardize(&mean, &Vector::new([1.0; $d]), Tolerance::ZERO),
+                        Ok(Vector::zero())
+                    );
                 }
             }
         };
     }
 
-    gen_vector_const_eval_tests!(2, 4.0, 2.0);
-    gen_vector_const_eval_tests!(3, 6.0, 3.0);
-    gen_vector_const_eval_tests!(4, 8.0, 4.0);
-    gen_vector_const_eval_tests!(5, 10.0, 5.0);
+    gen_standardize_tests!(2);
+    gen_standardize_tests!(3);
+    gen_standardize_tests!(4);
+    gen_standardize_tests!(5);
 
     #[test]
-    fn vector_dot_and_norm2_sq_overflow_const_eval() {
-        const DOT: Result<f64, LaError> =
-            Vector::<2>::new([f64::MAX; 2]).dot(&Vector::<2>::new([1.0; 2]));
-        const NORM2_SQ: Result<f64, LaError> = Vector::<2>::new([f64::MAX; 2]).norm2_sq();
+    fn standardize_rejects_zero_and_tiny_variance_and_reports_overflow() {
+        let x = Vector::<3>::new([1.0, 2.0, 3.0]);
+        let mean = Vector::<3>::zero();
+        assert_eq!(
+            x.standardize(&mean, &Vector::new([1.0, 0.0, 1.0]), Tolerance::ZERO),
+            Err(LaError::singular_exact(1))
+        );
+        let tol = Tolerance::try_new(1e-6).unwrap();
+        assert_eq!(
+            x.standardize(&mean, &Vector::new([1.0, 1.0, 1e-7]), tol),
+            Err(LaError::singular_numerical(
+                2,
+                FactorizationKind::Diagonal,
+                1e-7,
+                1e-6
+            ))
+        );
 
+        let huge = Vector::<2>::new([1.0, f64::MAX]);
         assert_eq!(
-            DOT,
+            huge.standardize(
+                &Vector::new([0.0, -f64::MAX]),
+                &Vector::new([1.0, 1.0]),
+                tol
+            ),
             Err(LaError::non_finite_computation_step(
-                ArithmeticOperation::VectorDotProduct,
-                1,
+                ArithmeticOperation::Standardization,
+                1
             ))
         );
         assert_eq!(
-            NORM2_SQ,
+            huge.standardize(&Vector::zero(), &Vector::new([1.0, 0.5]), tol),
             Err(LaError::non_finite_computation_step(
-                ArithmeticOperation::VectorSquaredNorm,
-                0,
+                ArithmeticOperation::Standardization,
+                1
             ))
         );
     }
 
-    #[test]
-    fn vector_dot_and_norm2_sq_preserve_fma_and_left_to_right_order() {
-        let dot_large = 9_007_199_254_740_992.0;
-        let dot_lhs = Vector::<4>::new([dot_large, 1.0, 1.0, 1.0]);
-        let dot_rhs = Vector::<4>::new([1.0; 4]);
-        assert_eq!(dot_lhs.dot(&dot_rhs), Ok(dot_large));
-
-        let fused_lhs = Vector::<2>::new([f64::MAX, f64::MAX]);
-        let fused_rhs = Vector::<2>::new([-1.0, 2.0]);
-        assert_eq!(fused_lhs.dot(&fused_rhs), Ok(f64::MAX));
+    macro_rules! gen_sqrt_exp_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<sqrt_and_exp_apply_componentwise_ $d d>]() {
+                    let squares = Vector::<$d>::new(core::array::from_fn(|i| {
+                        let k = f64::from(u32::try_from(i).unwrap());
+                        k * k
+                    }));
+                    let roots = squares.sqrt().unwrap();
+                    for i in 0..$d {
+                        assert_eq!(
+                            roots.data[i].to_bits(),
+                            f64::from(u32::try_from(i).unwrap()).to_bits()
+                        );
+                    }
 
-        let norm_large = 134_217_728.0;
-        let vector = Vector::<4>::new([norm_large, 1.0, 1.0, 1.0]);
-        assert_eq!(vector.norm2_sq(), Ok(norm_large * norm_large));
+                    let logs = Vector::<$d>::new(core::array::from_fn(|i| {
+                        f64::from(u32::try_from(i).unwrap()) - 1.5
+                    }));
+                    let e = logs.exp().unwrap();
+                    for i in 0..$d {
+                        assert_eq!(e.data[i].to_bits(), logs.data[i].exp().to_bits());
+                        assert_abs_diff_eq!(e.data[i].ln(), logs.data[i], epsilon = 1e-15);
+                    }
+                    assert_eq!(Vector::<$d>::new([0.0; $d]).exp(), Ok(Vector::new([1.0; $d])));
+                }
+            }
+        };
     }
 
+    gen_sqrt_exp_tests!(2);
+    gen_sqrt_exp_tests!(3);
+    gen_sqrt_exp_tests!(4);
+    gen_sqrt_exp_tests!(5);
+
     #[test]
-    fn vector_dot_and_norm2_sq_report_first_middle_overflowing_step() {
-        let dot_lhs = Vector::<3>::new([f64::MAX, f64::MAX, 1.0]);
-        let dot_rhs = Vector::<3>::new([1.0; 3]);
+    fn sqrt_rejects_negative_components_and_keeps_signed_zero() {
+        let v = Vector::<4>::new([1.0, -0.0, -1e-300, -4.0]);
         assert_eq!(
-            dot_lhs.dot(&dot_rhs),
+            v.sqrt(),
             Err(LaError::non_finite_computation_step(
-                ArithmeticOperation::VectorDotProduct,
-                1,
+                ArithmeticOperation::ComponentSquareRoot,
+                2
             ))
         );
+        let zeros = Vector::<2>::new([-0.0, 0.0]).sqrt().unwrap();
+        assert_eq!(zeros.data[0].to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(zeros.data[1].to_bits(), 0.0_f64.to_bits());
+    }
 
-        let norm_large = 1.0e154;
-        let vector = Vector::<3>::new([norm_large, norm_large, 1.0]);
+    #[test]
+    fn exp_reports_overflow_index_and_underflows_to_zero() {
         assert_eq!(
-            vector.norm2_sq(),
+            Vector::<3>::new([0.0, 709.0, 710.0]).exp(),
             Err(LaError::non_finite_computation_step(
-                ArithmeticOperation::VectorSquaredNorm,
-                1,
+                ArithmeticOperation::ComponentExponential,
+                2
             ))
         );
+        let tiny = Vector::<2>::new([-1000.0, f64::MIN]).exp().unwrap();
+        assert_eq!(tiny.data[0].to_bits(), 0.0_f64.to_bits());
+        assert_eq!(tiny.data[1].to_bits(), 0.0_f64.to_bits());
     }
 
-    #[test]
-    fn zero_dimension_vector_has_zero_dot_and_norm() {
-        let vector = Vector::<0>::try_new([]).unwrap();
+    // === householder_vector ===
 
-        assert!(vector.as_array().is_empty());
-        assert!(vector.into_array().is_empty());
-        assert_eq!(vector.dot(&Vector::zero()), Ok(0.0));
-        assert_eq!(vector.norm2_sq(), Ok(0.0));
+    /// Apply `I − β v vᵀ` to `x`.
+    fn reflect<const D: usize>(v: &Vector<D>, beta: f64, x: &Vector<D>) -> [f64; D] {
+        let coefficient = beta * v.dot(x).unwrap();
+        core::array::from_fn(|i| (-coefficient).mul_add(v.data[i], x.data[i]))
+    }
+
+    macro_rules! gen_householder_vector_tests {
+        ($d:literal) => {
+            paste! {
+                #[test]
+                fn [<householder_vector_maps_x_to_norm_times_e0_ $d d>]() {
+                    for sign in [1.0, -1.0] {
+                        for scale in [1e-300, 1.0, 1e300] {
+                            let mut data = [0.0; $d];
+                            for (i, value) in data.iter_mut().enumerate() {
+                                *value = scale * (f64::from(u32::try_from(i).unwrap()) + 1.0);
+                            }
+                            data[0] *= sign;
+                            let x = Vector::<$d>::new(data);
+                            let (v, beta) = x.householder_vector();
+                            assert_abs_diff_eq!(v.data[0], 1.0, epsilon = 0.0);
+                            assert!((0.0..=2.0).contains(&beta));
+
+                            let (max_abs, norm) = x.scaled_norm2();
+                            let reflected = reflect(&v, beta, &x);
+                            assert_abs_diff_eq!(reflected[0] / max_abs, norm, epsilon = 1e-14);
+                            for &entry in &reflected[1..] {
+                                assert_abs_diff_eq!(entry / max_abs, 0.0, epsilon = 1e-14);
+                            }
+                        }
+                    }
+                }
+
+                #[test]
+                fn [<householder_vector_of_multiple_of_e0_ $d d>]() {
+                    let e0 = Vector::<$d>::basis(0);
+                    assert_eq!(Vector::<$d>::zero().householder_vector(), (e0, 0.0));
+                    assert_eq!(Vector::<$d>::new(e0.data.map(|x| 3.0 * x)).householder_vector(), (e0, 0.0));
+
+                    let negative = Vector::<$d>::new(e0.data.map(|x| -3.0 * x));
+                    let (v, beta) = negative.householder_vector();
+                    assert_eq!((v, beta), (e0, 2.0));
+                    assert_abs_diff_eq!(reflect(&v, beta, &negative)[0], 3.0, epsilon = 0.0);
+                }
+            }
+        };
+    }
+
+    gen_householder_vector_tests!(2);
+    gen_householder_vector_tests!(3);
+    gen_householder_vector_tests!(4);
+    gen_householder_vector_tests!(5);
+
+    #[test]
+    fn householder_vector_handles_degenerate_dimensions() {
+        assert_eq!(
+            Vector::<0>::zero().householder_vector(),
+            (Vector::zero(), 0.0)
+        );
+        assert_eq!(
+            Vector::<1>::new([-5.0]).householder_vector(),
+            (Vector::new([1.0]), 2.0)
+        );
     }
 }
@@ -24,6 +24,8 @@ fn common_prelude_supports_downstream_composition() -> Result<(), LaError> {
 
     let lu: Lu<2> = matrix.lu(tolerance)?;
     let ldlt: Ldlt<2> = matrix.ldlt(tolerance)?;
+    let tri: TriLu<2> = matrix.lu_tridiagonal(tolerance)?;
+    assert_eq!(tri.solve(vector)?, vector);
     let lu_solution = lu.solve(vector)?.into_array();
     let ldlt_solution = ldlt.solve(vector)?.into_array();
     for (actual, expected) in lu_solution.into_iter().zip([1.0, 2.0]) {
@@ -81,6 +83,18 @@ fn common_prelude_supports_downstream_composition() -> Result<(), LaError> {
     Ok(())
 }
 
+#[cfg(feature = "complex")]
+#[test]
+fn complex_prelude_supports_downstream_composition() -> Result<(), LaError> {
+    let rotation = Matrix::<2>::try_from_rows([[0.0, -1.0], [1.0, 0.0]])?;
+    let eigenvalues: [Complex<f64>; 2] = rotation.eigenvalues(Tolerance::try_new(1e-14)?, 10)?;
+    assert_eq!(
+        eigenvalues,
+        [Complex::new(0.0, 1.0), Complex::new(0.0, -1.0)]
+    );
+    Ok(())
+}
+
 #[cfg(feature = "exact")]
 #[test]
 fn exact_prelude_supports_downstream_composition() {
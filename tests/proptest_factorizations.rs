@@ -1,6 +1,6 @@
 #![forbid(unsafe_code)]
 
-//! Property-based tests for LU/LDLT factorization APIs.
+//! Property-based tests for LU/LDLT/tridiagonal LU factorization and SVD APIs.
 //!
 //! These tests construct matrices from known factors so we have a reliable oracle for
 //! determinant and solve behavior.
@@ -266,6 +266,111 @@ macro_rules! gen_factorization_proptests {
                         assert_abs_diff_eq!(x[i], x_true[i], epsilon = 1e-8);
                     }
                 }
+
+                #[test]
+                fn [<lu_no_pivot_matches_pivoting_lu_on_dominant_ $d d>](
+                    offdiag in array::[<uniform $d>](array::[<uniform $d>](small_factor_entry())),
+                    diag in array::[<uniform $d>](nonzero_diag_entry()),
+                    b_arr in array::[<uniform $d>](small_f64()),
+                ) {
+                    // Each off-diagonal entry is at most 0.5, so a diagonal of
+                    // magnitude 0.5·D + |diag| dominates its row strictly.
+                    let mut a_rows = offdiag;
+                    for i in 0..$d {
+                        let margin = 0.5 * f64::from(u32::try_from($d).unwrap());
+                        a_rows[i][i] = diag[i].signum().mul_add(margin, diag[i]);
+                    }
+
+                    let a = Matrix::<$d>::try_from_rows(a_rows).unwrap();
+                    prop_assert!(a.is_diagonally_dominant(true));
+                    let unpivoted = a.lu_no_pivot(DEFAULT_SINGULAR_TOL).unwrap();
+                    let lu = a.lu(DEFAULT_SINGULAR_TOL).unwrap();
+                    prop_assert_eq!(unpivoted.into_parts().1, core::array::from_fn(|i| i));
+
+                    let det_lu = lu.det().unwrap();
+                    assert_abs_diff_eq!(
+                        unpivoted.det().unwrap(),
+                        det_lu,
+                        epsilon = 1e-9 * det_lu.abs().max(1.0)
+                    );
+
+                    let b = Vector::<$d>::try_new(b_arr).unwrap();
+                    let x_unpivoted = unpivoted.solve(b).unwrap().into_array();
+                    let x_lu = lu.solve(b).unwrap().into_array();
+                    for i in 0..$d {
+                        assert_abs_diff_eq!(x_unpivoted[i], x_lu[i], epsilon = 1e-9);
+                    }
+                }
+
+                #[test]
+                fn [<tridiagonal_lu_matches_general_lu_ $d d>](
+                    sub in array::[<uniform $d>](small_factor_entry()),
+                    diag in array::[<uniform $d>](nonzero_diag_entry()),
+                    sup in array::[<uniform $d>](small_factor_entry()),
+                    b_arr in array::[<uniform $d>](small_f64()),
+                ) {
+                    // |diagonal| >= 1.1 exceeds the off-diagonal row sum (<= 1.0),
+                    // so the matrix is strictly diagonally dominant and the
+                    // unpivoted Thomas algorithm is stable.
+                    let mut a_rows = [[0.0f64; $d]; $d];
+                    for i in 0..$d {
+                        a_rows[i][i] = diag[i].signum() + diag[i];
+                        if i > 0 {
+                            a_rows[i][i - 1] = sub[i];
+                        }
+                        if i + 1 < $d {
+                            a_rows[i][i + 1] = sup[i];
+                        }
+                    }
+
+                    let a = Matrix::<$d>::try_from_rows(a_rows).unwrap();
+                    let tri = a.lu_tridiagonal(DEFAULT_SINGULAR_TOL).unwrap();
+                    let lu = a.lu(DEFAULT_SINGULAR_TOL).unwrap();
+
+                    let det_lu = lu.det().unwrap();
+                    assert_abs_diff_eq!(
+                        tri.det().unwrap(),
+                        det_lu,
+                        epsilon = 1e-12 * det_lu.abs().max(1.0)
+                    );
+
+                    let b = Vector::<$d>::try_new(b_arr).unwrap();
+                    let x_tri = tri.solve(b).unwrap().into_array();
+                    let x_lu = lu.solve(b).unwrap().into_array();
+                    for i in 0..$d {
+                        assert_abs_diff_eq!(x_tri[i], x_lu[i], epsilon = 1e-9);
+                    }
+                }
+
+                #[test]
+                fn [<svd_reconstructs_input_with_orthogonal_factors_ $d d>](
+                    a_rows in array::[<uniform $d>](array::[<uniform $d>](small_f64())),
+                ) {
+                    let a = Matrix::<$d>::try_from_rows(a_rows).unwrap();
+                    let (u, sigma, v) = a.svd(Tolerance::try_new(1e-14).unwrap(), 50).unwrap();
+                    let (u, s, v) = (u.into_rows(), sigma.into_array(), v.into_rows());
+
+                    for i in 0..$d {
+                        prop_assert!(s[i] >= 0.0);
+                        if i > 0 {
+                            prop_assert!(s[i - 1] >= s[i]);
+                        }
+                    }
+                    for i in 0..$d {
+                        for j in 0..$d {
+                            let expected = if i == j { 1.0 } else { 0.0 };
+                            let (mut utu, mut vtv, mut usv) = (0.0, 0.0, 0.0);
+                            for k in 0..$d {
+                                utu = u[k][i].mul_add(u[k][j], utu);
+                                vtv = v[k][i].mul_add(v[k][j], vtv);
+                                usv = (u[i][k] * s[k]).mul_add(v[j][k], usv);
+                            }
+                            assert_abs_diff_eq!(utu, expected, epsilon = 1e-8);
+                            assert_abs_diff_eq!(vtv, expected, epsilon = 1e-8);
+                            assert_abs_diff_eq!(usv, a_rows[i][j], epsilon = 1e-8);
+                        }
+                    }
+                }
             }
         }
     };
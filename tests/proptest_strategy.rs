@@ -0,0 +1,59 @@
+#![forbid(unsafe_code)]
+
+//! Self-tests for the public proptest strategies (requires `proptest`
+//! feature).
+//!
+//! Covers:
+//! - `matrix_strategy` respects its entry strategy
+//! - `spd_matrix_strategy` always yields exactly symmetric matrices that LDLT
+//!   factors with every eigenvalue at or above one
+
+#![cfg(feature = "proptest")]
+
+use pastey::paste;
+use proptest::prelude::*;
+
+use la_stack::prelude::*;
+use la_stack::strategy::{matrix_strategy, spd_matrix_strategy};
+
+macro_rules! gen_strategy_proptests {
+    ($d:literal) => {
+        paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig::with_cases(64))]
+
+                #[test]
+                fn [<matrix_strategy_draws_entries_from_entry_strategy_ $d d>](
+                    a in matrix_strategy::<$d>(-2.0..3.0),
+                ) {
+                    for row in a.as_rows() {
+                        for &x in row {
+                            prop_assert!((-2.0..3.0).contains(&x));
+                        }
+                    }
+                }
+
+                #[test]
+                fn [<spd_matrix_strategy_produces_spd_matrices_ $d d>](
+                    a in spd_matrix_strategy::<$d>(-100.0..100.0),
+                ) {
+                    let rows = a.as_rows();
+                    for r in 0..$d {
+                        for c in 0..$d {
+                            prop_assert_eq!(rows[r][c].to_bits(), rows[c][r].to_bits());
+                        }
+                    }
+                    let ldlt = a.ldlt(DEFAULT_SINGULAR_TOL).unwrap();
+                    prop_assert!(ldlt.det().unwrap() >= 1.0 - 1e-9);
+                    // A shifted Gram matrix has all eigenvalues at least one.
+                    prop_assert!(a.log_det_spd(DEFAULT_SINGULAR_TOL).unwrap() >= -1e-9);
+                }
+            }
+        }
+    };
+}
+
+gen_strategy_proptests!(2);
+gen_strategy_proptests!(3);
+gen_strategy_proptests!(4);
+gen_strategy_proptests!(5);